@@ -0,0 +1,141 @@
+"""Citation links of a PubMed article from NIH iCite.
+
+iCite records, for each PMID, the PubMed articles it cites
+(``references``) and the PubMed articles citing it (``cited_by``), so one
+request returns both directions of the citation network.
+"""
+
+import asyncio
+import json
+import logging
+from typing import Any
+
+from .. import http_client, render
+from ..constants import ICITE_API_URL, PUBMED_BASE_URL
+from . import fetch
+
+logger = logging.getLogger(__name__)
+
+# Maximum PMIDs listed per section in markdown (JSON lists all of them)
+CITATION_LIST_LIMIT = 50
+
+
+def _pmids(values: Any) -> list[int]:
+    """Normalize an iCite PMID list, which may be null or strings."""
+    pmids: list[int] = []
+    for value in values or []:
+        try:
+            pmids.append(int(value))
+        except (TypeError, ValueError):
+            continue
+    return pmids
+
+
+async def fetch_citation_links(
+    pmid: int,
+) -> tuple[dict[str, list[int]] | None, str | None]:
+    """Fetch the PMIDs an article cites and the PMIDs citing it.
+
+    Returns:
+        Tuple of ({"references": [...], "cited_by": [...]} or None,
+        error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=ICITE_API_URL,
+        request={"pmids": str(pmid), "legacy": "false"},
+        method="GET",
+        domain="icite",
+    )
+
+    if error:
+        return None, f"Error fetching citations for {pmid}: {error.message}"
+    records = (response or {}).get("data") or []
+    if not records:
+        return None, f"No iCite record found for PMID {pmid}"
+    record = records[0]
+    return {
+        "references": _pmids(record.get("references")),
+        "cited_by": _pmids(record.get("cited_by")),
+    }, None
+
+
+def format_citation_links(
+    links: dict[str, list[int]],
+    references: bool = True,
+    cited_by: bool = True,
+) -> str:
+    """Format citation links as markdown lists of PubMed links."""
+    sections: list[tuple[str, str]] = []
+    if references:
+        sections.append(("References", "references"))
+    if cited_by:
+        sections.append(("Cited By", "cited_by"))
+
+    lines: list[str] = []
+    for title, key in sections:
+        pmids = links.get(key) or []
+        lines.append(f"## {title} ({len(pmids)})")
+        lines.append("")
+        if not pmids:
+            lines.append("None found in iCite.")
+        for pmid in pmids[:CITATION_LIST_LIMIT]:
+            lines.append(f"- [PMID {pmid}]({PUBMED_BASE_URL}{pmid}/)")
+        if len(pmids) > CITATION_LIST_LIMIT:
+            lines.append(
+                f"- ... and {len(pmids) - CITATION_LIST_LIMIT} more "
+                "(use --json for the full list)"
+            )
+        lines.append("")
+    return "\n".join(lines).rstrip()
+
+
+async def fetch_article_with_citations(
+    pmid: int,
+    output_json: bool = False,
+    fulltext_format: fetch.FullTextFormat = fetch.FullTextFormat.PLAIN,
+    references: bool = True,
+    cited_by: bool = True,
+) -> str:
+    """Fetch a PubMed article together with its iCite citation links.
+
+    The article and the citation links are requested concurrently.
+
+    Returns:
+        Formatted markdown or JSON string
+    """
+    articles_text, (links, error) = await asyncio.gather(
+        fetch.fetch_articles(
+            [pmid],
+            full=True,
+            output_json=True,
+            fulltext_format=fulltext_format,
+        ),
+        fetch_citation_links(pmid),
+    )
+    articles = json.loads(articles_text)
+    for article in articles:
+        if "error" in article:
+            continue
+        if error:
+            article["citations_error"] = error
+            continue
+        if references:
+            article["references"] = links["references"]
+        if cited_by:
+            article["cited_by"] = links["cited_by"]
+
+    if output_json:
+        return json.dumps(articles, indent=2)
+    markdown = render.to_markdown([
+        {
+            k: v
+            for k, v in article.items()
+            if k not in ("references", "cited_by")
+        }
+        for article in articles
+    ])
+    if links:
+        markdown = markdown.rstrip() + "\n\n" + format_citation_links(
+            links, references=references, cited_by=cited_by
+        )
+    return markdown
@@ -0,0 +1,59 @@
+"""Date-range filtering of PubMed search results.
+
+PubMed records carry several dates: the publication date printed on the
+article, the Entrez date it entered PubMed and the date the record was
+last revised. A date range is checked against one of them, chosen with
+``date_field``, by asking PubMed (E-utilities) which of the PMIDs from
+the topical search fall in the range, as ``--mesh`` does.
+"""
+
+from datetime import date
+
+from ..core import StrEnum
+
+
+class ArticleDateField(StrEnum):
+    """PubMed date a date range applies to."""
+
+    PUB = "pub"
+    ENTRY = "entry"
+    REVISED = "revised"
+
+
+# PubMed search field tag and display label of each date
+PUBMED_DATE_TAGS = {
+    ArticleDateField.PUB: ("dp", "Publication date"),
+    ArticleDateField.ENTRY: ("edat", "Entry date"),
+    ArticleDateField.REVISED: ("lr", "Revision date"),
+}
+
+# Open ends of a PubMed date range
+PUBMED_MIN_DATE = "1800/01/01"
+PUBMED_MAX_DATE = "3000/12/31"
+
+
+def pubmed_date_query(
+    field: ArticleDateField,
+    date_from: date | None = None,
+    date_to: date | None = None,
+) -> str:
+    """Build the PubMed range clause, e.g. ``2020/01/01:2020/12/31[dp]``."""
+    start = date_from.strftime("%Y/%m/%d") if date_from else PUBMED_MIN_DATE
+    end = date_to.strftime("%Y/%m/%d") if date_to else PUBMED_MAX_DATE
+    return f"{start}:{end}[{PUBMED_DATE_TAGS[field][0]}]"
+
+
+def date_range_summary(
+    field: ArticleDateField,
+    date_from: date | None = None,
+    date_to: date | None = None,
+) -> str:
+    """Render a date range with the date it applies to."""
+    label = PUBMED_DATE_TAGS[field][1]
+    start = date_from.isoformat() if date_from else None
+    end = date_to.isoformat() if date_to else None
+    if start and end:
+        return f"{label}: {start} to {end}"
+    if start:
+        return f"{label}: >= {start}"
+    return f"{label}: <= {end}"
@@ -13,6 +13,7 @@ from ..constants import (
     NCBI_PMC_CONVERTER_URL,
     PUBTATOR3_FULLTEXT_URL,
 )
+from ..core import StrEnum
 from ..http_client import (
     RequestError,
     cache_response,
@@ -22,6 +23,30 @@ from ..http_client import (
 
 logger = logging.getLogger(__name__)
 
+# Display names for BioC section types
+SECTION_TITLES = {
+    "INTRO": "Introduction",
+    "METHODS": "Methods",
+    "RESULTS": "Results",
+    "DISCUSS": "Discussion",
+    "CONCL": "Conclusions",
+    "FIG": "Figures",
+    "TABLE": "Tables",
+}
+
+_HEADING_TYPE_PATTERN = re.compile(r"^title_(\d+)$", re.IGNORECASE)
+
+# Fields kept by the abstract-only article shape
+ABSTRACT_ONLY_FIELDS = ("pmid", "doi", "title", "abstract", "error")
+
+
+class FullTextFormat(StrEnum):
+    """How the full text of an article is rendered."""
+
+    PLAIN = "plain"  # paragraphs only
+    SECTIONS = "sections"  # text grouped under section names
+    MARKDOWN = "markdown"  # headings and italic captions
+
 
 class PassageInfo(BaseModel):
     section_type: str | None = Field(
@@ -50,6 +75,24 @@ class Passage(BaseModel):
         section_type = section_type or "UNKNOWN"
         return section_type.upper()
 
+    @property
+    def section_name(self) -> str:
+        return SECTION_TITLES.get(
+            self.section_type, self.section_type.title()
+        )
+
+    @property
+    def heading_level(self) -> int | None:
+        """Heading depth of a section-title passage (``title_1`` -> 1)."""
+        passage_type = self.info.passage_type if self.info else None
+        match = _HEADING_TYPE_PATTERN.match(passage_type or "")
+        return int(match.group(1)) if match else None
+
+    @property
+    def is_caption(self) -> bool:
+        passage_type = self.info.passage_type if self.info else None
+        return (passage_type or "").lower().endswith("caption")
+
     @property
     def is_title(self) -> bool:
         return self.section_type == "TITLE"
@@ -123,6 +166,49 @@ class Article(BaseModel):
                 lines.append(passage.text)
         return "\n\n".join(lines) or ""
 
+    def _text_passages(self) -> list[Passage]:
+        return [p for p in self.passages if p.is_text and p.text]
+
+    def full_text_sections(self) -> list[dict[str, str]]:
+        """Group full-text passages by article section, in reading order."""
+        sections: list[dict[str, str]] = []
+        for passage in self._text_passages():
+            name = passage.section_name
+            if sections and sections[-1]["section"] == name:
+                sections[-1]["text"] += f"\n\n{passage.text}"
+            else:
+                sections.append({"section": name, "text": passage.text or ""})
+        return sections
+
+    def full_text_markdown(self) -> str:
+        """Render the full text as markdown with headings and captions."""
+        blocks: list[str] = []
+        current_section = None
+        for passage in self._text_passages():
+            level = passage.heading_level
+            if passage.section_name != current_section:
+                current_section = passage.section_name
+                # Sections without their own title passage get a heading
+                if level is None:
+                    blocks.append(f"## {current_section}")
+            if level is not None:
+                blocks.append(f"{'#' * min(level + 1, 6)} {passage.text}")
+            elif passage.is_caption:
+                blocks.append(f"*{passage.text}*")
+            else:
+                blocks.append(passage.text or "")
+        return "\n\n".join(blocks)
+
+    def render_full_text(
+        self, fulltext_format: FullTextFormat
+    ) -> str | list[dict[str, str]]:
+        """Return the full text in the requested shape."""
+        if fulltext_format == FullTextFormat.SECTIONS:
+            return self.full_text_sections()
+        if fulltext_format == FullTextFormat.MARKDOWN:
+            return self.full_text_markdown()
+        return str(self.full_text)
+
     @computed_field
     def pubmed_url(self) -> str | None:
         url = None
@@ -206,13 +292,31 @@ async def _fetch_abstract_efetch(
     return None
 
 
+def abstract_only_records(data: list[dict[str, Any]]) -> list[dict[str, Any]]:
+    """Trim article records to their identifiers, title and abstract."""
+    return [
+        {k: item[k] for k in ABSTRACT_ONLY_FIELDS if item.get(k) is not None}
+        for item in data
+    ]
+
+
 async def fetch_articles(
     pmids: list[int],
     full: bool,
     output_json: bool = False,
+    fulltext_format: FullTextFormat = FullTextFormat.PLAIN,
+    abstract_only: bool = False,
 ) -> str:
-    """Fetch the text of a list of PubMed IDs."""
+    """Fetch the text of a list of PubMed IDs.
+
+    ``fulltext_format`` controls the shape of ``full_text`` when ``full``
+    is set; the ``sections`` format returns a list of section records.
+    ``abstract_only`` skips the full text and returns only the identifiers,
+    title and abstract of each article.
+    """
 
+    if abstract_only:
+        full = False
     response, error = await call_pubtator_api(pmids, full)
 
     # PubTator API returns full text even when full=False
@@ -224,14 +328,20 @@ async def fetch_articles(
             {"error": f"Error {error.code}: {error.message}"}
         ]
     else:
-        data = [
-            article.model_dump(
+        data = []
+        for article in response.articles if response else []:
+            item = article.model_dump(
                 mode="json",
                 exclude_none=True,
                 exclude=exclude_fields,
             )
-            for article in (response.articles if response else [])
-        ]
+            if (
+                full
+                and item.get("full_text")
+                and fulltext_format != FullTextFormat.PLAIN
+            ):
+                item["full_text"] = article.render_full_text(fulltext_format)
+            data.append(item)
 
     # Patch articles that have placeholder abstracts
     for item in data:
@@ -242,6 +352,9 @@ async def fetch_articles(
             if real_abstract:
                 item["abstract"] = real_abstract
 
+    if abstract_only:
+        data = abstract_only_records(data)
+
     if data and not output_json:
         return render.to_markdown(data)
     else:
@@ -0,0 +1,194 @@
+"""MeSH descriptor filtering of PubMed search results.
+
+PubTator3 searches free text and entities, not the MeSH indexing that
+PubMed assigns to each article. ``--mesh`` therefore runs the topical
+search first and then asks PubMed (E-utilities) which of the returned
+PMIDs are indexed with the requested descriptors.
+
+Each ``--mesh`` value is a descriptor name ("Melanoma") or unique ID
+("D008545"); ``|`` separates alternatives (OR) and repeated values must
+all match (AND). IDs are resolved to descriptor names through the MeSH
+database first.
+"""
+
+import re
+
+from .. import http_client
+from ..concurrency import gather_limited
+from ..constants import NCBI_EUTILS_BASE_URL
+
+# Descriptor unique IDs: D + 6 digits, or D + 9 digits since 2015
+MESH_ID_PATTERN = re.compile(r"^D(\d{6}|\d{9})$")
+
+# PMIDs checked per PubMed query, keeping the URL short
+PMID_BATCH_SIZE = 200
+MESH_CONCURRENCY = 3
+
+
+def split_mesh_terms(value: str) -> list[str]:
+    """Split a ``--mesh`` value into its OR-ed alternatives.
+
+    MeSH IDs are upper-cased; descriptor names pass through unchanged.
+
+    Raises:
+        ValueError: If an alternative looks like a MeSH ID but is malformed
+    """
+    terms = []
+    for term in (t.strip() for t in value.split("|")):
+        if re.fullmatch(r"[Dd]\d+", term):
+            term = term.upper()
+            if not MESH_ID_PATTERN.match(term):
+                raise ValueError(
+                    f"Invalid MeSH ID '{term}'. Descriptor IDs are D "
+                    "followed by 6 or 9 digits (e.g. D008545)"
+                )
+        if term:
+            terms.append(term)
+    if not terms:
+        raise ValueError("Empty MeSH term")
+    return terms
+
+
+def mesh_summary(mesh: list[str]) -> str:
+    """Render MeSH filters with their AND/OR structure."""
+    groups = []
+    for value in mesh:
+        terms = split_mesh_terms(value)
+        group = " OR ".join(terms)
+        groups.append(f"({group})" if len(terms) > 1 else group)
+    return " AND ".join(groups)
+
+
+async def _eutils(
+    tool: str, params: dict
+) -> tuple[dict | None, str | None]:
+    response, error = await http_client.request_api(
+        url=f"{NCBI_EUTILS_BASE_URL}/{tool}.fcgi",
+        request={**params, "retmode": "json"},
+        method="GET",
+        domain="pubmed",
+    )
+    if error:
+        return None, f"Error querying PubMed {tool}: {error.message}"
+    return response or {}, None
+
+
+async def resolve_mesh_ids(
+    mesh_ids: list[str],
+) -> tuple[dict[str, str] | None, str | None]:
+    """Map MeSH descriptor IDs to their descriptor names.
+
+    Returns:
+        Tuple of ({ID: name} or None, error message or None)
+    """
+    term = " OR ".join(f"{mesh_id}[MeSH Unique ID]" for mesh_id in mesh_ids)
+    found, error = await _eutils(
+        "esearch", {"db": "mesh", "term": term, "retmax": len(mesh_ids)}
+    )
+    if error or found is None:
+        return None, error
+    uids = (found.get("esearchresult") or {}).get("idlist") or []
+
+    names: dict[str, str] = {}
+    if uids:
+        summary, error = await _eutils(
+            "esummary", {"db": "mesh", "id": ",".join(uids)}
+        )
+        if error or summary is None:
+            return None, error
+        result = summary.get("result") or {}
+        for uid in uids:
+            record = result.get(uid) or {}
+            terms = record.get("ds_meshterms") or []
+            if record.get("ds_meshui") and terms:
+                names[record["ds_meshui"]] = terms[0]
+
+    missing = [mesh_id for mesh_id in mesh_ids if mesh_id not in names]
+    if missing:
+        return None, f"Unknown MeSH ID: {', '.join(missing)}"
+    return names, None
+
+
+async def mesh_query(mesh: list[str]) -> tuple[str | None, str | None]:
+    """Build the PubMed ``[MeSH Terms]`` clause for ``--mesh`` values.
+
+    Returns:
+        Tuple of (PubMed query or None, error message or None)
+    """
+    groups = [split_mesh_terms(value) for value in mesh]
+    all_terms = [term for terms in groups for term in terms]
+    mesh_ids = sorted({t for t in all_terms if MESH_ID_PATTERN.match(t)})
+    names: dict[str, str] = {}
+    if mesh_ids:
+        resolved, error = await resolve_mesh_ids(mesh_ids)
+        if error or resolved is None:
+            return None, error
+        names = resolved
+
+    clauses = []
+    for terms in groups:
+        alternatives = [
+            f'"{names.get(term, term)}"[MeSH Terms]' for term in terms
+        ]
+        clauses.append(f"({' OR '.join(alternatives)})")
+    return " AND ".join(clauses), None
+
+
+async def _matching_batch(
+    pmids: list[int], query: str
+) -> tuple[set[int] | None, str | None]:
+    uids = " OR ".join(f"{pmid}[uid]" for pmid in pmids)
+    found, error = await _eutils(
+        "esearch",
+        {
+            "db": "pubmed",
+            "term": f"({uids}) AND {query}",
+            "retmax": len(pmids),
+        },
+    )
+    if error or found is None:
+        return None, error
+    idlist = (found.get("esearchresult") or {}).get("idlist") or []
+    return {int(pmid) for pmid in idlist}, None
+
+
+async def filter_pmids_by_mesh(
+    pmids: list[int], mesh: list[str]
+) -> tuple[set[int] | None, str | None]:
+    """Return the PMIDs that PubMed indexes with the MeSH descriptors.
+
+    Returns:
+        Tuple of (matching PMIDs or None, error message or None)
+    """
+    if not pmids:
+        return set(), None
+    query, error = await mesh_query(mesh)
+    if error or query is None:
+        return None, error
+    return await filter_pmids(pmids, query)
+
+
+async def filter_pmids(
+    pmids: list[int], query: str
+) -> tuple[set[int] | None, str | None]:
+    """Return the PMIDs that also match a PubMed query.
+
+    Returns:
+        Tuple of (matching PMIDs or None, error message or None)
+    """
+    if not pmids:
+        return set(), None
+    batches = [
+        pmids[i : i + PMID_BATCH_SIZE]
+        for i in range(0, len(pmids), PMID_BATCH_SIZE)
+    ]
+    results = await gather_limited(
+        (_matching_batch(batch, query) for batch in batches),
+        MESH_CONCURRENCY,
+    )
+    matching: set[int] = set()
+    for found, batch_error in results:
+        if batch_error or found is None:
+            return None, batch_error
+        matching |= found
+    return matching, None
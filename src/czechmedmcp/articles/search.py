@@ -2,9 +2,16 @@ import asyncio
 import json
 import logging
 from collections.abc import Generator
+from datetime import date
 from typing import Annotated, Any, get_args
 
-from pydantic import BaseModel, Field, computed_field
+from pydantic import (
+    BaseModel,
+    Field,
+    computed_field,
+    field_validator,
+    model_validator,
+)
 
 from .. import http_client, render
 from ..constants import (
@@ -15,7 +22,9 @@ from ..constants import (
 )
 from ..core import PublicationState
 from .autocomplete import Concept, EntityRequest, autocomplete
+from .dates import ArticleDateField, date_range_summary, pubmed_date_query
 from .fetch import call_pubtator_api
+from .mesh import filter_pmids, mesh_query, mesh_summary, split_mesh_terms
 
 logger = logging.getLogger(__name__)
 
@@ -23,6 +32,37 @@ concepts: list[Concept] = sorted(get_args(Concept))
 fields: list[str] = [concept + "s" for concept in concepts]
 
 
+def split_author(name: str) -> tuple[str, str]:
+    """Split an author name into (last name, initials), both lower-cased.
+
+    Trailing upper-case tokens of up to three letters are read as
+    initials, so "Smith JA", "Smith J. A." and "Smith, J.A." all give
+    ("smith", "ja").
+    """
+    tokens = name.replace(",", " ").replace(".", " ").split()
+    initials: list[str] = []
+    while len(tokens) > 1 and tokens[-1].isupper() and len(tokens[-1]) <= 3:
+        initials.insert(0, tokens.pop())
+    return " ".join(tokens).lower(), "".join(initials).lower()
+
+
+def author_query(authors: list[str], exact: bool = False) -> str:
+    """Build the PubMed author clause, e.g. ``smith[au] AND lee[au]``.
+
+    By default only last names are searched, which PubMed matches with any
+    initials; ``exact`` quotes the full 'Last FM' form so PubMed does not
+    truncate it.
+    """
+    clauses = []
+    for name in authors:
+        last, initials = split_author(name)
+        if exact:
+            clauses.append(f'"{last} {initials}"[au]')
+        else:
+            clauses.append(f"{last}[au]")
+    return " AND ".join(clauses)
+
+
 class PubmedRequest(BaseModel):
     chemicals: list[str] = Field(
         default_factory=list,
@@ -44,6 +84,103 @@ class PubmedRequest(BaseModel):
         default_factory=list,
         description="List of variants for filtering results.",
     )
+    has_fulltext: bool = Field(
+        default=False,
+        description=(
+            "Only return articles with a PMCID, i.e. full text deposited "
+            "in PubMed Central."
+        ),
+    )
+    authors: list[str] = Field(
+        default_factory=list,
+        description=(
+            "Author names that must all be on the article (co-authorship), "
+            "as a last name or 'Last FM'."
+        ),
+    )
+    author_exact: bool = Field(
+        default=False,
+        description=(
+            "Match authors on the full 'Last FM' form instead of the "
+            "last name alone."
+        ),
+    )
+    mesh: list[str] = Field(
+        default_factory=list,
+        description=(
+            "MeSH descriptor names or IDs (e.g. D008545) the article must "
+            "be indexed with; '|' separates alternatives, repeated values "
+            "must all match."
+        ),
+    )
+    preprint_only: bool = Field(
+        default=False,
+        description=(
+            "Only return preprints (bioRxiv/medRxiv via Europe PMC); "
+            "PubMed is not searched."
+        ),
+    )
+    date_from: date | None = Field(
+        default=None,
+        description="Earliest date (inclusive) of the chosen date field.",
+    )
+    date_to: date | None = Field(
+        default=None,
+        description="Latest date (inclusive) of the chosen date field.",
+    )
+    date_field: ArticleDateField = Field(
+        default=ArticleDateField.PUB,
+        description=(
+            "PubMed date the range applies to: pub (publication date), "
+            "entry (Entrez date) or revised (last revision date)."
+        ),
+    )
+
+    @field_validator("mesh")
+    @classmethod
+    def _check_mesh(cls, v: list[str]) -> list[str]:
+        return ["|".join(split_mesh_terms(value)) for value in v]
+
+    @model_validator(mode="after")
+    def _check_author_exact(self) -> "PubmedRequest":
+        if self.author_exact:
+            missing = [a for a in self.authors if not split_author(a)[1]]
+            if missing:
+                raise ValueError(
+                    "Exact author matching needs 'Last FM' names "
+                    f"(e.g. 'Smith JA'); got: {', '.join(missing)}"
+                )
+        return self
+
+    @model_validator(mode="after")
+    def _check_preprint_only(self) -> "PubmedRequest":
+        # Preprints have no PMCID, PubTator author list or MeSH indexing
+        if self.preprint_only and (
+            self.has_fulltext
+            or self.authors
+            or self.mesh
+            or self.has_date_range()
+        ):
+            raise ValueError(
+                "Preprint-only search cannot be combined with the PubMed "
+                "filters for full text, authors, MeSH or dates"
+            )
+        return self
+
+    @model_validator(mode="after")
+    def _check_date_range(self) -> "PubmedRequest":
+        if self.date_from and self.date_to and self.date_from > self.date_to:
+            raise ValueError("date_from must not be after date_to")
+        return self
+
+    def has_date_range(self) -> bool:
+        """Return True when a date range is set."""
+        return self.date_from is not None or self.date_to is not None
+
+    def has_pubmed_filters(self) -> bool:
+        """Return True when PubMed must also check the author, MeSH or
+        date filters."""
+        return bool(self.authors or self.mesh or self.has_date_range())
 
     def iter_concepts(self) -> Generator[tuple[Concept, str], None, None]:
         for concept in concepts:
@@ -53,6 +190,90 @@ class PubmedRequest(BaseModel):
                 yield concept, value
 
 
+# Over-fetch factor when filtering for full text, since only part of
+# PubMed is deposited in PubMed Central
+FULLTEXT_OVERFETCH = 3
+
+# Over-fetch factor when PubMed checks the authors, MeSH indexing or
+# dates of the topical results; applied once however many are set
+PUBMED_FILTER_OVERFETCH = 5
+
+# Most topical results fetched to fill a filtered page; deeper filtered
+# pages are rejected rather than returned short
+ARTICLE_FETCH_CAP = 1000
+
+
+def article_overfetch(request: PubmedRequest) -> int:
+    """The largest over-fetch factor of the request's filters (1 if none)."""
+    factor = 1
+    if request.has_fulltext:
+        factor = FULLTEXT_OVERFETCH
+    if request.has_pubmed_filters():
+        factor = max(factor, PUBMED_FILTER_OVERFETCH)
+    return factor
+
+
+def article_fetch_size(request: PubmedRequest, page: int, limit: int) -> int:
+    """Number of topical results to fetch to fill the requested page.
+
+    Filtered searches over-fetch by the largest applicable factor, up to
+    ``ARTICLE_FETCH_CAP`` (but never below the page itself).
+    """
+    needed = page * limit
+    wanted = needed * article_overfetch(request)
+    return min(wanted, max(ARTICLE_FETCH_CAP, needed))
+
+
+def article_page_error(
+    request: PubmedRequest, page: int, limit: int
+) -> str | None:
+    """Explain why a filtered page lies beyond ``ARTICLE_FETCH_CAP``.
+
+    The first page is always served, over-fetched as far as the cap allows.
+    """
+    factor = article_overfetch(request)
+    if factor == 1 or page == 1:
+        return None
+    if page * limit * factor <= ARTICLE_FETCH_CAP:
+        return None
+    last_page = max(ARTICLE_FETCH_CAP // (limit * factor), 1)
+    return (
+        f"Filtered article searches read at most {ARTICLE_FETCH_CAP} "
+        f"PubTator3 results, enough for {last_page} pages of {limit}; "
+        f"page {page} is beyond them. Narrow the search instead."
+    )
+
+
+def article_search_query_summary(request: PubmedRequest) -> str:
+    """Render a one-line summary of the search terms and active filters."""
+    parts: list[str] = []
+    for label, values in [
+        ("Genes", request.genes),
+        ("Variants", request.variants),
+        ("Diseases", request.diseases),
+        ("Chemicals", request.chemicals),
+        ("Keywords", request.keywords),
+    ]:
+        if values:
+            parts.append(f"{label}: {', '.join(values)}")
+    if request.authors:
+        mode = "exact" if request.author_exact else "last name"
+        parts.append(f"Authors: {' AND '.join(request.authors)} ({mode})")
+    if request.mesh:
+        parts.append(f"MeSH: {mesh_summary(request.mesh)}")
+    if request.has_date_range():
+        parts.append(
+            date_range_summary(
+                request.date_field, request.date_from, request.date_to
+            )
+        )
+    if request.has_fulltext:
+        parts.append("Full text in PMC")
+    if request.preprint_only:
+        parts.append("Preprints only")
+    return " | ".join(parts) if parts else "All articles"
+
+
 class PubtatorRequest(BaseModel):
     text: str
     size: int = 50
@@ -156,6 +377,32 @@ async def convert_request(
     return PubtatorRequest(text=query_text, size=limit)
 
 
+async def pubmed_filter_query(
+    request: PubmedRequest,
+) -> tuple[str | None, str | None]:
+    """Build the PubMed query the topical results must also match.
+
+    Returns:
+        Tuple of (PubMed query or None when nothing is filtered, error
+        message or None)
+    """
+    clauses = []
+    if request.authors:
+        clauses.append(author_query(request.authors, request.author_exact))
+    if request.mesh:
+        query, error = await mesh_query(request.mesh)
+        if error or query is None:
+            return None, error
+        clauses.append(query)
+    if request.has_date_range():
+        clauses.append(
+            pubmed_date_query(
+                request.date_field, request.date_from, request.date_to
+            )
+        )
+    return (" AND ".join(clauses) if clauses else None), None
+
+
 async def add_abstracts(response: SearchResponse) -> None:
     pmids = [pr.pmid for pr in response.results if pr.pmid]
     abstract_response, _ = await call_pubtator_api(pmids, full=False)
@@ -173,16 +420,32 @@ def clean_authors(record):
     return record
 
 
-async def search_articles(
+async def fetch_article_page(
     request: PubmedRequest,
-    output_json: bool = False,
     limit: int = SYSTEM_PAGE_SIZE,
     page: int = 1,
-) -> str:
-    # Calculate total results needed for pagination
-    # To show page N, we need to fetch: (page * limit) results, then skip first (page-1)*limit
-    total_needed = page * limit
-    pubtator_request = await convert_request(request, limit=total_needed)
+    abstracts: bool = True,
+    timeout_is_error: bool = True,
+) -> tuple[list[ResultItem] | None, str | None]:
+    """Search PubTator3 and return one page of the filtered PubMed results.
+
+    Args:
+        request: Search terms and filters
+        limit: Results per page
+        page: Page number (1-based)
+        abstracts: Fetch the abstracts of the page's articles
+        timeout_is_error: Report a PubTator3 search timeout as an error;
+            otherwise it returns an empty page
+
+    Returns:
+        Tuple of (page of results or None, error message or None)
+    """
+    page_error = article_page_error(request, page, limit)
+    if page_error:
+        return None, page_error
+    pubtator_request = await convert_request(
+        request, limit=article_fetch_size(request, page, limit)
+    )
 
     # Execute search with timeout
     try:
@@ -200,40 +463,77 @@ async def search_articles(
             "PubTator3 search timed out after %.0fs",
             PUBTATOR_TIMEOUT,
         )
-        response, error = None, None
+        if not timeout_is_error:
+            return [], None
+        return None, (
+            f"PubTator3 search timed out after {PUBTATOR_TIMEOUT:.0f}s"
+        )
+    if error:
+        return None, f"Error {error.code}: {error.message}"
+    if not response:
+        return [], None
+
+    results = response.results
+    if request.has_fulltext:
+        results = [result for result in results if result.pmcid]
+
+    # Keep only articles PubMed finds with the authors, MeSH descriptors
+    # and dates in range
+    if request.has_pubmed_filters():
+        query, pubmed_error = await pubmed_filter_query(request)
+        matching = None
+        if query:
+            matching, pubmed_error = await filter_pmids(
+                [result.pmid for result in results if result.pmid], query
+            )
+        if pubmed_error:
+            return None, pubmed_error
+        results = [
+            result for result in results if result.pmid in (matching or set())
+        ]
 
-    if response:
-        # Fetch abstracts with timeout
+    offset = compute_skip(page, limit)
+    response.results = results[offset : offset + limit]
+
+    # Only the returned page needs abstracts
+    if abstracts:
         try:
             await asyncio.wait_for(
                 add_abstracts(response),
                 timeout=PUBTATOR_TIMEOUT,
             )
         except asyncio.TimeoutError:
-            logger.warning(
-                "PubTator3 abstract fetch timed out"
-            )
-        # Add source field to PubMed results
-        for result in response.results:
-            result.source = "PubMed"
+            logger.warning("PubTator3 abstract fetch timed out")
+    # Add source field to PubMed results
+    for result in response.results:
+        result.source = "PubMed"
+    return response.results, None
+
+
+async def search_articles(
+    request: PubmedRequest,
+    output_json: bool = False,
+    limit: int = SYSTEM_PAGE_SIZE,
+    page: int = 1,
+) -> str:
+    # A timed-out search lists no articles rather than failing
+    results, error = await fetch_article_page(
+        request, limit, page, timeout_is_error=False
+    )
 
     # noinspection DuplicatedCode
-    if error:
-        data: list[dict[str, Any]] = [
-            {"error": f"Error {error.code}: {error.message}"}
-        ]
+    if error or results is None:
+        data: list[dict[str, Any]] = [{"error": error}]
     else:
         data = list(
             map(
                 clean_authors,
                 [
                     result.model_dump(mode="json", exclude_none=True)
-                    for result in (response.results if response else [])
+                    for result in results
                 ],
             )
         )
-        offset = compute_skip(page, limit)
-        data = data[offset : offset + limit]
 
     if data and not output_json:
         return render.to_markdown(data)
@@ -141,6 +141,9 @@ async def search_articles_unified(  # noqa: C901
     # Import here to avoid circular imports
     from ..shared_context import SearchContextManager
 
+    if request.preprint_only:
+        include_pubmed, include_preprints = False, True
+
     # Use shared context to avoid redundant validations
     with SearchContextManager() as context:
         # Pre-validate genes once
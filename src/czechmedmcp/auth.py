@@ -1,5 +1,6 @@
 """Authentication module for CzechMedMCP remote server modes."""
 
+import ipaddress
 import os
 import secrets
 
@@ -8,14 +9,28 @@ from starlette.requests import Request
 from starlette.responses import JSONResponse
 
 
-def validate_auth_token() -> str | None:
+# Environment variables holding the bearer token, highest precedence first
+AUTH_TOKEN_ENV_VARS = ("BIOMCP_HTTP_TOKEN", "MCP_AUTH_TOKEN")
+
+
+def validate_auth_token(token: str | None = None) -> str | None:
     """
-    Validate MCP_AUTH_TOKEN environment variable at startup.
+    Resolve and validate the bearer token at startup.
+
+    The token comes from the ``--auth-token`` option when given, otherwise
+    from BIOMCP_HTTP_TOKEN, then MCP_AUTH_TOKEN.
 
     Returns the token if valid, None if not set.
     Raises ValueError if token is set but invalid.
     """
-    token = os.getenv("MCP_AUTH_TOKEN")
+    source = "--auth-token"
+    if token is None:
+        for env_var in AUTH_TOKEN_ENV_VARS:
+            token = os.getenv(env_var)
+            if token and token.strip():
+                source = env_var
+                break
+
     if not token:
         return None
 
@@ -24,11 +39,22 @@ def validate_auth_token() -> str | None:
         return None
 
     if len(token) < 32:
-        raise ValueError("MCP_AUTH_TOKEN must be at least 32 characters long")
+        raise ValueError(f"{source} must be at least 32 characters long")
 
     return token
 
 
+def is_loopback_host(host: str) -> bool:
+    """Check whether a bind address only accepts local connections."""
+    host = host.strip().strip("[]")
+    if host.lower() == "localhost":
+        return True
+    try:
+        return ipaddress.ip_address(host).is_loopback
+    except ValueError:
+        return False
+
+
 class BearerTokenMiddleware(BaseHTTPMiddleware):
     """
     Middleware to validate Bearer token for remote server authentication.
@@ -63,11 +89,7 @@ class BearerTokenMiddleware(BaseHTTPMiddleware):
                     "error_description": "Missing or invalid Authorization header",
                 },
                 status_code=401,
-                headers={
-                    "WWW-Authenticate": "Bearer",
-                    "Access-Control-Allow-Origin": "*",
-                    "Access-Control-Allow-Headers": "*",
-                },
+                headers={"WWW-Authenticate": "Bearer"},
             )
 
         token = auth_header[7:]  # Strip "Bearer " prefix
@@ -78,11 +100,7 @@ class BearerTokenMiddleware(BaseHTTPMiddleware):
                     "error_description": "Invalid token",
                 },
                 status_code=401,
-                headers={
-                    "WWW-Authenticate": "Bearer",
-                    "Access-Control-Allow-Origin": "*",
-                    "Access-Control-Allow-Headers": "*",
-                },
+                headers={"WWW-Authenticate": "Bearer"},
             )
 
         return await call_next(request)
@@ -0,0 +1,217 @@
+"""Fetch many records of one entity type in a single command.
+
+Each identifier is resolved with the entity's regular getter in JSON
+mode, so a record counts as failed when the getter reports an ``error``.
+By default the batch fails fast: the first failure aborts the whole
+batch. With ``continue_on_error`` failures are collected and reported
+next to the records that did resolve.
+
+``stream_batch`` yields each record as soon as it resolves instead of
+waiting for the whole batch, optionally holding records back so they
+come out in input order.
+"""
+
+import asyncio
+import json
+from collections.abc import AsyncIterator, Awaitable, Callable
+from typing import Any
+
+from . import render
+from .articles.fetch import fetch_articles, is_pmid
+from .concurrency import gather_limited, resolve_concurrency
+from .core import StrEnum
+from .diseases.getter import get_disease
+from .drugs.getter import get_drug
+from .genes.getter import get_gene
+from .trials.getter import get_trial
+from .variants.getter import get_variant
+
+# Default parallel lookups per batch
+BATCH_CONCURRENCY = 5
+
+
+class BatchEntity(StrEnum):
+    GENE = "gene"
+    VARIANT = "variant"
+    TRIAL = "trial"
+    ARTICLE = "article"
+    DRUG = "drug"
+    DISEASE = "disease"
+
+
+class BatchError(Exception):
+    """A single identifier in a batch could not be resolved."""
+
+    def __init__(self, identifier: str, message: str):
+        super().__init__(f"{identifier}: {message}")
+        self.identifier = identifier
+        self.message = message
+
+
+async def _fetch_article(identifier: str) -> str:
+    if not is_pmid(identifier):
+        return json.dumps({"error": "Batch article lookup expects a PMID"})
+    return await fetch_articles([int(identifier)], full=False, output_json=True)
+
+
+BATCH_FETCHERS: dict[BatchEntity, Callable[[str], Awaitable[str]]] = {
+    BatchEntity.GENE: lambda i: get_gene(i, output_json=True),
+    BatchEntity.VARIANT: lambda i: get_variant(i, output_json=True),
+    BatchEntity.TRIAL: lambda i: get_trial(i, output_json=True),
+    BatchEntity.ARTICLE: _fetch_article,
+    BatchEntity.DRUG: lambda i: get_drug(i, output_json=True),
+    BatchEntity.DISEASE: lambda i: get_disease(i, output_json=True),
+}
+
+
+def _error_message(data: Any) -> str | None:
+    """Return the error a getter reported in its JSON output, if any."""
+    records = data if isinstance(data, list) else [data]
+    if not records:
+        return "No record returned"
+    first = records[0]
+    if isinstance(first, dict) and first.get("error"):
+        details = first.get("details")
+        return f"{first['error']}: {details}" if details else first["error"]
+    return None
+
+
+async def fetch_batch_record(entity: BatchEntity, identifier: str) -> Any:
+    """Resolve one identifier; raises BatchError when the getter fails."""
+    raw = await BATCH_FETCHERS[entity](identifier)
+    try:
+        data = json.loads(raw)
+    except json.JSONDecodeError as e:
+        raise BatchError(identifier, f"Unreadable response: {e}") from e
+
+    error = _error_message(data)
+    if error:
+        raise BatchError(identifier, error)
+    return data
+
+
+async def run_batch(
+    entity: BatchEntity,
+    identifiers: list[str],
+    continue_on_error: bool = False,
+    concurrency: int | None = None,
+) -> tuple[list[dict[str, Any]], list[dict[str, str]]]:
+    """Fetch all identifiers, keeping input order.
+
+    Returns:
+        Tuple of (results as {"id", "data"}, errors as {"id", "error"})
+
+    Raises:
+        BatchError: On the first failure unless ``continue_on_error``
+    """
+    outcomes = await gather_limited(
+        (fetch_batch_record(entity, i) for i in identifiers),
+        resolve_concurrency(concurrency, BATCH_CONCURRENCY),
+        return_exceptions=continue_on_error,
+    )
+
+    results: list[dict[str, Any]] = []
+    errors: list[dict[str, str]] = []
+    for identifier, outcome in zip(identifiers, outcomes, strict=True):
+        if isinstance(outcome, BatchError):
+            errors.append({"id": identifier, "error": outcome.message})
+        elif isinstance(outcome, BaseException):
+            errors.append({"id": identifier, "error": str(outcome)})
+        else:
+            results.append({"id": identifier, "data": outcome})
+    return results, errors
+
+
+async def stream_batch(
+    entity: BatchEntity,
+    identifiers: list[str],
+    ordered: bool = False,
+    continue_on_error: bool = False,
+    concurrency: int | None = None,
+) -> AsyncIterator[dict[str, Any]]:
+    """Yield ``{"id", "data"}`` or ``{"id", "error"}`` as records resolve.
+
+    Records come out in completion order, or in input order when
+    ``ordered`` is set (a record waits for all records before it).
+
+    Raises:
+        BatchError: When a failed record is reached, unless
+            ``continue_on_error``; pending lookups are cancelled
+    """
+    limit = resolve_concurrency(concurrency, BATCH_CONCURRENCY)
+    semaphore = asyncio.Semaphore(limit or max(len(identifiers), 1))
+
+    async def settle(
+        index: int, identifier: str
+    ) -> tuple[int, dict[str, Any]]:
+        async with semaphore:
+            try:
+                data = await fetch_batch_record(entity, identifier)
+            except BatchError as e:
+                return index, {"id": identifier, "error": e.message}
+            except Exception as e:
+                return index, {"id": identifier, "error": str(e)}
+        return index, {"id": identifier, "data": data}
+
+    tasks = [
+        asyncio.ensure_future(settle(i, identifier))
+        for i, identifier in enumerate(identifiers)
+    ]
+    pending: dict[int, dict[str, Any]] = {}
+    next_index = 0
+    try:
+        for next_done in asyncio.as_completed(tasks):
+            index, outcome = await next_done
+            if not ordered:
+                ready = [outcome]
+            else:
+                pending[index] = outcome
+                ready = []
+                while next_index in pending:
+                    ready.append(pending.pop(next_index))
+                    next_index += 1
+
+            for record in ready:
+                if "error" in record and not continue_on_error:
+                    raise BatchError(record["id"], record["error"])
+                yield record
+    finally:
+        for task in tasks:
+            task.cancel()
+
+
+def format_batch_record(
+    entity: BatchEntity, record: dict[str, Any], output_json: bool = False
+) -> str:
+    """Render one streamed record: an NDJSON line or a markdown section."""
+    if output_json:
+        return json.dumps(record)
+    title = f"# {entity.value.title()} {record['id']}"
+    if "error" in record:
+        return f"{title}\n\nError: {record['error']}"
+    return f"{title}\n\n" + render.to_markdown(record["data"]).strip()
+
+
+def format_batch(
+    entity: BatchEntity,
+    results: list[dict[str, Any]],
+    errors: list[dict[str, str]] | None = None,
+    output_json: bool = False,
+) -> str:
+    """Render batch results, plus an error section when errors is given.
+
+    JSON output is a list of records, or an object with ``results`` and
+    ``errors`` arrays when errors are reported.
+    """
+    if output_json:
+        records = [r["data"] for r in results]
+        if errors is None:
+            return json.dumps(records, indent=2)
+        return json.dumps({"results": records, "errors": errors}, indent=2)
+
+    sections = [format_batch_record(entity, r) for r in results]
+    if errors:
+        lines = [f"# Batch errors ({len(errors)})", ""]
+        lines.extend(f"- **{e['id']}**: {e['error']}" for e in errors)
+        sections.append("\n".join(lines))
+    return "\n\n---\n\n".join(sections)
@@ -5,17 +5,52 @@ from typing import Annotated
 import typer
 
 from ..articles import fetch
-from ..articles.search import PubmedRequest, search_articles
+from ..articles.citations import fetch_article_with_citations
+from ..articles.dates import ArticleDateField
+from ..articles.search import (
+    PubmedRequest,
+    article_search_query_summary,
+    search_articles,
+)
 from ..articles.unified import search_articles_unified
+from ..sampling import sample_records, sample_window
 
 article_app = typer.Typer(help="Search and retrieve biomedical articles.")
 
 
 async def get_article_details(
-    identifier: str, output_json: bool = False
+    identifier: str,
+    output_json: bool = False,
+    fulltext_format: fetch.FullTextFormat = fetch.FullTextFormat.PLAIN,
+    references: bool = False,
+    cited_by: bool = False,
+    abstract_only: bool = False,
 ) -> str:
     """Get article details handling both PMIDs and DOIs with proper output format."""
+    if abstract_only and fetch.is_pmid(identifier):
+        return await fetch.fetch_articles(
+            [int(identifier)],
+            full=False,
+            output_json=output_json,
+            abstract_only=True,
+        )
+    if abstract_only and fetch.is_doi(identifier):
+        from .. import render
+        from ..articles.preprints import fetch_europe_pmc_article
+
+        data = fetch.abstract_only_records(
+            json.loads(
+                await fetch_europe_pmc_article(identifier, output_json=True)
+            )
+        )
+        if output_json:
+            return json.dumps(data, indent=2)
+        return render.to_markdown(data)
     # Use the fetch module functions directly to control output format
+    if (references or cited_by) and fetch.is_pmid(identifier):
+        return await fetch_article_with_citations(
+            int(identifier), output_json, fulltext_format, references, cited_by
+        )
     if fetch.is_doi(identifier):
         from ..articles.preprints import fetch_europe_pmc_article
 
@@ -24,7 +59,10 @@ async def get_article_details(
         )
     elif fetch.is_pmid(identifier):
         return await fetch.fetch_articles(
-            [int(identifier)], full=True, output_json=output_json
+            [int(identifier)],
+            full=True,
+            output_json=output_json,
+            fulltext_format=fulltext_format,
         )
     else:
         # Unknown identifier format
@@ -118,31 +156,176 @@ def search_article(
             help="Include preprint articles from bioRxiv/medRxiv and Europe PMC",
         ),
     ] = True,
+    preprint_only: Annotated[
+        bool,
+        typer.Option(
+            "--preprint-only",
+            help="Only preprints from bioRxiv/medRxiv and Europe PMC; "
+            "PubMed is not searched",
+        ),
+    ] = False,
+    has_fulltext: Annotated[
+        bool,
+        typer.Option(
+            "--has-fulltext",
+            help="Only PubMed articles with full text in PubMed Central "
+            "(have a PMCID); excludes preprints. Pages may be shorter "
+            "than --limit",
+        ),
+    ] = False,
+    authors: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--author",
+            "-a",
+            help="Author the article must have, matched on last name "
+            "(can be specified multiple times; all must match). "
+            "PubMed only",
+        ),
+    ] = None,
+    author_exact: Annotated[
+        bool,
+        typer.Option(
+            "--author-exact",
+            help="Match --author on the full 'Last FM' form "
+            "(e.g. 'Smith JA') instead of last name only",
+        ),
+    ] = False,
+    mesh: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--mesh",
+            help="MeSH descriptor name or ID (e.g. D008545) the article "
+            "must be indexed with; use | for alternatives (can be "
+            "specified multiple times; all must match). PubMed only",
+        ),
+    ] = None,
+    date_from: Annotated[
+        str | None,
+        typer.Option(
+            "--date-from",
+            help="Earliest date (YYYY-MM-DD) of --date-field. PubMed only",
+        ),
+    ] = None,
+    date_to: Annotated[
+        str | None,
+        typer.Option(
+            "--date-to",
+            help="Latest date (YYYY-MM-DD) of --date-field. PubMed only",
+        ),
+    ] = None,
+    date_field: Annotated[
+        ArticleDateField | None,
+        typer.Option(
+            "--date-field",
+            help="PubMed date the --date-from/--date-to range applies to: "
+            "publication, Entrez entry or last revision date "
+            "[default: pub]",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
+    sample: Annotated[
+        int | None,
+        typer.Option(
+            "--sample",
+            help="Return a random sample of N articles drawn from a "
+            "window 5x larger (fetches more than it returns; replaces "
+            "--limit and --page)",
+            min=1,
+            max=20,
+        ),
+    ] = None,
+    seed: Annotated[
+        int | None,
+        typer.Option(
+            "--seed",
+            help="Random seed for --sample, for a reproducible sample",
+        ),
+    ] = None,
 ):
     """Search biomedical research articles"""
-    request = PubmedRequest(
-        genes=genes or [],
-        variants=variants or [],
-        diseases=diseases or [],
-        chemicals=chemicals or [],
-        keywords=keywords or [],
-    )
-
-    if include_preprints:
+    if preprint_only and not include_preprints:
+        typer.echo(
+            "Error: --preprint-only cannot be combined with --no-preprints",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if seed is not None and sample is None:
+        typer.echo("Error: --seed requires --sample", err=True)
+        raise typer.Exit(1)
+    if date_field and not (date_from or date_to):
+        typer.echo(
+            "Error: --date-field requires --date-from or --date-to",
+            err=True,
+        )
+        raise typer.Exit(1)
+    dates = date_from or date_to
+    if (authors or mesh or dates) and not (
+        genes or variants or diseases or chemicals or keywords
+    ):
+        if authors:
+            option = "--author"
+        else:
+            option = "--mesh" if mesh else "--date-from/--date-to"
+        typer.echo(
+            f"Error: {option} filters a search; add --gene, --disease, "
+            "--chemical, --variant or --keyword",
+            err=True,
+        )
+        raise typer.Exit(1)
+    try:
+        request = PubmedRequest(
+            genes=genes or [],
+            variants=variants or [],
+            diseases=diseases or [],
+            chemicals=chemicals or [],
+            keywords=keywords or [],
+            has_fulltext=has_fulltext,
+            authors=authors or [],
+            author_exact=author_exact,
+            mesh=mesh or [],
+            preprint_only=preprint_only,
+            date_from=date_from,
+            date_to=date_to,
+            date_field=date_field or ArticleDateField.PUB,
+        )
+    except ValueError as exc:
+        typer.echo(f"Error: {exc}", err=True)
+        raise typer.Exit(1) from exc
+
+    # A sample is drawn from the JSON records of one large first page
+    fetch_json = output_json or sample is not None
+    if sample:
+        limit, page = sample_window(sample, 100), 1
+
+    # Preprints have no PMCID, PubTator author list, MeSH indexing or
+    # PubMed dates, so these filters search PubMed only
+    if include_preprints and not (has_fulltext or authors or mesh or dates):
         result = asyncio.run(
             search_articles_unified(
                 request,
                 include_pubmed=True,
                 include_preprints=True,
-                output_json=output_json,
+                output_json=fetch_json,
                 limit=limit,
                 page=page,
             )
         )
     else:
         result = asyncio.run(
-            search_articles(request, output_json, limit=limit, page=page)
+            search_articles(request, fetch_json, limit=limit, page=page)
         )
+    if sample:
+        from .. import render
+
+        data = sample_records(json.loads(result), sample, seed, "articles")
+        if data and not output_json:
+            result = render.to_markdown(data)
+        else:
+            result = json.dumps(data, indent=2)
+    if not output_json:
+        typer.echo(f"_{article_search_query_summary(request)}_\n")
     typer.echo(result)
 
 
@@ -171,6 +354,39 @@ def get_article(
             case_sensitive=False,
         ),
     ] = False,
+    fulltext_format: Annotated[
+        fetch.FullTextFormat,
+        typer.Option(
+            "--fulltext-format",
+            help="Full-text rendering: plain (paragraphs), sections "
+            "(grouped under section names) or markdown (headings, captions)",
+            case_sensitive=False,
+        ),
+    ] = fetch.FullTextFormat.PLAIN,
+    references: Annotated[
+        bool,
+        typer.Option(
+            "--references",
+            help="Add the PubMed articles this article cites (iCite; "
+            "PubMed IDs only)",
+        ),
+    ] = False,
+    cited_by: Annotated[
+        bool,
+        typer.Option(
+            "--cited-by",
+            help="Add the PubMed articles citing this article (iCite; "
+            "PubMed IDs only)",
+        ),
+    ] = False,
+    abstract_only: Annotated[
+        bool,
+        typer.Option(
+            "--abstract-only",
+            help="Fetch only the title and abstract; ignores --full, "
+            "--fulltext-format, --references and --cited-by",
+        ),
+    ] = False,
 ):
     """
     Retrieve articles by PubMed ID or DOI.
@@ -180,11 +396,36 @@ def get_article(
     - DOIs for Europe PMC preprints (e.g., 10.1101/2024.01.20.23288905)
 
     For multiple articles, results are returned as a list.
+
+    --references and --cited-by list citation links as PMIDs (markdown
+    shows the first 50 of each; --json returns all).
+
+    --abstract-only is a fast mode returning just identifiers, title and
+    abstract. It skips the full text and citation lookups, so any
+    requested sections are ignored.
     """
+    if abstract_only:
+        references = cited_by = False
+    if (references or cited_by) and not all(
+        fetch.is_pmid(identifier) for identifier in identifiers
+    ):
+        typer.echo(
+            "Error: --references/--cited-by require PubMed IDs",
+            err=True,
+        )
+        raise typer.Exit(1)
+
     # Handle single identifier
     if len(identifiers) == 1:
         result = asyncio.run(
-            get_article_details(identifiers[0], output_json=output_json)
+            get_article_details(
+                identifiers[0],
+                output_json=output_json,
+                fulltext_format=fulltext_format,
+                references=references,
+                cited_by=cited_by,
+                abstract_only=abstract_only,
+            )
         )
     else:
         # For multiple identifiers, we need to handle them individually
@@ -192,7 +433,14 @@ def get_article(
         results = []
         for identifier in identifiers:
             article_result = asyncio.run(
-                get_article_details(identifier, output_json=True)
+                get_article_details(
+                    identifier,
+                    output_json=True,
+                    fulltext_format=fulltext_format,
+                    references=references,
+                    cited_by=cited_by,
+                    abstract_only=abstract_only,
+                )
             )
             # Parse the result and add to list
             try:
@@ -0,0 +1,124 @@
+"""CLI command for fetching many records in one call."""
+
+import asyncio
+from typing import Annotated
+
+import typer
+
+from ..batch import (
+    BatchEntity,
+    BatchError,
+    format_batch,
+    format_batch_record,
+    run_batch,
+    stream_batch,
+)
+
+
+async def _stream_to_stdout(
+    entity: BatchEntity,
+    identifiers: list[str],
+    ordered: bool,
+    continue_on_error: bool,
+    output_json: bool,
+) -> None:
+    """Print each record as soon as the batch yields it."""
+    first = True
+    async for record in stream_batch(
+        entity,
+        identifiers,
+        ordered=ordered,
+        continue_on_error=continue_on_error,
+    ):
+        if not output_json and not first:
+            typer.echo("\n---\n")
+        typer.echo(format_batch_record(entity, record, output_json))
+        first = False
+
+
+def batch_command(
+    entity: Annotated[
+        BatchEntity,
+        typer.Argument(help="Entity type to fetch", case_sensitive=False),
+    ],
+    identifiers: Annotated[
+        list[str],
+        typer.Argument(help="Identifiers to fetch (e.g., TP53 BRAF EGFR)"),
+    ],
+    continue_on_error: Annotated[
+        bool,
+        typer.Option(
+            "--continue-on-error",
+            help="Keep going when an identifier fails and list failures "
+            "in a 'Batch errors' section (default: stop at the first failure)",
+        ),
+    ] = False,
+    stream: Annotated[
+        bool,
+        typer.Option(
+            "--stream",
+            help="Print each record as soon as it resolves (NDJSON with "
+            "--json), in completion order unless --ordered is set",
+        ),
+    ] = False,
+    ordered: Annotated[
+        bool,
+        typer.Option(
+            "--ordered",
+            help="With --stream, print records in input order",
+        ),
+    ] = False,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    Fetch several records of one entity type concurrently.
+
+    Examples:
+        czechmedmcp batch gene TP53 BRAF EGFR
+        czechmedmcp batch trial NCT04280705 NCT00000000 --continue-on-error
+        czechmedmcp batch variant rs113488022 rs121913529 --json
+        czechmedmcp batch trial NCT04280705 NCT02576665 --stream --json
+    """
+    if ordered and not stream:
+        typer.echo("Error: --ordered requires --stream", err=True)
+        raise typer.Exit(1)
+
+    try:
+        if stream:
+            asyncio.run(
+                _stream_to_stdout(
+                    entity,
+                    identifiers,
+                    ordered,
+                    continue_on_error,
+                    output_json,
+                )
+            )
+            return
+
+        results, errors = asyncio.run(
+            run_batch(
+                entity, identifiers, continue_on_error=continue_on_error
+            )
+        )
+    except BatchError as e:
+        typer.echo(
+            f"Error: batch stopped at {e.identifier}: {e.message}", err=True
+        )
+        raise typer.Exit(1) from None
+
+    typer.echo(
+        format_batch(
+            entity,
+            results,
+            errors if continue_on_error else None,
+            output_json=output_json,
+        )
+    )
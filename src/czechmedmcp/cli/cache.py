@@ -0,0 +1,69 @@
+"""CLI commands for managing the on-disk HTTP response cache."""
+
+from typing import Annotated
+
+import typer
+
+from .. import http_client
+from ..utils.duration import parse_duration
+
+cache_app = typer.Typer(
+    no_args_is_help=True,
+    help="Inspect and manage the on-disk HTTP response cache",
+)
+
+
+def _format_size(size_bytes: int) -> str:
+    """Format a byte count for display."""
+    if size_bytes < 1024:
+        return f"{size_bytes} B"
+    size = size_bytes / 1024
+    for unit in ("KB", "MB"):
+        if size < 1024:
+            return f"{size:.1f} {unit}"
+        size /= 1024
+    return f"{size:.1f} GB"
+
+
+@cache_app.command("path")
+def cache_path_cli() -> None:
+    """Print the cache directory location."""
+    typer.echo(http_client.get_cache_path())
+
+
+@cache_app.command("stats")
+def cache_stats_cli() -> None:
+    """Show the number of cached responses and their total size."""
+    stats = http_client.cache_stats()
+    typer.echo(f"Path: {stats['path']}")
+    typer.echo(f"Entries: {stats['entries']}")
+    typer.echo(f"Size: {_format_size(int(stats['size_bytes']))}")
+
+
+@cache_app.command("clear")
+def cache_clear_cli(
+    older_than: Annotated[
+        str | None,
+        typer.Option(
+            "--older-than",
+            help="Only remove entries older than this (e.g., 12h, 30d, 2w)",
+        ),
+    ] = None,
+) -> None:
+    """
+    Remove cached responses.
+
+    Examples:
+        czechmedmcp cache clear
+        czechmedmcp cache clear --older-than 30d
+    """
+    seconds = None
+    if older_than is not None:
+        try:
+            seconds = parse_duration(older_than).total_seconds()
+        except ValueError as e:
+            typer.echo(f"Error: {e}", err=True)
+            raise typer.Exit(1) from None
+
+    removed = http_client.clear_cache(older_than_seconds=seconds)
+    typer.echo(f"Removed {removed} cached entries.")
@@ -5,7 +5,12 @@ from typing import Annotated
 
 import typer
 
-from ..diseases import get_disease
+from .. import render
+from ..diseases import (
+    DiseaseSearchFilters,
+    get_disease,
+    search_diseases_filtered,
+)
 from ..diseases.search import format_disease_results, search_diseases
 from ..integrations.cts_api import CTSAPIError, get_api_key_instructions
 
@@ -21,6 +26,53 @@ def get_disease_cli(
         str,
         typer.Argument(help="Disease name or identifier"),
     ],
+    output_format: Annotated[
+        str | None,
+        typer.Option(
+            "--format",
+            help="Alternative output format. Options: json-ld (schema.org "
+            "linked data)",
+        ),
+    ] = None,
+    models: Annotated[
+        bool,
+        typer.Option(
+            "--models",
+            help="Add animal models (mouse, zebrafish, ...) with their "
+            "allele and phenotypes from Monarch",
+        ),
+    ] = False,
+    treatments: Annotated[
+        bool,
+        typer.Option(
+            "--treatments",
+            help="Add drugs indicated for the disease, ranked by the "
+            "highest trial phase reached (ChEMBL indications)",
+        ),
+    ] = False,
+    gard: Annotated[
+        bool,
+        typer.Option(
+            "--gard",
+            help="Add the GARD rare-disease entry (ID and patient "
+            "information page) from MONDO cross-references",
+        ),
+    ] = False,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+    compact: Annotated[
+        bool,
+        typer.Option(
+            "--compact",
+            help="Print a one-line summary instead of the full card",
+        ),
+    ] = False,
 ) -> None:
     """
     Get disease information from MyDisease.info.
@@ -32,8 +84,62 @@ def get_disease_cli(
         czechmedmcp disease get melanoma
         czechmedmcp disease get "lung cancer"
         czechmedmcp disease get GIST
+        czechmedmcp disease get MONDO:0005105 --format json-ld
+        czechmedmcp disease get "Waardenburg syndrome" --models
+        czechmedmcp disease get melanoma --treatments
+        czechmedmcp disease get "cystic fibrosis" --gard
     """
-    result = asyncio.run(get_disease(disease_name))
+    if compact:
+        if (
+            output_json
+            or output_format is not None
+            or models
+            or treatments
+            or gard
+        ):
+            typer.echo(
+                "Error: --compact cannot be combined with --json, --format, "
+                "--models, --treatments or --gard",
+                err=True,
+            )
+            raise typer.Exit(1)
+        line, error = render.json_to_summary_line(
+            asyncio.run(get_disease(disease_name, output_json=True)),
+            "disease",
+        )
+        if error or line is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(line)
+        return
+
+    if output_format is not None:
+        if output_format.lower() != "json-ld":
+            typer.echo(
+                f"Invalid format: '{output_format}'. "
+                "Available options: json-ld",
+                err=True,
+            )
+            raise typer.Exit(1)
+        document, error = render.json_to_json_ld(
+            asyncio.run(get_disease(disease_name, output_json=True)),
+            "disease",
+        )
+        if error or document is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(document)
+        return
+
+    result = asyncio.run(
+        get_disease(
+            disease_name,
+            output_json=output_json,
+            models=models,
+            treatments=treatments,
+            gard=gard,
+        )
+    )
     typer.echo(result)
 
 
@@ -92,6 +198,29 @@ def search_diseases_cli(
             show_choices=True,
         ),
     ] = "mydisease",
+    rare_only: Annotated[
+        bool,
+        typer.Option(
+            "--rare-only",
+            help="Only show diseases designated rare via MONDO/Orphanet/GARD (best-effort, MyDisease.info only)",
+        ),
+    ] = False,
+    gene: Annotated[
+        str | None,
+        typer.Option(
+            "--gene",
+            help="Only diseases associated with this gene symbol, from the "
+            "Monarch Initiative (the name, if given, narrows the page)",
+        ),
+    ] = None,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format (MyDisease.info filtered search)",
+        ),
+    ] = False,
 ) -> None:
     """
     Search for diseases in MyDisease.info or NCI CTS database.
@@ -111,7 +240,28 @@ def search_diseases_cli(
 
         # Filter by category
         czechmedmcp disease search --category neoplasm --source nci
+
+        # Rare diseases only
+        czechmedmcp disease search myopathy --rare-only
+
+        # Diseases associated with a gene
+        czechmedmcp disease search --gene BRAF
     """
+    if gene and rare_only:
+        typer.echo(
+            "Error: --gene cannot be combined with --rare-only", err=True
+        )
+        raise typer.Exit(1)
+
+    filters = DiseaseSearchFilters(rare_only=rare_only, gene=gene)
+    if source == "nci" and not filters.is_empty():
+        typer.echo(
+            "Error: --rare-only and --gene are only supported with "
+            "--source mydisease",
+            err=True,
+        )
+        raise typer.Exit(1)
+
     if source == "nci":
         # Use NCI CTS API
         try:
@@ -138,6 +288,20 @@ def search_diseases_cli(
         except Exception as e:
             typer.echo(f"Unexpected error: {e}", err=True)
             raise typer.Exit(1) from e
+    elif not filters.is_empty() or output_json:
+        if not name and not gene:
+            typer.echo("Please provide a disease name to search for.")
+            raise typer.Exit(1)
+        result = asyncio.run(
+            search_diseases_filtered(
+                name,
+                filters=filters,
+                page=page,
+                page_size=page_size,
+                output_json=output_json,
+            )
+        )
+        typer.echo(result)
     else:
         # Default to MyDisease.info
         # For now, just search by name
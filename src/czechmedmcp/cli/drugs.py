@@ -5,7 +5,17 @@ from typing import Annotated
 
 import typer
 
-from ..drugs import get_drug
+from .. import render
+from ..drugs import (
+    ApprovalStatus,
+    DrugSearchFilters,
+    DrugSort,
+    MoaClass,
+    TargetAction,
+    get_drug,
+    get_drug_targets,
+    search_drugs,
+)
 
 drug_app = typer.Typer(
     no_args_is_help=True,
@@ -30,6 +40,53 @@ def get_drug_cli(
             help="Output in JSON format",
         ),
     ] = False,
+    formulations: Annotated[
+        bool,
+        typer.Option(
+            "--formulations",
+            help="Add marketed formulations (route, dosage form, strength) "
+            "from the openFDA NDC Directory",
+        ),
+    ] = False,
+    route: Annotated[
+        str | None,
+        typer.Option(
+            "--route",
+            help="Only list formulations for this route (e.g., ORAL); "
+            "implies --formulations",
+        ),
+    ] = None,
+    pharmacology: Annotated[
+        bool,
+        typer.Option(
+            "--pharmacology",
+            help="Add pharmacokinetics and pharmacodynamics from DrugBank "
+            "(absorption, half-life, metabolism, elimination)",
+        ),
+    ] = False,
+    boxed_warning: Annotated[
+        bool,
+        typer.Option(
+            "--boxed-warning",
+            help="Add the boxed (black box) warning from the openFDA drug "
+            "label, or report that there is none",
+        ),
+    ] = False,
+    output_format: Annotated[
+        str | None,
+        typer.Option(
+            "--format",
+            help="Alternative output format. Options: json-ld (schema.org "
+            "linked data)",
+        ),
+    ] = None,
+    compact: Annotated[
+        bool,
+        typer.Option(
+            "--compact",
+            help="Print a one-line summary instead of the full card",
+        ),
+    ] = False,
 ) -> None:
     """
     Get drug information from MyChem.info.
@@ -41,6 +98,8 @@ def get_drug_cli(
     - Clinical indications
     - Mechanism of action
     - Links to external databases
+    - Optionally, marketed formulations from openFDA
+    - Optionally, pharmacokinetics and pharmacodynamics from DrugBank
 
     Examples:
         czechmedmcp drug get imatinib
@@ -48,8 +107,63 @@ def get_drug_cli(
         czechmedmcp drug get "idecabtagene vicleucel"
         czechmedmcp drug get DB00945
         czechmedmcp drug get imatinib --json
+        czechmedmcp drug get imatinib --formulations
+        czechmedmcp drug get methotrexate --route ORAL
+        czechmedmcp drug get warfarin --pharmacology
+        czechmedmcp drug get rosiglitazone --boxed-warning
+        czechmedmcp drug get imatinib --format json-ld
+        czechmedmcp drug get imatinib --compact
     """
-    result = asyncio.run(get_drug(drug_id_or_name, output_json=output_json))
+    if compact:
+        if (
+            output_json
+            or output_format is not None
+            or formulations
+            or route
+            or pharmacology
+            or boxed_warning
+        ):
+            typer.echo(
+                "Error: --compact cannot be combined with --json, --format, "
+                "--formulations, --route, --pharmacology or --boxed-warning",
+                err=True,
+            )
+            raise typer.Exit(1)
+        line, error = render.json_to_summary_line(
+            asyncio.run(get_drug(drug_id_or_name, output_json=True)),
+            "drug",
+        )
+        if error or line is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(line)
+        return
+
+    if output_format is not None:
+        if output_format.lower() != "json-ld":
+            typer.echo(
+                f"Invalid format: '{output_format}'. "
+                "Available options: json-ld",
+                err=True,
+            )
+            raise typer.Exit(1)
+        output_json = True
+
+    result = asyncio.run(
+        get_drug(
+            drug_id_or_name,
+            output_json=output_json,
+            include_formulations=formulations or route is not None,
+            route=route,
+            include_pharmacology=pharmacology,
+            include_boxed_warning=boxed_warning,
+        )
+    )
+    if output_format is not None:
+        result, error = render.json_to_json_ld(result, "drug")
+        if error or result is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
     typer.echo(result)
 
 
@@ -77,6 +191,69 @@ def search_drugs_cli(
             max=100,
         ),
     ] = 10,
+    approval_status: Annotated[
+        ApprovalStatus | None,
+        typer.Option(
+            "--approval-status",
+            help="Filter by DrugBank approval group",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
+    withdrawn: Annotated[
+        bool,
+        typer.Option(
+            "--withdrawn",
+            help="Only withdrawn drugs (shorthand for --approval-status withdrawn)",
+        ),
+    ] = False,
+    target: Annotated[
+        str | None,
+        typer.Option(
+            "--target",
+            help="Only drugs with this DrugBank target gene (e.g., EGFR)",
+        ),
+    ] = None,
+    target_action: Annotated[
+        TargetAction | None,
+        typer.Option(
+            "--target-action",
+            help="Only drugs with this action on their target (on the "
+            "--target gene when given)",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
+    moa_class: Annotated[
+        MoaClass | None,
+        typer.Option(
+            "--moa-class",
+            help="Only drugs in this mechanism-of-action class (DrugCentral "
+            "FDA pharmacologic class)",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
+    sort: Annotated[
+        DrugSort | None,
+        typer.Option(
+            "--sort",
+            help="Order results by name, max clinical phase, or first "
+            "approval year (missing values last); client-side, over the "
+            "top 100 to 1000 matches by relevance (pages beyond the top "
+            "1000 are rejected)",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
+    stable_sort: Annotated[
+        bool,
+        typer.Option(
+            "--stable-sort/--no-stable-sort",
+            help="Break relevance ties by MyChem ID so repeated searches "
+            "give identical output",
+        ),
+    ] = True,
     output_json: Annotated[
         bool,
         typer.Option(
@@ -97,7 +274,43 @@ def search_drugs_cli(
         czechmedmcp drug search "kinase inhibitor"
         czechmedmcp drug search aspirin --page 2 --page-size 20
         czechmedmcp drug search imatinib --json
+        czechmedmcp drug search rofecoxib --withdrawn
+        czechmedmcp drug search kinase --approval-status investigational
+        czechmedmcp drug search kinase --sort max-phase
+        czechmedmcp drug search "" --target EGFR --target-action inhibitor
+        czechmedmcp drug search "" --moa-class "kinase inhibitor"
     """
+    if withdrawn:
+        if approval_status not in (None, ApprovalStatus.WITHDRAWN):
+            typer.echo(
+                "Error: --withdrawn conflicts with "
+                f"--approval-status {approval_status.value}",
+                err=True,
+            )
+            raise typer.Exit(1)
+        approval_status = ApprovalStatus.WITHDRAWN
+
+    filters = DrugSearchFilters(
+        approval_status=approval_status,
+        target=target,
+        target_action=target_action,
+        moa_class=moa_class,
+        sort=sort,
+    )
+    if not filters.is_empty():
+        result = asyncio.run(
+            search_drugs(
+                query,
+                filters=filters,
+                page=page,
+                page_size=page_size,
+                output_json=output_json,
+                stable_sort=stable_sort,
+            )
+        )
+        typer.echo(result)
+        return
+
     # For now, use get_drug to search by the query
     # A full search implementation would require a separate search function
     result = asyncio.run(get_drug(query, output_json=output_json))
@@ -111,3 +324,56 @@ def search_drugs_cli(
             "Currently showing basic drug information for the query.\n",
             err=True,
         )
+
+
+@drug_app.command("targets")
+def drug_targets_cli(
+    drug_id_or_name: Annotated[
+        str,
+        typer.Argument(
+            help="Drug name (e.g., imatinib) or ID (e.g., DB00619, CHEMBL941)"
+        ),
+    ],
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit",
+            help="Maximum number of targets to show",
+            min=1,
+            max=200,
+        ),
+    ] = 20,
+    offset: Annotated[
+        int,
+        typer.Option(
+            "--offset",
+            help="Number of targets to skip",
+            min=0,
+        ),
+    ] = 0,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    List a drug's molecular targets with the drug's action on each.
+
+    Examples:
+        czechmedmcp drug targets imatinib
+        czechmedmcp drug targets DB00619 --limit 5 --offset 5
+        czechmedmcp drug targets imatinib --json
+    """
+    result = asyncio.run(
+        get_drug_targets(
+            drug_id_or_name,
+            limit=limit,
+            offset=offset,
+            output_json=output_json,
+        )
+    )
+    typer.echo(result)
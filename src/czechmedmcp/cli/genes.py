@@ -12,8 +12,28 @@ from typing import Annotated
 
 import typer
 
+from .. import render
 from ..enrichr import ENRICHR_DATABASES
-from ..genes import get_gene
+from ..enrichr.report import (
+    DEFAULT_TERM_LIMIT,
+    enrichment_report,
+    gene_list_source,
+    pathway_source,
+)
+from ..genes import (
+    GeneMatch,
+    GeneSearchFilters,
+    GoMode,
+    get_gene,
+    search_genes,
+)
+from ..genes.civic import (
+    fetch_gene_civic_evidence,
+    render_gene_civic,
+)
+from ..genes.search import normalize_go_id
+from ..pathways import fetch_pathway_genes, is_reactome_id
+from ..proteins import get_gene_protein_fasta
 
 gene_app = typer.Typer(
     no_args_is_help=True,
@@ -68,6 +88,72 @@ def get_gene_cli(
             f"{', '.join(ENRICHR_DATABASES.keys())} or full database name",
         ),
     ] = None,
+    output_format: Annotated[
+        str | None,
+        typer.Option(
+            "--format",
+            help="Alternative output format. Options: fasta (canonical "
+            "human protein sequence from UniProt), json-ld (schema.org / "
+            "Bioschemas linked data)",
+        ),
+    ] = None,
+    compact: Annotated[
+        bool,
+        typer.Option(
+            "--compact",
+            help="Print a one-line summary instead of the full card",
+        ),
+    ] = False,
+    associations: Annotated[
+        bool,
+        typer.Option(
+            "--associations",
+            help="Add diseases associated with the gene in Open Targets, "
+            "with overall and per data type scores",
+        ),
+    ] = False,
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit",
+            help="Number of disease associations to list (highest "
+            "score first)",
+            min=1,
+            max=100,
+        ),
+    ] = 10,
+    expression: Annotated[
+        bool,
+        typer.Option(
+            "--expression",
+            help="Add median tissue expression from GTEx, top "
+            "expressing tissues first",
+        ),
+    ] = False,
+    paralogs: Annotated[
+        bool,
+        typer.Option(
+            "--paralogs",
+            help="Add human paralogs (Ensembl Compara via Open Targets), "
+            "most identical first",
+        ),
+    ] = False,
+    orthologs: Annotated[
+        bool,
+        typer.Option(
+            "--orthologs",
+            help="Add orthologs in other species (Ensembl Compara via "
+            "Open Targets), most identical first",
+        ),
+    ] = False,
+    clinvar_summary: Annotated[
+        bool,
+        typer.Option(
+            "--clinvar-summary",
+            help="Add counts of the gene's ClinVar variants by clinical "
+            "significance",
+        ),
+    ] = False,
 ) -> None:
     """
     Get gene information from MyGene.info.
@@ -78,6 +164,10 @@ def get_gene_cli(
     - Aliases and alternative names
     - Gene type (protein-coding, etc.)
     - Links to external databases
+    - Open Targets disease association scores (with --associations)
+    - GTEx tissue expression (with --expression)
+    - Paralogs and orthologs (with --paralogs/--orthologs)
+    - ClinVar variant counts by significance (with --clinvar-summary)
 
     Examples:
         czechmedmcp gene get TP53
@@ -86,7 +176,67 @@ def get_gene_cli(
         czechmedmcp gene get TP53 --json
         czechmedmcp gene get TP53 --enrich pathway
         czechmedmcp gene get BRCA1 --enrich ontology --json
+        czechmedmcp gene get BRAF --format fasta
+        czechmedmcp gene get TP53 --format json-ld
+        czechmedmcp gene get BRAF --compact
+        czechmedmcp gene get EGFR --associations --limit 20
+        czechmedmcp gene get BRAF --expression
+        czechmedmcp gene get BRCA2 --paralogs --orthologs
+        czechmedmcp gene get BRCA1 --clinvar-summary
     """
+    sections = (associations, expression, paralogs, orthologs, clinvar_summary)
+    if any(sections) and (compact or output_format is not None):
+        typer.echo(
+            "Error: --associations/--expression/--paralogs/--orthologs/"
+            "--clinvar-summary cannot be combined with --compact or --format",
+            err=True,
+        )
+        raise typer.Exit(1)
+
+    if compact:
+        if output_json or output_format is not None or enrich is not None:
+            typer.echo(
+                "Error: --compact cannot be combined with --json, --format "
+                "or --enrich",
+                err=True,
+            )
+            raise typer.Exit(1)
+        line, error = render.json_to_summary_line(
+            asyncio.run(get_gene(gene_id_or_symbol, output_json=True)),
+            "gene",
+        )
+        if error or line is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(line)
+        return
+
+    if output_format is not None and output_format.lower() == "json-ld":
+        document, error = render.json_to_json_ld(
+            asyncio.run(get_gene(gene_id_or_symbol, output_json=True)),
+            "gene",
+        )
+        if error or document is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(document)
+        return
+
+    if output_format is not None:
+        if output_format.lower() != "fasta":
+            typer.echo(
+                f"Invalid format: '{output_format}'. "
+                "Available options: fasta, json-ld",
+                err=True,
+            )
+            raise typer.Exit(1)
+        fasta, error = asyncio.run(get_gene_protein_fasta(gene_id_or_symbol))
+        if error or fasta is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(fasta, nl=False)
+        return
+
     # Validate enrichment type before running async code
     try:
         enrichment_database = validate_enrich_type(enrich)
@@ -103,6 +253,11 @@ def get_gene_cli(
             include_enrichment=include_enrichment,
             enrichment_database=enrichment_database
             or "GO_Biological_Process_2021",
+            associations_limit=limit if associations else None,
+            include_expression=expression,
+            include_paralogs=paralogs,
+            include_orthologs=orthologs,
+            include_clinvar_summary=clinvar_summary,
         )
         typer.echo(result)
 
@@ -149,6 +304,94 @@ def search_genes_cli(
             max=100,
         ),
     ] = 10,
+    has_summary: Annotated[
+        bool,
+        typer.Option(
+            "--has-summary",
+            help="Only genes with a non-empty MyGene.info summary",
+        ),
+    ] = False,
+    min_summary_length: Annotated[
+        int | None,
+        typer.Option(
+            "--min-summary-length",
+            help="Only genes whose summary has at least N characters",
+            min=1,
+        ),
+    ] = None,
+    has_structure: Annotated[
+        bool,
+        typer.Option(
+            "--has-structure",
+            help="Only genes with solved PDB structures",
+        ),
+    ] = False,
+    symbol_only: Annotated[
+        bool,
+        typer.Option(
+            "--symbol-only",
+            help="Match the query as an exact gene symbol only",
+        ),
+    ] = False,
+    name_only: Annotated[
+        bool,
+        typer.Option(
+            "--name-only",
+            help="Match the query against gene names only",
+        ),
+    ] = False,
+    go: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--go",
+            help="GO term ID the genes must be annotated with, e.g. "
+            "GO:0006915 (can specify multiple)",
+        ),
+    ] = None,
+    go_mode: Annotated[
+        GoMode,
+        typer.Option(
+            "--go-mode",
+            help="Combine several --go terms: and (all annotated) or "
+            "or (any)",
+            case_sensitive=False,
+        ),
+    ] = GoMode.AND,
+    pathway: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--pathway",
+            help="Reactome pathway ID (R-HSA-109581) or name the genes "
+            "must belong to; names resolve to the top Reactome match "
+            "(can specify multiple, any pathway matches)",
+        ),
+    ] = None,
+    disease: Annotated[
+        str | None,
+        typer.Option(
+            "--disease",
+            help="Only genes Open Targets associates with this disease "
+            "(name or MONDO/EFO ID); results are ranked by score",
+        ),
+    ] = None,
+    min_score: Annotated[
+        float | None,
+        typer.Option(
+            "--min-score",
+            help="Minimum Open Targets association score (0-1) for "
+            "--disease",
+            min=0.0,
+            max=1.0,
+        ),
+    ] = None,
+    stable_sort: Annotated[
+        bool,
+        typer.Option(
+            "--stable-sort/--no-stable-sort",
+            help="Break relevance ties by Entrez ID so repeated searches "
+            "give identical output",
+        ),
+    ] = True,
     output_json: Annotated[
         bool,
         typer.Option(
@@ -164,12 +407,71 @@ def search_genes_cli(
     This searches across gene names, symbols, and descriptions
     to find matching genes.
 
+    Summary filters use the summary returned by the search itself (no
+    extra per-gene lookups), so a filtered page may hold fewer genes
+    than --page-size.
+
     Examples:
         czechmedmcp gene search TP53
         czechmedmcp gene search "tumor protein"
         czechmedmcp gene search kinase --page 2 --page-size 20
         czechmedmcp gene search BRCA --json
+        czechmedmcp gene search kinase --has-summary
+        czechmedmcp gene search kinase --min-summary-length 200
+        czechmedmcp gene search CAT --symbol-only
+        czechmedmcp gene search "catalase" --name-only
+        czechmedmcp gene search kinase --has-structure
+        czechmedmcp gene search kinase --go GO:0006915 --go GO:0005739
+        czechmedmcp gene search kinase --go 0006915 --go 0008283 --go-mode or
+        czechmedmcp gene search kinase --pathway R-HSA-109581
+        czechmedmcp gene search "*" --pathway apoptosis --pathway autophagy
+        czechmedmcp gene search "*" --disease melanoma --min-score 0.5
+        czechmedmcp gene search kinase --disease MONDO:0007254
     """
+    if symbol_only and name_only:
+        typer.echo(
+            "Error: --symbol-only and --name-only cannot be combined",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if min_score is not None and not disease:
+        typer.echo("Error: --min-score requires --disease", err=True)
+        raise typer.Exit(1)
+    match = GeneMatch.AUTO
+    if symbol_only:
+        match = GeneMatch.SYMBOL
+    elif name_only:
+        match = GeneMatch.NAME
+    try:
+        go_ids = [normalize_go_id(go_id) for go_id in go or []]
+    except ValueError as e:
+        typer.echo(f"Error: {e}", err=True)
+        raise typer.Exit(1) from None
+
+    filters = GeneSearchFilters(
+        has_summary=has_summary,
+        min_summary_length=min_summary_length,
+        has_structure=has_structure,
+        go_ids=go_ids,
+        go_mode=go_mode,
+        pathways=pathway or [],
+        disease=disease,
+        min_score=min_score,
+    )
+    if not filters.is_empty() or match != GeneMatch.AUTO:
+        result = asyncio.run(
+            search_genes(
+                query,
+                filters=filters,
+                page=page,
+                page_size=page_size,
+                output_json=output_json,
+                match=match,
+                stable_sort=stable_sort,
+            )
+        )
+        typer.echo(result)
+        return
 
     async def run():
         # For now, use get_gene to search by the query
@@ -194,3 +496,146 @@ def search_genes_cli(
             "Currently showing basic gene information for the query.\n",
             err=True,
         )
+
+
+@gene_app.command("enrich")
+def enrich_genes_cli(
+    genes: Annotated[
+        list[str] | None,
+        typer.Argument(help="Gene symbols to analyze (e.g., TP53 BRCA1)"),
+    ] = None,
+    pathway: Annotated[
+        str | None,
+        typer.Option(
+            "--pathway",
+            help="Analyze the genes of this Reactome pathway instead of a "
+            "gene list (e.g., R-HSA-5673001)",
+        ),
+    ] = None,
+    database: Annotated[
+        str,
+        typer.Option(
+            "--database",
+            "-d",
+            help=f"Enrichment database. Options: "
+            f"{', '.join(ENRICHR_DATABASES.keys())} or full database name",
+        ),
+    ] = "pathway",
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit",
+            help="Maximum number of terms to show (JSON returns all)",
+            min=1,
+        ),
+    ] = DEFAULT_TERM_LIMIT,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    Run Enrichr functional enrichment on a gene list or a pathway's genes.
+
+    Examples:
+        czechmedmcp gene enrich TP53 BRCA1 ATM
+        czechmedmcp gene enrich TP53 BRCA1 ATM --database ontology
+        czechmedmcp gene enrich --pathway R-HSA-5673001 --database kegg
+    """
+    if bool(genes) == bool(pathway):
+        typer.echo(
+            "Error: give either a gene list or --pathway, not both",
+            err=True,
+        )
+        raise typer.Exit(1)
+    try:
+        validate_enrich_type(database)
+    except typer.BadParameter as e:
+        typer.echo(f"Error: {e}", err=True)
+        raise typer.Exit(1) from None
+
+    if pathway:
+        if not is_reactome_id(pathway):
+            typer.echo(
+                f"Error: '{pathway}' is not a Reactome stable ID "
+                "(e.g., R-HSA-5673001)",
+                err=True,
+            )
+            raise typer.Exit(1)
+        pathway_genes, error = asyncio.run(fetch_pathway_genes(pathway))
+        if error or not pathway_genes:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        genes = pathway_genes
+        source = pathway_source(pathway.upper(), genes)
+    else:
+        genes = [gene.strip().upper() for gene in genes or []]
+        source = gene_list_source(genes)
+
+    result = asyncio.run(
+        enrichment_report(
+            genes,
+            database,
+            source,
+            output_json=output_json,
+            limit=limit,
+        )
+    )
+    typer.echo(result)
+
+
+@gene_app.command("civic")
+def gene_civic_cli(
+    gene_symbol: Annotated[
+        str,
+        typer.Argument(help="Gene symbol (e.g., BRAF, EGFR)"),
+    ],
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit",
+            help="Maximum number of evidence items to show",
+            min=1,
+            max=200,
+        ),
+    ] = 20,
+    offset: Annotated[
+        int,
+        typer.Option(
+            "--offset",
+            help="Number of evidence items to skip",
+            min=0,
+        ),
+    ] = 0,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    List CIViC clinical evidence for a gene's variants, strongest first.
+
+    Each row gives the evidence item's molecular profile, disease,
+    therapies, evidence level (A-E), type, direction and significance.
+
+    Examples:
+        czechmedmcp gene civic BRAF
+        czechmedmcp gene civic EGFR --limit 10 --offset 10
+        czechmedmcp gene civic KRAS --json
+    """
+    gene = gene_symbol.strip().upper()
+    records, unread, error = asyncio.run(fetch_gene_civic_evidence(gene))
+    if error or records is None:
+        typer.echo(f"Error: {error}", err=True)
+        raise typer.Exit(1)
+    typer.echo(
+        render_gene_civic(gene, records, unread, limit, offset, output_json)
+    )
@@ -10,7 +10,6 @@ from ssl import TLSVersion
 from typing import Any
 
 import typer
-from rich.console import Console
 from rich.panel import Panel
 from rich.table import Table
 
@@ -20,6 +19,7 @@ from ..constants import (
     MYVARIANT_BASE_URL,
     PUBTATOR3_BASE_URL,
 )
+from ..terminal import console
 
 # Try to import psutil, but handle case where it's not installed
 try:
@@ -30,7 +30,6 @@ except ImportError:
     PSUTIL_AVAILABLE = False
 
 health_app = typer.Typer(help="Health check operations")
-console = Console()
 
 
 async def check_api_endpoint(
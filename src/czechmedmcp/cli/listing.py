@@ -0,0 +1,80 @@
+"""CLI command for listing the available commands and their examples."""
+
+from typing import Annotated
+
+import typer
+
+from ..examples import (
+    PROGRAM,
+    collect_commands,
+    collect_examples,
+    format_example_list,
+    format_example_script,
+)
+
+
+def list_command(
+    entity: Annotated[
+        str | None,
+        typer.Argument(
+            help="Only list this entity's commands (e.g. gene, trial)",
+        ),
+    ] = None,
+    examples: Annotated[
+        bool,
+        typer.Option(
+            "--examples",
+            help="Print every example command from the help text, one "
+            "per line, ready to copy and paste",
+        ),
+    ] = False,
+    script: Annotated[
+        bool,
+        typer.Option(
+            "--script",
+            help="With --examples, print a shell script with a comment "
+            "per command and example",
+        ),
+    ] = False,
+) -> None:
+    """
+    List the CLI commands, or the examples from their help text.
+
+    Examples:
+        czechmedmcp list
+        czechmedmcp list --examples
+        czechmedmcp list gene --examples
+        czechmedmcp list --examples --script > examples.sh
+    """
+    # Import here to avoid circular imports
+    from .main import app
+
+    if script and not examples:
+        typer.echo("Error: --script requires --examples", err=True)
+        raise typer.Exit(1)
+
+    commands = collect_commands(app)
+    entities = list(dict.fromkeys(command.entity for command in commands))
+    if entity is not None and entity not in entities:
+        typer.echo(
+            f"Error: Unknown entity '{entity}'. "
+            f"Choose from: {', '.join(entities)}",
+            err=True,
+        )
+        raise typer.Exit(1)
+
+    if not examples:
+        for command in commands:
+            if entity is None or command.entity == entity:
+                summary = command.doc.split("\n", 1)[0]
+                typer.echo(f"{PROGRAM} {command.path:<28} {summary}".rstrip())
+        return
+
+    found = collect_examples(commands, entity)
+    if not found:
+        typer.echo(f"No examples for {entity or 'any command'}.", err=True)
+        raise typer.Exit(1)
+    if script:
+        typer.echo(format_example_script(found))
+    else:
+        typer.echo(format_example_list(found))
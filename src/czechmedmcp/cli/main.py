@@ -3,18 +3,30 @@ import logging
 from typing import Annotated
 
 import typer
+from typer.core import TyperGroup
 
+from ..concurrency import set_concurrency
+from ..dry_run import DryRunRequest, set_dry_run
+from ..terminal import ColorMode, configure_terminal
 from .articles import article_app
+from .batch import batch_command
 from .biomarkers import biomarker_app
+from .cache import cache_app
 from .czech import czech_app
 from .diseases import disease_app
 from .drugs import drug_app
 from .genes import gene_app
 from .health import health_app
 from .interventions import intervention_app
+from .listing import list_command
 from .openfda import openfda_app
 from .organizations import organization_app
+from .pathways import pathway_app
+from .pgx import pgx_app
+from .pipe import pipe_command
+from .proteins import protein_app
 from .server import run_server
+from .stats import stats_app
 from .trials import trial_app
 from .variants import variant_app
 
@@ -32,8 +44,27 @@ def version_callback(value: bool):
         raise typer.Exit()
 
 
+# Flags that switch a command to JSON output
+JSON_FLAGS = ("--json", "-j")
+
+
+class DryRunGroup(TyperGroup):
+    """Top-level group that reports the request stopped by --dry-run."""
+
+    def invoke(self, ctx: typer.Context):
+        # Click clears these while dispatching, so capture them first
+        args = [*ctx.protected_args, *ctx.args]
+        try:
+            return super().invoke(ctx)
+        except DryRunRequest as request:
+            output_json = any(arg in JSON_FLAGS for arg in args)
+            typer.echo(request.render(output_json=output_json))
+            raise typer.Exit(0) from None
+
+
 # --- Main Typer App ---
 app = typer.Typer(
+    cls=DryRunGroup,
     help="CzechMedMCP: Biomedical MCP with Czech Healthcare Extensions",
     no_args_is_help=True,
     # Add a callback to handle top-level options like --version
@@ -113,6 +144,36 @@ app.add_typer(
     no_args_is_help=True,
 )
 
+app.add_typer(
+    pathway_app,
+    name="pathway",
+    no_args_is_help=True,
+)
+
+app.add_typer(
+    pgx_app,
+    name="pgx",
+    no_args_is_help=True,
+)
+
+app.add_typer(
+    protein_app,
+    name="protein",
+    no_args_is_help=True,
+)
+
+app.add_typer(
+    cache_app,
+    name="cache",
+    no_args_is_help=True,
+)
+
+app.add_typer(
+    stats_app,
+    name="stats",
+    no_args_is_help=True,
+)
+
 
 # --- Add --version Option using Annotation ---
 # We add this directly to the app's callback invocation signature via annotation
@@ -138,9 +199,42 @@ def main_callback(
             is_eager=True,
         ),
     ] = False,
+    concurrency: Annotated[
+        int | None,
+        typer.Option(
+            "--concurrency",
+            help=(
+                "Max parallel upstream requests for fan-out operations "
+                "(default: auto, each operation's own limit; per-API rate "
+                "limits still apply). Also settable via BIOMCP_CONCURRENCY."
+            ),
+            min=1,
+        ),
+    ] = None,
+    dry_run: Annotated[
+        bool,
+        typer.Option(
+            "--dry-run",
+            help=(
+                "Validate arguments and print the resolved API request "
+                "instead of sending it. Also settable via BIOMCP_DRY_RUN."
+            ),
+        ),
+    ] = False,
+    color: Annotated[
+        ColorMode,
+        typer.Option(
+            "--color",
+            help=(
+                "When to use ANSI colors: auto (only on a terminal, unless "
+                "NO_COLOR is set), always or never."
+            ),
+            case_sensitive=False,
+        ),
+    ] = ColorMode.AUTO,
 ):
     """
-    CzechMedMCP main application callback. Handles global options like --version and --verbose.
+    CzechMedMCP main application callback. Handles global options like --version, --verbose, --concurrency, --dry-run and --color.
     """
     # Configure logging based on verbose flag
     # Set level for all czechmedmcp loggers
@@ -154,6 +248,11 @@ def main_callback(
         app_logger.setLevel(logging.WARNING)
         logging.getLogger().setLevel(logging.WARNING)
 
+    set_concurrency(concurrency)
+    # Only force dry-run on; leave BIOMCP_DRY_RUN in charge otherwise
+    set_dry_run(True if dry_run else None)
+    configure_terminal(color)
+
 
 # --- Add Explicit 'version' Command ---
 @app.command()
@@ -167,6 +266,12 @@ def version():
 # Directly expose run_server as the 'run' command with all its options
 app.command("run")(run_server)
 
+app.command("batch")(batch_command)
+
+app.command("pipe")(pipe_command)
+
+app.command("list")(list_command)
+
 
 if __name__ == "__main__":
     app()
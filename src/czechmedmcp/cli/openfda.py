@@ -6,7 +6,6 @@ import asyncio
 from typing import Annotated
 
 import typer
-from rich.console import Console
 
 from ..constants import compute_skip
 from ..openfda import (
@@ -23,8 +22,13 @@ from ..openfda import (
     search_drug_recalls,
     search_drug_shortages,
 )
-
-console = Console()
+from ..openfda.adverse_events import (
+    ADVERSE_EVENT_COUNT_FIELDS,
+    PatientAgeGroup,
+    parse_route,
+)
+from ..openfda.input_validation import validate_country_code
+from ..terminal import console
 
 # Create separate Typer apps for each subdomain
 adverse_app = typer.Typer(
@@ -75,6 +79,48 @@ def search_adverse_events_cli(
         bool | None,
         typer.Option("--serious/--all", help="Filter for serious events only"),
     ] = None,
+    country: Annotated[
+        str | None,
+        typer.Option(
+            "--country",
+            help="Reporter country, ISO 3166-1 alpha-2 code (e.g., US, DE)",
+        ),
+    ] = None,
+    indication: Annotated[
+        str | None,
+        typer.Option(
+            "--indication",
+            "--drug-indication",
+            help="Only reports where the drug was given for this "
+            "indication (e.g., 'hypertension')",
+        ),
+    ] = None,
+    age_group: Annotated[
+        PatientAgeGroup | None,
+        typer.Option(
+            "--age-group",
+            help="Only reports for patients in this age bucket (reported "
+            "age group, or onset age in years: infant 0-1, child 2-11, "
+            "adolescent 12-17, adult 18-64, elderly 65+)",
+            case_sensitive=False,
+        ),
+    ] = None,
+    route: Annotated[
+        str | None,
+        typer.Option(
+            "--route",
+            help="Only reports with a drug given by this administration "
+            "route (e.g., oral, iv, sc, im, topical, inhalation)",
+        ),
+    ] = None,
+    count_by: Annotated[
+        str | None,
+        typer.Option(
+            "--count",
+            help="Count matching reports by field instead of listing them. "
+            f"Options: {', '.join(ADVERSE_EVENT_COUNT_FIELDS)}",
+        ),
+    ] = None,
     limit: Annotated[
         int, typer.Option("--limit", "-l", help="Maximum number of results")
     ] = 25,
@@ -90,6 +136,19 @@ def search_adverse_events_cli(
     ] = None,
 ):
     """Search FDA adverse event reports for drugs."""
+    try:
+        country = validate_country_code(country)
+        administration_route = parse_route(route) if route else None
+    except ValueError as e:
+        console.print(f"[red]Error: {e}[/red]")
+        raise typer.Exit(1) from e
+    if count_by is not None and count_by not in ADVERSE_EVENT_COUNT_FIELDS:
+        console.print(
+            f"[red]Error: invalid --count field '{count_by}'. Options: "
+            f"{', '.join(ADVERSE_EVENT_COUNT_FIELDS)}[/red]"
+        )
+        raise typer.Exit(1)
+
     skip = compute_skip(page, limit)
 
     try:
@@ -101,9 +160,14 @@ def search_adverse_events_cli(
                 limit=limit,
                 skip=skip,
                 api_key=api_key,
+                country=country,
+                count_by=count_by,
+                indication=indication,
+                age_group=age_group,
+                route=administration_route,
             )
         )
-        console.print(results)
+        typer.echo(results)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -112,6 +176,25 @@ def search_adverse_events_cli(
 @adverse_app.command("get")
 def get_adverse_event_cli(
     report_id: Annotated[str, typer.Argument(help="Safety report ID")],
+    narrative: Annotated[
+        bool,
+        typer.Option(
+            "--narrative",
+            help="Only show the free-text MedWatch narrative",
+        ),
+    ] = False,
+    timeline: Annotated[
+        bool,
+        typer.Option(
+            "--timeline",
+            help="Add drug start, event onset and report dates with the "
+            "time to onset",
+        ),
+    ] = False,
+    output_json: Annotated[
+        bool,
+        typer.Option("--json", "-j", help="Output in JSON format"),
+    ] = False,
     api_key: Annotated[
         str | None,
         typer.Option(
@@ -121,9 +204,22 @@ def get_adverse_event_cli(
     ] = None,
 ):
     """Get detailed information for a specific adverse event report."""
+    if narrative and timeline:
+        console.print(
+            "[red]Error: --narrative cannot be combined with --timeline[/red]"
+        )
+        raise typer.Exit(1)
     try:
-        result = asyncio.run(get_adverse_event(report_id, api_key=api_key))
-        console.print(result)
+        result = asyncio.run(
+            get_adverse_event(
+                report_id,
+                api_key=api_key,
+                narrative_only=narrative,
+                output_json=output_json,
+                timeline=timeline,
+            )
+        )
+        typer.echo(result)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -185,7 +281,7 @@ def search_drug_labels_cli(
                 api_key=api_key,
             )
         )
-        console.print(results)
+        typer.echo(results)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -217,7 +313,7 @@ def get_drug_label_cli(
         result = asyncio.run(
             get_drug_label(set_id, section_list, api_key=api_key)
         )
-        console.print(result)
+        typer.echo(result)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -278,7 +374,7 @@ def search_device_events_cli(
                 api_key=api_key,
             )
         )
-        console.print(results)
+        typer.echo(results)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -298,7 +394,7 @@ def get_device_event_cli(
     """Get detailed information for a specific device event report."""
     try:
         result = asyncio.run(get_device_event(mdr_report_key, api_key=api_key))
-        console.print(result)
+        typer.echo(result)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -349,7 +445,7 @@ def search_drug_approvals_cli(
                 api_key=api_key,
             )
         )
-        console.print(results)
+        typer.echo(results)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -371,7 +467,7 @@ def get_drug_approval_cli(
     """Get detailed drug approval information."""
     try:
         result = asyncio.run(get_drug_approval(application, api_key=api_key))
-        console.print(result)
+        typer.echo(result)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -434,7 +530,7 @@ def search_drug_recalls_cli(
                 api_key=api_key,
             )
         )
-        console.print(results)
+        typer.echo(results)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -454,7 +550,7 @@ def get_drug_recall_cli(
     """Get detailed drug recall information."""
     try:
         result = asyncio.run(get_drug_recall(recall_number, api_key=api_key))
-        console.print(result)
+        typer.echo(result)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -505,7 +601,7 @@ def search_drug_shortages_cli(
                 api_key=api_key,
             )
         )
-        console.print(results)
+        typer.echo(results)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -525,7 +621,7 @@ def get_drug_shortage_cli(
     """Get detailed drug shortage information."""
     try:
         result = asyncio.run(get_drug_shortage(drug, api_key=api_key))
-        console.print(result)
+        typer.echo(result)
     except Exception as e:
         console.print(f"[red]Error: {e}[/red]")
         raise typer.Exit(1) from e
@@ -8,7 +8,10 @@ import typer
 from ..integrations.cts_api import CTSAPIError, get_api_key_instructions
 from ..organizations import get_organization, search_organizations
 from ..organizations.getter import format_organization_details
-from ..organizations.search import format_organization_results
+from ..organizations.search import (
+    format_organization_results,
+    organization_search_query_summary,
+)
 
 organization_app = typer.Typer(
     no_args_is_help=True,
@@ -45,6 +48,14 @@ def search_organizations_cli(
             help="State location (2-letter code)",
         ),
     ] = None,
+    has_trials: Annotated[
+        bool,
+        typer.Option(
+            "--has-trials",
+            help="Only organizations that are a site of at least one NCI "
+            "trial (one extra request per organization on the page)",
+        ),
+    ] = False,
     page_size: Annotated[
         int,
         typer.Option(
@@ -86,6 +97,9 @@ def search_organizations_cli(
 
         # Combine filters
         czechmedmcp organization search Cancer --type industry --state CA
+
+        # Only research-active sites
+        czechmedmcp organization search --city Boston --state MA --has-trials
     """
     try:
         results = asyncio.run(
@@ -97,9 +111,14 @@ def search_organizations_cli(
                 page_size=page_size,
                 page=page,
                 api_key=api_key,
+                has_trials=has_trials,
             )
         )
 
+        summary = organization_search_query_summary(
+            name, org_type, city, state, has_trials
+        )
+        typer.echo(f"_{summary}_\n")
         output = format_organization_results(results)
         typer.echo(output)
 
@@ -0,0 +1,140 @@
+"""CLI commands for pathway information retrieval."""
+
+import asyncio
+from typing import Annotated
+
+import typer
+
+from ..pathways import (
+    PathwaySearchFilters,
+    get_pathway,
+    is_reactome_id,
+    pathway_search,
+)
+
+pathway_app = typer.Typer(
+    no_args_is_help=True,
+    help="Retrieve pathway information from Reactome",
+)
+
+
+@pathway_app.command("get")
+def get_pathway_cli(
+    pathway_id: Annotated[
+        str,
+        typer.Argument(help="Reactome stable ID (e.g., R-HSA-5673001)"),
+    ],
+    diagram: Annotated[
+        bool,
+        typer.Option(
+            "--diagram",
+            "--diagram-url",
+            help="Add the diagram link and input/output/catalyst details "
+            "for top-level reactions",
+        ),
+    ] = False,
+    parents: Annotated[
+        bool,
+        typer.Option(
+            "--parents",
+            help="Add the super-pathways that directly contain this "
+            "pathway, with their path from the top-level pathway",
+        ),
+    ] = False,
+    children: Annotated[
+        bool,
+        typer.Option(
+            "--children",
+            help="Add the sub-pathways contained in this pathway",
+        ),
+    ] = False,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    Get a Reactome pathway with its contained events.
+
+    Examples:
+        czechmedmcp pathway get R-HSA-5673001
+        czechmedmcp pathway get R-HSA-5673001 --diagram
+        czechmedmcp pathway get R-HSA-5673001 --diagram --json
+        czechmedmcp pathway get R-HSA-5673001 --parents --children
+    """
+    if not is_reactome_id(pathway_id):
+        typer.echo(
+            f"Error: '{pathway_id}' is not a Reactome stable ID "
+            "(e.g., R-HSA-5673001)",
+            err=True,
+        )
+        raise typer.Exit(1)
+
+    result = asyncio.run(
+        get_pathway(
+            pathway_id,
+            include_diagram=diagram,
+            output_json=output_json,
+            parents=parents,
+            children=children,
+        )
+    )
+    typer.echo(result)
+
+
+@pathway_app.command("search")
+def search_pathway_cli(
+    query: Annotated[
+        str,
+        typer.Argument(help="Free-text search (e.g., MAPK signaling)"),
+    ],
+    species: Annotated[
+        str,
+        typer.Option(
+            "--species",
+            help="Species as a scientific name, common name or NCBI "
+            "taxon ID (e.g., mouse, 10090)",
+        ),
+    ] = "human",
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit",
+            help="Maximum number of pathways",
+            min=1,
+            max=100,
+        ),
+    ] = 10,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    Search Reactome pathways, constrained to one species (default human).
+
+    Examples:
+        czechmedmcp pathway search "MAPK signaling"
+        czechmedmcp pathway search apoptosis --species mouse
+        czechmedmcp pathway search "Cell Cycle" --species 7955 --json
+    """
+    try:
+        filters = PathwaySearchFilters(
+            query=query, species=species, limit=limit
+        )
+    except ValueError as e:
+        typer.echo(f"Error: {e}", err=True)
+        raise typer.Exit(1) from None
+
+    result = asyncio.run(pathway_search(filters, output_json=output_json))
+    typer.echo(result)
+    if result.startswith("Error:"):
+        raise typer.Exit(1)
@@ -0,0 +1,69 @@
+"""CLI commands for pharmacogenomic (CPIC) recommendations."""
+
+import asyncio
+from typing import Annotated
+
+import typer
+
+from ..pgx import MetabolizerPhenotype, PgxSearchFilters, pgx_search
+
+pgx_app = typer.Typer(
+    no_args_is_help=True,
+    help="Search pharmacogenomic dosing recommendations from CPIC",
+)
+
+
+@pgx_app.command("search")
+def search_pgx_cli(
+    gene: Annotated[
+        str | None,
+        typer.Option("--gene", "-g", help="Pharmacogene (e.g., CYP2D6)"),
+    ] = None,
+    drug: Annotated[
+        str | None,
+        typer.Option("--drug", "-d", help="Drug name (e.g., codeine)"),
+    ] = None,
+    phenotype: Annotated[
+        MetabolizerPhenotype | None,
+        typer.Option(
+            "--phenotype",
+            help="Only recommendations for this metabolizer status",
+            case_sensitive=False,
+        ),
+    ] = None,
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit",
+            "-l",
+            help="Maximum number of recommendations",
+            min=1,
+            max=100,
+        ),
+    ] = 25,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    Search CPIC guideline recommendations by gene, drug and phenotype.
+
+    Examples:
+        czechmedmcp pgx search --gene CYP2D6
+        czechmedmcp pgx search --drug codeine --phenotype ultrarapid
+        czechmedmcp pgx search --gene CYP2C19 --phenotype poor --json
+    """
+    if not gene and not drug:
+        typer.echo("Error: specify --gene or --drug", err=True)
+        raise typer.Exit(1)
+
+    filters = PgxSearchFilters(
+        gene=gene, drug=drug, phenotype=phenotype, limit=limit
+    )
+    result = asyncio.run(pgx_search(filters, output_json=output_json))
+    typer.echo(result)
@@ -0,0 +1,60 @@
+"""CLI command for running one command per identifier on stdin."""
+
+import asyncio
+import shlex
+import sys
+from typing import Annotated
+
+import typer
+
+from ..pipe import format_pipe_output, read_values, run_pipe
+
+# Flags that switch a command to JSON output
+JSON_FLAGS = ("--json", "-j")
+
+
+def pipe_command(
+    template: Annotated[
+        str,
+        typer.Argument(
+            help="Command to run for each input line, with {} where the "
+            "value goes (e.g. 'variant get {}')",
+        ),
+    ],
+) -> None:
+    """
+    Run a command once per identifier read from stdin.
+
+    Commands run concurrently (see --concurrency) and their output is
+    printed in input order. A failing value is reported on stderr and the
+    remaining values still run; the exit status is 1 if any failed.
+
+    Examples:
+        printf 'TP53\\nBRAF\\n' | czechmedmcp pipe 'gene get {}'
+        cat ncts.txt | czechmedmcp pipe 'trial get {} --json'
+    """
+    if sys.stdin.isatty():
+        typer.echo(
+            "Error: pipe reads identifiers from stdin, one per line",
+            err=True,
+        )
+        raise typer.Exit(1)
+
+    values = read_values(sys.stdin)
+    try:
+        results = asyncio.run(run_pipe(template, values))
+    except ValueError as e:
+        typer.echo(f"Error: {e}", err=True)
+        raise typer.Exit(1) from None
+
+    output_json = any(arg in JSON_FLAGS for arg in shlex.split(template))
+    separator = "\n" if output_json else "\n\n---\n\n"
+    output = format_pipe_output(results, separator)
+    if output:
+        typer.echo(output)
+
+    failed = [result for result in results if not result.ok]
+    for result in failed:
+        typer.echo(f"Error: {result.value}: {result.error}", err=True)
+    if failed:
+        raise typer.Exit(1)
@@ -0,0 +1,240 @@
+"""CLI commands for protein information retrieval."""
+
+import asyncio
+from typing import Annotated
+
+import typer
+
+from ..proteins import (
+    get_protein,
+    get_protein_domains,
+    get_protein_fasta,
+    is_uniprot_accession,
+    search_proteins,
+)
+from ..proteins.search import resolve_organism
+
+protein_app = typer.Typer(
+    no_args_is_help=True,
+    help="Search proteins and retrieve annotations from UniProtKB",
+)
+
+
+def _validate_accession(accession: str) -> None:
+    """Exit with an error when the accession is not UniProtKB-shaped."""
+    if not is_uniprot_accession(accession):
+        typer.echo(
+            f"Error: '{accession}' is not a valid UniProtKB accession",
+            err=True,
+        )
+        raise typer.Exit(1)
+
+
+@protein_app.command("get")
+def get_protein_cli(
+    accession: Annotated[
+        str,
+        typer.Argument(help="UniProtKB accession (e.g., P15056)"),
+    ],
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+    output_format: Annotated[
+        str | None,
+        typer.Option(
+            "--format",
+            help="Alternative output format. Options: fasta",
+        ),
+    ] = None,
+    variants: Annotated[
+        bool,
+        typer.Option(
+            "--variants",
+            help="Add disease-associated variants from the UniProt "
+            "feature table (position, change, disease, dbSNP)",
+        ),
+    ] = False,
+    ptm: Annotated[
+        bool,
+        typer.Option(
+            "--ptm",
+            help="Add post-translational modification sites "
+            "(phosphorylation, glycosylation, ubiquitination, lipidation) "
+            "with the modifying enzyme where annotated",
+        ),
+    ] = False,
+) -> None:
+    """
+    Get a protein record from UniProtKB.
+
+    Examples:
+        czechmedmcp protein get P15056
+        czechmedmcp protein get P15056 --json
+        czechmedmcp protein get P15056 --format fasta
+        czechmedmcp protein get P04637 --variants
+        czechmedmcp protein get P04637 --ptm
+    """
+    _validate_accession(accession)
+
+    if output_format is not None:
+        if variants or ptm:
+            typer.echo(
+                "Error: --variants and --ptm cannot be combined with --format",
+                err=True,
+            )
+            raise typer.Exit(1)
+        if output_format.lower() != "fasta":
+            typer.echo(
+                f"Invalid format: '{output_format}'. Available options: fasta",
+                err=True,
+            )
+            raise typer.Exit(1)
+        fasta, error = asyncio.run(get_protein_fasta(accession))
+        if error or fasta is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(fasta, nl=False)
+        return
+
+    result = asyncio.run(
+        get_protein(
+            accession, output_json=output_json, variants=variants, ptm=ptm
+        )
+    )
+    typer.echo(result)
+
+
+@protein_app.command("search")
+def search_protein_cli(
+    query: Annotated[
+        str | None,
+        typer.Argument(help="Free-text UniProt query (e.g., kinase)"),
+    ] = None,
+    gene: Annotated[
+        str | None,
+        typer.Option("--gene", "-g", help="Exact gene symbol"),
+    ] = None,
+    organism: Annotated[
+        str | None,
+        typer.Option(
+            "--organism",
+            help="NCBI taxonomy ID or common name (human, mouse, rat, "
+            "yeast) to search instead of human",
+        ),
+    ] = None,
+    all_species: Annotated[
+        bool,
+        typer.Option(
+            "--all-species",
+            help="Search every organism instead of only human",
+        ),
+    ] = False,
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit",
+            help="Maximum number of results",
+            min=1,
+            max=500,
+        ),
+    ] = 10,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    Search reviewed (Swiss-Prot) UniProtKB entries, human by default.
+
+    Examples:
+        czechmedmcp protein search --gene BRAF
+        czechmedmcp protein search --gene Trp53 --organism mouse
+        czechmedmcp protein search kinase --organism 10116 --limit 5
+        czechmedmcp protein search --gene CDC28 --all-species
+    """
+    if organism is not None and all_species:
+        typer.echo(
+            "Error: --organism cannot be combined with --all-species",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if organism is not None:
+        try:
+            resolve_organism(organism)
+        except ValueError as e:
+            typer.echo(f"Error: {e}", err=True)
+            raise typer.Exit(1) from e
+    if not query and not gene:
+        typer.echo("Error: provide a search query or --gene", err=True)
+        raise typer.Exit(1)
+
+    result = asyncio.run(
+        search_proteins(
+            query=query,
+            gene=gene,
+            organism=organism,
+            all_species=all_species,
+            limit=limit,
+            output_json=output_json,
+        )
+    )
+    typer.echo(result)
+
+
+@protein_app.command("domains")
+def protein_domains_cli(
+    accession: Annotated[
+        str,
+        typer.Argument(help="UniProtKB accession (e.g., P15056)"),
+    ],
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit",
+            help="Maximum number of domains to show",
+            min=1,
+            max=200,
+        ),
+    ] = 20,
+    offset: Annotated[
+        int,
+        typer.Option(
+            "--offset",
+            help="Number of domains to skip",
+            min=0,
+        ),
+    ] = 0,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Output in JSON format",
+        ),
+    ] = False,
+) -> None:
+    """
+    List a protein's Pfam and InterPro domains with position ranges.
+
+    Examples:
+        czechmedmcp protein domains P15056
+        czechmedmcp protein domains P04637 --limit 5 --offset 5
+        czechmedmcp protein domains P00533 --json
+    """
+    _validate_accession(accession)
+
+    result = asyncio.run(
+        get_protein_domains(
+            accession, limit=limit, offset=offset, output_json=output_json
+        )
+    )
+    typer.echo(result)
@@ -1,3 +1,4 @@
+import re
 from enum import Enum
 from typing import Annotated
 
@@ -18,13 +19,51 @@ class ServerMode(str, Enum):
     STREAMABLE_HTTP = "streamable_http"
 
 
+# Origins allowed when --cors-origin is not given (any origin)
+DEFAULT_CORS_ORIGINS = ["*"]
+
+# Response headers browser clients need to read (session handshake)
+CORS_EXPOSE_HEADERS = ["Mcp-Session-Id"]
+
+_ORIGIN_PATTERN = re.compile(r"^https?://[^/\s]+$", re.IGNORECASE)
+
+
+def normalize_cors_origins(origins: list[str] | None) -> list[str]:
+    """Validate --cors-origin values and return the allowed origins.
+
+    Each value is ``*`` or a bare origin such as ``http://localhost:3000``.
+    Trailing slashes are dropped since browsers send origins without one.
+    """
+    if not origins:
+        return list(DEFAULT_CORS_ORIGINS)
+
+    normalized: list[str] = []
+    for origin in origins:
+        value = origin.strip().rstrip("/")
+        if value != "*" and not _ORIGIN_PATTERN.match(value):
+            raise typer.BadParameter(
+                f"Invalid CORS origin {origin!r}. Use '*' or "
+                "scheme://host[:port], e.g. http://localhost:3000",
+                param_hint="--cors-origin",
+            )
+        if value not in normalized:
+            normalized.append(value)
+    return ["*"] if "*" in normalized else normalized
+
+
 def run_stdio_server():
     """Run server in STDIO mode."""
     logger.info("Starting MCP server with STDIO transport:")
     mcp_app.run(transport="stdio")
 
 
-def run_http_server(host: str, port: int, mode: ServerMode):
+def run_http_server(
+    host: str,
+    port: int,
+    mode: ServerMode,
+    auth_token: str | None = None,
+    cors_origins: list[str] | None = None,
+):
     """Run server in HTTP-based mode (worker or streamable_http)."""
     try:
         from typing import Any
@@ -36,14 +75,22 @@ def run_http_server(host: str, port: int, mode: ServerMode):
         if mode == ServerMode.WORKER:
             import os
 
+            from ..auth import AUTH_TOKEN_ENV_VARS
+
             # Fail fast if auth token is set - worker mode doesn't support auth
-            if os.getenv("MCP_AUTH_TOKEN"):
+            if auth_token or any(os.getenv(v) for v in AUTH_TOKEN_ENV_VARS):
                 logger.error(
-                    "MCP_AUTH_TOKEN is set but worker mode does not support "
+                    "An auth token is set but worker mode does not support "
                     "authentication. Use --mode streamable_http instead."
                 )
                 raise typer.Exit(1)
 
+            if cors_origins and cors_origins != DEFAULT_CORS_ORIGINS:
+                logger.warning(
+                    "--cors-origin is ignored in worker mode, which always "
+                    "allows any origin. Use --mode streamable_http instead."
+                )
+
             logger.info("Starting MCP server with Worker/SSE transport")
             try:
                 from ..workers.worker import app
@@ -72,15 +119,23 @@ def run_http_server(host: str, port: int, mode: ServerMode):
                 raise typer.Exit(1) from e
 
             from .. import mcp_app
-            from ..auth import BearerTokenMiddleware, validate_auth_token
+            from ..auth import (
+                BearerTokenMiddleware,
+                is_loopback_host,
+                validate_auth_token,
+            )
 
             # Validate auth token at startup (fail fast)
-            auth_token = validate_auth_token()
+            auth_token = validate_auth_token(auth_token)
             if auth_token:
                 logger.info("Bearer token authentication enabled")
+            elif is_loopback_host(host):
+                logger.info("No auth token set - server running without authentication")
             else:
                 logger.warning(
-                    "No MCP_AUTH_TOKEN set - server running without authentication"
+                    f"No auth token set and binding to non-loopback host {host} - "
+                    "anyone who can reach this port can use the server. "
+                    "Set --auth-token or BIOMCP_HTTP_TOKEN to require a bearer token."
                 )
 
             # Get FastMCP's streamable_http_app (Starlette app)
@@ -90,13 +145,17 @@ def run_http_server(host: str, port: int, mode: ServerMode):
             # Add Bearer token auth middleware
             app.add_middleware(BearerTokenMiddleware, auth_token=auth_token)
 
-            # Add CORS middleware
+            # Add CORS middleware (outermost, so 401s and preflights get
+            # the configured headers too)
+            allowed_origins = cors_origins or DEFAULT_CORS_ORIGINS
+            logger.info(f"CORS allowed origins: {', '.join(allowed_origins)}")
             app.add_middleware(
                 CORSMiddleware,
-                allow_origins=["*"],
+                allow_origins=allowed_origins,
                 allow_credentials=True,
                 allow_methods=["*"],
                 allow_headers=["*"],
+                expose_headers=CORS_EXPOSE_HEADERS,
             )
 
             # Add health endpoint
@@ -164,9 +223,33 @@ def run_server(
             help="Port to bind to (for HTTP modes)",
         ),
     ] = 8000,
+    auth_token: Annotated[
+        str | None,
+        typer.Option(
+            "--auth-token",
+            help="Require 'Authorization: Bearer <TOKEN>' on HTTP requests "
+            "(min. 32 characters; defaults to BIOMCP_HTTP_TOKEN or MCP_AUTH_TOKEN)",
+            show_default=False,
+        ),
+    ] = None,
+    cors_origin: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--cors-origin",
+            help="Origin allowed to call the HTTP server from a browser "
+            "(repeatable, or '*'; default: any origin)",
+            show_default=False,
+        ),
+    ] = None,
 ):
     """Run the CzechMedMCP server with selected transport mode."""
     if mode == ServerMode.STDIO:
         run_stdio_server()
     else:
-        run_http_server(host, port, mode)
+        run_http_server(
+            host,
+            port,
+            mode,
+            auth_token=auth_token,
+            cors_origins=normalize_cors_origins(cors_origin),
+        )
@@ -0,0 +1,219 @@
+"""CLI commands for facet count distributions."""
+
+import asyncio
+from typing import Annotated, Any
+
+import typer
+
+from ..stats import (
+    TRIAL_STATS_MAX_SCANNED,
+    AdverseEventFacet,
+    TrialFacet,
+    adverse_event_facet_counts,
+    format_distribution,
+    trial_facet_counts,
+)
+from ..trials.search import (
+    RecruitingStatus,
+    TrialPhase,
+    TrialQuery,
+    trial_search_query_summary,
+)
+
+stats_app = typer.Typer(
+    help="Count-only distributions of records by a facet",
+    no_args_is_help=True,
+)
+
+
+def _echo_distribution(
+    result: tuple[dict[str, Any] | None, str | None],
+    output_json: bool,
+    summary: str | None,
+) -> None:
+    distribution, error = result
+    if error or distribution is None:
+        typer.echo(f"Error: {error}", err=True)
+        raise typer.Exit(1)
+    typer.echo(format_distribution(distribution, output_json, summary))
+
+
+@stats_app.command("trial")
+def trial_stats(
+    by: Annotated[
+        TrialFacet,
+        typer.Option(
+            "--by",
+            help="Facet to count trials by",
+            case_sensitive=False,
+        ),
+    ],
+    condition: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--condition",
+            "-c",
+            help="Condition to search for (can specify multiple)",
+        ),
+    ] = None,
+    intervention: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--intervention",
+            "-i",
+            help="Intervention to search for (can specify multiple)",
+        ),
+    ] = None,
+    term: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--term",
+            "-t",
+            help="Search term (can specify multiple)",
+        ),
+    ] = None,
+    recruiting_status: Annotated[
+        RecruitingStatus,
+        typer.Option(
+            "--status",
+            "-s",
+            help="Recruiting status (default: all trials)",
+            case_sensitive=False,
+        ),
+    ] = RecruitingStatus.ANY,
+    phase: Annotated[
+        TrialPhase | None,
+        typer.Option(
+            "--phase",
+            "-p",
+            help="Trial phase",
+            case_sensitive=False,
+        ),
+    ] = None,
+    max_scanned: Annotated[
+        int,
+        typer.Option(
+            "--max-scanned",
+            min=1,
+            help="Maximum number of matching trials to aggregate",
+        ),
+    ] = TRIAL_STATS_MAX_SCANNED,
+    output_json: Annotated[
+        bool,
+        typer.Option("--json", "-j", help="Render in JSON format"),
+    ] = False,
+):
+    """
+    Count ClinicalTrials.gov trials by status, phase, sponsor type or
+    country.
+
+    Example:
+        biomcp stats trial -c melanoma --by status
+    """
+    query = TrialQuery(
+        conditions=condition,
+        interventions=intervention,
+        terms=term,
+        recruiting_status=recruiting_status,
+        phase=phase,
+    )
+    result = asyncio.run(trial_facet_counts(query, by, max_scanned))
+    _echo_distribution(
+        result, output_json, trial_search_query_summary(query)
+    )
+
+
+@stats_app.command("adverse-event")
+def adverse_event_stats(
+    by: Annotated[
+        AdverseEventFacet,
+        typer.Option(
+            "--by",
+            help="Facet to count reports by",
+            case_sensitive=False,
+        ),
+    ],
+    drug: Annotated[
+        str | None,
+        typer.Option("--drug", "-d", help="Drug name to search for"),
+    ] = None,
+    reaction: Annotated[
+        str | None,
+        typer.Option(
+            "--reaction", "-r", help="Adverse reaction to search for"
+        ),
+    ] = None,
+    serious: Annotated[
+        bool | None,
+        typer.Option("--serious/--all", help="Filter for serious events only"),
+    ] = None,
+    country: Annotated[
+        str | None,
+        typer.Option(
+            "--country",
+            help="Reporter country, ISO 3166-1 alpha-2 code (e.g., US, DE)",
+        ),
+    ] = None,
+    indication: Annotated[
+        str | None,
+        typer.Option(
+            "--indication",
+            help="Only reports where the drug was given for this indication",
+        ),
+    ] = None,
+    limit: Annotated[
+        int,
+        typer.Option(
+            "--limit", "-l", min=1, help="Maximum number of buckets"
+        ),
+    ] = 25,
+    api_key: Annotated[
+        str | None,
+        typer.Option(
+            "--api-key",
+            help="OpenFDA API key (overrides OPENFDA_API_KEY env var)",
+        ),
+    ] = None,
+    output_json: Annotated[
+        bool,
+        typer.Option("--json", "-j", help="Render in JSON format"),
+    ] = False,
+):
+    """
+    Count FDA adverse event reports by reaction, reporter country or
+    indication.
+
+    Example:
+        biomcp stats adverse-event --drug imatinib --by reaction
+        biomcp stats adverse-event --drug metformin --by indication
+    """
+    if not drug and not reaction and not country and not indication:
+        typer.echo(
+            "Error: provide --drug, --reaction, --country or --indication",
+            err=True,
+        )
+        raise typer.Exit(1)
+
+    summary = " | ".join(
+        f"{label}: {value}"
+        for label, value in (
+            ("Drug", drug),
+            ("Reaction", reaction),
+            ("Country", country),
+            ("Indication", indication),
+        )
+        if value
+    )
+    result = asyncio.run(
+        adverse_event_facet_counts(
+            by,
+            drug=drug,
+            reaction=reaction,
+            serious=serious,
+            country=country,
+            limit=limit,
+            api_key=api_key,
+            indication=indication,
+        )
+    )
+    _echo_distribution(result, output_json, summary)
@@ -1,16 +1,31 @@
 """CzechMedMCP Command Line Interface for clinical trials."""
 
 import asyncio
+import json
+from pathlib import Path
 from typing import Annotated
 
 import typer
 
+from .. import render
+from ..sampling import sample_records, sample_window
+from ..trials.cursor import (
+    load_cursor,
+    save_cursor,
+    search_trials_with_cursor,
+)
+from ..trials.eligibility import PatientSex
 from ..trials.getter import Module
 from ..trials.search import (
     AgeGroup,
     DateField,
+    DesignAllocation,
+    DesignMasking,
+    FunderType,
+    InterventionModel,
     InterventionType,
     LineOfTherapy,
+    LocationStatus,
     PrimaryPurpose,
     RecruitingStatus,
     SortOrder,
@@ -19,10 +34,54 @@ from ..trials.search import (
     StudyType,
     TrialPhase,
     TrialQuery,
+    normalize_nct_prefix,
+    trial_search_query_summary,
 )
+from ..utils.duration import parse_duration
 
 trial_app = typer.Typer(help="Clinical trial operations")
 
+# Options of ``trial get`` that each pick their own rendering of a trial,
+# with the modules they work with (None: the module argument is ignored)
+# and whether --json applies. They all read ClinicalTrials.gov, and at
+# most one can be given.
+TRIAL_GET_MODES: dict[str, tuple[tuple[Module, ...] | None, bool]] = {
+    "--arms-with-dosing": ((Module.PROTOCOL,), True),
+    "--eligibility-structured": ((Module.PROTOCOL,), True),
+    "--summary-only": (None, True),
+    "--format": (None, False),
+    "--compact": ((Module.PROTOCOL,), False),
+    "--outcomes-with-results": ((Module.OUTCOMES,), True),
+    "--near-lat/--near-lon": ((Module.LOCATIONS, Module.ALL), True),
+}
+
+
+def trial_get_mode_error(
+    active: list[str],
+    source: str,
+    module: Module | None,
+    output_json: bool,
+) -> str | None:
+    """Check the given ``TRIAL_GET_MODES`` options against each other.
+
+    Returns:
+        Error message for an unsupported combination, or None
+    """
+    if len(active) > 1:
+        return f"{active[0]} cannot be combined with {', '.join(active[1:])}"
+    if not active:
+        return None
+    mode = active[0]
+    modules, allows_json = TRIAL_GET_MODES[mode]
+    if source != "clinicaltrials":
+        return f"{mode} requires --source clinicaltrials"
+    if modules is not None and (module or Module.PROTOCOL) not in modules:
+        names = " or ".join(m.value for m in modules)
+        return f"{mode} requires the {names} module"
+    if output_json and not allows_json:
+        return f"{mode} cannot be combined with --json"
+    return None
+
 
 @trial_app.command("get")
 def get_trial_cli(
@@ -61,10 +120,185 @@ def get_trial_cli(
             envvar="NCI_API_KEY",
         ),
     ] = None,
+    near_lat: Annotated[
+        float | None,
+        typer.Option(
+            "--near-lat",
+            help="Reference latitude: annotate sites with distance and "
+            "sort nearest-first (requires --near-lon)",
+            min=-90,
+            max=90,
+        ),
+    ] = None,
+    near_lon: Annotated[
+        float | None,
+        typer.Option(
+            "--near-lon",
+            help="Reference longitude (requires --near-lat)",
+            min=-180,
+            max=180,
+        ),
+    ] = None,
+    outcomes_with_results: Annotated[
+        bool,
+        typer.Option(
+            "--outcomes-with-results",
+            help="With the Outcomes module, include posted results: "
+            "per-arm values, analyses and participant flow",
+        ),
+    ] = False,
+    arms_with_dosing: Annotated[
+        bool,
+        typer.Option(
+            "--arms-with-dosing",
+            help="Show each arm with its interventions and their "
+            "registered dosing and schedule",
+        ),
+    ] = False,
+    eligibility_structured: Annotated[
+        bool,
+        typer.Option(
+            "--eligibility-structured",
+            help="Show the eligibility criteria as separate, itemized "
+            "inclusion and exclusion lists",
+        ),
+    ] = False,
+    compact: Annotated[
+        bool,
+        typer.Option(
+            "--compact",
+            help="Print a one-line summary instead of the full card",
+        ),
+    ] = False,
+    summary_only: Annotated[
+        bool,
+        typer.Option(
+            "--summary-only",
+            help="Fetch only title, status, phases and conditions for a "
+            "quick status check; the module argument is ignored",
+        ),
+    ] = False,
+    output_format: Annotated[
+        str | None,
+        typer.Option(
+            "--format",
+            help="Alternative output format. Options: xml (the full "
+            "ClinicalTrials.gov record as XML)",
+        ),
+    ] = None,
 ):
-    """Get trial information by NCT ID from ClinicalTrials.gov or NCI CTS API."""
+    """Get trial information by NCT ID from ClinicalTrials.gov or NCI CTS API.
+
+    Example:
+        czechmedmcp trial get NCT04280705 Locations
+            --near-lat 42.36 --near-lon -71.06
+        czechmedmcp trial get NCT02576509 Outcomes --outcomes-with-results
+        czechmedmcp trial get NCT02576509 --arms-with-dosing
+        czechmedmcp trial get NCT04280705 --eligibility-structured --json
+        czechmedmcp trial get NCT04280705 --compact
+        czechmedmcp trial get NCT04280705 --summary-only --json
+        czechmedmcp trial get NCT04280705 --format xml > trial.xml
+    """
     # Import here to avoid circular imports
-    from ..trials.getter import get_trial_unified
+    from ..trials.arms import get_trial_arms
+    from ..trials.eligibility import get_structured_eligibility
+    from ..trials.getter import get_trial, get_trial_unified
+    from ..trials.outcomes import get_trial_outcomes
+    from ..trials.summary import get_trial_summary
+
+    if (near_lat is None) != (near_lon is None):
+        typer.echo(
+            "Error: --near-lat and --near-lon must be used together",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if output_format is not None and output_format.lower() != "xml":
+        typer.echo(
+            f"Invalid format: '{output_format}'. Available options: xml",
+            err=True,
+        )
+        raise typer.Exit(1)
+
+    active = [
+        mode
+        for mode, given in zip(
+            TRIAL_GET_MODES,
+            (
+                arms_with_dosing,
+                eligibility_structured,
+                summary_only,
+                output_format is not None,
+                compact,
+                outcomes_with_results,
+                near_lat is not None,
+            ),
+            strict=True,
+        )
+        if given
+    ]
+    error = trial_get_mode_error(active, source, module, output_json)
+    if error:
+        typer.echo(f"Error: {error}", err=True)
+        raise typer.Exit(1)
+
+    if arms_with_dosing:
+        typer.echo(asyncio.run(get_trial_arms(nct_id, output_json)))
+        return
+
+    if eligibility_structured:
+        result = asyncio.run(get_structured_eligibility(nct_id, output_json))
+        typer.echo(result)
+        if result.startswith("Error:"):
+            raise typer.Exit(1)
+        return
+
+    if summary_only:
+        typer.echo(asyncio.run(get_trial_summary(nct_id, output_json)))
+        return
+
+    if output_format is not None:
+        document, error = render.json_to_trial_xml(
+            asyncio.run(get_trial(nct_id, module=Module.ALL, output_json=True))
+        )
+        if error or document is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(document, nl=False)
+        return
+
+    if compact:
+        line, error = render.json_to_summary_line(
+            asyncio.run(
+                get_trial(nct_id, module=Module.PROTOCOL, output_json=True)
+            ),
+            "trial",
+        )
+        if error or line is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(1)
+        typer.echo(line)
+        return
+
+    if outcomes_with_results:
+        result = asyncio.run(
+            get_trial_outcomes(
+                nct_id, with_results=True, output_json=output_json
+            )
+        )
+        typer.echo(result)
+        return
+
+    if near_lat is not None and near_lon is not None:
+        result = asyncio.run(
+            get_trial(
+                nct_id,
+                module=module,
+                output_json=output_json,
+                near=(near_lat, near_lon),
+            )
+        )
+        typer.echo(result)
+        return
 
     # Check if NCI source requires API key
     if source == "nci" and not api_key:
@@ -75,8 +309,6 @@ def get_trial_cli(
 
     # For ClinicalTrials.gov, use the direct get_trial function when JSON is requested
     if source == "clinicaltrials" and output_json:
-        from ..trials.getter import get_trial
-
         if module is None:
             result = asyncio.run(get_trial(nct_id, output_json=True))
         else:
@@ -100,6 +332,67 @@ def get_trial_cli(
         typer.echo(result)
 
 
+@trial_app.command("eligibility-check")
+def eligibility_check_cli(
+    nct_id: Annotated[
+        str,
+        typer.Argument(help="Clinical trial NCT ID (e.g., NCT04280705)"),
+    ],
+    age: Annotated[
+        float | None,
+        typer.Option("--age", help="Patient age in years", min=0),
+    ] = None,
+    sex: Annotated[
+        PatientSex | None,
+        typer.Option("--sex", help="Patient sex", case_sensitive=False),
+    ] = None,
+    mutation: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--mutation",
+            help="Patient mutation or biomarker (can specify multiple)",
+        ),
+    ] = None,
+    prior_therapy: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--prior-therapy",
+            help="Therapy the patient has received (can specify multiple)",
+        ),
+    ] = None,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Render in JSON format",
+            case_sensitive=False,
+        ),
+    ] = False,
+):
+    """Check a patient profile against a trial's eligibility criteria.
+
+    Matching is best-effort: criteria mentioning a patient's mutation or
+    prior therapy are flagged, everything else is reported as unknown.
+
+    Examples:
+        czechmedmcp trial eligibility-check NCT04280705 --age 62 --sex female
+        czechmedmcp trial eligibility-check NCT04280705 --mutation EGFR --prior-therapy osimertinib
+    """
+    from ..trials.eligibility import PatientProfile, check_trial_eligibility
+
+    profile = PatientProfile(
+        age=age,
+        sex=sex,
+        mutations=mutation,
+        prior_therapies=prior_therapy,
+    )
+    result = asyncio.run(
+        check_trial_eligibility(nct_id, profile, output_json=output_json)
+    )
+    typer.echo(result)
+
+
 @trial_app.command("search")
 def search_trials_cli(
     condition: Annotated[
@@ -132,6 +425,14 @@ def search_trials_cli(
             case_sensitive=False,
         ),
     ] = None,
+    collaborator: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--collaborator",
+            help="Collaborator organization name, matched even when it is "
+            "not the lead sponsor (can specify multiple; any may match)",
+        ),
+    ] = None,
     term: Annotated[
         list[str] | None,
         typer.Option(
@@ -154,6 +455,22 @@ def search_trials_cli(
             case_sensitive=False,
         ),
     ] = None,
+    nct_prefix: Annotated[
+        str | None,
+        typer.Option(
+            "--nct-prefix",
+            help="Partial NCT number; matches every trial whose NCT ID "
+            "starts with it (e.g., NCT0428)",
+        ),
+    ] = None,
+    acronym: Annotated[
+        str | None,
+        typer.Option(
+            "--acronym",
+            help="Study acronym or nickname (e.g., KEYNOTE-006); add "
+            "--status ANY to include closed studies",
+        ),
+    ] = None,
     recruiting_status: Annotated[
         RecruitingStatus | None,
         typer.Option(
@@ -190,7 +507,10 @@ def search_trials_cli(
         SortOrder | None,
         typer.Option(
             "--sort",
-            help="Sort order",
+            help="Sort order. ClinicalTrials.gov sorts server-side, "
+            "consistently across pages, by relevance, last update, "
+            "enrollment, start, completion or submitted date; STATUS "
+            "reorders each page client-side, recruiting trials first",
             show_choices=True,
             show_default=True,
             case_sensitive=False,
@@ -241,11 +561,27 @@ def search_trials_cli(
             case_sensitive=False,
         ),
     ] = DateField.STUDY_START,
+    updated_within: Annotated[
+        str | None,
+        typer.Option(
+            "--updated-within",
+            help="Only trials updated within this duration, e.g. 90d, 12w, "
+            "6mo, 1y (cannot be combined with --min-date)",
+        ),
+    ] = None,
+    results_within: Annotated[
+        str | None,
+        typer.Option(
+            "--results-within",
+            help="Only trials whose results were first posted within this "
+            "duration, e.g. 30d, 12w, 6mo, 1y",
+        ),
+    ] = None,
     intervention_type: Annotated[
         InterventionType | None,
         typer.Option(
             "--intervention-type",
-            help="Intervention type filter",
+            help="Intervention type filter (e.g., drug, device, procedure)",
             show_choices=True,
             show_default=True,
             case_sensitive=False,
@@ -261,6 +597,15 @@ def search_trials_cli(
             case_sensitive=False,
         ),
     ] = None,
+    funder_exclude: Annotated[
+        list[FunderType] | None,
+        typer.Option(
+            "--funder-exclude",
+            help="Leave out trials whose lead sponsor is of this funder "
+            "type (can specify multiple; ClinicalTrials.gov only)",
+            case_sensitive=False,
+        ),
+    ] = None,
     study_design: Annotated[
         StudyDesign | None,
         typer.Option(
@@ -271,6 +616,35 @@ def search_trials_cli(
             case_sensitive=False,
         ),
     ] = None,
+    allocation: Annotated[
+        DesignAllocation | None,
+        typer.Option(
+            "--allocation",
+            help="Arm allocation filter (ClinicalTrials.gov only)",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
+    masking: Annotated[
+        DesignMasking | None,
+        typer.Option(
+            "--masking",
+            help="Masking filter, e.g. double for double-blind trials "
+            "(ClinicalTrials.gov only)",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
+    intervention_model: Annotated[
+        InterventionModel | None,
+        typer.Option(
+            "--model",
+            help="Intervention model filter, e.g. parallel or crossover "
+            "(ClinicalTrials.gov only)",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
     next_page_hash: Annotated[
         str | None,
         typer.Option(
@@ -278,6 +652,42 @@ def search_trials_cli(
             help="Next page hash for pagination",
         ),
     ] = None,
+    save_cursor_file: Annotated[
+        Path | None,
+        typer.Option(
+            "--save-cursor",
+            help="Write the next page token and the query to this file "
+            "(removed after the last page; ClinicalTrials.gov only)",
+            dir_okay=False,
+        ),
+    ] = None,
+    resume_file: Annotated[
+        Path | None,
+        typer.Option(
+            "--resume",
+            help="Continue from the page saved with --save-cursor; the "
+            "search options must match the saved query",
+            dir_okay=False,
+        ),
+    ] = None,
+    sample: Annotated[
+        int | None,
+        typer.Option(
+            "--sample",
+            help="Return a random sample of N trials drawn from a window "
+            "5x larger (fetches more than it returns; replaces "
+            "--page-size; ClinicalTrials.gov only)",
+            min=1,
+            max=200,
+        ),
+    ] = None,
+    seed: Annotated[
+        int | None,
+        typer.Option(
+            "--seed",
+            help="Random seed for --sample, for a reproducible sample",
+        ),
+    ] = None,
     latitude: Annotated[
         float | None,
         typer.Option(
@@ -300,6 +710,18 @@ def search_trials_cli(
             help="Distance in miles for location-based search (default: 50 miles if lat/lon provided)",
         ),
     ] = None,
+    location_status: Annotated[
+        LocationStatus | None,
+        typer.Option(
+            "--location-status",
+            help="Keep only trials with a site within --distance of "
+            "--lat/--lon that has this status, e.g. recruiting (one extra "
+            "request per result; markdown output reports how many trials "
+            "on the page were dropped, --json lists only the kept ones)",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
     output_json: Annotated[
         bool,
         typer.Option(
@@ -344,6 +766,15 @@ def search_trials_cli(
             help="Biomarker expression requirements in format 'MARKER:EXPRESSION' (e.g., 'PD-L1:≥50%')",
         ),
     ] = None,
+    explain: Annotated[
+        bool,
+        typer.Option(
+            "--explain",
+            help="Quote the eligibility criterion each result matched for "
+            "--required-mutation, --prior-therapy, --progression-on and "
+            "--biomarker (one extra request per result)",
+        ),
+    ] = False,
     line_of_therapy: Annotated[
         LineOfTherapy | None,
         typer.Option(
@@ -377,6 +808,40 @@ def search_trials_cli(
             max=1000,
         ),
     ] = None,
+    min_enrollment: Annotated[
+        int | None,
+        typer.Option(
+            "--min-enrollment",
+            help="Only trials enrolling at least N participants (ClinicalTrials.gov only)",
+            min=0,
+        ),
+    ] = None,
+    max_enrollment: Annotated[
+        int | None,
+        typer.Option(
+            "--max-enrollment",
+            help="Only trials enrolling at most N participants (ClinicalTrials.gov only)",
+            min=0,
+        ),
+    ] = None,
+    min_age: Annotated[
+        int | None,
+        typer.Option(
+            "--min-age",
+            help="Only trials whose minimum eligible age is at least N "
+            "years, e.g. 65 for geriatric trials (ClinicalTrials.gov only)",
+            min=0,
+        ),
+    ] = None,
+    max_age: Annotated[
+        int | None,
+        typer.Option(
+            "--max-age",
+            help="Only trials whose maximum eligible age is at most N "
+            "years, e.g. 17 for pediatric trials (ClinicalTrials.gov only)",
+            min=0,
+        ),
+    ] = None,
     source: Annotated[
         str,
         typer.Option(
@@ -404,12 +869,143 @@ def search_trials_cli(
                 marker, expr = item.split(":", 1)
                 biomarker_expression[marker] = expr
 
+    if updated_within is not None:
+        if min_date:
+            typer.echo(
+                "Error: --updated-within cannot be combined with --min-date",
+                err=True,
+            )
+            raise typer.Exit(1)
+        if source == "nci":
+            typer.echo(
+                "Error: --updated-within is only supported with "
+                "--source clinicaltrials",
+                err=True,
+            )
+            raise typer.Exit(1)
+        try:
+            parse_duration(updated_within)
+        except ValueError as e:
+            typer.echo(f"Error: {e}", err=True)
+            raise typer.Exit(1) from None
+
+    if results_within is not None:
+        if source == "nci":
+            typer.echo(
+                "Error: --results-within is only supported with "
+                "--source clinicaltrials",
+                err=True,
+            )
+            raise typer.Exit(1)
+        try:
+            parse_duration(results_within)
+        except ValueError as e:
+            typer.echo(f"Error: {e}", err=True)
+            raise typer.Exit(1) from None
+
+    if min_age is not None or max_age is not None:
+        if source == "nci":
+            typer.echo(
+                "Error: --min-age/--max-age are only supported with "
+                "--source clinicaltrials",
+                err=True,
+            )
+            raise typer.Exit(1)
+        if min_age is not None and max_age is not None and min_age > max_age:
+            typer.echo(
+                "Error: --min-age cannot be greater than --max-age",
+                err=True,
+            )
+            raise typer.Exit(1)
+
+    if (save_cursor_file or resume_file) and source == "nci":
+        typer.echo(
+            "Error: --save-cursor/--resume are only supported with "
+            "--source clinicaltrials",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if resume_file and next_page_hash:
+        typer.echo(
+            "Error: --resume cannot be combined with --next-page",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if seed is not None and sample is None:
+        typer.echo("Error: --seed requires --sample", err=True)
+        raise typer.Exit(1)
+    if sample is not None:
+        if (
+            source == "nci"
+            or page_size is not None
+            or next_page_hash
+            or save_cursor_file
+            or resume_file
+        ):
+            typer.echo(
+                "Error: --sample uses ClinicalTrials.gov and cannot be "
+                "combined with --page-size, --next-page, --save-cursor "
+                "or --resume",
+                err=True,
+            )
+            raise typer.Exit(1)
+        # ClinicalTrials.gov returns at most 1000 studies per page
+        page_size = sample_window(sample, 1000)
+
+    design_filters = allocation or masking or intervention_model
+    if (
+        acronym or nct_prefix or collaborator or funder_exclude
+    ) and source == "nci":
+        typer.echo(
+            "Error: --acronym/--nct-prefix/--collaborator/--funder-exclude "
+            "are only supported with --source clinicaltrials",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if design_filters and source == "nci":
+        typer.echo(
+            "Error: --allocation/--masking/--model are only supported "
+            "with --source clinicaltrials",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if sort_order == SortOrder.STATUS and source == "nci":
+        typer.echo(
+            "Error: --sort status is only supported with "
+            "--source clinicaltrials",
+            err=True,
+        )
+        raise typer.Exit(1)
+    if location_status:
+        if source == "nci":
+            typer.echo(
+                "Error: --location-status is only supported with "
+                "--source clinicaltrials",
+                err=True,
+            )
+            raise typer.Exit(1)
+        if latitude is None or longitude is None:
+            typer.echo(
+                "Error: --location-status requires --lat and --lon",
+                err=True,
+            )
+            raise typer.Exit(1)
+    if nct_prefix is not None:
+        try:
+            normalize_nct_prefix(nct_prefix)
+        except ValueError as e:
+            typer.echo(f"Error: {e}", err=True)
+            raise typer.Exit(1) from None
+
     query = TrialQuery(
         conditions=condition,
         interventions=intervention,
         lead_sponsor=lead_sponsor,
+        collaborators=collaborator,
         terms=term,
         nct_ids=nct_id,
+        nct_prefix=nct_prefix,
+        acronym=acronym,
         recruiting_status=recruiting_status,
         study_type=study_type,
         phase=phase,
@@ -419,13 +1015,20 @@ def search_trials_cli(
         min_date=min_date,
         max_date=max_date,
         date_field=date_field,
+        updated_within=updated_within,
+        results_within=results_within,
         intervention_type=intervention_type,
         sponsor_type=sponsor_type,
+        funder_exclude=funder_exclude,
         study_design=study_design,
+        allocation=allocation,
+        masking=masking,
+        intervention_model=intervention_model,
         next_page_hash=next_page_hash,
         lat=latitude,
         long=longitude,
         distance=distance,
+        location_status=location_status,
         prior_therapies=prior_therapy,
         progression_on=progression_on,
         required_mutations=required_mutation,
@@ -435,10 +1038,30 @@ def search_trials_cli(
         allow_brain_mets=allow_brain_mets,
         return_fields=return_field,
         page_size=page_size,
+        min_enrollment=min_enrollment,
+        max_enrollment=max_enrollment,
+        min_age=min_age,
+        max_age=max_age,
     )
+    if resume_file:
+        try:
+            query.next_page_hash = load_cursor(resume_file, query)
+        except ValueError as e:
+            typer.echo(f"Error: {e}", err=True)
+            raise typer.Exit(1) from None
 
     # Import here to avoid circular imports
-    from ..trials.search import search_trials_unified
+    from ..trials.search import search_trials, search_trials_unified
+
+    if source == "nci" and (
+        min_enrollment is not None or max_enrollment is not None
+    ):
+        typer.echo(
+            "Error: --min-enrollment/--max-enrollment are only supported "
+            "with --source clinicaltrials",
+            err=True,
+        )
+        raise typer.Exit(1)
 
     # Check if NCI source requires API key
     if source == "nci" and not api_key:
@@ -447,9 +1070,60 @@ def search_trials_cli(
         typer.echo(get_api_key_instructions())
         raise typer.Exit(1)
 
-    result = asyncio.run(
-        search_trials_unified(
-            query, source=source, api_key=api_key, output_json=output_json
+    if explain:
+        if source == "nci":
+            typer.echo(
+                "Error: --explain is only supported with "
+                "--source clinicaltrials",
+                err=True,
+            )
+            raise typer.Exit(1)
+        if not (
+            required_mutation or prior_therapy or progression_on or biomarker
+        ):
+            typer.echo(
+                "Error: --explain needs an eligibility filter: "
+                "--required-mutation, --prior-therapy, --progression-on "
+                "or --biomarker",
+                err=True,
+            )
+            raise typer.Exit(1)
+
+    if save_cursor_file:
+        result, token, token_error = asyncio.run(
+            search_trials_with_cursor(query, explain=explain)
         )
-    )
+        data = json.loads(result)
+        if token_error:
+            typer.echo(
+                f"Warning: cursor not updated: {token_error}", err=True
+            )
+        elif not (isinstance(data, dict) and "error" in data):
+            save_cursor(save_cursor_file, query, token)
+        if data and not output_json:
+            result = render.to_markdown(data)
+    elif sample:
+        result = asyncio.run(
+            search_trials(query, output_json=True, explain=explain)
+        )
+        data = sample_records(json.loads(result), sample, seed, "studies")
+        # A sample has no next page
+        if isinstance(data, dict):
+            data.pop("nextPageToken", None)
+        if data and not output_json:
+            result = render.to_markdown(data)
+        else:
+            result = json.dumps(data, indent=2)
+    elif explain:
+        result = asyncio.run(
+            search_trials(query, output_json=output_json, explain=True)
+        )
+    else:
+        result = asyncio.run(
+            search_trials_unified(
+                query, source=source, api_key=api_key, output_json=output_json
+            )
+        )
+    if not output_json:
+        typer.echo(f"_{trial_search_query_summary(query)}_\n")
     typer.echo(result)
@@ -5,8 +5,14 @@ from typing import Annotated
 
 import typer
 
+from .. import render
 from ..constants import DEFAULT_ASSEMBLY, SYSTEM_PAGE_SIZE
+from ..trials.search import RecruitingStatus
 from ..variants import getter, search
+from ..variants.literature import (
+    DEFAULT_LITERATURE_LIMIT,
+    MAX_LITERATURE_LIMIT,
+)
 
 variant_app = typer.Typer(help="Search and get variants from MyVariant.info.")
 
@@ -39,7 +45,7 @@ def get_variant(
         str,
         typer.Option(
             "--assembly",
-            help="Genome assembly (hg19 or hg38)",
+            help="Genome build: GRCh37 (hg19) or GRCh38 (hg38)",
             case_sensitive=False,
         ),
     ] = DEFAULT_ASSEMBLY,
@@ -50,6 +56,80 @@ def get_variant(
             help="Show full details (default: compact format optimized for LLMs)",
         ),
     ] = False,
+    clinvar_submissions: Annotated[
+        bool,
+        typer.Option(
+            "--clinvar-submissions",
+            help="List each ClinVar record (significance, review status, condition, last evaluated)",
+        ),
+    ] = False,
+    transcript: Annotated[
+        str | None,
+        typer.Option(
+            "--transcript",
+            help="Ensembl or RefSeq transcript whose HGVS and consequence "
+            "are reported",
+        ),
+    ] = None,
+    show_transcript: Annotated[
+        bool,
+        typer.Option(
+            "--show-transcript",
+            help="Report the HGVS and consequence on the MANE Select "
+            "transcript (via ClinVar, else the most severe SnpEff "
+            "annotation)",
+        ),
+    ] = False,
+    compact: Annotated[
+        bool,
+        typer.Option(
+            "--compact",
+            help="Print a one-line summary instead of the full card",
+        ),
+    ] = False,
+    liftover: Annotated[
+        str | None,
+        typer.Option(
+            "--liftover",
+            help="Also report coordinates in this genome build "
+            "(GRCh37 or GRCh38), side by side with --assembly",
+            case_sensitive=False,
+        ),
+    ] = None,
+    pubmed: Annotated[
+        bool,
+        typer.Option(
+            "--pubmed",
+            help="Embed the top articles citing the variant (PubTator3 "
+            "search on its gene and rsID)",
+        ),
+    ] = False,
+    pubmed_limit: Annotated[
+        int,
+        typer.Option(
+            "--pubmed-limit",
+            help="Number of articles embedded with --pubmed",
+            min=1,
+            max=MAX_LITERATURE_LIMIT,
+        ),
+    ] = DEFAULT_LITERATURE_LIMIT,
+    acmg: Annotated[
+        bool,
+        typer.Option(
+            "--acmg",
+            help="Add advisory ACMG/AMP criterion hints (BA1, BS1, PM2, PM1, "
+            "PP3, BP4, PP5, BP6) from frequency, REVEL/CADD, ClinVar and "
+            "hotspots; not a classification",
+        ),
+    ] = False,
+    gnomad_detail: Annotated[
+        bool,
+        typer.Option(
+            "--gnomad-detail",
+            help="Add per-population gnomAD tables (allele frequency, "
+            "allele count, allele number, homozygotes)",
+        ),
+    ] = False,
 ):
     """
     Get detailed information about a specific genetic variant.
@@ -65,20 +145,67 @@ def get_variant(
         Get by rsID: czechmedmcp variant get rs113488022
         Get as JSON: czechmedmcp variant get rs113488022 --json
         Get without external annotations: czechmedmcp variant get rs113488022 --no-external
-        Get with hg38 assembly: czechmedmcp variant get rs113488022 --assembly hg38
+        Get with GRCh37 coordinates: czechmedmcp variant get rs113488022 --assembly GRCh37
         Get full details: czechmedmcp variant get rs113488022 --extensive
+        List ClinVar records: czechmedmcp variant get rs113488022 --clinvar-submissions
+        Pick a transcript: czechmedmcp variant get rs113488022 --transcript NM_004333
+        One-line summary: czechmedmcp variant get rs113488022 --compact
+        Liftover to GRCh37: czechmedmcp variant get rs113488022 --liftover GRCh37
+        With literature: czechmedmcp variant get rs113488022 --pubmed
+        ACMG criterion hints: czechmedmcp variant get rs113488022 --acmg
+        gnomAD by population: czechmedmcp variant get rs113488022 --gnomad-detail
     """
     if not variant_id:
         typer.echo("Error: A variant identifier must be provided.", err=True)
         raise typer.Exit(code=1)
 
-    # Validate assembly value
-    if assembly not in ["hg19", "hg38"]:
-        typer.echo(
-            f"Error: Invalid assembly '{assembly}'. Must be 'hg19' or 'hg38'.",
-            err=True,
+    # Validate assembly values
+    try:
+        assembly = getter.normalize_assembly(assembly)
+        if liftover is not None:
+            liftover = getter.normalize_assembly(liftover)
+            if liftover == assembly:
+                raise ValueError(
+                    "--liftover must name a different build than --assembly"
+                )
+    except ValueError as e:
+        typer.echo(f"Error: {e}", err=True)
+        raise typer.Exit(code=1) from None
+
+    if compact:
+        if (
+            output_json
+            or extensive
+            or clinvar_submissions
+            or liftover
+            or pubmed
+            or acmg
+            or gnomad_detail
+        ):
+            typer.echo(
+                "Error: --compact cannot be combined with --json, "
+                "--extensive, --clinvar-submissions, --liftover, --pubmed, "
+                "--acmg or --gnomad-detail",
+                err=True,
+            )
+            raise typer.Exit(code=1)
+        line, error = render.json_to_summary_line(
+            asyncio.run(
+                getter.get_variant(
+                    variant_id,
+                    output_json=True,
+                    include_external=False,
+                    assembly=assembly,
+                    transcript=transcript,
+                )
+            ),
+            "variant",
         )
-        raise typer.Exit(code=1)
+        if error or line is None:
+            typer.echo(f"Error: {error}", err=True)
+            raise typer.Exit(code=1)
+        typer.echo(line)
+        return
 
     result = asyncio.run(
         getter.get_variant(
@@ -87,6 +214,13 @@ def get_variant(
             include_external=include_external,
             assembly=assembly,
             extensive=extensive,
+            clinvar_submissions=clinvar_submissions,
+            transcript=transcript,
+            show_transcript=show_transcript,
+            liftover=liftover,
+            literature_limit=pubmed_limit if pubmed else None,
+            acmg=acmg,
+            gnomad_detail=gnomad_detail,
         )
     )
     typer.echo(result)
@@ -117,7 +251,8 @@ def search_variant_cmd(
         str | None,
         typer.Option(
             "--hgvsc",
-            help="cDNA notation (e.g., c.1799T>A).",
+            help="cDNA notation (e.g., c.1799T>A); the c. prefix is "
+            "optional and a transcript prefix is dropped.",
         ),
     ] = None,
     rsid: Annotated[
@@ -142,6 +277,22 @@ def search_variant_cmd(
             case_sensitive=False,
         ),
     ] = None,
+    origin: Annotated[
+        search.AlleleOrigin,
+        typer.Option(
+            "--origin",
+            help="ClinVar allele origin: germline, somatic, or both",
+            case_sensitive=False,
+        ),
+    ] = search.AlleleOrigin.BOTH,
+    clinvar_condition: Annotated[
+        str | None,
+        typer.Option(
+            "--clinvar-condition",
+            help="ClinVar condition by identifier: MedGen concept (e.g., "
+            "C0025202) or OMIM number (e.g., OMIM:155600)",
+        ),
+    ] = None,
     min_frequency: Annotated[
         float | None,
         typer.Option(
@@ -185,6 +336,48 @@ def search_variant_cmd(
             case_sensitive=False,
         ),
     ] = None,
+    consequence: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--consequence",
+            help="SnpEff consequence as a Sequence Ontology term (e.g., "
+            "missense_variant, stop_gained); repeat to match any",
+        ),
+    ] = None,
+    impact: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--impact",
+            help="SnpEff putative impact: HIGH, MODERATE, LOW or "
+            "MODIFIER; repeat to match any",
+        ),
+    ] = None,
+    therapy: Annotated[
+        list[str] | None,
+        typer.Option(
+            "--therapy",
+            help="CIViC therapy name (e.g., vemurafenib); repeat to match "
+            "any",
+        ),
+    ] = None,
+    therapy_evidence: Annotated[
+        search.CivicEvidenceLevel | None,
+        typer.Option(
+            "--therapy-evidence",
+            help="Minimum CIViC evidence level: A (validated) to E "
+            "(inferential); B keeps A and B",
+            case_sensitive=False,
+        ),
+    ] = None,
+    hotspot: Annotated[
+        bool,
+        typer.Option(
+            "--hotspot",
+            help="Only variants at recurrent cancer hotspot residues of "
+            "--gene in cBioPortal (best-effort: covers the most frequent "
+            "protein changes in the studies queried)",
+        ),
+    ] = False,
     size: Annotated[
         int,
         typer.Option(
@@ -201,6 +394,14 @@ def search_variant_cmd(
             help="Specific sources to include in results (comma-separated)",
         ),
     ] = None,
+    assembly: Annotated[
+        str,
+        typer.Option(
+            "--assembly",
+            help="Genome build: GRCh37 (hg19) or GRCh38 (hg38)",
+            case_sensitive=False,
+        ),
+    ] = DEFAULT_ASSEMBLY,
     output_json: Annotated[
         bool,
         typer.Option(
@@ -211,6 +412,23 @@ def search_variant_cmd(
         ),
     ] = False,
 ):
+    try:
+        assembly = getter.normalize_assembly(assembly)
+        consequences = search.normalize_consequences(consequence or [])
+        impacts = search.normalize_impacts(impact or [])
+        if hgvsc:
+            hgvsc = search.normalize_hgvsc(hgvsc)
+        if clinvar_condition:
+            clinvar_condition = search.normalize_clinvar_condition(
+                clinvar_condition
+            )
+    except ValueError as e:
+        typer.echo(f"Error: {e}", err=True)
+        raise typer.Exit(code=1) from None
+    if hotspot and not gene:
+        typer.echo("Error: --hotspot requires --gene", err=True)
+        raise typer.Exit(code=1)
+
     query = search.VariantQuery(
         gene=gene,
         hgvsp=hgvsp,
@@ -218,16 +436,106 @@ def search_variant_cmd(
         rsid=rsid,
         region=region,
         significance=significance,
+        origin=origin,
+        clinvar_condition=clinvar_condition,
         min_frequency=min_frequency,
         max_frequency=max_frequency,
         cadd=cadd,
         polyphen=polyphen,
         sift=sift,
+        consequences=consequences,
+        impacts=impacts,
+        therapies=therapy or [],
+        therapy_evidence=therapy_evidence,
         size=size,
         sources=sources.split(",") if sources else [],
+        assembly=assembly,
+        hotspot=hotspot,
     )
 
     result = asyncio.run(search.search_variants(query, output_json))
+    if not output_json:
+        typer.echo(f"_{search.variant_search_query_summary(query)}_\n")
+    typer.echo(result)
+
+
+@variant_app.command("trials")
+def variant_trials_cmd(
+    gene: Annotated[
+        str,
+        typer.Argument(help="Gene symbol (e.g., BRAF)"),
+    ],
+    change: Annotated[
+        str,
+        typer.Argument(help="Protein change (e.g., V600E)"),
+    ],
+    rsid: Annotated[
+        str | None,
+        typer.Option(
+            "--rsid",
+            help="Also query the dbSNP rsID (e.g., rs113488022)",
+        ),
+    ] = None,
+    hgvs: Annotated[
+        str | None,
+        typer.Option(
+            "--hgvs",
+            help="Also query an HGVS notation (e.g., c.1799T>A)",
+        ),
+    ] = None,
+    recruiting_status: Annotated[
+        RecruitingStatus | None,
+        typer.Option(
+            "--status",
+            "-s",
+            help="Recruiting status.",
+            show_choices=True,
+            case_sensitive=False,
+        ),
+    ] = None,
+    page_size: Annotated[
+        int,
+        typer.Option(
+            "--page-size",
+            help="Number of trials requested per representation",
+            min=1,
+            max=100,
+        ),
+    ] = 10,
+    output_json: Annotated[
+        bool,
+        typer.Option(
+            "--json",
+            "-j",
+            help="Render in JSON format",
+            case_sensitive=False,
+        ),
+    ] = False,
+):
+    """
+    Find clinical trials mentioning a variant (best-effort).
+
+    The normalized "GENE CHANGE" query is always run; --rsid and --hgvs
+    add further representations (at most 3 queries in total). Results
+    are merged, deduplicated by NCT ID and ordered by recruiting status.
+
+    Examples:
+        czechmedmcp variant trials BRAF V600E
+        czechmedmcp variant trials BRAF V600E --rsid rs113488022 --hgvs c.1799T>A
+    """
+    from ..variants.trials import search_variant_trials
+
+    result = asyncio.run(
+        search_variant_trials(
+            gene,
+            change,
+            rsid=rsid,
+            hgvs=hgvs,
+            recruiting_status=recruiting_status,
+            page_size=page_size,
+            output_json=output_json,
+        )
+    )
     typer.echo(result)
 
 
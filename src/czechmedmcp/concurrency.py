@@ -0,0 +1,105 @@
+"""Global cap on concurrency for fan-out operations.
+
+Several operations issue many upstream requests at once (SÚKL index
+builds, multi-domain unified search, NCI synonym expansion, variant
+annotation sources, variant-to-trial lookups, batch fetches). Each has
+its own default width. A global limit, set with the ``--concurrency`` CLI
+option or the ``BIOMCP_CONCURRENCY`` environment variable, overrides all
+of them so users on constrained networks or strict API quotas can dial
+fan-out down to 1.
+
+The cap bounds how many requests are in flight; it does not replace the
+per-domain rate limiter in ``rate_limiter``. Requests still wait for a
+rate-limit token, so a high cap cannot exceed an API's requests/second
+budget, and a low cap only makes bursts smaller.
+"""
+
+import asyncio
+import logging
+import os
+from collections.abc import Awaitable, Iterable
+from typing import Any, TypeVar
+
+from .dry_run import DryRunRequest
+
+logger = logging.getLogger(__name__)
+
+T = TypeVar("T")
+
+CONCURRENCY_ENV = "BIOMCP_CONCURRENCY"
+
+_concurrency_override: int | None = None
+
+
+def set_concurrency(limit: int | None) -> None:
+    """Set the global concurrency cap (None restores automatic defaults)."""
+    global _concurrency_override
+    if limit is not None and limit < 1:
+        raise ValueError("Concurrency limit must be at least 1")
+    _concurrency_override = limit
+
+
+def get_concurrency() -> int | None:
+    """Return the global concurrency cap, or None when set to auto."""
+    if _concurrency_override is not None:
+        return _concurrency_override
+
+    value = os.getenv(CONCURRENCY_ENV, "").strip()
+    if not value or value.lower() == "auto":
+        return None
+    try:
+        limit = int(value)
+    except ValueError:
+        logger.warning(f"Ignoring invalid {CONCURRENCY_ENV}={value!r}")
+        return None
+    return limit if limit >= 1 else None
+
+
+def resolve_concurrency(
+    concurrency: int | None, default: int | None
+) -> int | None:
+    """Pick the effective limit for one fan-out operation.
+
+    Precedence: explicit argument, then the global cap, then the
+    operation's own default. None means unbounded.
+    """
+    if concurrency is not None:
+        return max(1, concurrency)
+    global_limit = get_concurrency()
+    if global_limit is not None:
+        return global_limit
+    return default
+
+
+async def gather_limited(
+    aws: Iterable[Awaitable[T]],
+    limit: int | None,
+    return_exceptions: bool = False,
+) -> list[Any]:
+    """Like ``asyncio.gather`` but with at most ``limit`` awaitables running.
+
+    Results keep the input order. A limit of None runs everything at once.
+    A ``DryRunRequest`` is always raised, even with ``return_exceptions``,
+    so a dry run stops at the first resolved request instead of being
+    collected as one failed result among many.
+    """
+    aws = list(aws)
+    if limit is None or limit >= len(aws):
+        results = await asyncio.gather(
+            *aws, return_exceptions=return_exceptions
+        )
+    else:
+        semaphore = asyncio.Semaphore(limit)
+
+        async def _run(aw: Awaitable[T]) -> T:
+            async with semaphore:
+                return await aw
+
+        results = await asyncio.gather(
+            *(_run(aw) for aw in aws), return_exceptions=return_exceptions
+        )
+
+    for result in results:
+        if isinstance(result, DryRunRequest):
+            raise result
+    return list(results)
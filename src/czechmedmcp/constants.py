@@ -44,6 +44,41 @@ EUROPE_PMC_BASE_URL = "https://www.ebi.ac.uk/europepmc/webservices/rest/search"
 # External Variant APIs
 CBIOPORTAL_BASE_URL = "https://www.cbioportal.org/api"
 
+# UniProt REST API (protein entries and sequences)
+# https://www.uniprot.org/help/api
+UNIPROT_BASE_URL = "https://rest.uniprot.org/uniprotkb"
+UNIPROT_SEARCH_URL = f"{UNIPROT_BASE_URL}/search"
+
+# Reactome Content Service (pathways, reactions, diagrams)
+# https://reactome.org/ContentService/
+REACTOME_CONTENT_URL = "https://reactome.org/ContentService"
+REACTOME_BROWSER_URL = "https://reactome.org/PathwayBrowser/#/"
+
+# CPIC API (pharmacogenomic dosing guidelines)
+# https://api.cpicpgx.org/
+CPIC_API_URL = "https://api.cpicpgx.org/v1"
+CPIC_RECOMMENDATION_URL = f"{CPIC_API_URL}/recommendation_view"
+
+# InterPro API (protein domain annotations)
+# https://www.ebi.ac.uk/interpro/api/
+INTERPRO_BASE_URL = "https://www.ebi.ac.uk/interpro/api"
+
+# Monarch Initiative API (gene-disease associations)
+# https://api-v3.monarchinitiative.org/v3/docs
+MONARCH_API_URL = "https://api-v3.monarchinitiative.org/v3/api"
+
+# NIH iCite API (PubMed citation links)
+# https://icite.od.nih.gov/api
+ICITE_API_URL = "https://icite.od.nih.gov/api/pubs"
+
+# Open Targets Platform GraphQL API (target-disease association scores)
+# https://platform-docs.opentargets.org/data-access/graphql-api
+OPENTARGETS_GRAPHQL_URL = "https://api.platform.opentargets.org/api/v4/graphql"
+
+# GTEx Portal API (median gene expression per tissue)
+# https://gtexportal.org/api/v2/redoc
+GTEX_API_BASE_URL = "https://gtexportal.org/api/v2"
+
 # Czech Healthcare APIs
 SUKL_BASE_URL = "https://prehledy.sukl.cz"
 SUKL_API_URL = f"{SUKL_BASE_URL}/dlp/api"
@@ -103,6 +138,7 @@ PMC_BASE_URL = "https://www.ncbi.nlm.nih.gov/pmc/articles/"
 NCBI_PMC_CONVERTER_URL = (
     "https://pmc.ncbi.nlm.nih.gov/tools/idconv/api/v1/articles/"
 )
+NCBI_EUTILS_BASE_URL = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils"
 DOI_BASE_URL = "https://doi.org/"
 DBSNP_BASE_URL = "https://www.ncbi.nlm.nih.gov/snp/"
 CLINVAR_BASE_URL = "https://www.ncbi.nlm.nih.gov/clinvar/variation/"
@@ -164,7 +200,17 @@ MAX_WIDTH = 72  # Maximum width for text wrapping in console output
 SNIPPET_LENGTH = 200  # Maximum length for text snippets in search results
 
 # Genome Assembly
-DEFAULT_ASSEMBLY = "hg19"  # Default genome assembly for MyVariant.info API
+DEFAULT_ASSEMBLY = "hg38"  # Default genome assembly for MyVariant.info API
+ASSEMBLY_ALIASES = {
+    "hg19": "hg19",
+    "grch37": "hg19",
+    "hg38": "hg38",
+    "grch38": "hg38",
+}
+ASSEMBLY_LABELS = {
+    "hg19": "GRCh37 (hg19)",
+    "hg38": "GRCh38 (hg38)",
+}
 
 # Rate Limiting
 DEFAULT_RATE_LIMIT_PER_SECOND = 10.0
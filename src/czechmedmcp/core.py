@@ -1,5 +1,6 @@
 """Core module for CzechMedMCP containing shared resources."""
 
+from collections.abc import Callable
 from contextlib import asynccontextmanager
 from enum import Enum
 from typing import Any
@@ -101,3 +102,19 @@ httpx_logger.setLevel("WARN")
 
 # Set main logger level
 logger.setLevel("INFO")
+
+
+def stable_hit_order(
+    hits: list[dict[str, Any]], id_key: Callable[[dict[str, Any]], Any]
+) -> list[dict[str, Any]]:
+    """Order BioThings hits by relevance, breaking score ties by ID.
+
+    MyGene.info and MyChem.info may return hits with equal ``_score`` in
+    varying order, so repeated identical queries are not guaranteed to
+    give identical output. Sorting by score (highest first) and then by
+    ``id_key`` makes the order deterministic.
+    """
+    return sorted(
+        hits,
+        key=lambda hit: (-float(hit.get("_score") or 0), str(id_key(hit))),
+    )
@@ -17,6 +17,7 @@ from dataclasses import asdict, dataclass
 
 import httpx
 
+from czechmedmcp.concurrency import resolve_concurrency
 from czechmedmcp.constants import (
     BULK_DOWNLOAD_TIMEOUT,
     CACHE_TTL_DAY,
@@ -39,6 +40,7 @@ logger = logging.getLogger(__name__)
 _INDEX_CACHE_TTL = CACHE_TTL_DAY
 _INDEX_DISK_KEY = "sukl_drug_index_v1"
 _MIN_SUCCESS_RATIO = 0.50  # build succeeds if >= 50% fetched
+_DETAIL_CONCURRENCY = 20  # default parallel detail fetches
 
 
 @dataclass(frozen=True, slots=True)
@@ -247,9 +249,11 @@ async def _fetch_drug_list(
 
 async def _fetch_all_details(
     codes: list[str],
+    concurrency: int | None = None,
 ) -> list[DrugIndexEntry]:
     """Fetch details for all codes with bounded concurrency."""
-    sem = asyncio.Semaphore(20)
+    limit = resolve_concurrency(concurrency, _DETAIL_CONCURRENCY)
+    sem = asyncio.Semaphore(limit or _DETAIL_CONCURRENCY)
     entries: list[DrugIndexEntry] = []
     errors = 0
 
@@ -1,11 +1,14 @@
 """Disease information tools for CzechMedMCP."""
 
+from .filtered_search import DiseaseSearchFilters, search_diseases_filtered
 from .getter import get_disease
 from .search import search_diseases, get_disease_by_id, search_diseases_with_or
 
 __all__ = [
+    "DiseaseSearchFilters",
     "get_disease",
     "get_disease_by_id",
     "search_diseases",
+    "search_diseases_filtered",
     "search_diseases_with_or",
 ]
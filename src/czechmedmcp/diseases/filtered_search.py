@@ -0,0 +1,290 @@
+"""Disease search against MyDisease.info with epidemiology filters.
+
+Rare-disease designation is inferred from MONDO: a disease counts as rare
+when MONDO places it in a rare subset (GARD, NORD, Orphanet, ...) or
+cross-references it to Orphanet or GARD. Coverage is partial, so the
+filter is best-effort and applied after candidates are resolved; it can
+leave a page with fewer than ``page_size`` diseases.
+
+The gene filter switches the source to Monarch: the result list is the
+diseases associated with the gene, paginated server-side, optionally
+narrowed by a name query within the page.
+"""
+
+import json
+import logging
+from typing import Any
+
+from pydantic import BaseModel, Field, model_validator
+
+from .. import http_client
+from ..constants import compute_skip
+from ..integrations.biothings_client import MYDISEASE_QUERY_URL
+from ..render import result_count_line, search_page_payload
+from .monarch import fetch_all_gene_diseases, fetch_gene_diseases
+
+logger = logging.getLogger(__name__)
+
+SEARCH_FIELDS = [
+    "_id",
+    "name",
+    "mondo.label",
+    "mondo.definition",
+    "mondo.subset",
+    "mondo.xrefs.orphanet",
+    "mondo.xrefs.gard",
+]
+
+# Cross-reference namespaces that imply a rare-disease registry entry
+RARE_DISEASE_XREFS = ("orphanet", "gard")
+
+_DEFINITION_PREVIEW_LENGTH = 300
+
+
+class DiseaseSearchFilters(BaseModel):
+    """Optional filters applied to a MyDisease.info disease search."""
+
+    rare_only: bool = Field(
+        default=False,
+        description="Only return diseases designated rare (best-effort)",
+    )
+    gene: str | None = Field(
+        default=None,
+        description="Only diseases associated with this gene symbol "
+        "(Monarch Initiative)",
+    )
+
+    @model_validator(mode="after")
+    def check_gene_filter(self) -> "DiseaseSearchFilters":
+        """Monarch associations carry no rare-disease designation."""
+        if self.gene and self.rare_only:
+            raise ValueError("gene cannot be combined with rare_only")
+        return self
+
+    def is_empty(self) -> bool:
+        """Return True when no filter is set."""
+        return not self.rare_only and not self.gene
+
+
+def _mondo(hit: dict[str, Any]) -> dict[str, Any]:
+    """Return the MONDO block of a MyDisease hit as a dict."""
+    mondo = hit.get("mondo")
+    if isinstance(mondo, list):
+        mondo = mondo[0] if mondo else {}
+    return mondo if isinstance(mondo, dict) else {}
+
+
+def is_rare_disease(hit: dict[str, Any]) -> bool:
+    """Check whether MONDO designates a MyDisease hit as rare."""
+    mondo = _mondo(hit)
+
+    subsets = mondo.get("subset") or []
+    if isinstance(subsets, str):
+        subsets = [subsets]
+    if any("rare" in str(subset).lower() for subset in subsets):
+        return True
+
+    xrefs = mondo.get("xrefs") or {}
+    if isinstance(xrefs, dict):
+        return any(xrefs.get(ns) for ns in RARE_DISEASE_XREFS)
+    return False
+
+
+def matches_filters(
+    hit: dict[str, Any], filters: DiseaseSearchFilters
+) -> bool:
+    """Check a MyDisease hit against the filters."""
+    return not (filters.rare_only and not is_rare_disease(hit))
+
+
+def format_query_summary(
+    query: str | None, filters: DiseaseSearchFilters
+) -> str:
+    """Render a one-line summary of the query and active filters."""
+    parts = [f"Query: {query}"] if query else []
+    if filters.gene:
+        parts.append(f"Gene: {filters.gene}")
+    if filters.rare_only:
+        parts.append("Rare only (best-effort)")
+    return " | ".join(parts)
+
+
+def _hit_to_record(hit: dict[str, Any]) -> dict[str, Any]:
+    """Flatten a MyDisease hit into a compact search record."""
+    mondo = _mondo(hit)
+    record = {
+        "disease_id": hit.get("_id"),
+        "name": hit.get("name") or mondo.get("label"),
+        "definition": mondo.get("definition"),
+        "rare": is_rare_disease(hit),
+    }
+    return {k: v for k, v in record.items() if v is not None}
+
+
+def _format_results(
+    summary: str,
+    records: list[dict[str, Any]],
+    total: int,
+    post_filtered: bool = False,
+) -> str:
+    """Format disease search records as markdown."""
+    lines = ["# Disease Search Results", "", f"_{summary}_", ""]
+    if not records:
+        lines.append("No diseases found matching the search criteria.")
+        return "\n".join(lines)
+
+    lines.append(
+        result_count_line(len(records), total, "diseases", post_filtered)
+    )
+    lines.append("")
+    for record in records:
+        title = record.get("name") or record.get("disease_id", "Unknown")
+        lines.append(f"## {title}")
+        lines.append(f"- **ID**: {record.get('disease_id', 'N/A')}")
+        if record.get("rare"):
+            lines.append("- **Rare disease**: yes")
+        if record.get("association"):
+            lines.append(f"- **Association**: {record['association']}")
+        if record.get("definition"):
+            text = record["definition"]
+            if len(text) > _DEFINITION_PREVIEW_LENGTH:
+                text = text[:_DEFINITION_PREVIEW_LENGTH] + "..."
+            lines.append(f"- **Definition**: {text}")
+        lines.append("")
+    return "\n".join(lines).rstrip()
+
+
+def _matches_name(record: dict[str, Any], query: str | None) -> bool:
+    """Case-insensitive substring match of a query on the disease name."""
+    if not query:
+        return True
+    return query.lower() in str(record.get("name", "")).lower()
+
+
+async def search_diseases_filtered(
+    query: str | None,
+    filters: DiseaseSearchFilters | None = None,
+    page: int = 1,
+    page_size: int = 20,
+    output_json: bool = False,
+) -> str:
+    """Search MyDisease.info for diseases matching a query and filters.
+
+    Args:
+        query: Free-text disease query (optional with a gene filter)
+        filters: Optional result filters
+        page: Page number (1-based)
+        page_size: Number of results requested per page
+        output_json: Return JSON instead of formatted markdown
+
+    Returns:
+        Formatted markdown or JSON string with the matching diseases
+    """
+    filters = filters or DiseaseSearchFilters()
+    summary = format_query_summary(query, filters)
+
+    if filters.gene:
+        return await _search_gene_diseases(
+            query, filters.gene, summary, page, page_size, output_json
+        )
+
+    params = {
+        "q": query,
+        "fields": ",".join(SEARCH_FIELDS),
+        "size": page_size,
+        "from": compute_skip(page, page_size),
+    }
+
+    response, error = await http_client.request_api(
+        url=MYDISEASE_QUERY_URL,
+        request=params,
+        method="GET",
+        domain="mydisease",
+    )
+
+    if error:
+        error_msg = f"Error searching diseases: {error.message}"
+        if output_json:
+            return json.dumps({"error": error_msg}, indent=2)
+        return error_msg
+
+    hits = (response or {}).get("hits", [])
+    total = (response or {}).get("total", len(hits))
+    records = [_hit_to_record(h) for h in hits if matches_filters(h, filters)]
+    return _render(
+        summary,
+        records,
+        total,
+        page,
+        page_size,
+        output_json,
+        post_filtered=filters.rare_only,
+    )
+
+
+async def _search_gene_diseases(
+    query: str | None,
+    gene: str,
+    summary: str,
+    page: int,
+    page_size: int,
+    output_json: bool,
+) -> str:
+    """Page through a gene's Monarch diseases, optionally name-filtered.
+
+    With a query the gene's associations are fetched in full and
+    filtered by name before paging, so the total counts the matches
+    rather than all of the gene's associations.
+    """
+    skip = compute_skip(page, page_size)
+    if query:
+        records, upstream_total, error_msg = await fetch_all_gene_diseases(
+            gene
+        )
+    else:
+        records, upstream_total, error_msg = await fetch_gene_diseases(
+            gene, page_size, skip
+        )
+    if error_msg:
+        if output_json:
+            return json.dumps({"error": error_msg}, indent=2)
+        return error_msg
+    if not query:
+        return _render(
+            summary, records, upstream_total, page, page_size, output_json
+        )
+
+    # Associations beyond the scan limit were never checked
+    truncated = upstream_total > len(records)
+    matches = [r for r in records if _matches_name(r, query)]
+    return _render(
+        summary,
+        matches[skip : skip + page_size],
+        upstream_total if truncated else len(matches),
+        page,
+        page_size,
+        output_json,
+        post_filtered=truncated,
+    )
+
+
+def _render(
+    summary: str,
+    records: list[dict[str, Any]],
+    total: int,
+    page: int,
+    page_size: int,
+    output_json: bool,
+    post_filtered: bool = False,
+) -> str:
+    """Render a page of disease records as JSON or markdown.
+
+    ``post_filtered`` marks pages narrowed client-side, whose ``total``
+    is only the upstream count.
+    """
+    if output_json:
+        payload = search_page_payload(
+            summary, records, total, page, page_size, post_filtered
+        )
+        return json.dumps(payload, indent=2)
+    return _format_results(summary, records, total, post_filtered)
@@ -0,0 +1,58 @@
+"""GARD (Genetic and Rare Diseases Information Center) linkage.
+
+MONDO cross-references rare diseases to their GARD entries
+(``GARD:0007893``). GARD has no public API, so the section is built
+from the cross-reference alone: the GARD ID and a link to the entry's
+page, where GARD keeps its patient-oriented summary.
+"""
+
+from typing import Any
+
+from .. import ensure_list
+
+GARD_PREFIX = "GARD:"
+GARD_URL = "https://rarediseases.info.nih.gov/diseases/{number}"
+
+
+def normalize_gard_id(value: Any) -> str | None:
+    """Normalize a GARD cross-reference to ``GARD:0007893`` form."""
+    number = str(value).strip().upper().removeprefix(GARD_PREFIX)
+    if not number.isdigit():
+        return None
+    return f"{GARD_PREFIX}{int(number):07d}"
+
+
+def gard_records(*xref_blocks: Any) -> list[dict[str, str]]:
+    """Build GARD records from MyDisease cross-reference blocks."""
+    records: dict[str, dict[str, str]] = {}
+    for xrefs in xref_blocks:
+        if not isinstance(xrefs, dict):
+            continue
+        for value in ensure_list(xrefs.get("gard")):
+            gard_id = normalize_gard_id(value)
+            if gard_id and gard_id not in records:
+                number = int(gard_id.removeprefix(GARD_PREFIX))
+                records[gard_id] = {
+                    "gard_id": gard_id,
+                    "url": GARD_URL.format(number=number),
+                }
+    return list(records.values())
+
+
+def format_disease_gard(
+    records: list[dict[str, str]], error: str | None = None
+) -> str:
+    """Format the GARD entries as a markdown section."""
+    lines = ["## GARD", ""]
+    if error:
+        lines.append(f"GARD information unavailable: {error}")
+        return "\n".join(lines)
+
+    for record in records:
+        lines.append(f"- **{record['gard_id']}**: {record['url']}")
+    lines.append("")
+    lines.append(
+        "GARD pages summarize the disease for patients and families, with "
+        "symptoms, diagnosis and support organizations."
+    )
+    return "\n".join(lines)
@@ -8,9 +8,22 @@ from pydantic import Field
 
 from ..integrations import BioThingsClient
 from ..render import to_markdown
+from .gard import format_disease_gard, gard_records
+from .monarch import fetch_disease_models, format_disease_models
+from .treatments import fetch_disease_treatments, format_disease_treatments
 
 logger = logging.getLogger(__name__)
 
+# Optional sections rendered after the card instead of inside it
+SECTION_KEYS = (
+    "models",
+    "models_error",
+    "treatments",
+    "treatments_error",
+    "gard",
+    "gard_error",
+)
+
 
 def _add_disease_links(disease_info, result: dict) -> None:
     """Add helpful links to disease result."""
@@ -83,9 +96,45 @@ def _format_disease_output(disease_info, result: dict) -> None:
         result.pop("phenotypes", None)
 
 
+def _mondo_id(disease_info) -> str | None:
+    """Return the disease's MONDO identifier, if known."""
+    if isinstance(disease_info.mondo, dict):
+        mondo_id = disease_info.mondo.get("mondo")
+        if isinstance(mondo_id, str) and mondo_id.startswith("MONDO:"):
+            return mondo_id
+    if disease_info.disease_id.startswith("MONDO:"):
+        return disease_info.disease_id
+    return None
+
+
+def _mesh_id(disease_info) -> str | None:
+    """Return the disease's MeSH descriptor ID, if cross-referenced."""
+    mondo_xrefs = None
+    if isinstance(disease_info.mondo, dict):
+        mondo_xrefs = disease_info.mondo.get("xrefs")
+    for xrefs in (disease_info.xrefs, mondo_xrefs):
+        mesh = xrefs.get("mesh") if isinstance(xrefs, dict) else None
+        if isinstance(mesh, list):
+            mesh = mesh[0] if mesh else None
+        if isinstance(mesh, str) and mesh:
+            return mesh.removeprefix("MESH:")
+    return None
+
+
+def _gard_records(disease_info) -> list[dict[str, str]]:
+    """Return the disease's GARD entries from its cross-references."""
+    mondo_xrefs = None
+    if isinstance(disease_info.mondo, dict):
+        mondo_xrefs = disease_info.mondo.get("xrefs")
+    return gard_records(mondo_xrefs, disease_info.xrefs)
+
+
 async def get_disease(
     disease_id_or_name: str,
     output_json: bool = False,
+    models: bool = False,
+    treatments: bool = False,
+    gard: bool = False,
 ) -> str:
     """
     Get disease information from MyDisease.info.
@@ -93,6 +142,9 @@ async def get_disease(
     Args:
         disease_id_or_name: Disease ID (MONDO, DOID) or name (e.g., "melanoma", "MONDO:0016575")
         output_json: Return as JSON instead of markdown
+        models: Add animal models (genotype, allele, phenotypes) from Monarch
+        treatments: Add drugs indicated for the disease, ranked by phase
+        gard: Add the disease's GARD rare-disease entries
 
     Returns:
         Disease information as markdown or JSON string
@@ -122,10 +174,56 @@ async def get_disease(
         # Format output for display
         _format_disease_output(disease_info, result)
 
+        models_section = None
+        if models:
+            mondo_id = _mondo_id(disease_info)
+            model_records, models_error = (
+                await fetch_disease_models(mondo_id)
+                if mondo_id
+                else ([], "no MONDO identifier for this disease")
+            )
+            result["models"] = model_records
+            if models_error:
+                result["models_error"] = models_error
+            models_section = format_disease_models(
+                model_records, models_error
+            )
+
+        treatments_section = None
+        if treatments:
+            treatment_records, treatments_error = (
+                await fetch_disease_treatments(
+                    disease_info.name or disease_id_or_name,
+                    _mesh_id(disease_info),
+                )
+            )
+            result["treatments"] = treatment_records
+            if treatments_error:
+                result["treatments_error"] = treatments_error
+            treatments_section = format_disease_treatments(
+                treatment_records, treatments_error
+            )
+
+        gard_section = None
+        if gard:
+            records = _gard_records(disease_info)
+            gard_error = (
+                None if records else "no GARD cross-reference for this disease"
+            )
+            result["gard"] = records
+            if gard_error:
+                result["gard_error"] = gard_error
+            gard_section = format_disease_gard(records, gard_error)
+
         if output_json:
             return json.dumps(result, indent=2)
-        else:
-            return to_markdown([result])
+
+        card = {k: v for k, v in result.items() if k not in SECTION_KEYS}
+        markdown = to_markdown([card])
+        for section in (models_section, treatments_section, gard_section):
+            if section:
+                markdown += "\n" + section + "\n"
+        return markdown
 
     except Exception as e:
         logger.error(
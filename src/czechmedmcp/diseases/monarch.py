@@ -0,0 +1,318 @@
+"""Gene-to-disease and animal model lookups against the Monarch API.
+
+A gene symbol is first resolved to its human HGNC identifier with the
+Monarch entity search, then the gene's disease associations (causal and
+correlated) are paged through the association endpoint.
+
+Animal models of a disease are the model organism genotypes (MGI, ZFIN,
+...) Monarch associates with it. Each model's phenotypes are fetched
+alongside, and those whose label also appears among the disease's own
+phenotypes are reported as shared.
+"""
+
+import logging
+import re
+from typing import Any
+
+from .. import http_client
+from ..concurrency import gather_limited, resolve_concurrency
+from ..constants import MONARCH_API_URL
+
+logger = logging.getLogger(__name__)
+
+HUMAN_TAXON = "NCBITaxon:9606"
+
+GENE_DISEASE_CATEGORIES = [
+    "biolink:CausalGeneToDiseaseAssociation",
+    "biolink:CorrelatedGeneToDiseaseAssociation",
+]
+
+MODEL_CATEGORY = "biolink:GenotypeToDiseaseAssociation"
+MODEL_PHENOTYPE_CATEGORY = "biolink:GenotypeToPhenotypicFeatureAssociation"
+DISEASE_PHENOTYPE_CATEGORY = "biolink:DiseaseToPhenotypicFeatureAssociation"
+
+# Phenotypes listed per model
+MODEL_PHENOTYPE_LIMIT = 20
+# Disease phenotypes compared against model phenotypes
+DISEASE_PHENOTYPE_LIMIT = 500
+MODEL_CONCURRENCY = 5
+
+# Largest page the association endpoint serves
+ASSOCIATION_PAGE_LIMIT = 500
+# Most gene-disease associations scanned when filtering them client-side
+GENE_DISEASE_SCAN_LIMIT = 2000
+
+# Allele in a genotype label, e.g. "Pax6<Sey>" or "shha<tbx392>"
+ALLELE_PATTERN = re.compile(r"([A-Za-z0-9][\w.\-]*)<([^<>]+)>")
+
+
+async def resolve_gene_id(symbol: str) -> tuple[str | None, str | None]:
+    """Resolve a human gene symbol to its Monarch (HGNC) identifier.
+
+    Returns:
+        Tuple of (gene CURIE or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=f"{MONARCH_API_URL}/search",
+        request={"q": symbol, "category": "biolink:Gene", "limit": 20},
+        method="GET",
+        domain="monarch",
+    )
+    if error:
+        return None, f"Error resolving gene {symbol}: {error.message}"
+
+    for item in (response or {}).get("items") or []:
+        if (
+            isinstance(item, dict)
+            and str(item.get("name", "")).upper() == symbol.strip().upper()
+            and item.get("in_taxon") == HUMAN_TAXON
+        ):
+            return item.get("id"), None
+    return None, f"No human gene found in Monarch for '{symbol}'"
+
+
+def _association_to_record(item: dict[str, Any]) -> dict[str, Any]:
+    """Flatten a Monarch gene-disease association into a search record."""
+    predicate = str(item.get("predicate") or "")
+    record = {
+        "disease_id": item.get("object"),
+        "name": item.get("object_label"),
+        "association": predicate.removeprefix("biolink:") or None,
+        "source": item.get("primary_knowledge_source"),
+    }
+    return {k: v for k, v in record.items() if v is not None}
+
+
+async def fetch_gene_diseases(
+    symbol: str, limit: int, offset: int = 0
+) -> tuple[list[dict[str, Any]], int, str | None]:
+    """Fetch one page of diseases associated with a gene.
+
+    Returns:
+        Tuple of (disease records, total associations, error message)
+    """
+    gene_id, error = await resolve_gene_id(symbol)
+    if error or gene_id is None:
+        return [], 0, error
+    return await _fetch_association_page(gene_id, symbol, limit, offset)
+
+
+async def fetch_all_gene_diseases(
+    symbol: str, concurrency: int | None = None
+) -> tuple[list[dict[str, Any]], int, str | None]:
+    """Fetch all of a gene's disease associations, for client-side filters.
+
+    At most ``GENE_DISEASE_SCAN_LIMIT`` associations are fetched; compare
+    the record count with the returned total to detect truncation.
+
+    Returns:
+        Tuple of (disease records, total associations, error message)
+    """
+    gene_id, error = await resolve_gene_id(symbol)
+    if error or gene_id is None:
+        return [], 0, error
+    records, total, error = await _fetch_association_page(
+        gene_id, symbol, ASSOCIATION_PAGE_LIMIT
+    )
+    if error:
+        return [], 0, error
+
+    offsets = range(
+        ASSOCIATION_PAGE_LIMIT,
+        min(total, GENE_DISEASE_SCAN_LIMIT),
+        ASSOCIATION_PAGE_LIMIT,
+    )
+    pages = await gather_limited(
+        (
+            _fetch_association_page(
+                gene_id, symbol, ASSOCIATION_PAGE_LIMIT, offset
+            )
+            for offset in offsets
+        ),
+        resolve_concurrency(concurrency, MODEL_CONCURRENCY),
+    )
+    for page_records, _, page_error in pages:
+        if page_error:
+            return [], 0, page_error
+        records.extend(page_records)
+    return records, total, None
+
+
+async def _fetch_association_page(
+    gene_id: str, symbol: str, limit: int, offset: int = 0
+) -> tuple[list[dict[str, Any]], int, str | None]:
+    """Fetch one page of a resolved gene's disease associations."""
+    response, request_error = await http_client.request_api(
+        url=f"{MONARCH_API_URL}/association",
+        request={
+            "subject": gene_id,
+            "category": GENE_DISEASE_CATEGORIES,
+            "limit": limit,
+            "offset": offset,
+        },
+        method="GET",
+        domain="monarch",
+    )
+    if request_error:
+        return (
+            [],
+            0,
+            f"Error fetching diseases for {symbol}: {request_error.message}",
+        )
+
+    items = [
+        item
+        for item in (response or {}).get("items") or []
+        if isinstance(item, dict)
+    ]
+    total = (response or {}).get("total", len(items))
+    return [_association_to_record(item) for item in items], total, None
+
+
+def parse_genotype_label(label: str) -> tuple[str | None, str | None]:
+    """Extract the gene symbol and first allele from a genotype label."""
+    match = ALLELE_PATTERN.search(label or "")
+    if not match:
+        return None, None
+    return match.group(1), f"{match.group(1)}<{match.group(2)}>"
+
+
+async def _fetch_labels(
+    request: dict[str, Any], what: str
+) -> tuple[list[str], str | None]:
+    """Fetch association object labels (deduplicated, in order)."""
+    response, error = await http_client.request_api(
+        url=f"{MONARCH_API_URL}/association",
+        request=request,
+        method="GET",
+        domain="monarch",
+    )
+    if error:
+        return [], f"Error fetching {what}: {error.message}"
+    labels: list[str] = []
+    for item in (response or {}).get("items") or []:
+        if not isinstance(item, dict):
+            continue
+        label = item.get("object_label")
+        if label and label not in labels:
+            labels.append(label)
+    return labels, None
+
+
+def _normalize_phenotype(label: str) -> str:
+    return " ".join(label.lower().split())
+
+
+async def fetch_disease_models(
+    disease_id: str, limit: int = 10
+) -> tuple[list[dict[str, Any]], str | None]:
+    """Fetch animal models of a disease with their phenotypes.
+
+    Returns:
+        Tuple of (model records, error message or None). A disease
+        without models yields an empty list, not an error.
+    """
+    response, error = await http_client.request_api(
+        url=f"{MONARCH_API_URL}/association",
+        request={
+            "object": disease_id,
+            "category": MODEL_CATEGORY,
+            "limit": limit,
+        },
+        method="GET",
+        domain="monarch",
+    )
+    if error:
+        return [], f"Error fetching models for {disease_id}: {error.message}"
+
+    items = [
+        item
+        for item in (response or {}).get("items") or []
+        if isinstance(item, dict) and item.get("subject")
+    ]
+    if not items:
+        return [], None
+
+    disease_phenotypes, _ = await _fetch_labels(
+        {
+            "subject": disease_id,
+            "category": DISEASE_PHENOTYPE_CATEGORY,
+            "limit": DISEASE_PHENOTYPE_LIMIT,
+        },
+        f"phenotypes of {disease_id}",
+    )
+    disease_keys = {_normalize_phenotype(p) for p in disease_phenotypes}
+
+    phenotype_results = await gather_limited(
+        (
+            _fetch_labels(
+                {
+                    "subject": item["subject"],
+                    "category": MODEL_PHENOTYPE_CATEGORY,
+                    "limit": MODEL_PHENOTYPE_LIMIT,
+                },
+                f"phenotypes of {item['subject']}",
+            )
+            for item in items
+        ),
+        resolve_concurrency(None, MODEL_CONCURRENCY),
+    )
+
+    models: list[dict[str, Any]] = []
+    for item, (phenotypes, phenotype_error) in zip(
+        items, phenotype_results, strict=True
+    ):
+        if phenotype_error:
+            logger.debug(phenotype_error)
+        label = item.get("subject_label") or item["subject"]
+        gene, allele = parse_genotype_label(label)
+        record = {
+            "organism": item.get("subject_taxon_label"),
+            "gene": gene,
+            "allele": allele,
+            "genotype": label,
+            "genotype_id": item["subject"],
+            "phenotypes": phenotypes,
+            "shared_phenotypes": [
+                p
+                for p in phenotypes
+                if _normalize_phenotype(p) in disease_keys
+            ],
+            "url": f"https://monarchinitiative.org/{item['subject']}",
+        }
+        models.append({k: v for k, v in record.items() if v is not None})
+    return models, None
+
+
+def format_disease_models(
+    models: list[dict[str, Any]], error: str | None = None
+) -> str:
+    """Format animal models as a markdown section."""
+    lines = ["## Animal Models", ""]
+    if error:
+        lines.append(f"Animal models unavailable: {error}")
+        return "\n".join(lines)
+    if not models:
+        lines.append("No animal models found in Monarch.")
+        return "\n".join(lines)
+
+    for model in models:
+        title = model.get("allele") or model["genotype"]
+        if model.get("organism"):
+            title += f" ({model['organism']})"
+        lines.append(f"### {title}")
+        if model.get("gene"):
+            lines.append(f"- **Gene**: {model['gene']}")
+        lines.append(f"- **Genotype**: {model['genotype']}")
+        if model.get("shared_phenotypes"):
+            lines.append(
+                "- **Shared with disease**: "
+                + ", ".join(model["shared_phenotypes"])
+            )
+        if model.get("phenotypes"):
+            lines.append(
+                f"- **Phenotypes**: {', '.join(model['phenotypes'])}"
+            )
+        lines.append(f"- **Monarch**: {model['url']}")
+        lines.append("")
+    return "\n".join(lines).rstrip()
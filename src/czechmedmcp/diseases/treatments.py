@@ -0,0 +1,149 @@
+"""Drugs indicated for a disease, from ChEMBL indications in MyChem.info.
+
+ChEMBL records each drug's indications with a MeSH descriptor and the
+highest clinical trial phase reached for that indication
+(``max_phase_for_ind``; 4 means approved). Searching MyChem.info on the
+indication's MeSH ID, or its heading when the disease has no MeSH
+cross-reference, gives the drugs for a disease; the phase is reported as
+the evidence level and drugs are ranked by it.
+"""
+
+from typing import Any
+
+from .. import http_client
+from ..integrations.biothings_client import MYCHEM_QUERY_URL
+
+# Drugs listed in the treatment table
+TREATMENT_LIMIT = 25
+
+TREATMENT_FIELDS = ["name", "chembl.pref_name", "chembl.drug_indications"]
+
+TREATMENT_SOURCE = "ChEMBL"
+
+PHASE_LABELS = {
+    4: "Approved",
+    3: "Phase 3",
+    2: "Phase 2",
+    1: "Phase 1",
+    0.5: "Early Phase 1",
+}
+
+
+def _indications(hit: dict[str, Any]) -> list[dict[str, Any]]:
+    chembl = hit.get("chembl")
+    if isinstance(chembl, list):
+        chembl = chembl[0] if chembl else {}
+    indications = (chembl or {}).get("drug_indications") or []
+    if isinstance(indications, dict):
+        indications = [indications]
+    return [item for item in indications if isinstance(item, dict)]
+
+
+def _drug_name(hit: dict[str, Any]) -> str | None:
+    chembl = hit.get("chembl")
+    if isinstance(chembl, list):
+        chembl = chembl[0] if chembl else {}
+    name = (chembl or {}).get("pref_name") or hit.get("name")
+    return name.title() if isinstance(name, str) else None
+
+
+def _phase(value: Any) -> float | None:
+    try:
+        return float(value)
+    except (TypeError, ValueError):
+        return None
+
+
+def _matches(item: dict[str, Any], mesh_id: str | None, name: str) -> bool:
+    if mesh_id:
+        return item.get("mesh_id") == mesh_id
+    return str(item.get("mesh_heading") or "").lower() == name.lower()
+
+
+def indication_query(name: str, mesh_id: str | None = None) -> str:
+    """Build the MyChem.info query for drugs indicated for a disease."""
+    if mesh_id:
+        return f"chembl.drug_indications.mesh_id:{mesh_id}"
+    heading = name.replace('"', "")
+    return f'chembl.drug_indications.mesh_heading:"{heading}"'
+
+
+def rank_treatments(
+    hits: list[dict[str, Any]], name: str, mesh_id: str | None = None
+) -> list[dict[str, Any]]:
+    """Turn MyChem hits into treatment records, highest phase first."""
+    best: dict[str, float | None] = {}
+    for hit in hits:
+        drug = _drug_name(hit)
+        if not drug:
+            continue
+        phases = [
+            _phase(item.get("max_phase_for_ind"))
+            for item in _indications(hit)
+            if _matches(item, mesh_id, name)
+        ]
+        known = [phase for phase in phases if phase is not None]
+        phase = max(known) if known else None
+        if drug not in best or (phase or 0) > (best[drug] or 0):
+            best[drug] = phase
+
+    ranked = sorted(best.items(), key=lambda item: (-(item[1] or 0), item[0]))
+    return [
+        {
+            "drug": drug,
+            "evidence_level": PHASE_LABELS.get(phase) if phase else None,
+            "source": TREATMENT_SOURCE,
+        }
+        for drug, phase in ranked
+    ]
+
+
+async def fetch_disease_treatments(
+    name: str, mesh_id: str | None = None
+) -> tuple[list[dict[str, Any]], str | None]:
+    """Fetch the drugs indicated for a disease, ranked by phase.
+
+    Returns:
+        Tuple of (treatment records, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=MYCHEM_QUERY_URL,
+        request={
+            "q": indication_query(name, mesh_id),
+            "fields": ",".join(TREATMENT_FIELDS),
+            "size": 100,
+        },
+        method="GET",
+        domain="mychem",
+    )
+    if error:
+        return [], f"Error fetching treatments: {error.message}"
+    hits = (response or {}).get("hits") or []
+    return rank_treatments(hits, name, mesh_id), None
+
+
+def format_disease_treatments(
+    treatments: list[dict[str, Any]], error: str | None = None
+) -> str:
+    """Format the treatments as a ranked markdown table."""
+    lines = ["## Treatments", ""]
+    if error:
+        lines.append(f"Treatments unavailable: {error}")
+        return "\n".join(lines)
+    if not treatments:
+        lines.append("No drugs with a ChEMBL indication for this disease.")
+        return "\n".join(lines)
+
+    lines.append("| Rank | Drug | Evidence | Source |")
+    lines.append("|------|------|----------|--------|")
+    for rank, record in enumerate(treatments[:TREATMENT_LIMIT], start=1):
+        evidence = record.get("evidence_level") or "Unknown"
+        lines.append(
+            f"| {rank} | {record['drug']} | {evidence} | {record['source']} |"
+        )
+    if len(treatments) > TREATMENT_LIMIT:
+        lines.append("")
+        lines.append(
+            f"Showing top {TREATMENT_LIMIT} of {len(treatments)} drugs."
+        )
+    return "\n".join(lines)
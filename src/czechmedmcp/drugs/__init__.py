@@ -1,5 +1,23 @@
 """Drug information tools using MyChem.info."""
 
 from .getter import get_drug
+from .search import (
+    ApprovalStatus,
+    DrugSearchFilters,
+    DrugSort,
+    MoaClass,
+    TargetAction,
+    search_drugs,
+)
+from .targets import get_drug_targets
 
-__all__ = ["get_drug"]
+__all__ = [
+    "ApprovalStatus",
+    "DrugSearchFilters",
+    "DrugSort",
+    "get_drug",
+    "get_drug_targets",
+    "MoaClass",
+    "TargetAction",
+    "search_drugs",
+]
@@ -6,8 +6,15 @@ import re
 
 from ..integrations import BioThingsClient
 from ..integrations.biothings_client import (
+    DrugInfo,
     search_drug_by_name,
 )
+from ..openfda.boxed_warning import format_boxed_warning, get_boxed_warning
+from ..openfda.drug_formulations import (
+    format_formulations,
+    get_drug_formulations,
+)
+from .pharmacology import format_pharmacology, parse_pharmacology
 
 logger = logging.getLogger(__name__)
 
@@ -61,6 +68,11 @@ def _format_basic_info(drug_info, output_lines: list[str]) -> None:
     if drug_info.inchikey:
         output_lines.append(f"- **InChIKey**: {drug_info.inchikey}")
 
+    if drug_info.groups:
+        output_lines.append(
+            f"- **Approval Status**: {', '.join(drug_info.groups)}"
+        )
+
 
 def _format_clinical_info(drug_info, output_lines: list[str]) -> None:
     """Format clinical drug information."""
@@ -104,28 +116,46 @@ def _format_drug_output(drug_info, result: dict) -> None:
     result["_formatted"] = "\n".join(output_lines)
 
 
-async def get_drug(drug_id_or_name: str, output_json: bool = False) -> str:
+async def resolve_drug_info(drug_id_or_name: str) -> DrugInfo | None:
+    """Look up a drug by ID, falling back to a name search."""
+    client = BioThingsClient()
+    drug_info = await client.get_drug_info(drug_id_or_name)
+
+    # Fallback: if result has name "Unknown" and input
+    # looks like a common name (not a DB/CHEMBL ID),
+    # search by name to get a proper ID and retry.
+    _is_id = re.match(r"^(DB\d+|CHEMBL\d+)", drug_id_or_name, re.I)
+    if not _is_id and (not drug_info or not drug_info.name):
+        resolved_id = await search_drug_by_name(drug_id_or_name)
+        if resolved_id:
+            drug_info = await client.get_drug_info(resolved_id)
+    return drug_info
+
+
+async def get_drug(
+    drug_id_or_name: str,
+    output_json: bool = False,
+    include_formulations: bool = False,
+    route: str | None = None,
+    include_pharmacology: bool = False,
+    include_boxed_warning: bool = False,
+) -> str:
     """Get drug information from MyChem.info.
 
     Args:
         drug_id_or_name: Drug ID (DrugBank, ChEMBL, etc.) or name
         output_json: Return JSON instead of formatted text
+        include_formulations: Add marketed formulations from openFDA
+        route: Limit formulations to one route (e.g., ORAL)
+        include_pharmacology: Add DrugBank's pharmacokinetics and
+            pharmacodynamics (absorption, half-life, metabolism, ...)
+        include_boxed_warning: Add the boxed warning of the FDA label
 
     Returns:
         Formatted drug information or JSON string
     """
     try:
-        client = BioThingsClient()
-        drug_info = await client.get_drug_info(drug_id_or_name)
-
-        # Fallback: if result has name "Unknown" and input
-        # looks like a common name (not a DB/CHEMBL ID),
-        # search by name to get a proper ID and retry.
-        _is_id = re.match(r"^(DB\d+|CHEMBL\d+)", drug_id_or_name, re.I)
-        if not _is_id and (not drug_info or not drug_info.name):
-            resolved_id = await search_drug_by_name(drug_id_or_name)
-            if resolved_id:
-                drug_info = await client.get_drug_info(resolved_id)
+        drug_info = await resolve_drug_info(drug_id_or_name)
 
         if not drug_info:
             error_msg = f"Drug '{drug_id_or_name}' not found in MyChem.info"
@@ -139,12 +169,54 @@ async def get_drug(drug_id_or_name: str, output_json: bool = False) -> str:
         # Add external links
         _add_drug_links(drug_info, result)
 
+        # Raw DrugBank pharmacology is only returned on request, structured
+        pharmacology_section = None
+        result.pop("pharmacology", None)
+        if include_pharmacology:
+            pharmacology = parse_pharmacology(drug_info.pharmacology)
+            result["pharmacology"] = pharmacology.model_dump(exclude_none=True)
+            pharmacology_section = format_pharmacology(pharmacology)
+
+        formulations_section = None
+        if include_formulations:
+            formulations, error = await get_drug_formulations(
+                drug_info.name or drug_id_or_name, route=route
+            )
+            if error:
+                result["formulations_error"] = error
+                formulations_section = f"### Formulations\n\n{error}"
+            else:
+                result["formulations"] = formulations
+                formulations_section = format_formulations(
+                    formulations or [], route=route
+                )
+
+        boxed_warning_section = None
+        if include_boxed_warning:
+            warning, error = await get_boxed_warning(
+                drug_info.name or drug_id_or_name
+            )
+            if error or warning is None:
+                result["boxed_warning"] = {"error": error}
+                boxed_warning_section = f"### Boxed Warning\n\n{error}"
+            else:
+                result["boxed_warning"] = warning
+                boxed_warning_section = format_boxed_warning(warning)
+
         if output_json:
             return json.dumps(result, indent=2)
 
         # Format for text output
         _format_drug_output(drug_info, result)
-        return result["_formatted"]
+        sections = [result["_formatted"]]
+        # The boxed warning leads, as on the label itself
+        if boxed_warning_section:
+            sections.insert(0, boxed_warning_section)
+        if pharmacology_section:
+            sections.append(pharmacology_section)
+        if formulations_section:
+            sections.append(formulations_section)
+        return "\n\n".join(sections)
 
     except Exception as e:
         logger.error(f"Error getting drug info: {e}")
@@ -0,0 +1,76 @@
+"""Clinical pharmacology (PK/PD) of a drug from MyChem.info.
+
+The ADME and pharmacodynamics texts come from the DrugBank section of
+the MyChem.info record that ``drug get`` already retrieves, so showing
+them costs no extra request. DrugBank keeps them as free text (e.g.
+half-life "Approximately 18 hours"), which is passed through as-is.
+"""
+
+from typing import Any
+
+from pydantic import BaseModel
+
+# Longest text shown per field in markdown; JSON keeps the full text
+PHARMACOLOGY_PREVIEW_LENGTH = 500
+
+
+class DrugPharmacology(BaseModel):
+    """Pharmacokinetic and pharmacodynamic properties of a drug."""
+
+    absorption: str | None = None
+    half_life: str | None = None
+    metabolism: str | None = None
+    route_of_elimination: str | None = None
+    clearance: str | None = None
+    volume_of_distribution: str | None = None
+    protein_binding: str | None = None
+    pharmacodynamics: str | None = None
+
+
+PHARMACOLOGY_LABELS = {
+    "absorption": "Absorption",
+    "half_life": "Half-life",
+    "metabolism": "Metabolism",
+    "route_of_elimination": "Route of elimination",
+    "clearance": "Clearance",
+    "volume_of_distribution": "Volume of distribution",
+    "protein_binding": "Protein binding",
+    "pharmacodynamics": "Pharmacodynamics",
+}
+
+
+def _text(value: Any) -> str | None:
+    if isinstance(value, list):
+        value = " ".join(str(item).strip() for item in value if item)
+    if value is None:
+        return None
+    return str(value).strip() or None
+
+
+def parse_pharmacology(
+    pharmacology: dict[str, Any] | None,
+) -> DrugPharmacology:
+    """Build the PK/PD record from DrugBank's pharmacology section."""
+    pharmacology = pharmacology or {}
+    return DrugPharmacology(**{
+        field: _text(pharmacology.get(field))
+        for field in PHARMACOLOGY_LABELS
+    })
+
+
+def format_pharmacology(pharmacology: DrugPharmacology) -> str:
+    """Format the PK/PD record as a labeled markdown block."""
+    lines = ["### Pharmacology"]
+    values = pharmacology.model_dump(exclude_none=True)
+    if not values:
+        lines.append("No pharmacokinetic data available in DrugBank.")
+        return "\n".join(lines)
+
+    for field, label in PHARMACOLOGY_LABELS.items():
+        text = values.get(field)
+        if not text:
+            continue
+        if len(text) > PHARMACOLOGY_PREVIEW_LENGTH:
+            text = text[:PHARMACOLOGY_PREVIEW_LENGTH] + "..."
+        lines.append(f"- **{label}**: {text}")
+    return "\n".join(lines)
@@ -0,0 +1,512 @@
+"""Drug search against MyChem.info with DrugBank-backed filters."""
+
+import json
+import logging
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from .. import StrEnum, http_client
+from ..constants import compute_skip
+from ..core import stable_hit_order
+from ..integrations.biothings_client import (
+    MYCHEM_QUERY_URL,
+    _extract_name_from_hit,
+)
+from ..render import result_count_line, search_page_payload
+
+logger = logging.getLogger(__name__)
+
+
+class ApprovalStatus(StrEnum):
+    APPROVED = "approved"
+    INVESTIGATIONAL = "investigational"
+    WITHDRAWN = "withdrawn"
+
+
+class DrugSort(StrEnum):
+    NAME = "name"
+    MAX_PHASE = "max-phase"
+    APPROVAL_DATE = "approval-date"
+
+
+class TargetAction(StrEnum):
+    """DrugBank action of a drug on its target."""
+
+    INHIBITOR = "inhibitor"
+    AGONIST = "agonist"
+    ANTAGONIST = "antagonist"
+    PARTIAL_AGONIST = "partial agonist"
+    INVERSE_AGONIST = "inverse agonist"
+    ACTIVATOR = "activator"
+    BLOCKER = "blocker"
+    MODULATOR = "modulator"
+    BINDER = "binder"
+    INDUCER = "inducer"
+    SUBSTRATE = "substrate"
+
+
+class MoaClass(StrEnum):
+    """Mechanism-of-action class of a drug."""
+
+    KINASE_INHIBITOR = "kinase inhibitor"
+    IMMUNE_CHECKPOINT_INHIBITOR = "immune checkpoint inhibitor"
+    PARP_INHIBITOR = "parp inhibitor"
+    PROTEASOME_INHIBITOR = "proteasome inhibitor"
+    AROMATASE_INHIBITOR = "aromatase inhibitor"
+    STATIN = "statin"
+    PROTON_PUMP_INHIBITOR = "proton pump inhibitor"
+    ACE_INHIBITOR = "ace inhibitor"
+    BETA_BLOCKER = "beta blocker"
+    SSRI = "ssri"
+    NSAID = "nsaid"
+    CORTICOSTEROID = "corticosteroid"
+    OPIOID_AGONIST = "opioid agonist"
+    FACTOR_XA_INHIBITOR = "factor xa inhibitor"
+
+
+# DrugCentral carries the FDA Established Pharmacologic Class (EPC), a
+# controlled vocabulary; each MoA class maps to the EPC terms it covers
+MOA_CLASS_EPC_TERMS: dict[MoaClass, list[str]] = {
+    MoaClass.KINASE_INHIBITOR: ["Kinase Inhibitor"],
+    MoaClass.IMMUNE_CHECKPOINT_INHIBITOR: [
+        "Programmed Death Receptor-1 Blocking Antibody",
+        "Programmed Death Ligand-1 Blocking Antibody",
+        "Cytotoxic T-Lymphocyte Antigen-4 Blocking Antibody",
+    ],
+    MoaClass.PARP_INHIBITOR: ["Poly(ADP-Ribose) Polymerase Inhibitor"],
+    MoaClass.PROTEASOME_INHIBITOR: ["Proteasome Inhibitor"],
+    MoaClass.AROMATASE_INHIBITOR: ["Aromatase Inhibitor"],
+    MoaClass.STATIN: ["HMG-CoA Reductase Inhibitor"],
+    MoaClass.PROTON_PUMP_INHIBITOR: ["Proton Pump Inhibitor"],
+    MoaClass.ACE_INHIBITOR: ["Angiotensin Converting Enzyme Inhibitor"],
+    MoaClass.BETA_BLOCKER: ["beta-Adrenergic Blocker"],
+    MoaClass.SSRI: ["Serotonin Reuptake Inhibitor"],
+    MoaClass.NSAID: ["Nonsteroidal Anti-inflammatory Drug"],
+    MoaClass.CORTICOSTEROID: ["Corticosteroid"],
+    MoaClass.OPIOID_AGONIST: ["Opioid Agonist"],
+    MoaClass.FACTOR_XA_INHIBITOR: ["Factor Xa Inhibitor"],
+}
+
+
+SEARCH_FIELDS = [
+    "_id",
+    "name",
+    "drugbank.id",
+    "drugbank.name",
+    "drugbank.groups",
+    "chembl.molecule_chembl_id",
+    "chembl.pref_name",
+    "chembl.max_phase",
+    "chembl.first_approval",
+    "unii.display_name",
+    "chebi.name",
+]
+
+# Fetched only for target filters, since target lists can be long
+TARGET_FIELDS = ["drugbank.targets.gene_name", "drugbank.targets.actions"]
+
+# Fetched only for the MoA class filter
+EPC_FIELD = "drugcentral.pharmacology_class.fda_epc.description"
+
+
+class DrugSearchFilters(BaseModel):
+    """Optional filters applied to a MyChem.info drug search."""
+
+    approval_status: ApprovalStatus | None = Field(
+        default=None,
+        description="DrugBank approval group (approved, investigational, withdrawn)",
+    )
+    target: str | None = Field(
+        default=None,
+        description="Gene symbol of a DrugBank target (e.g. EGFR)",
+    )
+    target_action: TargetAction | None = Field(
+        default=None,
+        description="DrugBank action on the target (e.g. inhibitor)",
+    )
+    moa_class: MoaClass | None = Field(
+        default=None,
+        description="Mechanism-of-action class, matched on the DrugCentral "
+        "FDA Established Pharmacologic Class",
+    )
+    sort: DrugSort | None = Field(
+        default=None,
+        description="Result order (name, max-phase, approval-date)",
+    )
+
+    def has_target_filter(self) -> bool:
+        """Return True when a target or target action filter is set."""
+        return bool(self.target) or self.target_action is not None
+
+    def is_empty(self) -> bool:
+        """Return True when no filter or sort is set."""
+        return (
+            self.approval_status is None
+            and self.sort is None
+            and self.moa_class is None
+            and not self.has_target_filter()
+        )
+
+    def has_client_filters(self) -> bool:
+        """Return True when hits are filtered after MyChem returns them."""
+        return (
+            self.approval_status is not None
+            or self.moa_class is not None
+            or self.has_target_filter()
+        )
+
+
+# Hits fetched and ranked together when sorting, so later pages follow
+# the same order as the first
+SORT_WINDOW = 100
+
+# Largest page MyChem.info serves in one request
+MYCHEM_MAX_SIZE = 1000
+
+
+def build_mychem_query(query: str, filters: DrugSearchFilters) -> str:
+    """Translate a free-text query and filters into a MyChem.info query.
+
+    Approval status is pushed down to MyChem as a ``drugbank.groups``
+    clause so only DrugBank-annotated records come back. Target filters
+    become ``drugbank.targets`` clauses; whether the action applies to
+    the requested target is checked by ``matches_filters``. The MoA
+    class becomes an OR of its exact FDA EPC terms.
+    """
+    clauses = [query.strip()] if query.strip() else []
+    if filters.approval_status:
+        clauses.append(f"drugbank.groups:{filters.approval_status.value}")
+    if filters.target:
+        clauses.append(
+            f"drugbank.targets.gene_name:{filters.target.strip().upper()}"
+        )
+    if filters.target_action:
+        clauses.append(
+            f'drugbank.targets.actions:"{filters.target_action.value}"'
+        )
+    if filters.moa_class:
+        terms = " OR ".join(
+            f'{EPC_FIELD}:"{term}"'
+            for term in MOA_CLASS_EPC_TERMS[filters.moa_class]
+        )
+        clauses.append(f"({terms})")
+    return " AND ".join(clauses) or "*"
+
+
+def _hit_groups(hit: dict[str, Any]) -> list[str]:
+    """Return the lower-cased DrugBank groups of a MyChem hit."""
+    drugbank = hit.get("drugbank")
+    if isinstance(drugbank, list):
+        drugbank = drugbank[0] if drugbank else {}
+    if not isinstance(drugbank, dict):
+        return []
+    groups = drugbank.get("groups") or []
+    if isinstance(groups, str):
+        groups = [groups]
+    return [g.lower() for g in groups if isinstance(g, str)]
+
+
+def _hit_targets(hit: dict[str, Any]) -> list[tuple[str, list[str]]]:
+    """Return (upper-cased gene, lower-cased actions) per DrugBank target."""
+    drugbank = hit.get("drugbank")
+    if isinstance(drugbank, list):
+        drugbank = drugbank[0] if drugbank else {}
+    if not isinstance(drugbank, dict):
+        return []
+    targets = drugbank.get("targets") or []
+    if isinstance(targets, dict):
+        targets = [targets]
+    result: list[tuple[str, list[str]]] = []
+    for target in targets:
+        if not isinstance(target, dict):
+            continue
+        actions = target.get("actions") or []
+        if isinstance(actions, str):
+            actions = [actions]
+        gene = str(target.get("gene_name") or "").upper()
+        result.append(
+            (gene, [a.lower() for a in actions if isinstance(a, str)])
+        )
+    return result
+
+
+def _hit_epc_classes(hit: dict[str, Any]) -> list[str]:
+    """Return the DrugCentral FDA EPC descriptions of a MyChem hit."""
+    drugcentral = hit.get("drugcentral")
+    if isinstance(drugcentral, list):
+        drugcentral = drugcentral[0] if drugcentral else {}
+    if not isinstance(drugcentral, dict):
+        return []
+    epc = (drugcentral.get("pharmacology_class") or {}).get("fda_epc") or []
+    if isinstance(epc, dict):
+        epc = [epc]
+    return [
+        str(entry["description"])
+        for entry in epc
+        if isinstance(entry, dict) and entry.get("description")
+    ]
+
+
+def matches_filters(hit: dict[str, Any], filters: DrugSearchFilters) -> bool:
+    """Check a MyChem hit against the filters (post-filter safety net).
+
+    With both ``target`` and ``target_action`` set, the action must be
+    annotated on that target, not on another target of the drug.
+    """
+    if (
+        filters.approval_status
+        and filters.approval_status.value not in _hit_groups(hit)
+    ):
+        return False
+    if filters.moa_class:
+        wanted = {t.lower() for t in MOA_CLASS_EPC_TERMS[filters.moa_class]}
+        if not any(c.lower() in wanted for c in _hit_epc_classes(hit)):
+            return False
+    if filters.has_target_filter():
+        gene = (filters.target or "").strip().upper()
+        action = filters.target_action.value if filters.target_action else ""
+        return any(
+            (not gene or target_gene == gene)
+            and (not action or action in actions)
+            for target_gene, actions in _hit_targets(hit)
+        )
+    return True
+
+
+def format_query_summary(query: str, filters: DrugSearchFilters) -> str:
+    """Render a one-line summary of the query and active filters."""
+    parts = [f"Query: {query}"]
+    if filters.approval_status:
+        parts.append(f"Approval status: {filters.approval_status.value}")
+    if filters.target:
+        parts.append(f"Target: {filters.target.strip().upper()}")
+    if filters.target_action:
+        parts.append(f"Target action: {filters.target_action.value}")
+    if filters.moa_class:
+        parts.append(f"MoA class: {filters.moa_class.value}")
+    if filters.sort:
+        parts.append(f"Sort: {filters.sort.value}")
+    return " | ".join(parts)
+
+
+def _hit_to_record(hit: dict[str, Any]) -> dict[str, Any]:
+    """Flatten a MyChem hit into a compact search record."""
+    drugbank = hit.get("drugbank")
+    if isinstance(drugbank, list):
+        drugbank = drugbank[0] if drugbank else {}
+    chembl = hit.get("chembl")
+    if isinstance(chembl, list):
+        chembl = chembl[0] if chembl else {}
+    record: dict[str, Any] = {
+        "drug_id": hit.get("_id"),
+        "name": _extract_name_from_hit(hit),
+        "drugbank_id": (drugbank or {}).get("id"),
+        "chembl_id": (chembl or {}).get("molecule_chembl_id"),
+        "groups": _hit_groups(hit),
+        "max_phase": (chembl or {}).get("max_phase"),
+        "first_approval": (chembl or {}).get("first_approval"),
+        "moa_classes": _hit_epc_classes(hit),
+    }
+    return {k: v for k, v in record.items() if v not in (None, "", [])}
+
+
+def sort_fetch_size(page: int, page_size: int) -> int:
+    """Number of hits to fetch from offset 0 to sort up to a given page."""
+    return min(max(page * page_size, SORT_WINDOW), MYCHEM_MAX_SIZE)
+
+
+def sort_page_error(page: int, page_size: int) -> str | None:
+    """Explain why a sorted page lies beyond ``MYCHEM_MAX_SIZE``.
+
+    A sort ranks one fetch from offset 0, so it can only page through the
+    hits that fit in a single MyChem.info request.
+    """
+    if page * page_size <= MYCHEM_MAX_SIZE:
+        return None
+    last_page = max(MYCHEM_MAX_SIZE // page_size, 1)
+    return (
+        f"Sorted drug searches rank at most the top {MYCHEM_MAX_SIZE} "
+        f"matches, enough for {last_page} pages of {page_size}; "
+        f"page {page} is beyond them. Narrow the search instead."
+    )
+
+
+def sort_window_note(
+    sort: DrugSort, total: int, window: int
+) -> str | None:
+    """Explain a client-side sort that saw only part of the matches.
+
+    Sorting happens after fetching, over the first ``window`` hits in
+    relevance order, so matches beyond it are never ranked.
+    """
+    if total <= window:
+        return None
+    return (
+        f"Sorted by {sort.value} within the top {window} of {total} "
+        "matches by relevance; narrow the query to rank all of them."
+    )
+
+
+def _name_key(record: dict[str, Any]) -> tuple[bool, str, str]:
+    name = record.get("name")
+    return (
+        not name,
+        str(name or "").casefold(),
+        str(record.get("drug_id", "")),
+    )
+
+
+def _numeric(value: Any) -> float | None:
+    try:
+        return float(value)
+    except (TypeError, ValueError):
+        return None
+
+
+def sort_records(
+    records: list[dict[str, Any]], sort: DrugSort
+) -> list[dict[str, Any]]:
+    """Order search records; ties and missing values fall back to name.
+
+    Name sorts ascending and case-insensitively; max phase and approval
+    year sort descending. Records missing the sort value come last.
+    """
+    if sort == DrugSort.NAME:
+        return sorted(records, key=_name_key)
+
+    field = "max_phase" if sort == DrugSort.MAX_PHASE else "first_approval"
+
+    def key(record: dict[str, Any]) -> tuple[bool, float, tuple]:
+        value = _numeric(record.get(field))
+        return (value is None, -(value or 0.0), _name_key(record))
+
+    return sorted(records, key=key)
+
+
+def _format_results(
+    summary: str,
+    records: list[dict[str, Any]],
+    total: int,
+    post_filtered: bool = False,
+    sort_note: str | None = None,
+) -> str:
+    """Format drug search records as markdown."""
+    lines = ["# Drug Search Results", "", f"_{summary}_", ""]
+    if not records:
+        lines.append("No drugs found matching the search criteria.")
+        return "\n".join(lines)
+
+    lines.append(
+        result_count_line(len(records), total, "drugs", post_filtered)
+    )
+    if sort_note:
+        lines.append(f"_{sort_note}_")
+    lines.append("")
+    for record in records:
+        name = record.get("name") or record.get("drug_id", "Unknown")
+        lines.append(f"## {name}")
+        if record.get("drugbank_id"):
+            lines.append(f"- **DrugBank ID**: {record['drugbank_id']}")
+        if record.get("chembl_id"):
+            lines.append(f"- **ChEMBL ID**: {record['chembl_id']}")
+        if record.get("groups"):
+            lines.append(f"- **Groups**: {', '.join(record['groups'])}")
+        if record.get("max_phase") is not None:
+            lines.append(f"- **Max phase**: {record['max_phase']}")
+        if record.get("first_approval"):
+            lines.append(f"- **First approval**: {record['first_approval']}")
+        if record.get("moa_classes"):
+            classes = ", ".join(record["moa_classes"])
+            lines.append(f"- **Pharmacologic class**: {classes}")
+        lines.append("")
+    return "\n".join(lines).rstrip()
+
+
+async def search_drugs(
+    query: str,
+    filters: DrugSearchFilters | None = None,
+    page: int = 1,
+    page_size: int = 10,
+    output_json: bool = False,
+    stable_sort: bool = True,
+) -> str:
+    """Search MyChem.info for drugs matching a query and filters.
+
+    MyChem.info returns hits in relevance order. With ``filters.sort``
+    the first ``sort_fetch_size`` hits are fetched and ranked together
+    before the requested page is sliced out; when there are more
+    matches than that, the output says so, and pages past
+    ``MYCHEM_MAX_SIZE`` are rejected. Otherwise, with
+    ``stable_sort``, hits with equal relevance scores are ordered by ID
+    so repeated identical searches give identical output.
+
+    Args:
+        query: Free-text drug query (name, trade name, or ID)
+        filters: Optional DrugBank-backed filters and sort order
+        page: Page number (1-based)
+        page_size: Number of results per page
+        output_json: Return JSON instead of formatted markdown
+        stable_sort: Break relevance ties by MyChem ID
+
+    Returns:
+        Formatted markdown or JSON string with the matching drugs
+    """
+    filters = filters or DrugSearchFilters()
+    if filters.sort:
+        page_error = sort_page_error(page, page_size)
+        if page_error:
+            error_msg = f"Error searching drugs: {page_error}"
+            if output_json:
+                return json.dumps({"error": error_msg}, indent=2)
+            return error_msg
+    summary = format_query_summary(query, filters)
+    params = {
+        "q": build_mychem_query(query, filters),
+        "fields": ",".join(
+            SEARCH_FIELDS
+            + (TARGET_FIELDS if filters.has_target_filter() else [])
+            + ([EPC_FIELD] if filters.moa_class else [])
+        ),
+        "size": page_size,
+        "from": compute_skip(page, page_size),
+    }
+    if filters.sort:
+        params["size"] = sort_fetch_size(page, page_size)
+        params["from"] = 0
+
+    response, error = await http_client.request_api(
+        url=MYCHEM_QUERY_URL,
+        request=params,
+        method="GET",
+        domain="mychem",
+    )
+
+    if error:
+        error_msg = f"Error searching drugs: {error.message}"
+        if output_json:
+            return json.dumps({"error": error_msg}, indent=2)
+        return error_msg
+
+    hits = (response or {}).get("hits", [])
+    total = (response or {}).get("total", len(hits))
+    if stable_sort and not filters.sort:
+        hits = stable_hit_order(hits, lambda h: h.get("_id", ""))
+    records = [_hit_to_record(h) for h in hits if matches_filters(h, filters)]
+    sort_note = None
+    if filters.sort:
+        skip = compute_skip(page, page_size)
+        records = sort_records(records, filters.sort)[skip : skip + page_size]
+        sort_note = sort_window_note(filters.sort, total, params["size"])
+
+    post_filtered = filters.has_client_filters()
+    if output_json:
+        payload = search_page_payload(
+            summary, records, total, page, page_size, post_filtered
+        )
+        if sort_note:
+            payload["sort_note"] = sort_note
+        return json.dumps(payload, indent=2)
+    return _format_results(
+        summary, records, total, post_filtered, sort_note
+    )
@@ -0,0 +1,97 @@
+"""Molecular targets of a drug from MyChem.info.
+
+Targets come from the DrugBank section of the MyChem.info record that
+``drug get`` already retrieves, so listing them costs no extra request.
+Each target carries its gene symbol, UniProt accession and the drug's
+action on it (e.g. inhibitor, agonist).
+"""
+
+import json
+from typing import Any
+
+from pydantic import BaseModel
+
+from .getter import resolve_drug_info
+
+
+class DrugTarget(BaseModel):
+    """A molecular target of a drug."""
+
+    gene: str | None = None
+    uniprot: str | None = None
+    name: str | None = None
+    action: str | None = None
+
+
+def parse_drug_targets(targets: list[dict[str, Any]]) -> list[DrugTarget]:
+    """Convert DrugBank target entries into target records."""
+    records: list[DrugTarget] = []
+    for target in targets:
+        actions = target.get("actions") or []
+        if isinstance(actions, str):
+            actions = [actions]
+        records.append(
+            DrugTarget(
+                gene=target.get("gene_name"),
+                uniprot=target.get("uniprot"),
+                name=target.get("name"),
+                action=", ".join(a.lower() for a in actions) or None,
+            )
+        )
+    return records
+
+
+def _format_targets(
+    drug: str, targets: list[DrugTarget], total: int, offset: int
+) -> str:
+    """Format target records as a markdown table."""
+    lines = [f"# Drug Targets: {drug}", ""]
+    if not targets:
+        lines.append("No molecular targets found.")
+        return "\n".join(lines)
+
+    lines.append(
+        f"Showing {offset + 1}-{offset + len(targets)} of {total} targets."
+    )
+    lines.append("")
+    lines.append("| Gene | UniProt | Target | Action |")
+    lines.append("|------|---------|--------|--------|")
+    for target in targets:
+        lines.append(
+            f"| {target.gene or ''} | {target.uniprot or ''} | "
+            f"{target.name or ''} | {target.action or ''} |"
+        )
+    return "\n".join(lines)
+
+
+async def get_drug_targets(
+    drug_id_or_name: str,
+    limit: int = 20,
+    offset: int = 0,
+    output_json: bool = False,
+) -> str:
+    """List the molecular targets of a drug.
+
+    Args:
+        drug_id_or_name: Drug name or ID (DrugBank, ChEMBL, etc.)
+        limit: Maximum number of targets to return
+        offset: Number of targets to skip
+        output_json: Return JSON instead of formatted markdown
+
+    Returns:
+        Markdown table or JSON list of {gene, uniprot, name, action} records
+    """
+    drug_info = await resolve_drug_info(drug_id_or_name)
+    if not drug_info:
+        error = f"Drug '{drug_id_or_name}' not found in MyChem.info"
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return error
+
+    targets = parse_drug_targets(drug_info.targets or [])
+    page = targets[offset : offset + limit]
+    if output_json:
+        return json.dumps([t.model_dump() for t in page], indent=2)
+    return _format_targets(
+        drug_info.name or drug_id_or_name, page, len(targets), offset
+    )
@@ -0,0 +1,91 @@
+"""Dry-run mode: resolve a command's upstream request without sending it.
+
+With ``--dry-run`` (or ``BIOMCP_DRY_RUN=true``) every command still parses
+and normalizes its arguments and builds the API query, but the first call
+to ``http_client.request_api`` stops execution by raising
+``DryRunRequest``. The CLI catches it and prints the resolved request.
+
+``DryRunRequest`` derives from ``BaseException`` so the broad
+``except Exception`` handlers around searches cannot turn it into an
+ordinary error message. ``asyncio.gather(return_exceptions=True)`` still
+collects it as a result, so ``concurrency.gather_limited`` re-raises it.
+Only requests made through ``request_api`` are intercepted.
+"""
+
+import json
+import os
+import re
+from typing import Any
+
+DRY_RUN_ENV = "BIOMCP_DRY_RUN"
+
+# Parameter names whose values are masked in dry-run output
+_SECRET_PARAM_PATTERN = re.compile(r"key|token|secret|password", re.I)
+
+_dry_run_override: bool | None = None
+
+
+def set_dry_run(enabled: bool | None) -> None:
+    """Enable or disable dry-run mode (None defers to the environment)."""
+    global _dry_run_override
+    _dry_run_override = enabled
+
+
+def is_dry_run() -> bool:
+    """Return True when upstream requests must not be sent."""
+    if _dry_run_override is not None:
+        return _dry_run_override
+    return os.getenv(DRY_RUN_ENV, "").lower() in ("true", "1", "yes")
+
+
+def _redact(params: Any) -> Any:
+    """Mask credential values in request parameters."""
+    if not isinstance(params, dict):
+        return params
+    return {
+        name: "***" if _SECRET_PARAM_PATTERN.search(str(name)) else value
+        for name, value in params.items()
+    }
+
+
+class DryRunRequest(BaseException):
+    """Raised instead of sending a request while dry-run mode is on."""
+
+    def __init__(
+        self,
+        method: str,
+        url: str,
+        params: Any,
+        domain: str | None = None,
+    ):
+        super().__init__(f"Dry run: {method} {url}")
+        self.method = method
+        self.url = url
+        self.params = _redact(params)
+        self.domain = domain
+
+    def to_dict(self) -> dict[str, Any]:
+        """Return the resolved request as a JSON-serializable dict."""
+        return {
+            "dry_run": True,
+            "method": self.method,
+            "url": self.url,
+            "domain": self.domain,
+            "params": self.params,
+        }
+
+    def render(self, output_json: bool = False) -> str:
+        """Render the resolved request for the terminal."""
+        if output_json:
+            return json.dumps(self.to_dict(), indent=2, default=str)
+
+        lines = ["Dry run - no request sent.", f"{self.method} {self.url}"]
+        if self.domain:
+            lines.append(f"Domain: {self.domain}")
+        if isinstance(self.params, dict) and self.params:
+            lines.append("Parameters:")
+            for name, value in self.params.items():
+                lines.append(f"  {name}: {value}")
+        elif self.params:
+            lines.append(f"Body: {self.params}")
+        return "\n".join(lines)
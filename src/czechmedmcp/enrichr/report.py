@@ -0,0 +1,99 @@
+"""Standalone Enrichr analysis of a gene set.
+
+The gene set is either a list given directly or the genes of a Reactome
+pathway; the source is carried into the report so the output says which
+it was. Enriching a pathway's own genes is a quick way to see how it
+lines up with the pathway and ontology databases Enrichr indexes.
+"""
+
+import json
+from typing import Any
+
+from .client import EnrichmentTerm, EnrichrClient
+from .databases import get_database_name
+
+# Terms shown in the markdown table by default
+DEFAULT_TERM_LIMIT = 10
+
+
+def _gene_count(genes: list[str]) -> str:
+    return f"{len(genes)} gene" + ("" if len(genes) == 1 else "s")
+
+
+def gene_list_source(genes: list[str]) -> str:
+    """Describe a directly supplied gene list."""
+    return f"Gene list ({_gene_count(genes)})"
+
+
+def pathway_source(pathway_id: str, genes: list[str]) -> str:
+    """Describe a gene set expanded from a Reactome pathway."""
+    return f"Reactome pathway {pathway_id} ({_gene_count(genes)})"
+
+
+def enrich_markdown(
+    source: str,
+    database: str,
+    terms: list[EnrichmentTerm],
+    limit: int = DEFAULT_TERM_LIMIT,
+) -> str:
+    """Format enrichment terms as markdown headed by the gene set source."""
+    lines = [f"# Enrichment: {source}", "", f"_Database: {database}_", ""]
+    if not terms:
+        lines.append("No significant enrichment terms found.")
+        return "\n".join(lines)
+
+    lines.append("| Rank | Term | Adj. p-value | Overlapping genes |")
+    lines.append("|------|------|--------------|-------------------|")
+    for term in terms[:limit]:
+        genes = ", ".join(term.overlapping_genes)
+        lines.append(
+            f"| {term.rank} | {term.path_name} | {term.adj_p_val:.3g} "
+            f"| {genes} |"
+        )
+    if len(terms) > limit:
+        lines.extend(["", f"Showing {limit} of {len(terms)} terms."])
+    return "\n".join(lines)
+
+
+async def enrichment_report(
+    genes: list[str],
+    database: str,
+    source: str,
+    output_json: bool = False,
+    limit: int = DEFAULT_TERM_LIMIT,
+) -> str:
+    """Run Enrichr on a gene set and render the terms.
+
+    Args:
+        genes: Gene symbols to analyze
+        database: Database category (e.g., "pathway") or full Enrichr name
+        source: Description of where the gene set came from
+        output_json: Return JSON instead of formatted markdown
+        limit: Number of terms shown in markdown (JSON returns all)
+    """
+    try:
+        database_name = get_database_name(database)
+    except ValueError as e:
+        error = str(e)
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return f"Error: {error}"
+
+    terms = await EnrichrClient().enrich(
+        genes=genes, database=database_name, description=source
+    )
+    if terms is None:
+        error = "Failed to retrieve enrichment results from Enrichr API"
+        if output_json:
+            return json.dumps({"error": error, "source": source}, indent=2)
+        return f"Error: {error}"
+
+    if output_json:
+        data: dict[str, Any] = {
+            "source": source,
+            "genes": genes,
+            "database": database_name,
+            "enrichment_terms": [term.model_dump() for term in terms],
+        }
+        return json.dumps(data, indent=2)
+    return enrich_markdown(source, database_name, terms, limit)
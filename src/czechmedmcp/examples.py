@@ -0,0 +1,149 @@
+"""Enumerate the usage examples of every CLI command.
+
+Each command documents itself with an ``Examples:`` section in its
+docstring, which Typer shows in ``--help``. The examples are collected
+from the registered commands rather than kept in a second copy, so
+``czechmedmcp list --examples`` always matches the help text. An example
+line is either a bare command, a command preceded by ``# description``
+comment lines, or ``Description: command``.
+"""
+
+import inspect
+import re
+from collections.abc import Callable, Iterator
+from dataclasses import dataclass
+from typing import Any
+
+PROGRAM = "czechmedmcp"
+
+SECTION_HEADER = "Examples:"
+
+# "Description: czechmedmcp ..." lines in the variant commands
+LABELLED_EXAMPLE = re.compile(r"^(?P<label>[^:]+):\s+(?P<command>.+)$")
+
+
+@dataclass
+class CliCommand:
+    """A registered CLI command and its docstring."""
+
+    entity: str
+    name: str | None
+    doc: str
+
+    @property
+    def path(self) -> str:
+        """Command as typed after the program name, e.g. ``gene get``."""
+        return f"{self.entity} {self.name}" if self.name else self.entity
+
+
+@dataclass
+class CliExample:
+    """One example invocation from a command's help text."""
+
+    entity: str
+    command: str
+    text: str
+    description: str | None = None
+
+
+def _command_name(info: Any) -> str:
+    callback: Callable = info.callback
+    return info.name or callback.__name__.replace("_", "-")
+
+
+def collect_commands(app: Any) -> list[CliCommand]:
+    """List the visible commands of a Typer app, one level of groups deep.
+
+    Top-level commands (``batch``, ``pipe``...) are their own entity.
+    """
+    commands = [
+        CliCommand(
+            _command_name(info), None, inspect.getdoc(info.callback) or ""
+        )
+        for info in app.registered_commands
+        if not info.hidden
+    ]
+    for group in app.registered_groups:
+        if group.hidden:
+            continue
+        commands.extend(
+            CliCommand(
+                group.name,
+                _command_name(info),
+                inspect.getdoc(info.callback) or "",
+            )
+            for info in group.typer_instance.registered_commands
+            if not info.hidden
+        )
+    return commands
+
+
+def _example_lines(doc: str) -> Iterator[str]:
+    """Yield the stripped lines of a docstring's ``Examples:`` section."""
+    lines = inspect.cleandoc(doc).splitlines()
+    for start, line in enumerate(lines):
+        if line.strip() == SECTION_HEADER:
+            indent = len(line) - len(line.lstrip())
+            for item in lines[start + 1 :]:
+                stripped = item.strip()
+                if stripped and len(item) - len(item.lstrip()) <= indent:
+                    return
+                yield stripped
+            return
+
+
+def parse_examples(doc: str) -> list[tuple[str | None, str]]:
+    """Extract ``(description, command)`` pairs from a docstring.
+
+    A ``# comment`` describes the command on the next line; a blank line
+    ends the description. Lines that do not run the CLI are skipped.
+    """
+    examples: list[tuple[str | None, str]] = []
+    description: str | None = None
+    for line in _example_lines(doc):
+        if not line:
+            description = None
+        elif line.startswith("#"):
+            description = line.lstrip("#").strip() or None
+        elif PROGRAM in line:
+            labelled = LABELLED_EXAMPLE.match(line)
+            if labelled and PROGRAM not in labelled["label"]:
+                examples.append((
+                    labelled["label"].strip(),
+                    labelled["command"].strip(),
+                ))
+            else:
+                examples.append((description, line))
+            description = None
+    return examples
+
+
+def collect_examples(
+    commands: list[CliCommand], entity: str | None = None
+) -> list[CliExample]:
+    """Gather the examples of all commands, or of one entity's commands."""
+    return [
+        CliExample(command.entity, command.path, text, description)
+        for command in commands
+        if entity is None or command.entity == entity
+        for description, text in parse_examples(command.doc)
+    ]
+
+
+def format_example_list(examples: list[CliExample]) -> str:
+    """Render examples as a flat list, one command per line."""
+    return "\n".join(example.text for example in examples)
+
+
+def format_example_script(examples: list[CliExample]) -> str:
+    """Render examples as a commented shell script, grouped by command."""
+    lines = ["#!/bin/sh", f"# {PROGRAM} CLI examples"]
+    current = None
+    for example in examples:
+        if example.command != current:
+            current = example.command
+            lines.extend(["", f"# --- {PROGRAM} {current} ---"])
+        if example.description:
+            lines.append(f"# {example.description}")
+        lines.append(example.text)
+    return "\n".join(lines)
@@ -1,5 +1,12 @@
 """Gene information tools for CzechMedMCP."""
 
 from .getter import get_gene
+from .search import GeneMatch, GeneSearchFilters, GoMode, search_genes
 
-__all__ = ["get_gene"]
+__all__ = [
+    "GeneMatch",
+    "GeneSearchFilters",
+    "GoMode",
+    "get_gene",
+    "search_genes",
+]
@@ -0,0 +1,283 @@
+"""Gene-disease association scores from the Open Targets Platform.
+
+Open Targets scores how strongly each disease is associated with a
+target (0-1), overall and per evidence data type such as genetic
+association, somatic mutation, known drug or literature. Targets are
+keyed by Ensembl gene ID, which MyGene.info already returns for
+``gene get``.
+
+The reverse direction, the targets associated with a disease, backs the
+``gene search --disease`` filter. Diseases are keyed by ontology IDs in
+Open Targets form (``MONDO_0005105``); a disease name is first resolved
+with the Open Targets search.
+"""
+
+import re
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from .. import http_client
+from ..constants import OPENTARGETS_GRAPHQL_URL
+
+# Largest page the Open Targets associatedDiseases query serves
+MAX_ASSOCIATIONS = 100
+
+ASSOCIATIONS_QUERY = """
+query GeneDiseaseAssociations($ensemblId: String!, $size: Int!) {
+  target(ensemblId: $ensemblId) {
+    associatedDiseases(page: {index: 0, size: $size}) {
+      count
+      rows {
+        disease { id name dbXRefs }
+        score
+        datatypeScores { id score }
+      }
+    }
+  }
+}
+"""
+
+
+DISEASE_SEARCH_QUERY = """
+query DiseaseLookup($name: String!) {
+  search(
+    queryString: $name
+    entityNames: ["disease"]
+    page: {index: 0, size: 1}
+  ) {
+    hits { id name }
+  }
+}
+"""
+
+DISEASE_TARGETS_QUERY = """
+query DiseaseTargets($efoId: String!, $size: Int!) {
+  disease(efoId: $efoId) {
+    id
+    name
+    associatedTargets(page: {index: 0, size: $size}) {
+      count
+      rows {
+        target { id approvedSymbol }
+        score
+      }
+    }
+  }
+}
+"""
+
+# Ontology CURIEs Open Targets keys diseases by, in either separator form
+DISEASE_ID_PATTERN = re.compile(
+    r"^(MONDO|EFO|Orphanet|HP|DOID|OTAR)[:_](\w+)$", re.IGNORECASE
+)
+
+
+class DiseaseAssociation(BaseModel):
+    """A disease associated with a gene and its Open Targets scores."""
+
+    disease: str
+    mondo_id: str | None = None
+    score: float
+    datatypes: dict[str, float] = Field(default_factory=dict)
+
+
+def _mondo_id(disease: dict[str, Any]) -> str | None:
+    """Return the MONDO CURIE of an Open Targets disease, if known."""
+    disease_id = disease.get("id") or ""
+    if disease_id.startswith("MONDO_"):
+        return disease_id.replace("_", ":", 1)
+    for xref in disease.get("dbXRefs") or []:
+        if isinstance(xref, str) and xref.upper().startswith("MONDO:"):
+            return f"MONDO:{xref.split(':', 1)[1]}"
+    return None
+
+
+def parse_associations(
+    rows: list[dict[str, Any]],
+) -> list[DiseaseAssociation]:
+    """Convert association rows, ordered by overall score (highest first).
+
+    Data types that did not contribute (score 0) are left out.
+    """
+    associations = []
+    for row in rows:
+        disease = row.get("disease") or {}
+        datatypes = {
+            item["id"]: item["score"]
+            for item in row.get("datatypeScores") or []
+            if item.get("id") and item.get("score")
+        }
+        associations.append(
+            DiseaseAssociation(
+                disease=disease.get("name") or disease.get("id", ""),
+                mondo_id=_mondo_id(disease),
+                score=row.get("score") or 0.0,
+                datatypes=dict(
+                    sorted(datatypes.items(), key=lambda kv: -kv[1])
+                ),
+            )
+        )
+    return sorted(associations, key=lambda a: -a.score)
+
+
+async def fetch_disease_associations(
+    ensembl_id: str, limit: int
+) -> tuple[list[DiseaseAssociation] | None, str | None]:
+    """Fetch the top-scoring diseases associated with an Ensembl gene.
+
+    Returns:
+        Tuple of (associations or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=OPENTARGETS_GRAPHQL_URL,
+        request={
+            "query": ASSOCIATIONS_QUERY,
+            "variables": {
+                "ensemblId": ensembl_id,
+                "size": min(limit, MAX_ASSOCIATIONS),
+            },
+        },
+        method="POST",
+        domain="opentargets",
+    )
+    if error:
+        return None, f"Error fetching Open Targets data: {error.message}"
+    errors = (response or {}).get("errors")
+    if errors:
+        message = errors[0].get("message", "unknown error")
+        return None, f"Open Targets query failed: {message}"
+
+    target = ((response or {}).get("data") or {}).get("target")
+    if not target:
+        return None, f"Target {ensembl_id} not found in Open Targets"
+    rows = (target.get("associatedDiseases") or {}).get("rows") or []
+    return parse_associations(rows), None
+
+
+def format_associations(associations: list[DiseaseAssociation]) -> str:
+    """Format associations as a markdown section with a score table."""
+    lines = ["## Disease Associations (Open Targets)", ""]
+    if not associations:
+        lines.append("No associated diseases found.")
+        return "\n".join(lines)
+
+    lines.append("| Disease | MONDO | Score | Data types |")
+    lines.append("|---------|-------|-------|------------|")
+    for association in associations:
+        datatypes = ", ".join(
+            f"{name} ({score:.2f})"
+            for name, score in association.datatypes.items()
+        )
+        lines.append(
+            f"| {association.disease} | {association.mondo_id or ''} | "
+            f"{association.score:.3f} | {datatypes} |"
+        )
+    return "\n".join(lines)
+
+
+class TargetAssociation(BaseModel):
+    """A gene associated with a disease and its overall Open Targets score."""
+
+    symbol: str
+    ensembl_id: str
+    score: float
+
+
+def opentargets_disease_id(value: str) -> str | None:
+    """Convert a disease CURIE (``MONDO:0005105``) to Open Targets form.
+
+    Returns None when the value is not an ontology ID (i.e. a name).
+    """
+    match = DISEASE_ID_PATTERN.match(value.strip())
+    if not match:
+        return None
+    prefix = match.group(1).upper()
+    if prefix == "ORPHANET":
+        prefix = "Orphanet"
+    return f"{prefix}_{match.group(2)}"
+
+
+async def _opentargets_query(
+    query: str, variables: dict[str, Any]
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Run an Open Targets GraphQL query and return its data block."""
+    response, error = await http_client.request_api(
+        url=OPENTARGETS_GRAPHQL_URL,
+        request={"query": query, "variables": variables},
+        method="POST",
+        domain="opentargets",
+    )
+    if error:
+        return None, f"Error fetching Open Targets data: {error.message}"
+    errors = (response or {}).get("errors")
+    if errors:
+        message = errors[0].get("message", "unknown error")
+        return None, f"Open Targets query failed: {message}"
+    return (response or {}).get("data") or {}, None
+
+
+async def resolve_disease_id(disease: str) -> tuple[str | None, str | None]:
+    """Resolve a disease name or CURIE to an Open Targets disease ID.
+
+    Returns:
+        Tuple of (disease ID or None, error message or None)
+    """
+    disease_id = opentargets_disease_id(disease)
+    if disease_id:
+        return disease_id, None
+    data, error = await _opentargets_query(
+        DISEASE_SEARCH_QUERY, {"name": disease}
+    )
+    if error:
+        return None, error
+    hits = ((data or {}).get("search") or {}).get("hits") or []
+    if not hits or not hits[0].get("id"):
+        return None, f"Disease '{disease}' not found in Open Targets"
+    return hits[0]["id"], None
+
+
+async def fetch_disease_targets(
+    disease: str, min_score: float = 0.0, limit: int = MAX_ASSOCIATIONS
+) -> tuple[list[TargetAssociation] | None, int, str | None]:
+    """Fetch the top-scoring genes associated with a disease.
+
+    Genes scoring below ``min_score`` are dropped. At most ``limit``
+    (capped at ``MAX_ASSOCIATIONS``) of the disease's associations are
+    read, so for common diseases genes beyond the top ones are missing.
+
+    Returns:
+        Tuple of (associations, highest score first, or None; the total
+        number of the disease's associations in Open Targets; error or
+        None)
+    """
+    disease_id, error = await resolve_disease_id(disease)
+    if error or disease_id is None:
+        return None, 0, error
+    data, error = await _opentargets_query(
+        DISEASE_TARGETS_QUERY,
+        {"efoId": disease_id, "size": min(limit, MAX_ASSOCIATIONS)},
+    )
+    if error:
+        return None, 0, error
+    record = (data or {}).get("disease")
+    if not record:
+        return None, 0, f"Disease {disease_id} not found in Open Targets"
+
+    associated = record.get("associatedTargets") or {}
+    rows = associated.get("rows") or []
+    targets = []
+    for row in rows:
+        target = row.get("target") or {}
+        score = row.get("score") or 0.0
+        if not target.get("id") or score < min_score:
+            continue
+        targets.append(
+            TargetAssociation(
+                symbol=target.get("approvedSymbol") or target["id"],
+                ensembl_id=target["id"],
+                score=score,
+            )
+        )
+    count = associated.get("count") or len(rows)
+    return sorted(targets, key=lambda t: -t.score), count, None
@@ -0,0 +1,250 @@
+"""CIViC clinical evidence for the variants of a gene.
+
+MyVariant.info embeds each variant's CIViC record, including the
+evidence items of its molecular profiles, so one query for the gene's
+CIViC variants returns the evidence without a separate CIViC client.
+The query is paged until every matching variant is read (up to
+``CIVIC_MAX_PAGES`` requests; the output says how many variants were left
+unread beyond that), the items are
+flattened across variants, deduplicated (a molecular profile can span
+several variants) and ordered strongest level first.
+"""
+
+import json
+from typing import Any
+
+from .. import ensure_list, http_client
+from ..concurrency import gather_limited, resolve_concurrency
+from ..constants import MYVARIANT_QUERY_URL
+from ..variants.search import CivicEvidenceLevel
+
+# CIViC variants fetched per MyVariant.info request
+CIVIC_PAGE_SIZE = 200
+
+# Most MyVariant.info requests made for one gene's CIViC variants
+CIVIC_MAX_PAGES = 10
+
+# Parallel page requests once the first page gives the total
+CIVIC_PAGE_CONCURRENCY = 5
+
+# Evidence items withdrawn by CIViC curators
+REJECTED_STATUS = "REJECTED"
+
+_LEVEL_ORDER = {level.value: i for i, level in enumerate(CivicEvidenceLevel)}
+
+
+def civic_gene_query(gene: str) -> str:
+    """Build the MyVariant.info query for a gene's CIViC variants."""
+    return (
+        f'(civic.entrezName:"{gene}" OR dbnsfp.genename:"{gene}") '
+        "AND _exists_:civic"
+    )
+
+
+def _names(items: Any) -> str | None:
+    names = [
+        item["name"]
+        for item in ensure_list(items)
+        if isinstance(item, dict) and item.get("name")
+    ]
+    return ", ".join(names) or None
+
+
+def extract_evidence_items(variant: dict[str, Any]) -> list[dict[str, Any]]:
+    """Return the evidence items of a MyVariant.info hit's CIViC record."""
+    civic = variant.get("civic")
+    if not isinstance(civic, dict):
+        return []
+
+    records = []
+    for profile in ensure_list(civic.get("molecularProfiles")):
+        if not isinstance(profile, dict):
+            continue
+        for item in ensure_list(profile.get("evidenceItems")):
+            if not isinstance(item, dict):
+                continue
+            if str(item.get("status", "")).upper() == REJECTED_STATUS:
+                continue
+            disease = item.get("disease")
+            records.append({
+                "evidence_id": item.get("name") or f"EID{item.get('id')}",
+                "molecular_profile": profile.get("name") or civic.get("name"),
+                "disease": _names(disease),
+                "therapies": _names(item.get("therapies")),
+                "evidence_level": item.get("evidenceLevel"),
+                "evidence_type": item.get("evidenceType"),
+                "evidence_direction": item.get("evidenceDirection"),
+                "significance": item.get("significance"),
+            })
+    return records
+
+
+def _sort_key(record: dict[str, Any]) -> tuple[int, str]:
+    level = _LEVEL_ORDER.get(str(record["evidence_level"]), len(_LEVEL_ORDER))
+    return level, str(record["evidence_id"])
+
+
+async def _fetch_civic_page(
+    gene: str, start: int
+) -> tuple[dict[str, Any] | None, Any]:
+    return await http_client.request_api(
+        url=MYVARIANT_QUERY_URL,
+        request={
+            "q": civic_gene_query(gene),
+            "fields": "civic",
+            "size": CIVIC_PAGE_SIZE,
+            "from": start,
+        },
+        method="GET",
+        domain="myvariant",
+    )
+
+
+async def fetch_gene_civic_evidence(
+    gene: str,
+    concurrency: int | None = None,
+) -> tuple[list[dict[str, Any]] | None, int, str | None]:
+    """Fetch the CIViC evidence items of all of a gene's variants.
+
+    Returns:
+        Tuple of (evidence records or None, number of CIViC variants
+        beyond the ``CIVIC_MAX_PAGES`` request budget that were not read,
+        error message or None)
+    """
+    first, error = await _fetch_civic_page(gene, 0)
+    if error:
+        return None, 0, f"Error fetching CIViC evidence: {error.message}"
+
+    first = first or {}
+    total = int(first.get("total") or 0)
+    reachable = min(total, CIVIC_PAGE_SIZE * CIVIC_MAX_PAGES)
+    pages = await gather_limited(
+        (
+            _fetch_civic_page(gene, start)
+            for start in range(CIVIC_PAGE_SIZE, reachable, CIVIC_PAGE_SIZE)
+        ),
+        resolve_concurrency(concurrency, CIVIC_PAGE_CONCURRENCY),
+    )
+
+    hits = list(first.get("hits") or [])
+    for response, error in pages:
+        if error:
+            return (
+                None,
+                0,
+                f"Error fetching CIViC evidence: {error.message}",
+            )
+        hits.extend((response or {}).get("hits") or [])
+
+    records: dict[str, dict[str, Any]] = {}
+    for hit in hits:
+        for record in extract_evidence_items(hit):
+            records.setdefault(record["evidence_id"], record)
+    return sorted(records.values(), key=_sort_key), total - reachable, None
+
+
+def truncation_note(unread: int) -> str:
+    """Explain that some of a gene's CIViC variants could not be read."""
+    return (
+        f"The evidence of {unread} CIViC variants beyond the first "
+        f"{CIVIC_PAGE_SIZE * CIVIC_MAX_PAGES} read is missing."
+    )
+
+
+def format_civic_evidence(
+    gene: str,
+    records: list[dict[str, Any]],
+    total: int,
+    offset: int,
+    unread: int = 0,
+) -> str:
+    """Format CIViC evidence records as a markdown table."""
+    lines = [f"# CIViC Evidence: {gene}", ""]
+    if unread:
+        lines.extend([truncation_note(unread), ""])
+    if not records:
+        lines.append("No CIViC evidence items found.")
+        return "\n".join(lines)
+
+    lines.append(
+        f"Showing {offset + 1}-{offset + len(records)} of {total} "
+        "evidence items."
+    )
+    lines.append("")
+    lines.append(
+        "| Evidence | Molecular profile | Disease | Therapies | Level "
+        "| Type | Direction | Significance |"
+    )
+    lines.append("|---|---|---|---|---|---|---|---|")
+    for r in records:
+        cells = [
+            r["evidence_id"],
+            r["molecular_profile"],
+            r["disease"],
+            r["therapies"],
+            r["evidence_level"],
+            r["evidence_type"],
+            r["evidence_direction"],
+            r["significance"],
+        ]
+        lines.append(
+            "| " + " | ".join(str(c) if c else "-" for c in cells) + " |"
+        )
+    return "\n".join(lines)
+
+
+def render_gene_civic(
+    gene: str,
+    records: list[dict[str, Any]],
+    unread: int = 0,
+    limit: int = 20,
+    offset: int = 0,
+    output_json: bool = False,
+) -> str:
+    """Render one page of a gene's CIViC evidence records.
+
+    JSON output is always an object: the page of records under
+    ``evidence``, the total number of records, and the number of the
+    gene's CIViC variants left unread under ``unread_variants``.
+    """
+    page = records[offset : offset + limit]
+    if not output_json:
+        return format_civic_evidence(
+            gene, page, len(records), offset, unread
+        )
+    payload = {
+        "gene": gene,
+        "total": len(records),
+        "offset": offset,
+        "unread_variants": unread,
+        "evidence": page,
+    }
+    return json.dumps(payload, indent=2)
+
+
+async def get_gene_civic(
+    gene: str,
+    limit: int = 20,
+    offset: int = 0,
+    output_json: bool = False,
+) -> str:
+    """List the CIViC evidence items for a gene's variants.
+
+    Args:
+        gene: Gene symbol (e.g., BRAF)
+        limit: Maximum number of evidence items to return
+        offset: Number of evidence items to skip
+        output_json: Return JSON instead of formatted markdown
+
+    Returns:
+        Markdown table or JSON object with the page of evidence records
+    """
+    gene = gene.strip().upper()
+    records, unread, error = await fetch_gene_civic_evidence(gene)
+    if error or records is None:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return f"Error: {error}"
+    return render_gene_civic(
+        gene, records, unread, limit, offset, output_json
+    )
@@ -0,0 +1,89 @@
+"""ClinVar variant burden of a gene, counted through MyVariant.info.
+
+Each significance category is one MyVariant.info count query (``size=0``)
+combining the gene's ClinVar symbol with the same
+``clinvar.rcv.clinical_significance`` term ``variant search
+--significance`` uses. A variant whose ClinVar records disagree counts in
+every category it was classified under, so the categories can add up to
+more than the total. The count queries run with bounded concurrency
+(``CLINVAR_COUNT_CONCURRENCY``, or the global ``--concurrency``).
+"""
+
+from typing import Any
+
+from .. import http_client
+from ..concurrency import gather_limited, resolve_concurrency
+from ..constants import MYVARIANT_QUERY_URL
+from ..variants.search import ClinicalSignificance
+
+# Significance categories counted, in table order
+CLINVAR_CATEGORIES = [
+    *(significance.value for significance in ClinicalSignificance),
+    "conflicting interpretations of pathogenicity",
+]
+
+# Parallel MyVariant.info count queries
+CLINVAR_COUNT_CONCURRENCY = 5
+
+
+def clinvar_count_query(gene: str, significance: str | None = None) -> str:
+    """Build the MyVariant.info query counting a gene's ClinVar variants."""
+    term = f'clinvar.gene.symbol:"{gene}"'
+    if significance:
+        term += f' AND clinvar.rcv.clinical_significance:"{significance}"'
+    return term
+
+
+async def _count(
+    gene: str, significance: str | None = None
+) -> tuple[int | None, str | None]:
+    response, error = await http_client.request_api(
+        url=MYVARIANT_QUERY_URL,
+        request={"q": clinvar_count_query(gene, significance), "size": 0},
+        method="GET",
+        domain="myvariant",
+    )
+    if error:
+        return None, error.message
+    return int((response or {}).get("total") or 0), None
+
+
+async def fetch_clinvar_summary(
+    gene: str, concurrency: int | None = None
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Count a gene's ClinVar variants overall and per significance.
+
+    Returns:
+        Tuple of (counts or None, error message or None)
+    """
+    results = await gather_limited(
+        [_count(gene), *(_count(gene, c) for c in CLINVAR_CATEGORIES)],
+        resolve_concurrency(concurrency, CLINVAR_COUNT_CONCURRENCY),
+    )
+    errors = [error for _, error in results if error]
+    if errors:
+        return None, f"Error counting ClinVar variants: {errors[0]}"
+
+    total = results[0][0]
+    counts = {
+        category: count
+        for category, (count, _) in zip(
+            CLINVAR_CATEGORIES, results[1:], strict=True
+        )
+    }
+    return {"gene": gene, "total": total, "counts": counts}, None
+
+
+def format_clinvar_summary(summary: dict[str, Any]) -> str:
+    """Format the ClinVar counts as a markdown section."""
+    lines = ["## ClinVar Variant Summary", ""]
+    if not summary["total"]:
+        lines.append(f"No ClinVar variants for {summary['gene']}.")
+        return "\n".join(lines)
+
+    lines.append("| Significance | Variants |")
+    lines.append("|--------------|----------|")
+    for category, count in summary["counts"].items():
+        lines.append(f"| {category.capitalize()} | {count} |")
+    lines.append(f"| **Total** | {summary['total']} |")
+    return "\n".join(lines)
@@ -0,0 +1,101 @@
+"""Tissue expression of a gene from the GTEx Portal.
+
+GTEx reports the median expression (TPM) of each gene across ~54 normal
+human tissues. Its API is keyed by versioned GENCODE IDs
+(``ENSG00000157764.13``), so the Ensembl gene ID that MyGene.info
+returns for ``gene get`` is resolved through the GTEx gene reference
+first. Genes outside GENCODE v26 (the GTEx v8 annotation) have no data.
+"""
+
+from pydantic import BaseModel
+
+from .. import http_client
+from ..constants import GTEX_API_BASE_URL
+
+GTEX_DATASET = "gtex_v8"
+
+# Tissues listed in the markdown table; JSON carries all of them
+TOP_TISSUES = 10
+
+# Median TPM below which expression is reported as low
+LOW_EXPRESSION_TPM = 1.0
+
+
+class TissueExpression(BaseModel):
+    """Median expression of a gene in one tissue."""
+
+    tissue: str
+    level: float
+    unit: str = "TPM"
+
+
+async def _gtex(
+    endpoint: str, params: dict
+) -> tuple[list[dict] | None, str | None]:
+    response, error = await http_client.request_api(
+        url=f"{GTEX_API_BASE_URL}/{endpoint}",
+        request=params,
+        method="GET",
+        domain="gtex",
+    )
+    if error:
+        return None, f"Error fetching GTEx data: {error.message}"
+    return (response or {}).get("data") or [], None
+
+
+async def fetch_tissue_expression(
+    ensembl_id: str,
+) -> tuple[list[TissueExpression] | None, str | None]:
+    """Fetch a gene's median expression per tissue, highest first.
+
+    Returns:
+        Tuple of (tissue expression or None, error message or None)
+    """
+    genes, error = await _gtex("reference/gene", {"geneId": ensembl_id})
+    if error or genes is None:
+        return None, error
+    if not genes or not genes[0].get("gencodeId"):
+        # Not in the GTEx annotation: no expression data, not a failure
+        return [], None
+
+    rows, error = await _gtex(
+        "expression/medianGeneExpression",
+        {"gencodeId": genes[0]["gencodeId"], "datasetId": GTEX_DATASET},
+    )
+    if error or rows is None:
+        return None, error
+    records = [
+        TissueExpression(
+            tissue=row["tissueSiteDetailId"].replace("_", " "),
+            level=row.get("median") or 0.0,
+            unit=row.get("unit") or "TPM",
+        )
+        for row in rows
+        if row.get("tissueSiteDetailId")
+    ]
+    return sorted(records, key=lambda r: -r.level), None
+
+
+def format_expression(records: list[TissueExpression]) -> str:
+    """Format the top expressing tissues as a ranked markdown table."""
+    lines = ["## Tissue Expression (GTEx)", ""]
+    if not records:
+        lines.append("No GTEx expression data for this gene.")
+        return "\n".join(lines)
+    if records[0].level < LOW_EXPRESSION_TPM:
+        lines.append(
+            f"Low expression: no tissue reaches {LOW_EXPRESSION_TPM:g} "
+            f"{records[0].unit}."
+        )
+        lines.append("")
+
+    lines.append("| Rank | Tissue | Median |")
+    lines.append("|------|--------|--------|")
+    for rank, record in enumerate(records[:TOP_TISSUES], start=1):
+        lines.append(
+            f"| {rank} | {record.tissue} | {record.level:.1f} {record.unit} |"
+        )
+    if len(records) > TOP_TISSUES:
+        lines.append("")
+        lines.append(f"Showing top {TOP_TISSUES} of {len(records)} tissues.")
+    return "\n".join(lines)
@@ -6,20 +6,31 @@ Citation: Luebbert & Pachter (2023). Bioinformatics, 39(1), btac836.
 CzechMedMCP directly integrates with Enrichr API rather than using gget as a dependency.
 """
 
+import asyncio
 import json
 import logging
-from typing import Annotated
+from collections.abc import Awaitable, Callable
+from typing import Annotated, Any
 
 from pydantic import Field
 
+from ..concurrency import gather_limited, resolve_concurrency
 from ..enrichr import EnrichrClient
 from ..integrations import BioThingsClient
 from ..render import to_markdown
+from .associations import fetch_disease_associations, format_associations
+from .clinvar_summary import fetch_clinvar_summary, format_clinvar_summary
+from .expression import fetch_tissue_expression, format_expression
+from .homologs import fetch_homologs, format_homologs, split_homologs
 
 logger = logging.getLogger(__name__)
 
 _ISOFORM_LIMIT = 3
 
+# Optional sections (associations, expression, homologs, ClinVar) fetched
+# at once
+GENE_SECTION_CONCURRENCY = 5
+
 
 def _truncate_isoforms(
     result: dict,
@@ -52,11 +63,148 @@ def _truncate_isoforms(
     return result
 
 
+async def _fetch_section(
+    title: str,
+    lookup: Awaitable[tuple[Any, str | None]] | None,
+    missing: str,
+    render: Callable[[Any], tuple[Any, str]],
+) -> tuple[Any, str]:
+    """Fetch one optional gene section.
+
+    Args:
+        title: Markdown heading of the section
+        lookup: Pending ``(value, error)`` lookup, or None when the gene
+            lacks the ID it needs
+        missing: Error reported when ``lookup`` is None
+        render: Turns the looked-up value into (JSON value, markdown)
+
+    Returns:
+        Tuple of (JSON value or ``{"error": ...}``, markdown section)
+    """
+    error = missing
+    if lookup is not None:
+        value, error = await lookup
+        if value is not None:
+            return render(value)
+    return {"error": error}, f"## {title}\n\nError: {error}"
+
+
+def _run_once(
+    lookup: Callable[[], Awaitable[Any]],
+) -> Callable[[], Awaitable[Any]]:
+    """Share one ``lookup`` between callers; it runs on the first await.
+
+    Unlike a task started up front, the lookup only runs inside the
+    awaiting section, so it is bounded and cancelled with the sections.
+    """
+    lock = asyncio.Lock()
+    done: list[Any] = []
+
+    async def run() -> Any:
+        async with lock:
+            if not done:
+                done.append(await lookup())
+        return done[0]
+
+    return run
+
+
+def _render_homologs(
+    key: str, title: str
+) -> Callable[[Any], tuple[Any, str]]:
+    """Render the paralogs or orthologs of a homolog lookup."""
+
+    def render(homologs: list) -> tuple[Any, str]:
+        within, across = split_homologs(homologs)
+        group = within if key == "paralogs" else across
+        return [h.model_dump() for h in group], format_homologs(title, group)
+
+    return render
+
+
+def _gene_sections(
+    gene_info: Any,
+    associations_limit: int | None,
+    include_expression: bool,
+    include_paralogs: bool,
+    include_orthologs: bool,
+    include_clinvar_summary: bool,
+) -> list[tuple[str, Awaitable[tuple[Any, str]]]]:
+    """Pending optional sections of a gene, as (result key, fetch) pairs."""
+    ensembl_id = (gene_info.ensembl or {}).get("gene")
+    no_ensembl = "No Ensembl gene ID to look up in {}"
+    sections: list[tuple[str, Awaitable[tuple[Any, str]]]] = []
+    if associations_limit:
+        sections.append((
+            "associations",
+            _fetch_section(
+                "Disease Associations (Open Targets)",
+                fetch_disease_associations(ensembl_id, associations_limit)
+                if ensembl_id
+                else None,
+                no_ensembl.format("Open Targets"),
+                lambda found: (
+                    [a.model_dump() for a in found],
+                    format_associations(found),
+                ),
+            ),
+        ))
+    if include_expression:
+        sections.append((
+            "expression",
+            _fetch_section(
+                "Tissue Expression (GTEx)",
+                fetch_tissue_expression(ensembl_id) if ensembl_id else None,
+                no_ensembl.format("GTEx"),
+                lambda found: (
+                    [r.model_dump() for r in found],
+                    format_expression(found),
+                ),
+            ),
+        ))
+    # Paralogs and orthologs share one Open Targets lookup
+    homologs = (
+        _run_once(lambda: fetch_homologs(ensembl_id)) if ensembl_id else None
+    )
+    for key, title, include in (
+        ("paralogs", "Paralogs", include_paralogs),
+        ("orthologs", "Orthologs", include_orthologs),
+    ):
+        if include:
+            sections.append((
+                key,
+                _fetch_section(
+                    title,
+                    homologs() if homologs else None,
+                    no_ensembl.format("Open Targets"),
+                    _render_homologs(key, title),
+                ),
+            ))
+    if include_clinvar_summary:
+        sections.append((
+            "clinvar_summary",
+            _fetch_section(
+                "ClinVar Variant Summary",
+                fetch_clinvar_summary(gene_info.symbol)
+                if gene_info.symbol
+                else None,
+                "No gene symbol to look up in ClinVar",
+                lambda found: (found, format_clinvar_summary(found)),
+            ),
+        ))
+    return sections
+
+
 async def get_gene(
     gene_id_or_symbol: str,
     output_json: bool = False,
     include_enrichment: bool = False,
     enrichment_database: str = "GO_Biological_Process_2021",
+    associations_limit: int | None = None,
+    include_expression: bool = False,
+    include_paralogs: bool = False,
+    include_orthologs: bool = False,
+    include_clinvar_summary: bool = False,
 ) -> str:
     """
     Get gene information from MyGene.info with optional enrichment analysis.
@@ -66,6 +214,13 @@ async def get_gene(
         output_json: Return as JSON instead of markdown
         include_enrichment: Whether to include Enrichr functional enrichment
         enrichment_database: Enrichr database to use (default: GO_Biological_Process_2021)
+        associations_limit: If set, add up to this many Open Targets
+            disease associations, highest score first
+        include_expression: Add GTEx median expression per tissue
+        include_paralogs: Add human paralogs from Open Targets
+        include_orthologs: Add orthologs in other species from Open Targets
+        include_clinvar_summary: Add ClinVar variant counts by clinical
+            significance (MyVariant.info)
 
     Returns:
         Gene information as markdown or JSON string
@@ -141,10 +296,37 @@ async def get_gene(
                     "error": f"Enrichment analysis failed: {e!s}"
                 }
 
+        sections = _gene_sections(
+            gene_info,
+            associations_limit,
+            include_expression,
+            include_paralogs,
+            include_orthologs,
+            include_clinvar_summary,
+        )
+        fetched = await gather_limited(
+            (fetch for _, fetch in sections),
+            resolve_concurrency(None, GENE_SECTION_CONCURRENCY),
+        )
+        markdown_sections = []
+        for (key, _), (value, section) in zip(sections, fetched, strict=True):
+            result[key] = value
+            markdown_sections.append(section)
+
         if output_json:
             return json.dumps(result, indent=2)
-        else:
-            return to_markdown([result])
+        for key in (
+            "associations",
+            "expression",
+            "paralogs",
+            "orthologs",
+            "clinvar_summary",
+        ):
+            result.pop(key, None)
+        markdown = to_markdown([result])
+        for section in markdown_sections:
+            markdown = f"{markdown.rstrip()}\n\n{section}\n"
+        return markdown
 
     except Exception as e:
         logger.error(f"Error fetching gene info for {gene_id_or_symbol}: {e}")
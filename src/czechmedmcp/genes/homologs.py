@@ -0,0 +1,131 @@
+"""Paralogs and orthologs of a gene from the Open Targets Platform.
+
+Open Targets carries the Ensembl Compara homology calls for each target:
+paralogs within the human genome and orthologs in other species, with
+the percentage of the query protein identical to the homolog. Both come
+from one query keyed by the Ensembl gene ID that MyGene.info returns for
+``gene get``, and are split by homology type.
+"""
+
+from typing import Any
+
+from pydantic import BaseModel
+
+from .. import http_client
+from ..constants import OPENTARGETS_GRAPHQL_URL
+
+# Homologs listed per markdown table; JSON carries all of them
+HOMOLOG_LIMIT = 20
+
+HOMOLOGUES_QUERY = """
+query GeneHomologues($ensemblId: String!) {
+  target(ensemblId: $ensemblId) {
+    homologues {
+      speciesName
+      homologyType
+      targetGeneId
+      targetGeneSymbol
+      queryPercentageIdentity
+      isHighConfidence
+    }
+  }
+}
+"""
+
+
+class Homolog(BaseModel):
+    """A gene related to the queried gene by common descent."""
+
+    symbol: str | None = None
+    gene_id: str
+    species: str
+    homology_type: str
+    identity: float | None = None
+    high_confidence: bool | None = None
+
+
+def parse_homologs(rows: list[dict[str, Any]]) -> list[Homolog]:
+    """Convert homologue rows, most identical first."""
+    homologs = [
+        Homolog(
+            symbol=row.get("targetGeneSymbol") or None,
+            gene_id=row["targetGeneId"],
+            species=row.get("speciesName") or "",
+            homology_type=row.get("homologyType") or "",
+            identity=row.get("queryPercentageIdentity"),
+            high_confidence=_confidence(row.get("isHighConfidence")),
+        )
+        for row in rows
+        if row.get("targetGeneId")
+    ]
+    return sorted(homologs, key=lambda h: -(h.identity or 0.0))
+
+
+def _confidence(value: Any) -> bool | None:
+    # Served as "1"/"0" strings, or null when Compara makes no call
+    if value is None or value == "NULL":
+        return None
+    return str(value) in ("1", "True", "true")
+
+
+def split_homologs(
+    homologs: list[Homolog],
+) -> tuple[list[Homolog], list[Homolog]]:
+    """Split homologs into (paralogs, orthologs)."""
+    paralogs = [h for h in homologs if "paralog" in h.homology_type]
+    orthologs = [h for h in homologs if "ortholog" in h.homology_type]
+    return paralogs, orthologs
+
+
+async def fetch_homologs(
+    ensembl_id: str,
+) -> tuple[list[Homolog] | None, str | None]:
+    """Fetch the paralogs and orthologs of an Ensembl gene.
+
+    Returns:
+        Tuple of (homologs or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=OPENTARGETS_GRAPHQL_URL,
+        request={
+            "query": HOMOLOGUES_QUERY,
+            "variables": {"ensemblId": ensembl_id},
+        },
+        method="POST",
+        domain="opentargets",
+    )
+    if error:
+        return None, f"Error fetching Open Targets data: {error.message}"
+    errors = (response or {}).get("errors")
+    if errors:
+        message = errors[0].get("message", "unknown error")
+        return None, f"Open Targets query failed: {message}"
+
+    target = ((response or {}).get("data") or {}).get("target")
+    if not target:
+        return None, f"Target {ensembl_id} not found in Open Targets"
+    return parse_homologs(target.get("homologues") or []), None
+
+
+def format_homologs(title: str, homologs: list[Homolog]) -> str:
+    """Format homologs as a markdown section with an identity table."""
+    lines = [f"## {title}", ""]
+    if not homologs:
+        lines.append(f"No {title.lower()} found.")
+        return "\n".join(lines)
+
+    lines.append("| Gene | Species | Type | Identity |")
+    lines.append("|------|---------|------|----------|")
+    for homolog in homologs[:HOMOLOG_LIMIT]:
+        identity = (
+            f"{homolog.identity:.1f}%" if homolog.identity is not None else ""
+        )
+        kind = homolog.homology_type.replace("_", " ")
+        lines.append(
+            f"| {homolog.symbol or homolog.gene_id} | {homolog.species} | "
+            f"{kind} | {identity} |"
+        )
+    if len(homologs) > HOMOLOG_LIMIT:
+        lines.append("")
+        lines.append(f"Showing {HOMOLOG_LIMIT} of {len(homologs)}.")
+    return "\n".join(lines)
@@ -0,0 +1,519 @@
+"""Gene search against MyGene.info with result filters.
+
+Summary filters are applied to the fields MyGene.info returns in the
+search response itself (``summary`` is requested alongside the symbol),
+so they never trigger per-gene follow-up lookups. Post-filtering can
+leave a page with fewer than ``page_size`` genes. ``has_structure`` is
+added to the query itself (``_exists_:pdb``, the PDB cross-references
+MyGene.info imports from UniProt), so it needs no lookups and pages
+normally.
+
+By default the query is passed to MyGene.info as-is, which matches
+symbols, aliases and names alike. ``GeneMatch`` narrows that to an exact
+``symbol:`` term or a ``name:`` term for disambiguation. GO terms are
+added to the query itself: a gene matches a GO ID annotated under any of
+its biological process, molecular function or cellular component terms,
+and several IDs are combined with AND (all annotated) or OR (any).
+Reactome pathways work the same way against MyGene.info's pathway
+annotations: each ``pathways`` value is a stable ID (``R-HSA-109581``),
+used as given, or a name resolved concurrently to the top Reactome
+search hit first (the query summary shows the pathway it resolved to),
+and a gene matches when it is in any of the pathways.
+
+``disease`` restricts the search to genes Open Targets associates with a
+disease: the top associated targets (optionally above ``min_score``) are
+fetched first and their Ensembl IDs added to the query. All matching
+genes are then fetched at once and ranked by association score before
+the page is taken, so the ranking holds across pages. Only the top
+``MAX_ASSOCIATIONS`` targets are read; when a disease has more that could
+pass ``min_score``, the count is reported as an upstream total of the
+disease's associations instead of an exact one.
+
+Genes with equal relevance scores are listed by Entrez ID, so repeated
+identical searches give identical output (``stable_sort=False`` keeps
+MyGene.info's order).
+"""
+
+import json
+import logging
+import re
+from typing import Any
+
+from pydantic import BaseModel, Field, field_validator, model_validator
+
+from .. import StrEnum, http_client
+from ..concurrency import gather_limited, resolve_concurrency
+from ..constants import compute_skip
+from ..core import stable_hit_order
+from ..integrations.biothings_client import MYGENE_QUERY_URL
+from ..pathways import is_reactome_id
+from ..pathways.search import resolve_pathway
+from ..render import result_count_line, search_page_payload
+from .associations import MAX_ASSOCIATIONS, fetch_disease_targets
+
+logger = logging.getLogger(__name__)
+
+SEARCH_FIELDS = [
+    "_id",
+    "symbol",
+    "name",
+    "summary",
+    "entrezgene",
+    "type_of_gene",
+    "taxid",
+]
+
+# Minimum summary length enforced by --has-summary alone
+DEFAULT_MIN_SUMMARY_LENGTH = 1
+
+_SUMMARY_PREVIEW_LENGTH = 300
+
+# Characters with special meaning in MyGene.info (Lucene) query strings
+_QUERY_SPECIAL_CHARS = re.compile(r'([+\-=&|><!(){}\[\]^"~*?:\\/])')
+
+# MyGene.info GO annotation branches (process, function, component)
+GO_ID_FIELDS = ("go.BP.id", "go.MF.id", "go.CC.id")
+
+_GO_ID_PATTERN = re.compile(r"^(?:GO[:_]?)?(\d{7})$", re.IGNORECASE)
+
+# MyGene.info Reactome pathway annotation
+PATHWAY_ID_FIELD = "pathway.reactome.id"
+
+# Parallel Reactome searches resolving --pathway names
+PATHWAY_RESOLVE_CONCURRENCY = 5
+
+# MyGene.info's largest page, used to fetch every disease-associated gene
+# (an Ensembl ID can map to more than one MyGene.info gene)
+MYGENE_MAX_SIZE = 1000
+
+# MyGene.info clause matching genes with solved PDB structures
+STRUCTURE_QUERY_CLAUSE = "_exists_:pdb"
+
+
+class GeneMatch(StrEnum):
+    """Which gene fields a search query is matched against."""
+
+    AUTO = "auto"
+    SYMBOL = "symbol"
+    NAME = "name"
+
+
+def escape_query(text: str) -> str:
+    """Escape query-syntax characters so text is matched literally."""
+    return _QUERY_SPECIAL_CHARS.sub(r"\\\1", text.strip())
+
+
+def mygene_query_term(query: str, match: GeneMatch = GeneMatch.AUTO) -> str:
+    """Build the MyGene.info ``q`` term for a query and match mode.
+
+    Raises:
+        ValueError: If symbol matching is requested for more than one word.
+    """
+    if match == GeneMatch.SYMBOL:
+        if len(query.split()) != 1:
+            raise ValueError(
+                f"'{query}' is not a single gene symbol; "
+                "--symbol-only needs exactly one symbol"
+            )
+        return f"symbol:{escape_query(query)}"
+    if match == GeneMatch.NAME:
+        return f"name:({escape_query(query)})"
+    return query
+
+
+class GoMode(StrEnum):
+    """How several GO terms are combined."""
+
+    AND = "and"
+    OR = "or"
+
+
+def normalize_go_id(value: str) -> str:
+    """Normalize a GO term ID to the ``GO:0006915`` form.
+
+    Accepts ``GO:0006915``, ``go_0006915`` or the bare seven digits.
+
+    Raises:
+        ValueError: If the value is not a GO term ID
+    """
+    match = _GO_ID_PATTERN.match(value.strip())
+    if not match:
+        raise ValueError(
+            f"Invalid GO ID '{value}'. Expected GO: followed by 7 digits "
+            "(e.g. GO:0006915)"
+        )
+    return f"GO:{match.group(1)}"
+
+
+def go_query_clause(go_ids: list[str], mode: GoMode = GoMode.AND) -> str:
+    """Build the MyGene.info clause matching genes annotated with GO IDs."""
+    clauses = [
+        "("
+        + " OR ".join(f'{field}:"{go_id}"' for field in GO_ID_FIELDS)
+        + ")"
+        for go_id in go_ids
+    ]
+    if len(clauses) == 1:
+        return clauses[0]
+    return "(" + f" {mode.value.upper()} ".join(clauses) + ")"
+
+
+def normalize_pathway(value: str) -> str:
+    """Upper-case Reactome stable IDs; keep pathway names as given."""
+    value = value.strip()
+    return value.upper() if is_reactome_id(value) else value
+
+
+def pathway_query_clause(pathway_ids: list[str]) -> str:
+    """Build the MyGene.info clause matching genes in any pathway."""
+    clauses = [
+        f'{PATHWAY_ID_FIELD}:"{pathway_id}"' for pathway_id in pathway_ids
+    ]
+    if len(clauses) == 1:
+        return clauses[0]
+    return "(" + " OR ".join(clauses) + ")"
+
+
+async def resolve_pathways(
+    pathways: list[str],
+    concurrency: int | None = None,
+) -> tuple[dict[str, dict[str, str | None]] | None, str | None]:
+    """Resolve pathway names and IDs to Reactome stable IDs.
+
+    Returns:
+        Tuple of (value -> {"id", "name"} or None, error message or None)
+    """
+    results = await gather_limited(
+        (resolve_pathway(value) for value in pathways),
+        resolve_concurrency(concurrency, PATHWAY_RESOLVE_CONCURRENCY),
+    )
+    resolved: dict[str, dict[str, str | None]] = {}
+    for value, (pathway, error) in zip(pathways, results, strict=True):
+        if error or pathway is None:
+            return None, error
+        resolved[value] = pathway
+    return resolved, None
+
+
+class GeneSearchFilters(BaseModel):
+    """Optional filters applied to a MyGene.info gene search."""
+
+    has_summary: bool = Field(
+        default=False,
+        description="Only return genes with a non-empty summary",
+    )
+    min_summary_length: int | None = Field(
+        default=None,
+        ge=1,
+        description="Minimum summary length in characters (implies has_summary)",
+    )
+    has_structure: bool = Field(
+        default=False,
+        description="Only return genes with solved PDB structures",
+    )
+    go_ids: list[str] = Field(
+        default_factory=list,
+        description="GO term IDs the genes must be annotated with",
+    )
+    go_mode: GoMode = Field(
+        default=GoMode.AND,
+        description="Require all GO terms (and) or any of them (or)",
+    )
+    pathways: list[str] = Field(
+        default_factory=list,
+        description="Reactome pathway IDs or names; genes in any of them "
+        "match",
+    )
+    disease: str | None = Field(
+        default=None,
+        description="Disease name or ID (MONDO/EFO) the genes must be "
+        "associated with in Open Targets",
+    )
+    min_score: float | None = Field(
+        default=None,
+        ge=0,
+        le=1,
+        description="Minimum Open Targets association score (0-1)",
+    )
+
+    @field_validator("go_ids")
+    @classmethod
+    def normalize_go_ids(cls, v: list[str]) -> list[str]:
+        return [normalize_go_id(go_id) for go_id in v]
+
+    @field_validator("pathways")
+    @classmethod
+    def normalize_pathways(cls, v: list[str]) -> list[str]:
+        return [normalize_pathway(value) for value in v if value.strip()]
+
+    @model_validator(mode="after")
+    def _check_min_score(self) -> "GeneSearchFilters":
+        if self.min_score is not None and not self.disease:
+            raise ValueError("min_score requires a disease filter")
+        return self
+
+    def is_empty(self) -> bool:
+        """Return True when no filter is set."""
+        return (
+            not self.has_summary
+            and self.min_summary_length is None
+            and not self.has_structure
+            and not self.go_ids
+            and not self.pathways
+            and not self.disease
+        )
+
+    def summary_threshold(self) -> int | None:
+        """Return the minimum summary length to enforce, if any."""
+        if self.min_summary_length is not None:
+            return self.min_summary_length
+        if self.has_summary:
+            return DEFAULT_MIN_SUMMARY_LENGTH
+        return None
+
+    def has_client_filters(self) -> bool:
+        """Return True when hits are filtered after MyGene returns them."""
+        return self.summary_threshold() is not None
+
+
+def matches_filters(hit: dict[str, Any], filters: GeneSearchFilters) -> bool:
+    """Check a MyGene hit against the filters."""
+    threshold = filters.summary_threshold()
+    if threshold is not None:
+        summary = (hit.get("summary") or "").strip()
+        if len(summary) < threshold:
+            return False
+    return True
+
+
+def format_query_summary(
+    query: str,
+    filters: GeneSearchFilters,
+    match: GeneMatch = GeneMatch.AUTO,
+    pathways: dict[str, dict[str, str | None]] | None = None,
+) -> str:
+    """Render a one-line summary of the query and active filters.
+
+    Pathway names are shown as the Reactome pathway and stable ID they
+    resolved to when ``pathways`` is given.
+    """
+    parts = [f"Query: {query}"]
+    if match != GeneMatch.AUTO:
+        parts.append(f"Match: {match.value} only")
+    if filters.min_summary_length is not None:
+        parts.append(f"Min summary length: {filters.min_summary_length}")
+    elif filters.has_summary:
+        parts.append("Has summary")
+    if filters.has_structure:
+        parts.append("Has PDB structure")
+    if filters.go_ids:
+        joiner = f" {filters.go_mode.value.upper()} "
+        parts.append(f"GO: {joiner.join(filters.go_ids)}")
+    if filters.pathways:
+        labels = []
+        for value in filters.pathways:
+            pathway = (pathways or {}).get(value) or {}
+            name = pathway.get("name")
+            labels.append(f"{name} ({pathway['id']})" if name else value)
+        parts.append(f"Pathway: {' OR '.join(labels)}")
+    if filters.disease:
+        parts.append(f"Associated with: {filters.disease}")
+    if filters.min_score is not None:
+        parts.append(f"Min association score: {filters.min_score}")
+    return " | ".join(parts)
+
+
+def _hit_to_record(hit: dict[str, Any]) -> dict[str, Any]:
+    """Flatten a MyGene hit into a compact search record."""
+    record = {
+        "gene_id": hit.get("_id"),
+        "symbol": hit.get("symbol"),
+        "name": hit.get("name"),
+        "entrezgene": hit.get("entrezgene"),
+        "type_of_gene": hit.get("type_of_gene"),
+        "summary": hit.get("summary"),
+    }
+    return {k: v for k, v in record.items() if v}
+
+
+def _association_score(
+    hit: dict[str, Any], scores: dict[str, float]
+) -> float | None:
+    """Best association score among a hit's Ensembl gene IDs."""
+    ensembl = hit.get("ensembl") or []
+    if isinstance(ensembl, dict):
+        ensembl = [ensembl]
+    matched = [
+        scores[entry["gene"]]
+        for entry in ensembl
+        if isinstance(entry, dict) and entry.get("gene") in scores
+    ]
+    return max(matched) if matched else None
+
+
+def _format_results(
+    summary: str,
+    records: list[dict[str, Any]],
+    total: int,
+    post_filtered: bool = False,
+) -> str:
+    """Format gene search records as markdown."""
+    lines = ["# Gene Search Results", "", f"_{summary}_", ""]
+    if not records:
+        lines.append("No genes found matching the search criteria.")
+        return "\n".join(lines)
+
+    lines.append(
+        result_count_line(len(records), total, "genes", post_filtered)
+    )
+    lines.append("")
+    for record in records:
+        title = record.get("symbol") or record.get("gene_id", "Unknown")
+        if record.get("name"):
+            title += f": {record['name']}"
+        lines.append(f"## {title}")
+        if record.get("entrezgene"):
+            lines.append(f"- **Entrez ID**: {record['entrezgene']}")
+        if record.get("type_of_gene"):
+            lines.append(f"- **Type**: {record['type_of_gene']}")
+        if record.get("association_score") is not None:
+            score = record["association_score"]
+            lines.append(f"- **Association score**: {score:.3f}")
+        if record.get("summary"):
+            text = record["summary"]
+            if len(text) > _SUMMARY_PREVIEW_LENGTH:
+                text = text[:_SUMMARY_PREVIEW_LENGTH] + "..."
+            lines.append(f"- **Summary**: {text}")
+        lines.append("")
+    return "\n".join(lines).rstrip()
+
+
+async def search_genes(
+    query: str,
+    filters: GeneSearchFilters | None = None,
+    page: int = 1,
+    page_size: int = 10,
+    output_json: bool = False,
+    match: GeneMatch = GeneMatch.AUTO,
+    stable_sort: bool = True,
+) -> str:
+    """Search MyGene.info for human genes matching a query and filters.
+
+    Args:
+        query: Free-text query (symbol, name, or description)
+        filters: Optional result filters
+        page: Page number (1-based)
+        page_size: Number of results requested per page
+        output_json: Return JSON instead of formatted markdown
+        match: Match the query as given, as an exact symbol, or as a name
+        stable_sort: Break relevance ties by Entrez ID for reproducible
+            output
+
+    Returns:
+        Formatted markdown or JSON string with the matching genes
+    """
+    filters = filters or GeneSearchFilters()
+    try:
+        term = mygene_query_term(query, match)
+    except ValueError as e:
+        if output_json:
+            return json.dumps({"error": str(e)}, indent=2)
+        return f"Error: {e}"
+    pathways: dict[str, dict[str, str | None]] = {}
+    if filters.pathways:
+        resolved, error_msg = await resolve_pathways(filters.pathways)
+        if error_msg or resolved is None:
+            if output_json:
+                return json.dumps({"error": error_msg}, indent=2)
+            return f"Error: {error_msg}"
+        pathways = resolved
+    summary = format_query_summary(query, filters, match, pathways)
+    if filters.go_ids:
+        go_clause = go_query_clause(filters.go_ids, filters.go_mode)
+        term = f"({term}) AND {go_clause}"
+    if pathways:
+        pathway_clause = pathway_query_clause(
+            list(dict.fromkeys(p["id"] for p in pathways.values()))
+        )
+        term = f"({term}) AND {pathway_clause}"
+    scores: dict[str, float] = {}
+    association_count: int | None = None
+    if filters.disease:
+        targets, count, error_msg = await fetch_disease_targets(
+            filters.disease, filters.min_score or 0.0
+        )
+        if error_msg or targets is None:
+            if output_json:
+                return json.dumps({"error": error_msg}, indent=2)
+            return f"Error: {error_msg}"
+        if not targets:
+            if output_json:
+                payload = search_page_payload(summary, [], 0, page, page_size)
+                return json.dumps(payload, indent=2)
+            return _format_results(summary, [], 0)
+        scores = {t.ensembl_id: t.score for t in targets}
+        if count > MAX_ASSOCIATIONS and len(targets) == MAX_ASSOCIATIONS:
+            # Lower-ranked associations may still pass min_score
+            association_count = count
+            summary += (
+                f" | Top {MAX_ASSOCIATIONS} of {count} associated genes"
+            )
+        ensembl_ids = " OR ".join(scores)
+        term = f"({term}) AND ensembl.gene:({ensembl_ids})"
+    if filters.has_structure:
+        term = f"({term}) AND {STRUCTURE_QUERY_CLAUSE}"
+    params = {
+        "q": term,
+        "species": "human",
+        "fields": ",".join(SEARCH_FIELDS),
+        "size": page_size,
+        "from": compute_skip(page, page_size),
+    }
+    if scores:
+        # Every associated gene is fetched so the ranking by association
+        # score holds across pages; the page is sliced afterwards
+        params["fields"] = ",".join([*SEARCH_FIELDS, "ensembl.gene"])
+        params["size"] = MYGENE_MAX_SIZE
+        params["from"] = 0
+
+    response, error = await http_client.request_api(
+        url=MYGENE_QUERY_URL,
+        request=params,
+        method="GET",
+        domain="mygene",
+    )
+
+    if error:
+        error_msg = f"Error searching genes: {error.message}"
+        if output_json:
+            return json.dumps({"error": error_msg}, indent=2)
+        return error_msg
+
+    hits = (response or {}).get("hits", [])
+    total = (response or {}).get("total", len(hits))
+    hits = [h for h in hits if matches_filters(h, filters)]
+    if stable_sort:
+        hits = stable_hit_order(
+            hits, lambda h: h.get("entrezgene") or h.get("_id", "")
+        )
+    records = [_hit_to_record(h) for h in hits]
+    post_filtered = filters.has_client_filters()
+    if scores:
+        for hit, record in zip(hits, records, strict=True):
+            record["association_score"] = _association_score(hit, scores)
+        records.sort(key=lambda r: -(r["association_score"] or 0.0))
+        # All fetched associated genes were filtered, so the count is
+        # exact unless Open Targets had more associations than were read
+        total = len(records)
+        post_filtered = False
+        if association_count is not None:
+            total = association_count
+            post_filtered = True
+        offset = compute_skip(page, page_size)
+        records = records[offset : offset + page_size]
+    if output_json:
+        payload = search_page_payload(
+            summary, records, total, page, page_size, post_filtered
+        )
+        return json.dumps(payload, indent=2)
+    return _format_results(summary, records, total, post_filtered)
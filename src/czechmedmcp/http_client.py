@@ -1,8 +1,10 @@
 import csv
 import hashlib
 import json
+import logging
 import os
 import ssl
+import time
 from io import StringIO
 from ssl import PROTOCOL_TLS_CLIENT, SSLContext, TLSVersion
 from typing import Literal, TypeVar
@@ -23,6 +25,7 @@ from .constants import (
     DEFAULT_RECOVERY_TIMEOUT,
     DEFAULT_SUCCESS_THRESHOLD,
 )
+from .dry_run import DryRunRequest, is_dry_run
 from .http_client_simple import execute_http_request
 from .metrics import Timer
 from .rate_limiter import domain_limiter
@@ -36,6 +39,8 @@ from .utils.endpoint_registry import get_registry
 
 T = TypeVar("T", bound=BaseModel)
 
+logger = logging.getLogger(__name__)
+
 _DEFAULT_BREAKER_CONFIG = CircuitBreakerConfig(
     failure_threshold=DEFAULT_FAILURE_THRESHOLD,
     recovery_timeout=DEFAULT_RECOVERY_TIMEOUT,
@@ -52,11 +57,15 @@ class RequestError(BaseModel):
 _cache: Cache | None = None
 
 
+def get_cache_path() -> str:
+    """Return the on-disk location of the HTTP response cache."""
+    return os.path.join(user_cache_dir("czechmedmcp"), "http_cache")
+
+
 def get_cache() -> Cache:
     global _cache
     if _cache is None:
-        cache_path = os.path.join(user_cache_dir("czechmedmcp"), "http_cache")
-        _cache = Cache(cache_path)
+        _cache = Cache(get_cache_path())
     return _cache
 
 
@@ -77,7 +86,8 @@ def generate_cache_key(method: str, url: str, params: dict) -> str:
 def cache_response(cache_key: str, content: str, ttl: int):
     expire = None if ttl == -1 else ttl
     cache = get_cache()
-    cache.set(cache_key, content, expire=expire)
+    # The tag records when the entry was stored so it can be pruned by age
+    cache.set(cache_key, content, expire=expire, tag=time.time())
 
 
 def get_cached_response(cache_key: str) -> str | None:
@@ -85,6 +95,53 @@ def get_cached_response(cache_key: str) -> str | None:
     return cache.get(cache_key)
 
 
+def invalidate_cached_response(cache_key: str) -> None:
+    """Drop a single cached response."""
+    get_cache().delete(cache_key)
+
+
+def is_stale_cache_hit(
+    parsed: BaseModel | dict | list | None, error: RequestError | None
+) -> bool:
+    """Whether a cached response no longer parses.
+
+    Cached bodies that fail to deserialize (upstream schema drift) are
+    refetched once instead of being served. Empty dicts and lists are
+    valid answers (e.g. a search with no hits) and are served as cached.
+    """
+    return error is not None or parsed is None
+
+
+def cache_stats() -> dict[str, int | str]:
+    """Return the cache location, entry count and total size in bytes."""
+    cache = get_cache()
+    return {
+        "path": get_cache_path(),
+        "entries": len(cache),
+        "size_bytes": cache.volume(),
+    }
+
+
+def clear_cache(older_than_seconds: float | None = None) -> int:
+    """Remove cached responses and return how many were removed.
+
+    Args:
+        older_than_seconds: Only remove entries stored longer ago than this.
+            Entries without a recorded store time count as old.
+    """
+    cache = get_cache()
+    if older_than_seconds is None:
+        return cache.clear()
+
+    cutoff = time.time() - older_than_seconds
+    removed = 0
+    for key in list(cache.iterkeys()):
+        _, stored_at = cache.get(key, default=None, tag=True)
+        if (stored_at is None or stored_at < cutoff) and cache.delete(key):
+            removed += 1
+    return removed
+
+
 def get_ssl_context(tls_version: TLSVersion) -> SSLContext:
     """Create an SSLContext with the specified TLS version."""
     context = SSLContext(PROTOCOL_TLS_CLIENT)
@@ -241,6 +298,11 @@ async def request_api(
     enable_retry: bool = True,
     endpoint_key: str | None = None,
 ) -> tuple[T | None, RequestError | None]:
+    # Dry-run mode: stop before anything is sent (or read from cache)
+    if is_dry_run():
+        params, _ = _prepare_request_params(request)
+        raise DryRunRequest(method, url, params, domain)
+
     # Handle offline mode
     offline_result = _handle_offline_mode(
         url, method, request, cache_ttl, response_model_type
@@ -276,11 +338,15 @@ async def request_api(
         cached_content = get_cached_response(cache_key)
 
         if cached_content:
-            return parse_response(
-                200, cached_content, response_model_type
-            )
-
-        # Make HTTP request if not cached
+            cached = parse_response(200, cached_content, response_model_type)
+            if not is_stale_cache_hit(*cached):
+                return cached
+            # Retry once with the cache bypassed; a good response below
+            # replaces the stale entry
+            logger.debug(f"Refreshing stale cached response for {url}")
+            invalidate_cached_response(cache_key)
+
+        # Make HTTP request if not cached (or the cached copy was stale)
         status, content = await call_http(
             method,
             url,
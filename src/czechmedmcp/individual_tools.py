@@ -519,6 +519,13 @@ async def variant_searcher(
         Literal["probably_damaging", "possibly_damaging", "benign"] | None,
         Field(description="PolyPhen-2 functional prediction"),
     ] = None,
+    hotspot: Annotated[
+        bool,
+        Field(
+            description="Only variants at recurrent cBioPortal cancer "
+            "hotspot residues (requires gene; best-effort)"
+        ),
+    ] = False,
     include_cbioportal: Annotated[
         bool,
         Field(
@@ -580,6 +587,8 @@ async def variant_searcher(
         cadd=cadd_score_min,
         sift=sift_prediction,
         polyphen=polyphen_prediction,
+        consequence=consequence,
+        hotspot=hotspot,
         size=page_size,
         offset=compute_skip(page, page_size) if page > 1 else 0,
     )
@@ -775,11 +784,17 @@ async def gene_getter(
 @track_performance("czechmedmcp.enrichr_analyzer")
 async def enrichr_analyzer(
     genes: Annotated[
-        list[str] | str,
+        list[str] | str | None,
         Field(
             description="Gene symbols to analyze (e.g., ['TP53', 'BRCA1'] or 'TP53')"
         ),
-    ],
+    ] = None,
+    pathway_id: Annotated[
+        str | None,
+        Field(
+            description="Reactome pathway ID whose genes are analyzed instead of a gene list (e.g., 'R-HSA-5673001')"
+        ),
+    ] = None,
     database: Annotated[
         str,
         Field(
@@ -821,13 +836,31 @@ async def enrichr_analyzer(
     - diseases, gwas: Disease associations
     - transcription_factors, tf: Transcription factor targets
 
+    Pass either genes or pathway_id. With pathway_id the pathway's genes are
+    fetched from Reactome and enriched, and "source" names the pathway.
+
     Note: This tool submits gene lists to the public Enrichr API. For single gene
     enrichment, consider using gene_getter with --enrich flag via CLI.
     """
     import json
 
+    from czechmedmcp.enrichr.report import gene_list_source, pathway_source
+    from czechmedmcp.pathways import fetch_pathway_genes
+
+    if genes and pathway_id:
+        return json.dumps({
+            "error": "Provide either genes or pathway_id, not both",
+        })
+
     # Convert single gene to list
     gene_list = ensure_list(genes) if genes else []
+    source = gene_list_source(gene_list)
+    if pathway_id:
+        pathway_genes, error = await fetch_pathway_genes(pathway_id)
+        if error or not pathway_genes:
+            return json.dumps({"error": error, "genes": []})
+        gene_list = pathway_genes
+        source = pathway_source(pathway_id.upper(), gene_list)
 
     if not gene_list:
         return json.dumps({
@@ -873,6 +906,7 @@ async def enrichr_analyzer(
 
         return json.dumps(
             {
+                "source": source,
                 "genes": gene_list,
                 "database": terms[0].database if terms else database,
                 "enrichment_terms": enrichment_terms,
@@ -83,6 +83,7 @@ class GeneInfo(BaseModel):
     ensembl: dict[str, Any] | None = None
     refseq: dict[str, Any] | None = None
     type_of_gene: str | None = None
+    map_location: str | None = None
     taxid: int | None = None
 
 
@@ -115,6 +116,8 @@ class DrugInfo(BaseModel):
     indication: str | None = None
     pharmacology: dict[str, Any] | None = None
     mechanism_of_action: str | None = None
+    groups: list[str] = Field(default_factory=list)
+    targets: list[dict[str, Any]] | None = None
 
 
 class BioThingsClient:
@@ -195,6 +198,7 @@ class BioThingsClient:
                 "summary",
                 "alias",
                 "type_of_gene",
+                "map_location",
                 "ensembl",
                 "refseq",
                 "entrezgene",
@@ -604,6 +608,18 @@ class BioThingsClient:
             response["indication"] = db.get("indication")
             response["mechanism_of_action"] = db.get("mechanism_of_action")
             response["description"] = db.get("description")
+            if isinstance(db.get("pharmacology"), dict):
+                response["pharmacology"] = db["pharmacology"]
+            groups = db.get("groups") or []
+            if isinstance(groups, str):
+                groups = [groups]
+            response["groups"] = groups
+            targets = db.get("targets") or []
+            if isinstance(targets, dict):
+                targets = [targets]
+            response["targets"] = [
+                t for t in targets if isinstance(t, dict)
+            ] or None
 
     def _extract_chebi_fields(self, response: dict[str, Any]) -> None:
         """Extract ChEBI fields from response."""
@@ -2,14 +2,20 @@
 OpenFDA Drug Adverse Events (FAERS) integration.
 """
 
+import json
 import logging
 
+from .. import StrEnum
 from .adverse_events_helpers import (
+    extract_narrative,
+    extract_timeline,
+    format_count_results,
     format_drug_details,
     format_reaction_details,
     format_report_metadata,
     format_report_summary,
     format_search_summary,
+    format_timeline,
     format_top_reactions,
 )
 from .constants import (
@@ -23,14 +29,216 @@ from .exceptions import (
     OpenFDARateLimitError,
     OpenFDATimeoutError,
 )
-from .input_validation import sanitize_input
+from .input_validation import sanitize_input, validate_country_code
 from .utils import clean_text, make_openfda_request
 
 logger = logging.getLogger(__name__)
 
+# Fields available to the count aggregation
+ADVERSE_EVENT_COUNT_FIELDS = {
+    "reaction": "patient.reaction.reactionmeddrapt.exact",
+    "country": "primarysource.reportercountry.exact",
+    "indication": "patient.drug.drugindication.exact",
+    "route": "patient.drug.drugadministrationroute",
+}
+
+# OpenFDA returns at most 1000 buckets for a count query
+OPENFDA_MAX_COUNT_LIMIT = 1000
+
+
+class PatientAgeGroup(StrEnum):
+    """Standard patient age buckets (ICH E2B)."""
+
+    NEONATE = "neonate"
+    INFANT = "infant"
+    CHILD = "child"
+    ADOLESCENT = "adolescent"
+    ADULT = "adult"
+    ELDERLY = "elderly"
+
+
+# FAERS patient.patientagegroup codes, set by some reporters
+AGE_GROUP_CODES = {
+    PatientAgeGroup.NEONATE: "1",
+    PatientAgeGroup.INFANT: "2",
+    PatientAgeGroup.CHILD: "3",
+    PatientAgeGroup.ADOLESCENT: "4",
+    PatientAgeGroup.ADULT: "5",
+    PatientAgeGroup.ELDERLY: "6",
+}
+
+# patient.patientonsetageunit code for ages reported in years
+ONSET_AGE_UNIT_YEARS = "801"
+
+
+def age_group_range(group: PatientAgeGroup) -> tuple[int, int | None] | None:
+    """Inclusive onset age range in whole years for an age bucket.
+
+    Neonates (under one month) cannot be told apart in whole years, so
+    they have no range and are matched by the reported age group only.
+    An open upper bound is None.
+    """
+    return {
+        PatientAgeGroup.NEONATE: None,
+        PatientAgeGroup.INFANT: (0, 1),
+        PatientAgeGroup.CHILD: (2, 11),
+        PatientAgeGroup.ADOLESCENT: (12, 17),
+        PatientAgeGroup.ADULT: (18, 64),
+        PatientAgeGroup.ELDERLY: (65, None),
+    }[group]
+
+
+def age_group_label(group: PatientAgeGroup) -> str:
+    """Render an age bucket with its age range for summaries."""
+    bounds = age_group_range(group)
+    if bounds is None:
+        return f"{group.value} (under 1 month)"
+    low, high = bounds
+    span = f"{low}+" if high is None else f"{low}-{high}"
+    return f"{group.value} ({span} years)"
+
+
+def age_group_query(group: PatientAgeGroup) -> str:
+    """Match reports by reported age group or onset age in years."""
+    code_clause = f'patient.patientagegroup:"{AGE_GROUP_CODES[group]}"'
+    bounds = age_group_range(group)
+    if bounds is None:
+        return code_clause
+    low, high = bounds
+    age_clause = (
+        f'(patient.patientonsetageunit:"{ONSET_AGE_UNIT_YEARS}" AND '
+        f"patient.patientonsetage:[{low} TO {'*' if high is None else high}])"
+    )
+    return f"({code_clause} OR {age_clause})"
+
+
+class AdministrationRoute(StrEnum):
+    """Common drug administration routes reported to FAERS."""
+
+    ORAL = "oral"
+    INTRAVENOUS = "intravenous"
+    SUBCUTANEOUS = "subcutaneous"
+    INTRAMUSCULAR = "intramuscular"
+    TOPICAL = "topical"
+    CUTANEOUS = "cutaneous"
+    TRANSDERMAL = "transdermal"
+    INHALATION = "inhalation"
+    NASAL = "nasal"
+    OPHTHALMIC = "ophthalmic"
+    RECTAL = "rectal"
+    VAGINAL = "vaginal"
+    SUBLINGUAL = "sublingual"
+    BUCCAL = "buccal"
+    INTRATHECAL = "intrathecal"
+    INTRADERMAL = "intradermal"
+    EPIDURAL = "epidural"
+    INTRA_ARTERIAL = "intra-arterial"
+    INTRAVESICAL = "intravesical"
+
+
+# patient.drug.drugadministrationroute codes (ICH E2B); intravenous
+# covers bolus, drip and unspecified
+ROUTE_CODES = {
+    AdministrationRoute.ORAL: ("048",),
+    AdministrationRoute.INTRAVENOUS: ("040", "041", "042"),
+    AdministrationRoute.SUBCUTANEOUS: ("058",),
+    AdministrationRoute.INTRAMUSCULAR: ("030",),
+    AdministrationRoute.TOPICAL: ("061",),
+    AdministrationRoute.CUTANEOUS: ("003",),
+    AdministrationRoute.TRANSDERMAL: ("062",),
+    AdministrationRoute.INHALATION: ("055",),
+    AdministrationRoute.NASAL: ("045",),
+    AdministrationRoute.OPHTHALMIC: ("047",),
+    AdministrationRoute.RECTAL: ("054",),
+    AdministrationRoute.VAGINAL: ("067",),
+    AdministrationRoute.SUBLINGUAL: ("060",),
+    AdministrationRoute.BUCCAL: ("002",),
+    AdministrationRoute.INTRATHECAL: ("037",),
+    AdministrationRoute.INTRADERMAL: ("023",),
+    AdministrationRoute.EPIDURAL: ("008",),
+    AdministrationRoute.INTRA_ARTERIAL: ("013",),
+    AdministrationRoute.INTRAVESICAL: ("043",),
+}
+
+# Abbreviations accepted in place of the route name
+ROUTE_ALIASES = {
+    "po": AdministrationRoute.ORAL,
+    "iv": AdministrationRoute.INTRAVENOUS,
+    "sc": AdministrationRoute.SUBCUTANEOUS,
+    "subq": AdministrationRoute.SUBCUTANEOUS,
+    "im": AdministrationRoute.INTRAMUSCULAR,
+    "inhaled": AdministrationRoute.INHALATION,
+    "respiratory": AdministrationRoute.INHALATION,
+    "intranasal": AdministrationRoute.NASAL,
+    "intraarterial": AdministrationRoute.INTRA_ARTERIAL,
+}
+
+# Codes whose label differs from the route they are grouped under
+ROUTE_CODE_NAMES = {
+    "040": "intravenous bolus",
+    "041": "intravenous drip",
+    "050": "other",
+    "065": "unknown",
+}
+
+
+def parse_route(value: str) -> AdministrationRoute:
+    """Resolve a route name or abbreviation (e.g. "iv").
+
+    Raises:
+        ValueError: If the route is not recognised
+    """
+    key = value.strip().lower()
+    if key in ROUTE_ALIASES:
+        return ROUTE_ALIASES[key]
+    try:
+        return AdministrationRoute(key)
+    except ValueError:
+        options = [route.value for route in AdministrationRoute]
+        raise ValueError(
+            f"Unknown route '{value}'. Options: {', '.join(options)} "
+            f"(or {', '.join(ROUTE_ALIASES)})"
+        ) from None
+
+
+def route_query(route: AdministrationRoute) -> str:
+    """Match reports with a drug given by the route.
+
+    FAERS records the route per drug, so the matching drug need not be
+    the one named by ``--drug`` when a report lists several.
+    """
+    clauses = [
+        f'patient.drug.drugadministrationroute:"{code}"'
+        for code in ROUTE_CODES[route]
+    ]
+    if len(clauses) == 1:
+        return clauses[0]
+    return f"({' OR '.join(clauses)})"
+
+
+def route_code_label(code: str) -> str:
+    """Render a FAERS route code with its name for count tables."""
+    name = ROUTE_CODE_NAMES.get(code)
+    if name is None:
+        name = next(
+            (
+                route.value
+                for route, codes in ROUTE_CODES.items()
+                if code in codes
+            ),
+            None,
+        )
+    return f"{name} ({code})" if name else code
+
 
 def _build_search_query(
-    drug: str | None, reaction: str | None, serious: bool | None
+    drug: str | None,
+    reaction: str | None,
+    serious: bool | None,
+    country: str | None = None,
+    indication: str | None = None,
+    age_group: PatientAgeGroup | None = None,
+    route: AdministrationRoute | None = None,
 ) -> str:
     """Build the search query for adverse events."""
     search_parts = []
@@ -58,9 +266,111 @@ def _build_search_query(
         serious_value = "1" if serious else "2"
         search_parts.append(f"serious:{serious_value}")
 
+    if country:
+        search_parts.append(f'primarysource.reportercountry:"{country}"')
+
+    if indication:
+        # Indication the drug was given for (MedDRA preferred term)
+        indication = sanitize_input(indication, max_length=200)
+        if indication:
+            search_parts.append(
+                f'patient.drug.drugindication:"{indication}"'
+            )
+
+    if age_group:
+        search_parts.append(age_group_query(age_group))
+
+    if route:
+        search_parts.append(route_query(route))
+
     return " AND ".join(search_parts)
 
 
+async def count_adverse_events(
+    count_by: str,
+    drug: str | None = None,
+    reaction: str | None = None,
+    serious: bool | None = None,
+    country: str | None = None,
+    limit: int = OPENFDA_MAX_COUNT_LIMIT,
+    api_key: str | None = None,
+    indication: str | None = None,
+    route: AdministrationRoute | None = None,
+    age_group: PatientAgeGroup | None = None,
+) -> tuple[list[dict], str | None]:
+    """Run a count-only adverse event query.
+
+    Shared by ``search_adverse_events --count-by`` and the stats facets,
+    so both accept the same filters. Route buckets are labelled with the
+    route name next to the E2B code.
+
+    Returns:
+        Tuple of (``{term, count}`` buckets, error message or None)
+    """
+    if count_by not in ADVERSE_EVENT_COUNT_FIELDS:
+        return [], (
+            f"Invalid count field '{count_by}'. Options: "
+            f"{', '.join(ADVERSE_EVENT_COUNT_FIELDS)}"
+        )
+    try:
+        country = validate_country_code(country)
+    except ValueError as e:
+        return [], str(e)
+
+    params = {
+        "search": _build_search_query(
+            drug, reaction, serious, country, indication, age_group, route
+        ),
+        "count": ADVERSE_EVENT_COUNT_FIELDS[count_by],
+        "limit": min(limit, OPENFDA_MAX_COUNT_LIMIT),
+    }
+    try:
+        response, error = await make_openfda_request(
+            OPENFDA_DRUG_EVENTS_URL, params, "openfda_adverse_events", api_key
+        )
+    except OpenFDARateLimitError:
+        return [], "FDA API rate limit exceeded"
+    except OpenFDATimeoutError:
+        return [], "FDA API request timed out"
+    except OpenFDAConnectionError as e:
+        return [], f"Unable to connect to FDA API: {e}"
+
+    if error:
+        return [], error
+    results = (response or {}).get("results") or []
+    if count_by == "route":
+        results = [
+            {**bucket, "term": route_code_label(str(bucket.get("term")))}
+            for bucket in results
+        ]
+    return results, None
+
+
+def _no_reports_message(
+    drug: str | None,
+    reaction: str | None,
+    country: str | None,
+    indication: str | None,
+    age_group: PatientAgeGroup | None,
+    route: AdministrationRoute | None,
+) -> str:
+    """Describe an adverse event search that matched no reports."""
+    search_desc = []
+    if drug:
+        search_desc.append(f"drug '{drug}'")
+    if reaction:
+        search_desc.append(f"reaction '{reaction}'")
+    if country:
+        search_desc.append(f"country '{country}'")
+    if indication:
+        search_desc.append(f"indication '{indication}'")
+    if age_group:
+        search_desc.append(f"age group '{age_group.value}'")
+    if route:
+        search_desc.append(f"route '{route.value}'")
+    return f"No adverse event reports found for {' and '.join(search_desc)}."
+
+
 async def search_adverse_events(  # noqa: C901
     drug: str | None = None,
     reaction: str | None = None,
@@ -68,6 +378,11 @@ async def search_adverse_events(  # noqa: C901
     limit: int = OPENFDA_DEFAULT_LIMIT,
     skip: int = 0,
     api_key: str | None = None,
+    country: str | None = None,
+    count_by: str | None = None,
+    indication: str | None = None,
+    age_group: PatientAgeGroup | None = None,
+    route: AdministrationRoute | None = None,
 ) -> str:
     """
     Search FDA adverse event reports (FAERS).
@@ -79,24 +394,82 @@ async def search_adverse_events(  # noqa: C901
         limit: Maximum number of results
         skip: Number of results to skip
         api_key: Optional OpenFDA API key (overrides OPENFDA_API_KEY env var)
+        country: Reporter country as an ISO 3166-1 alpha-2 code (e.g., "US")
+        count_by: Aggregate matching reports by "reaction", "country",
+            "indication" or "route" instead of listing them
+        indication: Only reports where the drug was given for this
+            indication (e.g., "hypertension")
+        age_group: Only reports for patients in this age bucket
+        route: Only reports with a drug given by this route
 
     Returns:
         Formatted string with adverse event information
     """
-    if not drug and not reaction:
+    try:
+        country = validate_country_code(country)
+    except ValueError as e:
+        return f"⚠️ {e}"
+
+    if count_by is not None and count_by not in ADVERSE_EVENT_COUNT_FIELDS:
         return (
-            "⚠️ Please specify either a drug name or reaction term to search "
-            "adverse events.\n\n"
+            f"⚠️ Invalid count field '{count_by}'. Options: "
+            f"{', '.join(ADVERSE_EVENT_COUNT_FIELDS)}"
+        )
+
+    if not drug and not reaction and not country and not indication:
+        return (
+            "⚠️ Please specify either a drug name or reaction term (or a "
+            "reporter country or indication) to search adverse events.\n\n"
             "Examples:\n"
             "- Search by drug: --drug 'imatinib'\n"
             "- Search by reaction: --reaction 'nausea'\n"
-            "- Both: --drug 'imatinib' --reaction 'nausea'"
+            "- Both: --drug 'imatinib' --reaction 'nausea'\n"
+            "- Regional: --drug 'imatinib' --country DE\n"
+            "- By use: --drug 'metformin' --indication 'type 2 diabetes "
+            "mellitus'"
+        )
+
+    if count_by:
+        buckets, error = await count_adverse_events(
+            count_by,
+            drug=drug,
+            reaction=reaction,
+            serious=serious,
+            country=country,
+            limit=limit,
+            api_key=api_key,
+            indication=indication,
+            route=route,
+            age_group=age_group,
+        )
+        if error:
+            return f"⚠️ Error searching adverse events: {error}"
+        if not buckets:
+            return _no_reports_message(
+                drug, reaction, country, indication, age_group, route
+            )
+        output = ["## FDA Adverse Event Report Counts\n"]
+        output.extend(
+            format_search_summary(
+                drug,
+                reaction,
+                serious,
+                None,
+                country,
+                indication,
+                age_group_label(age_group) if age_group else None,
+                route.value if route else None,
+            )
         )
+        output.extend(format_count_results(count_by, buckets))
+        output.append(f"\n{OPENFDA_DISCLAIMER}")
+        return "\n".join(output)
 
     # Build and execute search
-    search_query = _build_search_query(drug, reaction, serious)
     params = {
-        "search": search_query,
+        "search": _build_search_query(
+            drug, reaction, serious, country, indication, age_group, route
+        ),
         "limit": min(limit, OPENFDA_MAX_LIMIT),
         "skip": skip,
     }
@@ -133,13 +506,8 @@ async def search_adverse_events(  # noqa: C901
         return f"⚠️ Error searching adverse events: {error}"
 
     if not response or not response.get("results"):
-        search_desc = []
-        if drug:
-            search_desc.append(f"drug '{drug}'")
-        if reaction:
-            search_desc.append(f"reaction '{reaction}'")
-        return (
-            f"No adverse event reports found for {' and '.join(search_desc)}."
+        return _no_reports_message(
+            drug, reaction, country, indication, age_group, route
         )
 
     results = response["results"]
@@ -149,7 +517,18 @@ async def search_adverse_events(  # noqa: C901
 
     # Build output
     output = ["## FDA Adverse Event Reports\n"]
-    output.extend(format_search_summary(drug, reaction, serious, total))
+    output.extend(
+        format_search_summary(
+            drug,
+            reaction,
+            serious,
+            total,
+            country,
+            indication,
+            age_group_label(age_group) if age_group else None,
+            route.value if route else None,
+        )
+    )
 
     # Add top reactions if searching by drug
     if drug and not reaction:
@@ -166,13 +545,23 @@ async def search_adverse_events(  # noqa: C901
     return "\n".join(output)
 
 
-async def get_adverse_event(report_id: str, api_key: str | None = None) -> str:
+async def get_adverse_event(
+    report_id: str,
+    api_key: str | None = None,
+    narrative_only: bool = False,
+    output_json: bool = False,
+    timeline: bool = False,
+) -> str:
     """
     Get detailed information for a specific adverse event report.
 
     Args:
         report_id: Safety report ID
         api_key: Optional OpenFDA API key (overrides OPENFDA_API_KEY env var)
+        narrative_only: Only return the free-text MedWatch narrative
+        output_json: Return JSON (with a top-level ``narrative`` field)
+        timeline: Add the drug start, event onset and report dates with
+            the days between them (time to onset)
 
     Returns:
         Formatted string with detailed report information
@@ -190,13 +579,40 @@ async def get_adverse_event(report_id: str, api_key: str | None = None) -> str:
     )
 
     if error:
+        if output_json:
+            return json.dumps({"error": str(error)}, indent=2)
         return f"⚠️ Error retrieving adverse event report: {error}"
 
     if not response or not response.get("results"):
+        if output_json:
+            return json.dumps(
+                {"error": f"Adverse event report '{report_id}' not found."},
+                indent=2,
+            )
         return f"Adverse event report '{report_id}' not found."
 
     result = response["results"][0]
     patient = result.get("patient", {})
+    narrative = extract_narrative(result)
+
+    if output_json:
+        if narrative_only:
+            data = {"safetyreportid": report_id, "narrative": narrative}
+        else:
+            data = {**result, "narrative": narrative}
+        if timeline:
+            data["timeline"] = extract_timeline(result)
+        return json.dumps(data, indent=2)
+
+    if narrative_only:
+        return "\n".join([
+            f"## Adverse Event Report: {report_id}\n",
+            "### Event Narrative",
+            clean_text(narrative)
+            if narrative
+            else "No narrative text provided for this report.",
+            f"\n{OPENFDA_DISCLAIMER}",
+        ])
 
     # Build detailed output
     output = [f"## Adverse Event Report: {report_id}\n"]
@@ -212,10 +628,13 @@ async def get_adverse_event(report_id: str, api_key: str | None = None) -> str:
     if reactions := patient.get("reaction", []):
         output.extend(format_reaction_details(reactions))
 
+    if timeline:
+        output.extend(format_timeline(extract_timeline(result)))
+
     # Event Summary
-    if summary := patient.get("summary", {}).get("narrativeincludeclinical"):
+    if narrative:
         output.append("### Event Narrative")
-        output.append(clean_text(summary))
+        output.append(clean_text(narrative))
         output.append("")
 
     # Report metadata
@@ -3,6 +3,7 @@ Helper functions for OpenFDA adverse events to reduce complexity.
 """
 
 from collections import Counter
+from datetime import date, datetime
 from typing import Any
 
 from .utils import (
@@ -13,10 +14,31 @@ from .utils import (
 )
 
 
+def extract_narrative(result: dict[str, Any]) -> str | None:
+    """Extract the free-text MedWatch narrative from a FAERS report."""
+    summary = result.get("patient", {}).get("summary") or {}
+    if not isinstance(summary, dict):
+        return None
+    narrative = summary.get("narrativeincludeclinical")
+    if isinstance(narrative, list):
+        narrative = " ".join(str(n) for n in narrative)
+    return narrative or None
+
+
 def format_search_summary(
-    drug: str | None, reaction: str | None, serious: bool | None, total: int
+    drug: str | None,
+    reaction: str | None,
+    serious: bool | None,
+    total: int | None,
+    country: str | None = None,
+    indication: str | None = None,
+    age_group: str | None = None,
+    route: str | None = None,
 ) -> list[str]:
-    """Format the search summary section."""
+    """Format the search summary section.
+
+    The total line is omitted when ``total`` is None (count queries).
+    """
     output = []
 
     # Add search criteria
@@ -27,16 +49,44 @@ def format_search_summary(
         search_desc.append(f"**Reaction**: {reaction}")
     if serious is not None:
         search_desc.append(f"**Serious Events**: {'Yes' if serious else 'No'}")
+    if country:
+        search_desc.append(f"**Reporter Country**: {country}")
+    if indication:
+        search_desc.append(f"**Indication**: {indication}")
+    if age_group:
+        search_desc.append(f"**Age Group**: {age_group}")
+    if route:
+        search_desc.append(f"**Route**: {route}")
 
     if search_desc:
         output.append(" | ".join(search_desc))
-    output.append(
-        f"**Total Reports Found**: {format_count(total, 'report')}\n"
-    )
+    if total is not None:
+        output.append(
+            f"**Total Reports Found**: {format_count(total, 'report')}\n"
+        )
 
     return output
 
 
+def format_count_results(
+    count_by: str, results: list[dict[str, Any]]
+) -> list[str]:
+    """Format OpenFDA count buckets as a markdown table."""
+    label = count_by.replace("_", " ").title()
+    output = [
+        "",
+        f"### Reports by {label}\n",
+        f"| {label} | Reports |",
+        "|---|---|",
+    ]
+    for bucket in results:
+        output.append(
+            f"| {bucket.get('term', 'Unknown')} | "
+            f"{bucket.get('count', 0):,} |"
+        )
+    return output
+
+
 def format_top_reactions(results: list[dict[str, Any]]) -> list[str]:
     """Format top reported reactions from search results."""
     output = []
@@ -218,3 +268,105 @@ def format_report_metadata(result: dict[str, Any]) -> list[str]:
             output.append(f"- **Serious Outcomes**: {', '.join(outcomes)}")
 
     return output
+
+
+def _faers_date(value: Any) -> date | None:
+    """Parse a full FAERS CCYYMMDD date; partial dates give None."""
+    text = str(value or "")
+    if len(text) != 8 or not text.isdigit():
+        return None
+    try:
+        return datetime.strptime(text, "%Y%m%d").date()
+    except ValueError:
+        return None
+
+
+def _display_date(value: Any) -> str | None:
+    """Render a full (CCYYMMDD) or partial (CCYYMM, CCYY) FAERS date."""
+    text = str(value or "")
+    if not text.isdigit() or len(text) not in (4, 6, 8):
+        return None
+    return "-".join(part for part in (text[:4], text[4:6], text[6:]) if part)
+
+
+def _earliest(values: list[Any]) -> Any:
+    dated = [v for v in values if _display_date(v)]
+    return min(dated, key=lambda v: str(v).ljust(8, "0")) if dated else None
+
+
+def _days_between(start: Any, end: Any) -> int | None:
+    first, last = _faers_date(start), _faers_date(end)
+    if first is None or last is None:
+        return None
+    return (last - first).days
+
+
+def extract_timeline(result: dict[str, Any]) -> dict[str, Any]:
+    """Collect a report's dates and the intervals between them.
+
+    The sequence is earliest drug start, earliest reaction onset, and the
+    date FDA first received the report. Intervals are in days and only
+    computed between full dates.
+    """
+    patient = result.get("patient") or {}
+    drugs = [d for d in patient.get("drug") or [] if isinstance(d, dict)]
+    reactions = [
+        r for r in patient.get("reaction") or [] if isinstance(r, dict)
+    ]
+
+    drug_start = _earliest([d.get("drugstartdate") for d in drugs])
+    onset = _earliest([r.get("reactionstartdate") for r in reactions])
+    received = result.get("receiptdate") or result.get("receivedate")
+
+    return {
+        "drug_start": _display_date(drug_start),
+        "event_onset": _display_date(onset),
+        "report_received": _display_date(received),
+        "drugs": [
+            {
+                "drug": d.get("medicinalproduct"),
+                "start": _display_date(d.get("drugstartdate")),
+                "end": _display_date(d.get("drugenddate")),
+            }
+            for d in drugs
+            if d.get("drugstartdate") or d.get("drugenddate")
+        ],
+        "intervals": {
+            "time_to_onset_days": _days_between(drug_start, onset),
+            "onset_to_report_days": _days_between(onset, received),
+            "start_to_report_days": _days_between(drug_start, received),
+        },
+    }
+
+
+def format_timeline(timeline: dict[str, Any]) -> list[str]:
+    """Format the drug start -> onset -> report sequence."""
+    output = ["### Timeline"]
+    steps = [
+        ("Drug start", timeline["drug_start"]),
+        ("Event onset", timeline["event_onset"]),
+        ("Report received", timeline["report_received"]),
+    ]
+    known = [f"{label} ({when})" for label, when in steps if when]
+    if not known:
+        output.append("No dates reported.")
+        output.append("")
+        return output
+    output.append(" → ".join(known))
+
+    labels = {
+        "time_to_onset_days": "Time to onset",
+        "onset_to_report_days": "Onset to report",
+        "start_to_report_days": "Drug start to report",
+    }
+    for key, label in labels.items():
+        days = timeline["intervals"][key]
+        if days is not None:
+            output.append(f"- **{label}**: {days} days")
+
+    for drug in timeline["drugs"]:
+        period = f"{drug['start'] or '?'} to {drug['end'] or '?'}"
+        output.append(f"- **{drug['drug'] or 'Unknown drug'}**: {period}")
+
+    output.append("")
+    return output
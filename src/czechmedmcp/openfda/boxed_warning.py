@@ -0,0 +1,66 @@
+"""Boxed ("black box") warning of a drug from its FDA label.
+
+Only labels that carry a ``boxed_warning`` section are searched, so one
+small request answers whether any label of the drug has a boxed warning
+and returns its text. openFDA answers "No matches found" when none does.
+"""
+
+from typing import Any
+
+from .constants import OPENFDA_DISCLAIMER, OPENFDA_DRUG_LABELS_URL
+from .utils import clean_text, make_openfda_request
+
+# openFDA error message when a search matches no records
+NO_MATCHES = "No matches found"
+
+
+def boxed_warning_query(drug: str) -> str:
+    """Build the label search for a drug's labels with a boxed warning."""
+    return (
+        f'(openfda.generic_name:"{drug}" OR openfda.brand_name:"{drug}") '
+        "AND _exists_:boxed_warning"
+    )
+
+
+async def get_boxed_warning(
+    drug: str,
+    api_key: str | None = None,
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Fetch the boxed warning of a drug's FDA label.
+
+    Returns:
+        Tuple of (boxed warning record or None, error message or None)
+    """
+    params = {"search": boxed_warning_query(drug), "limit": "1"}
+    response, error = await make_openfda_request(
+        OPENFDA_DRUG_LABELS_URL, params, "openfda_drug_labels", api_key
+    )
+    if error and NO_MATCHES.lower() not in error.lower():
+        return None, f"Error fetching boxed warning for {drug}: {error}"
+
+    labels = (response or {}).get("results") or []
+    if not labels:
+        return {"has_boxed_warning": False, "text": None}, None
+
+    label = labels[0]
+    openfda = label.get("openfda") or {}
+    return {
+        "has_boxed_warning": True,
+        "text": clean_text(" ".join(label.get("boxed_warning") or [])),
+        "set_id": label.get("set_id"),
+        "brand_name": (openfda.get("brand_name") or [None])[0],
+    }, None
+
+
+def format_boxed_warning(warning: dict[str, Any]) -> str:
+    """Format a boxed warning record as a markdown section."""
+    if not warning["has_boxed_warning"]:
+        return "### Boxed Warning\n\nNo boxed warning."
+
+    lines = ["### ⚠️ BOXED WARNING", ""]
+    source = warning.get("brand_name") or "FDA label"
+    if warning.get("set_id"):
+        source += f" (set ID {warning['set_id']})"
+    lines.extend([f"_From {source}_", "", f"> {warning['text']}"])
+    lines.extend(["", OPENFDA_DISCLAIMER])
+    return "\n".join(lines)
@@ -0,0 +1,136 @@
+"""
+Marketed drug formulations from the openFDA NDC Directory.
+
+Each NDC product lists its dosage form, route(s) and active ingredient
+strengths. Products sharing all three are merged into one formulation
+with the brand names and labelers that market it.
+"""
+
+import logging
+from typing import Any
+
+from .constants import OPENFDA_DISCLAIMER, OPENFDA_MAX_LIMIT, OPENFDA_NDC_URL
+from .exceptions import (
+    OpenFDAConnectionError,
+    OpenFDARateLimitError,
+    OpenFDATimeoutError,
+)
+from .utils import make_openfda_request
+
+logger = logging.getLogger(__name__)
+
+
+def _strength(product: dict[str, Any]) -> str:
+    """Render active ingredient strengths, e.g. 'IMATINIB MESYLATE 100 mg/1'."""
+    parts = []
+    for ingredient in product.get("active_ingredients") or []:
+        name = ingredient.get("name", "")
+        strength = ingredient.get("strength", "")
+        parts.append(f"{name} {strength}".strip())
+    return "; ".join(p for p in parts if p)
+
+
+def group_formulations(
+    products: list[dict[str, Any]],
+    route: str | None = None,
+) -> list[dict[str, Any]]:
+    """Merge NDC products into distinct formulations.
+
+    Args:
+        products: NDC Directory product records
+        route: Only keep products given by this route (case-insensitive)
+
+    Returns:
+        Formulation records sorted by route, dosage form and strength
+    """
+    wanted_route = route.strip().upper() if route else None
+    formulations: dict[tuple[str, str, str], dict[str, Any]] = {}
+
+    for product in products:
+        routes = [r.upper() for r in product.get("route") or []]
+        if wanted_route and wanted_route not in routes:
+            continue
+
+        route_label = ", ".join(routes) or "UNKNOWN"
+        dosage_form = product.get("dosage_form") or "UNKNOWN"
+        strength = _strength(product)
+        key = (route_label, dosage_form, strength)
+
+        record = formulations.setdefault(
+            key,
+            {
+                "route": route_label,
+                "dosage_form": dosage_form,
+                "strength": strength,
+                "brand_names": [],
+                "labelers": [],
+                "product_count": 0,
+            },
+        )
+        record["product_count"] += 1
+        for field, value in (
+            ("brand_names", product.get("brand_name")),
+            ("labelers", product.get("labeler_name")),
+        ):
+            if value and value not in record[field]:
+                record[field].append(value)
+
+    return [formulations[key] for key in sorted(formulations)]
+
+
+async def get_drug_formulations(
+    drug: str,
+    route: str | None = None,
+    api_key: str | None = None,
+) -> tuple[list[dict[str, Any]] | None, str | None]:
+    """Fetch the marketed formulations of a drug.
+
+    Returns:
+        Tuple of (formulation records or None, error message or None)
+    """
+    params = {
+        "search": (
+            f'(generic_name:"{drug}" OR brand_name:"{drug}" OR '
+            f'active_ingredients.name:"{drug}")'
+        ),
+        "limit": str(OPENFDA_MAX_LIMIT),
+    }
+    try:
+        response, error = await make_openfda_request(
+            OPENFDA_NDC_URL, params, "openfda_ndc", api_key
+        )
+    except OpenFDARateLimitError:
+        error = "FDA API rate limit exceeded"
+    except OpenFDATimeoutError:
+        error = "FDA API request timed out"
+    except OpenFDAConnectionError as e:
+        error = f"Unable to connect to FDA API: {e}"
+    if error:
+        return None, f"Error fetching formulations for {drug}: {error}"
+
+    products = (response or {}).get("results") or []
+    return group_formulations(products, route=route), None
+
+
+def format_formulations(
+    formulations: list[dict[str, Any]], route: str | None = None
+) -> str:
+    """Format formulation records as a markdown section."""
+    heading = "### Formulations"
+    if route:
+        heading += f" ({route.upper()})"
+    lines = [heading, ""]
+    if not formulations:
+        lines.append("No marketed formulations found in the NDC Directory.")
+        return "\n".join(lines)
+
+    lines.append("| Route | Dosage form | Strength | Brands | Products |")
+    lines.append("|---|---|---|---|---|")
+    for f in formulations:
+        brands = ", ".join(f["brand_names"][:5]) or "-"
+        lines.append(
+            f"| {f['route']} | {f['dosage_form']} | {f['strength'] or '-'} "
+            f"| {brands} | {f['product_count']} |"
+        )
+    lines.extend(["", OPENFDA_DISCLAIMER])
+    return "\n".join(lines)
@@ -20,6 +20,7 @@ MAX_DATE_LENGTH = 10
 # Patterns for validation
 SAFE_CHARS_PATTERN = re.compile(r"^[a-zA-Z0-9\s\-\.\,\(\)\/\*]+$")
 DATE_PATTERN = re.compile(r"^\d{4}-\d{2}-\d{2}$")
+COUNTRY_CODE_PATTERN = re.compile(r"^[A-Z]{2}$")
 # Include SQL comment pattern -- and other injection patterns
 INJECTION_CHARS = re.compile(r"[<>\"\';&|\\`${}]|--")
 
@@ -232,6 +233,31 @@ def validate_classification(classification: str | None) -> str | None:
     return sanitized.title()  # "CLASS I" -> "Class I"
 
 
+def validate_country_code(country: str | None) -> str | None:
+    """
+    Validate an ISO 3166-1 alpha-2 country code.
+
+    Args:
+        country: Two-letter country code (e.g., "US", "cz")
+
+    Returns:
+        Upper-cased country code or None if not provided
+
+    Raises:
+        ValueError: If the code is not two ASCII letters
+    """
+    if country is None or not country.strip():
+        return None
+
+    code = country.strip().upper()
+    if not COUNTRY_CODE_PATTERN.match(code):
+        raise ValueError(
+            f"Invalid country code '{country}'. "
+            "Use a two-letter ISO 3166-1 code such as US, DE or CZ."
+        )
+    return code
+
+
 def validate_status(status: str | None) -> str | None:
     """
     Validate status parameter.
@@ -3,12 +3,88 @@
 import logging
 from typing import Any
 
-from ..constants import NCI_ORGANIZATIONS_URL, compute_skip
+from ..concurrency import gather_limited, resolve_concurrency
+from ..constants import NCI_ORGANIZATIONS_URL, NCI_TRIALS_URL, compute_skip
 from ..integrations.cts_api import CTSAPIError, make_cts_request
+from ..render import result_count_line
 from ..utils import parse_or_query
 
 logger = logging.getLogger(__name__)
 
+# Parallel NCI trial lookups for --has-trials, one per organization
+HAS_TRIALS_CONCURRENCY = 5
+
+
+async def _has_trials(
+    org: dict[str, Any], api_key: str | None
+) -> tuple[bool, str | None]:
+    """Check whether any NCI trial lists the organization as a site.
+
+    Returns:
+        Tuple of (whether it has trials, error message or None)
+    """
+    name = org.get("name")
+    if not name:
+        return False, None
+    try:
+        response = await make_cts_request(
+            url=NCI_TRIALS_URL,
+            params={"sites.org_name": name, "size": 1, "include": "nct_id"},
+            api_key=api_key,
+        )
+    except CTSAPIError as e:
+        return False, str(e)
+    return bool(response.get("total") or response.get("data")), None
+
+
+async def filter_organizations_with_trials(
+    organizations: list[dict[str, Any]],
+    api_key: str | None = None,
+    concurrency: int | None = None,
+) -> tuple[list[dict[str, Any]], list[dict[str, str]]]:
+    """Keep the organizations linked to at least one NCI trial.
+
+    Every organization is checked with one NCI trials request. One whose
+    check fails is left out and reported instead.
+
+    Returns:
+        Tuple of (organizations with trials, failed checks as
+        ``{"name": ..., "error": ...}``)
+    """
+    checks = await gather_limited(
+        (_has_trials(org, api_key) for org in organizations),
+        resolve_concurrency(concurrency, HAS_TRIALS_CONCURRENCY),
+    )
+    linked = []
+    failures = []
+    for org, (has, error) in zip(organizations, checks, strict=True):
+        if error:
+            failures.append({"name": org.get("name", ""), "error": error})
+        elif has:
+            linked.append(org)
+    return linked, failures
+
+
+def organization_search_query_summary(
+    name: str | None = None,
+    org_type: str | None = None,
+    city: str | None = None,
+    state: str | None = None,
+    has_trials: bool = False,
+) -> str:
+    """Render a one-line summary of the organization search filters."""
+    parts: list[str] = []
+    if name:
+        parts.append(f"Name: {name}")
+    if org_type:
+        parts.append(f"Type: {org_type}")
+    location = ", ".join(p for p in (city, state) if p)
+    if location:
+        parts.append(f"Location: {location}")
+    if has_trials:
+        parts.append("Has trials: yes")
+    return " | ".join(parts) or "All organizations"
+
 
 async def search_organizations(
     name: str | None = None,
@@ -18,6 +94,7 @@ async def search_organizations(
     page_size: int = 20,
     page: int = 1,
     api_key: str | None = None,
+    has_trials: bool = False,
 ) -> dict[str, Any]:
     """
     Search for organizations in the NCI CTS database.
@@ -30,11 +107,16 @@ async def search_organizations(
         page_size: Number of results per page
         page: Page number
         api_key: Optional API key (if not provided, uses NCI_API_KEY env var)
+        has_trials: Keep only organizations that are a site of at least
+            one NCI trial. Costs one extra request per organization.
 
     Returns:
         Dictionary with search results containing:
         - organizations: List of organization records
-        - total: Total number of results
+        - total: Total number of results (without ``has_trials``)
+        - upstream_total, returned, trial_lookup_errors: With
+          ``has_trials``, the NCI total before the trial check, the
+          organizations kept on this page and the failed checks
         - page: Current page
         - page_size: Results per page
 
@@ -72,6 +154,19 @@ async def search_organizations(
         organizations = response.get("data", response.get("organizations", []))
         total = response.get("total", len(organizations))
 
+        if has_trials:
+            organizations, failures = await filter_organizations_with_trials(
+                organizations, api_key
+            )
+            return {
+                "organizations": organizations,
+                "upstream_total": total,
+                "returned": len(organizations),
+                "trial_lookup_errors": failures,
+                "page": page,
+                "page_size": page_size,
+            }
+
         return {
             "organizations": organizations,
             "total": total,
@@ -98,15 +193,35 @@ def format_organization_results(results: dict[str, Any]) -> str:
     """
     organizations = results.get("organizations", [])
     total = results.get("total", 0)
+    failures = results.get("trial_lookup_errors", [])
 
-    if not organizations:
+    if not organizations and not failures:
         return "No organizations found matching the search criteria."
 
     # Build markdown output
-    lines = [
-        f"## Organization Search Results ({total} found)",
-        "",
-    ]
+    if "upstream_total" in results:
+        lines = [
+            "## Organization Search Results",
+            "",
+            result_count_line(
+                len(organizations),
+                results["upstream_total"],
+                "organizations",
+                post_filtered=True,
+            ),
+            "",
+        ]
+    else:
+        lines = [
+            f"## Organization Search Results ({total} found)",
+            "",
+        ]
+    if failures:
+        lines.append(
+            f"Could not check {len(failures)} organizations for trials:"
+        )
+        lines.extend(f"- {f['name']}: {f['error']}" for f in failures)
+        lines.append("")
 
     for org in organizations:
         org_id = org.get("id", org.get("org_id", "Unknown"))
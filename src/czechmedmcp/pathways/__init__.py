@@ -0,0 +1,18 @@
+"""Pathway information tools for CzechMedMCP."""
+
+from .getter import (
+    PathwayReaction,
+    fetch_pathway_genes,
+    get_pathway,
+    is_reactome_id,
+)
+from .search import PathwaySearchFilters, pathway_search
+
+__all__ = [
+    "PathwayReaction",
+    "PathwaySearchFilters",
+    "fetch_pathway_genes",
+    "get_pathway",
+    "is_reactome_id",
+    "pathway_search",
+]
@@ -0,0 +1,442 @@
+"""Pathway retrieval from the Reactome Content Service.
+
+The base card lists the pathway's contained events. The optional diagram
+section adds the Reactome diagram links and, for each top-level
+reaction, its input and output molecules and catalysts. Reaction details
+take one request per reaction, so they are capped and fetched with
+bounded concurrency.
+
+The optional hierarchy sections walk the Reactome event tree: parents
+are the pathways that directly contain this one (from the event's
+ancestor chains), children are the sub-pathways among its own events.
+"""
+
+import json
+import logging
+import re
+from typing import Any
+from urllib.parse import quote
+
+from pydantic import BaseModel, Field
+
+from .. import ensure_list, http_client
+from ..concurrency import gather_limited, resolve_concurrency
+from ..constants import REACTOME_BROWSER_URL, REACTOME_CONTENT_URL
+
+logger = logging.getLogger(__name__)
+
+# Reactome stable identifier, e.g. R-HSA-5673001
+REACTOME_ID_PATTERN = re.compile(r"^R-[A-Z]{3}-\d+(?:\.\d+)?$")
+
+# Reaction-like event classes whose participants are reported
+REACTION_CLASSES = (
+    "Reaction",
+    "BlackBoxEvent",
+    "Polymerisation",
+    "Depolymerisation",
+    "FailedReaction",
+)
+
+# Event classes that are pathways rather than reactions
+PATHWAY_CLASSES = ("Pathway", "TopLevelPathway", "CellLineagePath")
+
+# Upper bound on reactions detailed in the diagram section
+MAX_DIAGRAM_REACTIONS = 10
+
+# Default parallel reaction lookups
+REACTION_DETAIL_CONCURRENCY = 5
+
+
+class PathwayReaction(BaseModel):
+    """Participants of a single Reactome reaction."""
+
+    st_id: str
+    name: str
+    inputs: list[str] = Field(default_factory=list)
+    outputs: list[str] = Field(default_factory=list)
+    catalysts: list[str] = Field(default_factory=list)
+
+
+def is_reactome_id(value: str) -> bool:
+    """Check whether a value looks like a Reactome stable identifier."""
+    return bool(REACTOME_ID_PATTERN.match(value.strip().upper()))
+
+
+def pathway_diagram_url(st_id: str) -> str:
+    """Return the URL of the rendered Reactome pathway diagram (PNG)."""
+    return f"{REACTOME_CONTENT_URL}/exporter/diagram/{st_id}.png"
+
+
+def pathway_browser_url(st_id: str) -> str:
+    """Return the interactive Reactome Pathway Browser URL."""
+    return f"{REACTOME_BROWSER_URL}{st_id}"
+
+
+def _display_names(entities: Any) -> list[str]:
+    """Collect display names from a list of Reactome objects."""
+    names: list[str] = []
+    for entity in entities or []:
+        if isinstance(entity, dict):
+            name = entity.get("displayName") or entity.get("name")
+            if isinstance(name, list):
+                name = name[0] if name else None
+            if name and name not in names:
+                names.append(name)
+    return names
+
+
+def _catalyst_names(activities: Any) -> list[str]:
+    """Collect catalyst entity names from catalystActivity records."""
+    names: list[str] = []
+    for activity in activities or []:
+        if not isinstance(activity, dict):
+            continue
+        entity = activity.get("physicalEntity")
+        name = (
+            entity.get("displayName")
+            if isinstance(entity, dict)
+            else activity.get("displayName")
+        )
+        if name and name not in names:
+            names.append(name)
+    return names
+
+
+def parse_reaction(data: dict[str, Any]) -> PathwayReaction:
+    """Convert a Reactome reaction object into a PathwayReaction."""
+    return PathwayReaction(
+        st_id=data.get("stId", ""),
+        name=data.get("displayName", "Unknown reaction"),
+        inputs=_display_names(data.get("input")),
+        outputs=_display_names(data.get("output")),
+        catalysts=_catalyst_names(data.get("catalystActivity")),
+    )
+
+
+def pathway_summary(pathway: dict[str, Any]) -> str | None:
+    """Return the first summation text of a pathway, if any."""
+    for summation in pathway.get("summation") or []:
+        if isinstance(summation, dict) and summation.get("text"):
+            return summation["text"]
+    return None
+
+
+def contained_events(pathway: dict[str, Any]) -> list[dict[str, Any]]:
+    """Return the pathway's direct child events as dicts."""
+    return [e for e in pathway.get("hasEvent") or [] if isinstance(e, dict)]
+
+
+def child_pathways(events: list[dict[str, Any]]) -> list[dict[str, Any]]:
+    """Return the sub-pathways among a pathway's direct events."""
+    return [
+        {"id": e.get("stId"), "name": e.get("displayName")}
+        for e in events
+        if e.get("schemaClass") in PATHWAY_CLASSES
+    ]
+
+
+def parent_pathways(
+    st_id: str, chains: list[list[dict[str, Any]]]
+) -> list[dict[str, Any]]:
+    """Return the pathways directly containing ``st_id``.
+
+    Each ancestor chain runs between the event and a top-level pathway;
+    the parent is the chain's entry next to the event, and ``path`` lists
+    the names from the top-level pathway down to that parent.
+    """
+    parents: list[dict[str, Any]] = []
+    seen: set[str] = set()
+    for chain in chains:
+        ids = [node.get("stId") for node in chain]
+        if st_id not in ids:
+            continue
+        # Orient the chain from the event up to its top-level pathway
+        index = ids.index(st_id)
+        lineage = chain[index:] if index == 0 else chain[index::-1]
+        if len(lineage) < 2:
+            continue
+        parent = lineage[1]
+        if parent.get("stId") in seen:
+            continue
+        seen.add(parent.get("stId"))
+        parents.append({
+            "id": parent.get("stId"),
+            "name": parent.get("displayName"),
+            "path": [node.get("displayName") for node in lineage[:0:-1]],
+        })
+    return parents
+
+
+async def fetch_parent_pathways(
+    st_id: str,
+) -> tuple[list[dict[str, Any]] | None, str | None]:
+    """Fetch the pathways that directly contain a Reactome event.
+
+    Returns:
+        Tuple of (parent pathways or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=f"{REACTOME_CONTENT_URL}/data/event/{quote(st_id, safe='')}"
+        "/ancestors",
+        request={},
+        method="GET",
+        domain="reactome",
+    )
+    if error:
+        return None, (
+            f"Error fetching ancestors of {st_id} from Reactome: "
+            f"{error.message}"
+        )
+    chains = [
+        [node for node in chain if isinstance(node, dict)]
+        for chain in response or []
+        if isinstance(chain, list)
+    ]
+    return parent_pathways(st_id, chains), None
+
+
+async def _query_reactome(
+    identifier: str,
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Fetch any Reactome object by stable identifier."""
+    response, error = await http_client.request_api(
+        url=f"{REACTOME_CONTENT_URL}/data/query/{quote(identifier, safe='')}",
+        request={},
+        method="GET",
+        domain="reactome",
+    )
+    if error:
+        return None, f"Error fetching {identifier} from Reactome: {error.message}"
+    if not response or not isinstance(response, dict):
+        return None, f"No Reactome record found for {identifier}"
+    return response, None
+
+
+async def fetch_pathway(
+    pathway_id: str,
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Fetch a Reactome pathway record.
+
+    Returns:
+        Tuple of (pathway dict or None, error message or None)
+    """
+    return await _query_reactome(pathway_id.strip().upper())
+
+
+async def fetch_pathway_genes(
+    pathway_id: str,
+) -> tuple[list[str] | None, str | None]:
+    """Fetch the gene symbols of a pathway's participating proteins.
+
+    Returns:
+        Tuple of (sorted gene symbols or None, error message or None)
+    """
+    st_id = quote(pathway_id.strip().upper(), safe="")
+    response, error = await http_client.request_api(
+        url=f"{REACTOME_CONTENT_URL}/data/participants/{st_id}"
+        "/referenceEntities",
+        request={},
+        method="GET",
+        domain="reactome",
+    )
+    if error:
+        return None, (
+            f"Error fetching participants of {pathway_id} from Reactome: "
+            f"{error.message}"
+        )
+    genes = {
+        ensure_list(entity["geneName"])[0]
+        for entity in response or []
+        if isinstance(entity, dict) and entity.get("geneName")
+    }
+    if not genes:
+        return None, f"No genes found for pathway {pathway_id}"
+    return sorted(genes), None
+
+
+async def fetch_reaction_details(
+    events: list[dict[str, Any]],
+    limit: int = MAX_DIAGRAM_REACTIONS,
+    concurrency: int | None = None,
+) -> list[PathwayReaction]:
+    """Fetch participants for the reaction-like events of a pathway.
+
+    Sub-pathways are skipped; reactions that fail to load are dropped.
+    """
+    reaction_ids = [
+        e["stId"]
+        for e in events
+        if e.get("schemaClass") in REACTION_CLASSES and e.get("stId")
+    ][:limit]
+
+    results = await gather_limited(
+        (_query_reactome(st_id) for st_id in reaction_ids),
+        resolve_concurrency(concurrency, REACTION_DETAIL_CONCURRENCY),
+    )
+
+    reactions: list[PathwayReaction] = []
+    for st_id, (data, error) in zip(reaction_ids, results, strict=True):
+        if error or data is None:
+            logger.warning(f"Skipping reaction {st_id}: {error}")
+            continue
+        reactions.append(parse_reaction(data))
+    return reactions
+
+
+def _format_reaction(reaction: PathwayReaction) -> list[str]:
+    """Format one reaction as a structured list."""
+    lines = [f"- **{reaction.name}** ({reaction.st_id})"]
+    for label, values in (
+        ("Inputs", reaction.inputs),
+        ("Outputs", reaction.outputs),
+        ("Catalysts", reaction.catalysts),
+    ):
+        if values:
+            lines.append(f"  - {label}: {', '.join(values)}")
+    return lines
+
+
+def _format_hierarchy(
+    parents: list[dict[str, Any]] | None,
+    parents_error: str | None,
+    children: list[dict[str, Any]] | None,
+) -> list[str]:
+    """Format the parent and child pathway sections."""
+    lines: list[str] = []
+    if parents is not None or parents_error:
+        lines.extend(["", "## Parent Pathways", ""])
+        if parents_error:
+            lines.append(f"Parent pathways unavailable: {parents_error}")
+        elif not parents:
+            lines.append("No parent pathways; this is a top-level pathway.")
+        for parent in parents or []:
+            lines.append(f"- {parent['name']} ({parent['id']})")
+            if len(parent["path"]) > 1:
+                lines.append(f"  - Path: {' > '.join(parent['path'])}")
+    if children is not None:
+        lines.extend(["", "## Child Pathways", ""])
+        if not children:
+            lines.append("No sub-pathways; this pathway only has reactions.")
+        for child in children:
+            lines.append(f"- {child['name']} ({child['id']})")
+    return lines
+
+
+def _format_pathway(
+    pathway: dict[str, Any],
+    events: list[dict[str, Any]],
+    diagram: dict[str, Any] | None,
+    hierarchy: list[str] | None = None,
+) -> str:
+    """Format a pathway card as markdown."""
+    st_id = pathway.get("stId", "")
+    lines = [f"# {pathway.get('displayName', st_id)}", ""]
+    lines.append(f"- **ID**: {st_id}")
+    if pathway.get("speciesName"):
+        lines.append(f"- **Species**: {pathway['speciesName']}")
+    lines.append(f"- **Reactome**: {pathway_browser_url(st_id)}")
+
+    summary = pathway_summary(pathway)
+    if summary:
+        lines.extend(["", "## Summary", "", summary])
+
+    if events:
+        lines.extend(["", "## Events", ""])
+        for event in events:
+            lines.append(
+                f"- {event.get('displayName', 'Unknown')} "
+                f"({event.get('stId', 'N/A')}, {event.get('schemaClass', '')})"
+            )
+
+    if diagram is not None:
+        lines.extend(["", "## Diagram", ""])
+        lines.append(f"- **Diagram image**: {diagram['diagram_url']}")
+        lines.append(f"- **Pathway browser**: {diagram['browser_url']}")
+        reactions: list[PathwayReaction] = diagram["reactions"]
+        if reactions:
+            lines.extend(["", "### Reactions", ""])
+            for reaction in reactions:
+                lines.extend(_format_reaction(reaction))
+        else:
+            lines.extend(["", "No top-level reactions in this pathway."])
+
+    lines.extend(hierarchy or [])
+    return "\n".join(lines)
+
+
+async def get_pathway(
+    pathway_id: str,
+    include_diagram: bool = False,
+    output_json: bool = False,
+    parents: bool = False,
+    children: bool = False,
+) -> str:
+    """Get a Reactome pathway with its events and optional diagram details.
+
+    Args:
+        pathway_id: Reactome stable identifier (e.g., "R-HSA-5673001")
+        include_diagram: Add diagram links and top-level reaction details
+        output_json: Return JSON instead of formatted markdown
+        parents: Add the pathways that directly contain this one
+        children: Add the sub-pathways contained in this one
+
+    Returns:
+        Formatted markdown or JSON string with the pathway record
+    """
+    pathway, error = await fetch_pathway(pathway_id)
+    if error or pathway is None:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return error or f"No Reactome record found for {pathway_id}"
+
+    st_id = pathway.get("stId", pathway_id.strip().upper())
+    events = contained_events(pathway)
+
+    diagram: dict[str, Any] | None = None
+    if include_diagram:
+        diagram = {
+            "diagram_url": pathway_diagram_url(st_id),
+            "browser_url": pathway_browser_url(st_id),
+            "reactions": await fetch_reaction_details(events),
+        }
+
+    parent_list: list[dict[str, Any]] | None = None
+    parents_error: str | None = None
+    if parents:
+        parent_list, parents_error = await fetch_parent_pathways(st_id)
+    child_list = child_pathways(events) if children else None
+
+    if output_json:
+        data: dict[str, Any] = {
+            "pathway_id": st_id,
+            "name": pathway.get("displayName"),
+            "species": pathway.get("speciesName"),
+            "summary": pathway_summary(pathway),
+            "url": pathway_browser_url(st_id),
+            "events": [
+                {
+                    "id": e.get("stId"),
+                    "name": e.get("displayName"),
+                    "type": e.get("schemaClass"),
+                }
+                for e in events
+            ],
+        }
+        if diagram is not None:
+            data["diagram"] = {
+                **diagram,
+                "reactions": [r.model_dump() for r in diagram["reactions"]],
+            }
+        if parents:
+            data["parents"] = parent_list
+            data["parents_error"] = parents_error
+        if child_list is not None:
+            data["children"] = child_list
+        return json.dumps(data, indent=2)
+
+    return _format_pathway(
+        pathway,
+        events,
+        diagram,
+        _format_hierarchy(parent_list, parents_error, child_list),
+    )
@@ -0,0 +1,200 @@
+"""Pathway search through the Reactome Content Service.
+
+Reactome curates human pathways and projects them onto other species by
+orthology, so a free-text search returns every species' copy of a
+pathway unless it is constrained. The species filter accepts a scientific
+name, a common name or an NCBI taxon ID and resolves it to the species
+name Reactome indexes; searches default to human.
+"""
+
+import json
+import re
+from typing import Any
+
+from pydantic import BaseModel, Field, field_validator
+
+from .. import http_client
+from ..constants import REACTOME_CONTENT_URL
+from .getter import is_reactome_id, pathway_browser_url
+
+DEFAULT_SPECIES = "Homo sapiens"
+
+# Reactome species: scientific name -> (NCBI taxon ID, common names)
+REACTOME_SPECIES: dict[str, tuple[str, tuple[str, ...]]] = {
+    "Homo sapiens": ("9606", ("human",)),
+    "Mus musculus": ("10090", ("mouse",)),
+    "Rattus norvegicus": ("10116", ("rat",)),
+    "Danio rerio": ("7955", ("zebrafish",)),
+    "Drosophila melanogaster": ("7227", ("fly", "fruit fly")),
+    "Caenorhabditis elegans": ("6239", ("worm", "c. elegans")),
+    "Saccharomyces cerevisiae": ("4932", ("yeast", "budding yeast")),
+    "Schizosaccharomyces pombe": ("4896", ("fission yeast",)),
+    "Gallus gallus": ("9031", ("chicken",)),
+    "Xenopus tropicalis": ("8364", ("frog", "xenopus")),
+    "Sus scrofa": ("9823", ("pig",)),
+    "Bos taurus": ("9913", ("cow", "cattle")),
+    "Canis familiaris": ("9615", ("dog",)),
+    "Dictyostelium discoideum": ("44689", ("dictyostelium",)),
+    "Plasmodium falciparum": ("36329", ("plasmodium", "malaria parasite")),
+    "Mycobacterium tuberculosis": ("1773", ("tuberculosis",)),
+}
+
+# Search highlighting wraps matched terms in <span> tags
+HIGHLIGHT_PATTERN = re.compile(r"<[^>]+>")
+
+
+def normalize_species(value: str) -> str:
+    """Resolve a species name, common name or taxon ID to Reactome's name.
+
+    Raises:
+        ValueError: If the species is not one Reactome annotates
+    """
+    key = value.strip().lower()
+    key = key.removeprefix("ncbitaxon:").removeprefix("taxon:")
+    for name, (taxon, common_names) in REACTOME_SPECIES.items():
+        if key in (name.lower(), taxon, *common_names):
+            return name
+    raise ValueError(
+        f"Unknown species '{value}'. Use a scientific name, common name "
+        "or NCBI taxon ID, e.g. human, mouse or 10090"
+    )
+
+
+class PathwaySearchFilters(BaseModel):
+    """Filters for a Reactome pathway search."""
+
+    query: str
+    species: str = Field(
+        default=DEFAULT_SPECIES,
+        description="Species name, common name or NCBI taxon ID",
+    )
+    limit: int = Field(default=10, ge=1, le=100)
+
+    @field_validator("species")
+    @classmethod
+    def _check_species(cls, value: str) -> str:
+        return normalize_species(value)
+
+
+def pathway_search_params(filters: PathwaySearchFilters) -> dict[str, Any]:
+    """Build the Reactome search query parameters."""
+    return {
+        "query": filters.query,
+        "species": filters.species,
+        "types": "Pathway",
+        "cluster": "true",
+        "rows": filters.limit,
+    }
+
+
+def search_query_summary(filters: PathwaySearchFilters) -> str:
+    """Render a one-line summary of the pathway search filters."""
+    taxon = REACTOME_SPECIES[filters.species][0]
+    return " | ".join([
+        f"Query: {filters.query}",
+        f"Species: {filters.species} (taxon {taxon})",
+    ])
+
+
+def _clean(text: Any) -> str:
+    return HIGHLIGHT_PATTERN.sub("", str(text or "")).strip()
+
+
+def parse_search_results(
+    response: dict[str, Any], limit: int
+) -> list[dict[str, Any]]:
+    """Flatten Reactome search result groups into pathway records."""
+    pathways: list[dict[str, Any]] = []
+    for group in response.get("results") or []:
+        for entry in group.get("entries") or []:
+            if not isinstance(entry, dict) or not entry.get("stId"):
+                continue
+            species = entry.get("species")
+            if isinstance(species, list):
+                species = species[0] if species else None
+            pathways.append({
+                "id": entry["stId"],
+                "name": _clean(entry.get("name")),
+                "species": species,
+                "url": pathway_browser_url(entry["stId"]),
+            })
+    return pathways[:limit]
+
+
+async def search_pathways(
+    filters: PathwaySearchFilters,
+) -> tuple[list[dict[str, Any]] | None, str | None]:
+    """Search Reactome pathways matching the filters.
+
+    Returns:
+        Tuple of (pathway records or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=f"{REACTOME_CONTENT_URL}/search/query",
+        request=pathway_search_params(filters),
+        method="GET",
+        domain="reactome",
+    )
+    if error:
+        # Reactome answers 404 when nothing matches
+        if error.code == 404:
+            return [], None
+        return None, f"Error searching Reactome: {error.message}"
+    return parse_search_results(response or {}, filters.limit), None
+
+
+async def resolve_pathway(
+    value: str, species: str = DEFAULT_SPECIES
+) -> tuple[dict[str, str | None] | None, str | None]:
+    """Resolve a pathway name or Reactome stable ID to a stable ID.
+
+    IDs are returned as given (upper-cased) without a name; names are
+    looked up with a Reactome search and resolve to the top-ranked
+    pathway, whose name is returned with it.
+
+    Returns:
+        Tuple of ({"id", "name"} or None, error message or None)
+    """
+    if is_reactome_id(value):
+        return {"id": value.strip().upper(), "name": None}, None
+    pathways, error = await search_pathways(
+        PathwaySearchFilters(query=value.strip(), species=species, limit=1)
+    )
+    if error or pathways is None:
+        return None, error
+    if not pathways:
+        return None, f"No Reactome pathway found for '{value}'"
+    return {"id": pathways[0]["id"], "name": pathways[0]["name"]}, None
+
+
+def format_pathway_results(
+    filters: PathwaySearchFilters, pathways: list[dict[str, Any]]
+) -> str:
+    """Format pathway search results as markdown."""
+    lines = ["# Pathway Search Results", ""]
+    lines.append(f"_{search_query_summary(filters)}_")
+    lines.append("")
+    if not pathways:
+        lines.append("No pathways found.")
+        return "\n".join(lines)
+    for pathway in pathways:
+        lines.append(f"- **{pathway['name']}** ({pathway['id']})")
+        lines.append(f"  - {pathway['url']}")
+    return "\n".join(lines)
+
+
+async def pathway_search(
+    filters: PathwaySearchFilters, output_json: bool = False
+) -> str:
+    """Search Reactome pathways and render the results."""
+    pathways, error = await search_pathways(filters)
+    if error or pathways is None:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return f"Error: {error}"
+    if output_json:
+        return json.dumps(
+            {"query": search_query_summary(filters), "pathways": pathways},
+            indent=2,
+        )
+    return format_pathway_results(filters, pathways)
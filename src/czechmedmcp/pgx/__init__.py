@@ -0,0 +1,9 @@
+"""Pharmacogenomics (CPIC) tools for CzechMedMCP."""
+
+from .search import MetabolizerPhenotype, PgxSearchFilters, pgx_search
+
+__all__ = [
+    "MetabolizerPhenotype",
+    "PgxSearchFilters",
+    "pgx_search",
+]
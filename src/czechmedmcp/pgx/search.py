@@ -0,0 +1,205 @@
+"""Pharmacogenomic dosing recommendations from the CPIC API.
+
+CPIC (Clinical Pharmacogenetics Implementation Consortium) publishes
+its guideline recommendations through a PostgREST API. Each
+recommendation applies to a combination of gene phenotypes, stored as a
+``{"CYP2D6": "Poor Metabolizer"}`` object, so a gene filter selects the
+recommendations that mention the gene and a phenotype filter the ones
+for that metabolizer status. With a gene the phenotype is matched
+server-side; without one it is matched against any gene's phenotype
+after the drug's recommendations are fetched.
+"""
+
+import json
+from typing import Any
+
+from pydantic import BaseModel, Field, field_validator, model_validator
+
+from .. import StrEnum, http_client
+from ..constants import CPIC_RECOMMENDATION_URL
+
+RECOMMENDATION_FIELDS = [
+    "drugname",
+    "guidelinename",
+    "guidelineurl",
+    "phenotypes",
+    "implications",
+    "drugrecommendation",
+    "classification",
+]
+
+
+class MetabolizerPhenotype(StrEnum):
+    """CPIC metabolizer phenotypes."""
+
+    POOR = "poor"
+    INTERMEDIATE = "intermediate"
+    NORMAL = "normal"
+    RAPID = "rapid"
+    ULTRARAPID = "ultrarapid"
+
+
+def phenotype_label(phenotype: MetabolizerPhenotype) -> str:
+    """Return the phenotype as CPIC spells it, e.g. "Poor Metabolizer"."""
+    return f"{phenotype.value.capitalize()} Metabolizer"
+
+
+class PgxSearchFilters(BaseModel):
+    """Filters for a CPIC recommendation search."""
+
+    gene: str | None = Field(
+        default=None, description="Pharmacogene symbol (e.g., CYP2D6)"
+    )
+    drug: str | None = Field(
+        default=None, description="Drug name (e.g., codeine)"
+    )
+    phenotype: MetabolizerPhenotype | None = Field(
+        default=None,
+        description="Only recommendations for this metabolizer status",
+    )
+    limit: int = Field(default=25, ge=1, le=100)
+
+    @field_validator("gene")
+    @classmethod
+    def _normalize_gene(cls, value: str | None) -> str | None:
+        return value.strip().upper() if value else None
+
+    @field_validator("drug")
+    @classmethod
+    def _normalize_drug(cls, value: str | None) -> str | None:
+        return value.strip().lower() if value else None
+
+    @model_validator(mode="after")
+    def _check_query(self) -> "PgxSearchFilters":
+        if not self.gene and not self.drug:
+            raise ValueError("specify a gene or a drug")
+        return self
+
+
+def recommendation_params(filters: PgxSearchFilters) -> dict[str, Any]:
+    """Build the CPIC (PostgREST) query parameters."""
+    params: dict[str, Any] = {"select": ",".join(RECOMMENDATION_FIELDS)}
+    if filters.drug:
+        params["drugname"] = f"eq.{filters.drug}"
+    if filters.gene:
+        params[f"phenotypes->>{filters.gene}"] = (
+            f"eq.{phenotype_label(filters.phenotype)}"
+            if filters.phenotype
+            else "not.is.null"
+        )
+    return params
+
+
+def matches_phenotype(
+    recommendation: dict[str, Any], filters: PgxSearchFilters
+) -> bool:
+    """Check a recommendation against the phenotype filter."""
+    if filters.phenotype is None:
+        return True
+    phenotypes = recommendation.get("phenotypes") or {}
+    if not isinstance(phenotypes, dict):
+        return False
+    label = phenotype_label(filters.phenotype)
+    if filters.gene:
+        return phenotypes.get(filters.gene) == label
+    return label in phenotypes.values()
+
+
+def search_query_summary(filters: PgxSearchFilters) -> str:
+    """Render a one-line summary of the PGx search filters."""
+    parts = []
+    if filters.gene:
+        parts.append(f"Gene: {filters.gene}")
+    if filters.drug:
+        parts.append(f"Drug: {filters.drug}")
+    if filters.phenotype:
+        parts.append(f"Phenotype: {phenotype_label(filters.phenotype)}")
+    return " | ".join(parts)
+
+
+def _to_record(recommendation: dict[str, Any]) -> dict[str, Any]:
+    phenotypes = recommendation.get("phenotypes") or {}
+    if not isinstance(phenotypes, dict):
+        phenotypes = {}
+    return {
+        "drug": recommendation.get("drugname"),
+        "phenotypes": phenotypes,
+        "recommendation": recommendation.get("drugrecommendation"),
+        "classification": recommendation.get("classification"),
+        "implications": recommendation.get("implications"),
+        "guideline": recommendation.get("guidelinename"),
+        "url": recommendation.get("guidelineurl"),
+    }
+
+
+async def search_pgx(
+    filters: PgxSearchFilters,
+) -> tuple[list[dict[str, Any]] | None, str | None]:
+    """Search CPIC recommendations matching the filters.
+
+    Returns:
+        Tuple of (recommendation records or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=CPIC_RECOMMENDATION_URL,
+        request=recommendation_params(filters),
+        method="GET",
+        domain="cpic",
+    )
+    if error:
+        return None, f"Error searching CPIC: {error.message}"
+    records = [
+        _to_record(item)
+        for item in response or []
+        if isinstance(item, dict) and matches_phenotype(item, filters)
+    ]
+    return records[: filters.limit], None
+
+
+def format_pgx_results(
+    filters: PgxSearchFilters, records: list[dict[str, Any]]
+) -> str:
+    """Format CPIC recommendations as markdown."""
+    lines = ["# Pharmacogenomic Recommendations", ""]
+    lines.append(f"_{search_query_summary(filters)}_")
+    lines.append("")
+    if not records:
+        lines.append("No CPIC recommendations found.")
+        return "\n".join(lines)
+    for record in records:
+        phenotypes = ", ".join(
+            f"{gene}: {status}"
+            for gene, status in record["phenotypes"].items()
+        )
+        lines.append(f"## {record['drug']} ({phenotypes or 'any phenotype'})")
+        if record["recommendation"]:
+            lines.append(f"- **Recommendation**: {record['recommendation']}")
+        if record["classification"]:
+            lines.append(f"- **Strength**: {record['classification']}")
+        if record["guideline"]:
+            guideline = record["guideline"]
+            if record["url"]:
+                guideline += f" ({record['url']})"
+            lines.append(f"- **Guideline**: {guideline}")
+        lines.append("")
+    return "\n".join(lines).rstrip()
+
+
+async def pgx_search(
+    filters: PgxSearchFilters, output_json: bool = False
+) -> str:
+    """Search CPIC recommendations and render the results."""
+    records, error = await search_pgx(filters)
+    if error or records is None:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return f"Error: {error}"
+    if output_json:
+        return json.dumps(
+            {
+                "query_summary": search_query_summary(filters),
+                "results": records,
+            },
+            indent=2,
+        )
+    return format_pgx_results(filters, records)
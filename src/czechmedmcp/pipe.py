@@ -0,0 +1,153 @@
+"""Run one CLI command per identifier read from stdin.
+
+``czechmedmcp pipe 'variant get {}'`` reads newline-delimited values,
+substitutes each one for the ``{}`` placeholder in the command template
+and runs the resulting command. This turns the common shell pattern of
+feeding IDs from one command into another into a single step:
+
+    printf 'TP53\\nBRAF\\n' | czechmedmcp pipe 'gene get {}'
+
+Each command runs through ``execute`` in its own interpreter, so the
+per-command ``asyncio.run`` calls, output and exit codes stay isolated
+from one another. The global ``--verbose``, ``--concurrency``,
+``--dry-run`` and ``--color`` options given to ``pipe`` are passed on to
+every command. A failing value does not stop the others; failures are
+reported alongside the outputs that succeeded.
+"""
+
+import asyncio
+import logging
+import shlex
+import sys
+from collections.abc import Iterable
+from dataclasses import dataclass
+
+from .concurrency import gather_limited, get_concurrency, resolve_concurrency
+from .dry_run import is_dry_run
+from .terminal import use_color
+
+# Default number of commands running at once
+PIPE_CONCURRENCY = 4
+
+PLACEHOLDER = "{}"
+
+
+@dataclass
+class PipeResult:
+    """Outcome of running the template for one input value."""
+
+    value: str
+    args: list[str]
+    returncode: int
+    stdout: str
+    stderr: str
+
+    @property
+    def ok(self) -> bool:
+        return self.returncode == 0
+
+    @property
+    def error(self) -> str:
+        """Last line of stderr, or a generic message when it is empty."""
+        lines = [line for line in self.stderr.splitlines() if line.strip()]
+        if lines:
+            return lines[-1].removeprefix("Error: ")
+        return f"command exited with status {self.returncode}"
+
+
+def parse_template(template: str) -> list[str]:
+    """Split a command template into arguments, shell style."""
+    try:
+        args = shlex.split(template)
+    except ValueError as exc:
+        raise ValueError(f"Invalid command template: {exc}") from exc
+    if not args:
+        raise ValueError("Command template is empty")
+    if args[0] == "pipe":
+        raise ValueError("Command template cannot start another pipe")
+    if not any(PLACEHOLDER in arg for arg in args):
+        raise ValueError(
+            f"Command template needs a {PLACEHOLDER} placeholder for "
+            "each input value"
+        )
+    return args
+
+
+def expand_template(args: list[str], value: str) -> list[str]:
+    """Substitute one input value for every placeholder."""
+    return [arg.replace(PLACEHOLDER, value) for arg in args]
+
+
+def read_values(lines: Iterable[str]) -> list[str]:
+    """Input values, one per non-blank line."""
+    return [line.strip() for line in lines if line.strip()]
+
+
+def global_options() -> list[str]:
+    """The global CLI options in effect, as arguments for a command.
+
+    Color is resolved against this process's stdout, since the command's
+    own stdout is a pipe back to us.
+    """
+    options = []
+    if logging.getLogger("czechmedmcp").isEnabledFor(logging.INFO):
+        options.append("--verbose")
+    concurrency = get_concurrency()
+    if concurrency is not None:
+        options += ["--concurrency", str(concurrency)]
+    if is_dry_run():
+        options.append("--dry-run")
+    options += ["--color", "always" if use_color() else "never"]
+    return options
+
+
+async def execute(args: list[str]) -> tuple[int, str, str]:
+    """Run one CLI command and return (exit code, stdout, stderr).
+
+    The command runs with the global options of this process.
+    """
+    process = await asyncio.create_subprocess_exec(
+        sys.executable,
+        "-m",
+        "czechmedmcp",
+        *global_options(),
+        *args,
+        stdin=asyncio.subprocess.DEVNULL,
+        stdout=asyncio.subprocess.PIPE,
+        stderr=asyncio.subprocess.PIPE,
+    )
+    stdout, stderr = await process.communicate()
+    return (
+        process.returncode or 0,
+        stdout.decode(errors="replace"),
+        stderr.decode(errors="replace"),
+    )
+
+
+async def _run_one(args: list[str], value: str) -> PipeResult:
+    command = expand_template(args, value)
+    try:
+        returncode, stdout, stderr = await execute(command)
+    except OSError as exc:
+        return PipeResult(value, command, 1, "", str(exc))
+    return PipeResult(value, command, returncode, stdout, stderr)
+
+
+async def run_pipe(
+    template: str,
+    values: list[str],
+    concurrency: int | None = None,
+) -> list[PipeResult]:
+    """Run the template for every value, keeping input order."""
+    args = parse_template(template)
+    return await gather_limited(
+        (_run_one(args, value) for value in values),
+        resolve_concurrency(concurrency, PIPE_CONCURRENCY),
+    )
+
+
+def format_pipe_output(results: list[PipeResult], separator: str) -> str:
+    """Concatenate the output of the commands that succeeded."""
+    return separator.join(
+        result.stdout.rstrip("\n") for result in results if result.ok
+    )
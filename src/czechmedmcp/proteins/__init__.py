@@ -0,0 +1,15 @@
+"""Protein information tools for CzechMedMCP."""
+
+from .domains import ProteinDomain, get_protein_domains, is_uniprot_accession
+from .getter import get_gene_protein_fasta, get_protein, get_protein_fasta
+from .search import search_proteins
+
+__all__ = [
+    "ProteinDomain",
+    "get_gene_protein_fasta",
+    "get_protein",
+    "get_protein_domains",
+    "get_protein_fasta",
+    "is_uniprot_accession",
+    "search_proteins",
+]
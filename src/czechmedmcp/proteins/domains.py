@@ -0,0 +1,169 @@
+"""Protein domain annotations from the InterPro API.
+
+Domains are read from InterPro's protein-to-entry mapping, which carries
+Pfam and InterPro matches together with their residue positions. Each
+location fragment becomes one domain record, so a repeated domain is
+listed once per occurrence.
+"""
+
+import json
+import logging
+import re
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from .. import http_client
+from ..constants import INTERPRO_BASE_URL
+
+logger = logging.getLogger(__name__)
+
+# Member databases reported as domains
+DOMAIN_DATABASES = ("pfam", "interpro")
+
+# InterPro entry types treated as domain-level annotations
+DOMAIN_ENTRY_TYPES = ("domain", "repeat")
+
+# InterPro caps page_size at 200, which covers nearly every protein
+INTERPRO_PAGE_SIZE = 200
+
+_UNIPROT_ACCESSION_PATTERN = re.compile(
+    r"^(?:[OPQ][0-9][A-Z0-9]{3}[0-9]|"
+    r"[A-NR-Z][0-9](?:[A-Z][A-Z0-9]{2}[0-9]){1,2})$"
+)
+
+
+class ProteinDomain(BaseModel):
+    """A single domain occurrence on a protein sequence."""
+
+    name: str
+    database: str
+    accession: str | None = Field(
+        default=None, description="Pfam or InterPro entry accession"
+    )
+    start: int
+    end: int
+
+
+def is_uniprot_accession(value: str) -> bool:
+    """Check whether a value looks like a UniProtKB accession."""
+    return bool(_UNIPROT_ACCESSION_PATTERN.match(value.strip().upper()))
+
+
+def parse_interpro_domains(response: dict[str, Any]) -> list[ProteinDomain]:
+    """Convert an InterPro entry-by-protein response into domain records.
+
+    Records are sorted by start position, then by database.
+    """
+    domains: list[ProteinDomain] = []
+    for result in response.get("results", []):
+        metadata = result.get("metadata", {})
+        database = (metadata.get("source_database") or "").lower()
+        entry_type = (metadata.get("type") or "").lower()
+        if (
+            database not in DOMAIN_DATABASES
+            or entry_type not in DOMAIN_ENTRY_TYPES
+        ):
+            continue
+
+        name = metadata.get("name")
+        if isinstance(name, dict):
+            name = name.get("name")
+        name = name or metadata.get("accession", "Unknown")
+
+        for protein in result.get("proteins", []):
+            for location in protein.get("entry_protein_locations") or []:
+                for fragment in location.get("fragments", []):
+                    if fragment.get("start") is None:
+                        continue
+                    domains.append(
+                        ProteinDomain(
+                            name=name,
+                            database=database,
+                            accession=metadata.get("accession"),
+                            start=fragment["start"],
+                            end=fragment.get("end", fragment["start"]),
+                        )
+                    )
+
+    return sorted(domains, key=lambda d: (d.start, d.database, d.end))
+
+
+async def fetch_protein_domains(
+    accession: str,
+) -> tuple[list[ProteinDomain], str | None]:
+    """Fetch the Pfam/InterPro domains of a UniProtKB accession.
+
+    Returns:
+        Tuple of (domains, error message or None)
+    """
+    accession = accession.strip().upper()
+    url = f"{INTERPRO_BASE_URL}/entry/all/protein/uniprot/{accession}/"
+    response, error = await http_client.request_api(
+        url=url,
+        request={"page_size": INTERPRO_PAGE_SIZE},
+        method="GET",
+        domain="interpro",
+    )
+
+    if error:
+        return [], f"Error fetching domains for {accession}: {error.message}"
+
+    return parse_interpro_domains(response or {}), None
+
+
+def _format_domains(
+    accession: str,
+    domains: list[ProteinDomain],
+    total: int,
+    offset: int,
+) -> str:
+    """Format domain records as a markdown table."""
+    lines = [f"# Protein Domains: {accession}", ""]
+    if not domains:
+        lines.append("No Pfam or InterPro domains found.")
+        return "\n".join(lines)
+
+    lines.append(
+        f"Showing {offset + 1}-{offset + len(domains)} of {total} domains."
+    )
+    lines.append("")
+    lines.append("| Domain | Database | Accession | Start | End |")
+    lines.append("|--------|----------|-----------|-------|-----|")
+    for domain in domains:
+        lines.append(
+            f"| {domain.name} | {domain.database} | "
+            f"{domain.accession or ''} | {domain.start} | {domain.end} |"
+        )
+    return "\n".join(lines)
+
+
+async def get_protein_domains(
+    accession: str,
+    limit: int = 20,
+    offset: int = 0,
+    output_json: bool = False,
+) -> str:
+    """List the domains of a protein with their positions.
+
+    Args:
+        accession: UniProtKB accession (e.g., "P15056")
+        limit: Maximum number of domains to return
+        offset: Number of domains to skip
+        output_json: Return JSON instead of formatted markdown
+
+    Returns:
+        Markdown table or JSON list of {name, database, start, end} records
+    """
+    accession = accession.strip().upper()
+    domains, error = await fetch_protein_domains(accession)
+
+    if error:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return error
+
+    page = domains[offset : offset + limit]
+    if output_json:
+        return json.dumps([d.model_dump() for d in page], indent=2)
+    return _format_domains(accession, page, len(domains), offset)
@@ -0,0 +1,108 @@
+"""Protein record and sequence retrieval from UniProtKB."""
+
+import json
+import logging
+
+from .. import render
+from .ptm import format_protein_ptms
+from .uniprot import (
+    UniProtEntry,
+    fetch_uniprot_entry,
+    search_gene_entry,
+    uniprot_fasta_header,
+)
+from .variants import format_protein_variants
+
+logger = logging.getLogger(__name__)
+
+
+def entry_to_fasta(entry: UniProtEntry) -> tuple[str | None, str | None]:
+    """Render an entry's canonical sequence as FASTA.
+
+    Returns:
+        Tuple of (FASTA text or None, error message or None)
+    """
+    if not entry.sequence:
+        return None, f"No sequence available for {entry.accession}"
+    return render.to_fasta(uniprot_fasta_header(entry), entry.sequence), None
+
+
+async def get_protein_fasta(accession: str) -> tuple[str | None, str | None]:
+    """Fetch the canonical protein sequence of an accession as FASTA."""
+    entry, error = await fetch_uniprot_entry(accession)
+    if error or entry is None:
+        return None, error
+    return entry_to_fasta(entry)
+
+
+async def get_gene_protein_fasta(
+    gene_symbol: str,
+) -> tuple[str | None, str | None]:
+    """Fetch the canonical human protein sequence of a gene as FASTA."""
+    entry, error = await search_gene_entry(gene_symbol)
+    if error or entry is None:
+        return None, error
+    return entry_to_fasta(entry)
+
+
+def _format_protein(entry: UniProtEntry) -> str:
+    """Format a protein card as markdown."""
+    title = entry.protein_name or entry.accession
+    lines = [f"# {title}", ""]
+    lines.append(f"- **Accession**: {entry.accession}")
+    if entry.entry_name:
+        lines.append(f"- **Entry name**: {entry.entry_name}")
+    lines.append(
+        f"- **Status**: {'reviewed (Swiss-Prot)' if entry.reviewed else 'unreviewed (TrEMBL)'}"
+    )
+    if entry.gene_symbol:
+        lines.append(f"- **Gene**: {entry.gene_symbol}")
+    if entry.organism:
+        lines.append(f"- **Organism**: {entry.organism}")
+    if entry.length:
+        lines.append(f"- **Length**: {entry.length} aa")
+    lines.append(
+        f"- **UniProt**: https://www.uniprot.org/uniprotkb/{entry.accession}"
+    )
+    return "\n".join(lines)
+
+
+async def get_protein(
+    accession: str,
+    output_json: bool = False,
+    variants: bool = False,
+    ptm: bool = False,
+) -> str:
+    """Get a protein record from UniProtKB.
+
+    Args:
+        accession: UniProtKB accession (e.g., "P15056")
+        output_json: Return JSON instead of formatted markdown
+        variants: Add the disease-associated variants UniProt annotates
+        ptm: Add the post-translational modification sites UniProt
+            annotates, with the modifying enzyme where known
+
+    Returns:
+        Formatted markdown or JSON string with the protein record
+    """
+    entry, error = await fetch_uniprot_entry(
+        accession, variants=variants, ptms=ptm
+    )
+    if error or entry is None:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return error or f"No UniProt entry found for {accession}"
+
+    exclude = {"sequence"}
+    if not variants:
+        exclude.add("variants")
+    if not ptm:
+        exclude.add("ptms")
+    if output_json:
+        return json.dumps(entry.model_dump(exclude=exclude), indent=2)
+    text = _format_protein(entry)
+    if variants:
+        text += "\n\n" + format_protein_variants(entry.variants)
+    if ptm:
+        text += "\n\n" + format_protein_ptms(entry.ptms)
+    return text
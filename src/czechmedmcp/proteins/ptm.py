@@ -0,0 +1,73 @@
+"""Post-translational modification sites from the UniProt feature table.
+
+UniProtKB curates modification sites as separate feature types:
+modified residues (phosphorylation, acetylation, methylation...),
+glycosylation, cross-links (ubiquitin and SUMO conjugation) and
+lipidation. The description names the modification and, when known,
+the modifying enzyme ("Phosphoserine; by CDK1 and CDK2").
+"""
+
+from typing import Any
+
+from pydantic import BaseModel
+
+from .variants import feature_position
+
+# UniProt REST return fields and the feature types they hold
+PTM_FIELDS = {
+    "ft_mod_res": "Modified residue",
+    "ft_carbohyd": "Glycosylation",
+    "ft_crosslnk": "Cross-link",
+    "ft_lipid": "Lipidation",
+}
+
+ENZYME_PREFIX = "by "
+
+
+class ProteinPtm(BaseModel):
+    """A post-translational modification site of a protein."""
+
+    position: str
+    modification: str
+    enzyme: str | None = None
+
+
+def parse_protein_ptms(features: list[dict[str, Any]]) -> list[ProteinPtm]:
+    """Extract modification sites from UniProt features."""
+    ptms: list[ProteinPtm] = []
+    for feature in features:
+        if feature.get("type") not in PTM_FIELDS.values():
+            continue
+        parts = [
+            part.strip()
+            for part in (feature.get("description") or "").split(";")
+        ]
+        enzymes = [
+            part.removeprefix(ENZYME_PREFIX)
+            for part in parts[1:]
+            if part.startswith(ENZYME_PREFIX)
+        ]
+        ptms.append(
+            ProteinPtm(
+                position=feature_position(feature.get("location") or {}),
+                modification=parts[0] or feature["type"],
+                enzyme="; ".join(enzymes) or None,
+            )
+        )
+    return ptms
+
+
+def format_protein_ptms(ptms: list[ProteinPtm]) -> str:
+    """Format modification sites as a markdown section."""
+    lines = [f"## Post-Translational Modifications ({len(ptms)})", ""]
+    if not ptms:
+        lines.append("No modification sites annotated in UniProt.")
+        return "\n".join(lines)
+
+    lines.append("| Position | Modification | Enzyme |")
+    lines.append("|----------|--------------|--------|")
+    for ptm in ptms:
+        lines.append(
+            f"| {ptm.position} | {ptm.modification} | {ptm.enzyme or ''} |"
+        )
+    return "\n".join(lines)
@@ -0,0 +1,209 @@
+"""UniProtKB protein search, limited to human entries by default.
+
+Searches are restricted to reviewed (Swiss-Prot) entries of one
+organism. Human is the default; ``organism`` targets another taxon by
+NCBI taxonomy ID or common name, and ``all_species`` drops the organism
+term altogether. The two cannot be combined.
+"""
+
+import json
+
+from .. import http_client
+from ..constants import UNIPROT_SEARCH_URL
+from .uniprot import (
+    ENTRY_FIELDS,
+    HUMAN_TAXON_ID,
+    UniProtEntry,
+    parse_uniprot_entry,
+)
+
+# Common names accepted for --organism, mapped to NCBI taxonomy IDs
+ORGANISM_ALIASES = {
+    "human": HUMAN_TAXON_ID,
+    "mouse": 10090,
+    "rat": 10116,
+    # S. cerevisiae S288C, the strain UniProt's reviewed entries use
+    "yeast": 559292,
+}
+
+MAX_PROTEIN_SEARCH_SIZE = 500
+
+# Search results are listed without sequences
+SEARCH_FIELDS = [field for field in ENTRY_FIELDS if field != "sequence"]
+
+
+def resolve_organism(organism: str) -> int:
+    """Resolve a taxonomy ID or common name to a taxonomy ID.
+
+    Raises:
+        ValueError: If the value is neither a positive integer nor a
+            known common name.
+    """
+    value = organism.strip().lower()
+    if value.isdigit() and int(value) > 0:
+        return int(value)
+    if value in ORGANISM_ALIASES:
+        return ORGANISM_ALIASES[value]
+    raise ValueError(
+        f"Unknown organism '{organism}'. Use an NCBI taxonomy ID or one "
+        f"of: {', '.join(ORGANISM_ALIASES)}"
+    )
+
+
+def _organism_label(organism: str | None) -> str:
+    """Describe an organism value with its taxonomy ID."""
+    if organism is None:
+        return f"human ({HUMAN_TAXON_ID})"
+    name = organism.strip().lower()
+    taxon_id = resolve_organism(organism)
+    if name in ORGANISM_ALIASES:
+        return f"{name} ({taxon_id})"
+    return str(taxon_id)
+
+
+def build_protein_query(
+    query: str | None = None,
+    gene: str | None = None,
+    organism: str | None = None,
+    all_species: bool = False,
+) -> str:
+    """Build a UniProt query string for reviewed entries.
+
+    Raises:
+        ValueError: On an unknown organism, when ``organism`` is combined
+            with ``all_species``, or when no search term is given.
+    """
+    if organism is not None and all_species:
+        raise ValueError("--organism cannot be combined with --all-species")
+    if not (query and query.strip()) and not (gene and gene.strip()):
+        raise ValueError("Provide a search query or --gene")
+
+    terms: list[str] = []
+    if query and query.strip():
+        terms.append(f"({query.strip()})")
+    if gene and gene.strip():
+        terms.append(f"gene_exact:{gene.strip().upper()}")
+    if not all_species:
+        taxon_id = (
+            resolve_organism(organism) if organism else HUMAN_TAXON_ID
+        )
+        terms.append(f"organism_id:{taxon_id}")
+    terms.append("reviewed:true")
+    return " AND ".join(terms)
+
+
+def protein_search_query_summary(
+    query: str | None = None,
+    gene: str | None = None,
+    organism: str | None = None,
+    all_species: bool = False,
+) -> str:
+    """Render a one-line summary of the search terms and species scope."""
+    parts: list[str] = []
+    if query:
+        parts.append(f"Query: {query}")
+    if gene:
+        parts.append(f"Gene: {gene.upper()}")
+    if all_species:
+        parts.append("Organism: all species")
+    else:
+        parts.append(f"Organism: {_organism_label(organism)}")
+    return " | ".join(parts)
+
+
+async def fetch_protein_search(
+    query: str | None = None,
+    gene: str | None = None,
+    organism: str | None = None,
+    all_species: bool = False,
+    limit: int = 10,
+) -> tuple[list[UniProtEntry], str | None]:
+    """Search reviewed UniProtKB entries.
+
+    Returns:
+        Tuple of (matching entries, error message or None)
+    """
+    try:
+        uniprot_query = build_protein_query(
+            query, gene, organism, all_species
+        )
+    except ValueError as e:
+        return [], str(e)
+
+    response, error = await http_client.request_api(
+        url=UNIPROT_SEARCH_URL,
+        request={
+            "query": uniprot_query,
+            "fields": ",".join(SEARCH_FIELDS),
+            "size": min(limit, MAX_PROTEIN_SEARCH_SIZE),
+        },
+        method="GET",
+        domain="uniprot",
+    )
+    if error:
+        return [], f"Error searching UniProt: {error.message}"
+    results = (response or {}).get("results") or []
+    return [parse_uniprot_entry(result) for result in results], None
+
+
+def _format_results(summary: str, entries: list[UniProtEntry]) -> str:
+    """Format protein search results as markdown."""
+    lines = ["# Protein search results", "", f"**Query**: {summary}", ""]
+    if not entries:
+        lines.append("No reviewed UniProt entries found.")
+        return "\n".join(lines)
+    for index, entry in enumerate(entries, start=1):
+        title = entry.protein_name or entry.accession
+        lines.append(f"## {index}. {title}")
+        lines.append(f"- **Accession**: {entry.accession}")
+        if entry.gene_symbol:
+            lines.append(f"- **Gene**: {entry.gene_symbol}")
+        if entry.organism:
+            lines.append(f"- **Organism**: {entry.organism}")
+        if entry.length:
+            lines.append(f"- **Length**: {entry.length} aa")
+        lines.append("")
+    return "\n".join(lines).rstrip() + "\n"
+
+
+async def search_proteins(
+    query: str | None = None,
+    gene: str | None = None,
+    organism: str | None = None,
+    all_species: bool = False,
+    limit: int = 10,
+    output_json: bool = False,
+) -> str:
+    """Search reviewed UniProtKB entries, human only unless told otherwise.
+
+    Args:
+        query: Free-text UniProt query (e.g., "kinase")
+        gene: Exact gene symbol
+        organism: NCBI taxonomy ID or common name (mouse, rat, yeast)
+        all_species: Search every organism instead of only human
+        limit: Maximum number of entries
+        output_json: Return JSON instead of formatted markdown
+
+    Returns:
+        Formatted markdown or JSON string with the matching entries
+    """
+    entries, error = await fetch_protein_search(
+        query, gene, organism, all_species, limit
+    )
+    if error:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return f"Error: {error}"
+
+    if output_json:
+        return json.dumps(
+            [
+                entry.model_dump(exclude={"sequence", "variants"})
+                for entry in entries
+            ],
+            indent=2,
+        )
+    summary = protein_search_query_summary(
+        query, gene, organism, all_species
+    )
+    return _format_results(summary, entries)
@@ -0,0 +1,160 @@
+"""UniProtKB entry retrieval via the UniProt REST API."""
+
+import logging
+from typing import Any
+from urllib.parse import quote
+
+from pydantic import BaseModel, Field
+
+from .. import http_client
+from ..constants import UNIPROT_BASE_URL, UNIPROT_SEARCH_URL
+from .ptm import PTM_FIELDS, ProteinPtm, parse_protein_ptms
+from .variants import VARIANT_FIELD, ProteinVariant, parse_protein_variants
+
+logger = logging.getLogger(__name__)
+
+HUMAN_TAXON_ID = 9606
+
+ENTRY_FIELDS = [
+    "accession",
+    "id",
+    "reviewed",
+    "protein_name",
+    "gene_primary",
+    "organism_name",
+    "organism_id",
+    "length",
+    "sequence",
+]
+
+
+class UniProtEntry(BaseModel):
+    """The UniProtKB fields CzechMedMCP uses for protein records."""
+
+    accession: str
+    entry_name: str | None = None
+    reviewed: bool = False
+    protein_name: str | None = None
+    gene_symbol: str | None = None
+    organism: str | None = None
+    taxon_id: int | None = None
+    length: int | None = None
+    sequence: str | None = Field(
+        default=None, description="Canonical isoform sequence"
+    )
+    variants: list[ProteinVariant] = Field(
+        default_factory=list,
+        description="Disease-associated natural variants (when requested)",
+    )
+    ptms: list[ProteinPtm] = Field(
+        default_factory=list,
+        description="Post-translational modification sites (when requested)",
+    )
+
+
+def parse_uniprot_entry(data: dict[str, Any]) -> UniProtEntry:
+    """Convert a UniProt REST JSON entry into a UniProtEntry."""
+    description = data.get("proteinDescription") or {}
+    recommended = description.get("recommendedName") or {}
+    submitted = (description.get("submissionNames") or [{}])[0]
+    protein_name = (recommended.get("fullName") or {}).get("value") or (
+        submitted.get("fullName") or {}
+    ).get("value")
+
+    genes = data.get("genes") or [{}]
+    gene_symbol = (genes[0].get("geneName") or {}).get("value")
+
+    organism = data.get("organism") or {}
+    sequence = data.get("sequence") or {}
+    # "UniProtKB reviewed (Swiss-Prot)" or "UniProtKB unreviewed (TrEMBL)"
+    entry_type = str(data.get("entryType", "")).lower()
+
+    return UniProtEntry(
+        accession=data.get("primaryAccession", ""),
+        entry_name=data.get("uniProtkbId"),
+        reviewed="swiss-prot" in entry_type,
+        protein_name=protein_name,
+        gene_symbol=gene_symbol,
+        organism=organism.get("scientificName"),
+        taxon_id=organism.get("taxonId"),
+        length=sequence.get("length"),
+        sequence=sequence.get("value"),
+        variants=parse_protein_variants(data.get("features") or []),
+        ptms=parse_protein_ptms(data.get("features") or []),
+    )
+
+
+def uniprot_fasta_header(entry: UniProtEntry) -> str:
+    """Build a UniProt-style FASTA header (``>sp|P15056|BRAF_HUMAN ...``)."""
+    database = "sp" if entry.reviewed else "tr"
+    header = f">{database}|{entry.accession}|{entry.entry_name or ''}"
+    if entry.protein_name:
+        header += f" {entry.protein_name}"
+    if entry.organism:
+        header += f" OS={entry.organism}"
+    if entry.taxon_id:
+        header += f" OX={entry.taxon_id}"
+    if entry.gene_symbol:
+        header += f" GN={entry.gene_symbol}"
+    return header
+
+
+async def fetch_uniprot_entry(
+    accession: str, variants: bool = False, ptms: bool = False
+) -> tuple[UniProtEntry | None, str | None]:
+    """Fetch a UniProtKB entry by accession.
+
+    With ``variants``, the natural variant features are fetched too and
+    parsed into ``UniProtEntry.variants``; with ``ptms``, the
+    modification features are parsed into ``UniProtEntry.ptms``.
+
+    Returns:
+        Tuple of (entry or None, error message or None)
+    """
+    accession = accession.strip().upper()
+    fields = ENTRY_FIELDS + ([VARIANT_FIELD] if variants else [])
+    if ptms:
+        fields += list(PTM_FIELDS)
+    response, error = await http_client.request_api(
+        url=f"{UNIPROT_BASE_URL}/{quote(accession, safe='')}.json",
+        request={"fields": ",".join(fields)},
+        method="GET",
+        domain="uniprot",
+    )
+
+    if error:
+        return None, f"Error fetching UniProt entry {accession}: {error.message}"
+    if not response or not response.get("primaryAccession"):
+        return None, f"No UniProt entry found for {accession}"
+    return parse_uniprot_entry(response), None
+
+
+async def search_gene_entry(
+    gene_symbol: str, taxon_id: int = HUMAN_TAXON_ID
+) -> tuple[UniProtEntry | None, str | None]:
+    """Find the reviewed (Swiss-Prot) UniProtKB entry for a gene symbol.
+
+    Returns:
+        Tuple of (entry or None, error message or None)
+    """
+    symbol = gene_symbol.strip().upper()
+    query = (
+        f"gene_exact:{symbol} AND organism_id:{taxon_id} AND reviewed:true"
+    )
+    response, error = await http_client.request_api(
+        url=UNIPROT_SEARCH_URL,
+        request={
+            "query": query,
+            "fields": ",".join(ENTRY_FIELDS),
+            "size": 1,
+        },
+        method="GET",
+        domain="uniprot",
+    )
+
+    if error:
+        return None, f"Error searching UniProt for {symbol}: {error.message}"
+    results = (response or {}).get("results") or []
+    if not results:
+        return None, f"No reviewed UniProt protein found for gene {symbol}"
+    return parse_uniprot_entry(results[0]), None
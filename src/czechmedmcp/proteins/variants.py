@@ -0,0 +1,97 @@
+"""Disease-associated sequence variants from the UniProt feature table.
+
+UniProtKB curates natural variants of reviewed entries as "Natural
+variant" features. The description of disease-associated ones starts
+with the disease ("in CFC1 and MM; somatic mutation; dbSNP:rs113488022"),
+while polymorphisms carry no disease. Only the former are listed.
+"""
+
+import re
+from typing import Any
+
+from pydantic import BaseModel
+
+# UniProt REST return field for natural variant features
+VARIANT_FIELD = "ft_variant"
+
+DBSNP_PATTERN = re.compile(r"dbSNP:(rs\d+)")
+
+
+class ProteinVariant(BaseModel):
+    """A disease-associated amino acid variant of a protein."""
+
+    position: str
+    change: str
+    disease: str
+    dbsnp: str | None = None
+    feature_id: str | None = None
+
+
+def feature_position(location: dict[str, Any]) -> str:
+    """Render a feature location as ``start`` or ``start-end``."""
+    start = (location.get("start") or {}).get("value")
+    end = (location.get("end") or {}).get("value")
+    if start is None:
+        return ""
+    return str(start) if end in (None, start) else f"{start}-{end}"
+
+
+def _disease(description: str) -> str | None:
+    """The disease named in a variant description, if any."""
+    for part in description.split(";"):
+        part = part.strip()
+        if part.startswith("in ") and not part.startswith("in dbSNP"):
+            return part[3:].strip()
+    return None
+
+
+def _dbsnp(feature: dict[str, Any]) -> str | None:
+    for xref in feature.get("featureCrossReferences") or []:
+        if xref.get("database") == "dbSNP" and xref.get("id"):
+            return xref["id"]
+    match = DBSNP_PATTERN.search(feature.get("description") or "")
+    return match.group(1) if match else None
+
+
+def parse_protein_variants(
+    features: list[dict[str, Any]],
+) -> list[ProteinVariant]:
+    """Extract disease-associated natural variants from UniProt features."""
+    variants: list[ProteinVariant] = []
+    for feature in features:
+        if feature.get("type") != "Natural variant":
+            continue
+        disease = _disease(feature.get("description") or "")
+        if not disease:
+            continue
+        position = feature_position(feature.get("location") or {})
+        sequences = feature.get("alternativeSequence") or {}
+        original = sequences.get("originalSequence") or ""
+        alternatives = "/".join(sequences.get("alternativeSequences") or [])
+        variants.append(
+            ProteinVariant(
+                position=position,
+                change=f"{original}{position}{alternatives or 'del'}",
+                disease=disease,
+                dbsnp=_dbsnp(feature),
+                feature_id=feature.get("featureId"),
+            )
+        )
+    return variants
+
+
+def format_protein_variants(variants: list[ProteinVariant]) -> str:
+    """Format disease-associated variants as a markdown section."""
+    lines = [f"## Disease-Associated Variants ({len(variants)})", ""]
+    if not variants:
+        lines.append("No disease-associated variants annotated in UniProt.")
+        return "\n".join(lines)
+
+    lines.append("| Position | Change | Disease | dbSNP |")
+    lines.append("|----------|--------|---------|-------|")
+    for variant in variants:
+        lines.append(
+            f"| {variant.position} | {variant.change} | {variant.disease} | "
+            f"{variant.dbsnp or ''} |"
+        )
+    return "\n".join(lines)
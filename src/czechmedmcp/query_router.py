@@ -1,12 +1,12 @@
 """Query router for unified search in CzechMedMCP."""
 
-import asyncio
 import logging
 from dataclasses import dataclass
 from typing import Any
 
 from czechmedmcp.articles.search import PubmedRequest
 from czechmedmcp.articles.unified import search_articles_unified
+from czechmedmcp.concurrency import gather_limited, resolve_concurrency
 from czechmedmcp.query_parser import ParsedQuery
 from czechmedmcp.trials.search import TrialQuery, search_trials
 from czechmedmcp.variants.search import VariantQuery, search_variants
@@ -247,9 +247,15 @@ class QueryRouter:
 
 
 async def execute_routing_plan(  # noqa: C901
-    plan: RoutingPlan, output_json: bool = True
+    plan: RoutingPlan,
+    output_json: bool = True,
+    concurrency: int | None = None,
 ) -> dict[str, Any]:
-    """Execute a routing plan by calling the appropriate tools."""
+    """Execute a routing plan by calling the appropriate tools.
+
+    Searches run in parallel, unbounded unless ``concurrency`` or the
+    global concurrency cap limits them.
+    """
     tasks = []
     task_names = []
 
@@ -320,7 +326,11 @@ async def execute_routing_plan(  # noqa: C901
             task_names.append("diseases")
 
     # Execute all searches in parallel
-    results = await asyncio.gather(*tasks, return_exceptions=True)
+    results = await gather_limited(
+        tasks,
+        resolve_concurrency(concurrency, None),
+        return_exceptions=True,
+    )
 
     # Package results
     output: dict[str, Any] = {}
@@ -91,6 +91,11 @@ class DomainRateLimiter:
             "mychem": {"rps": 10.0, "burst": 20},  # MyChem.info
             "myvariant": {"rps": 15.0, "burst": 30},  # MyVariant.info
             "oncokb": {"rps": 5.0, "burst": 10},  # OncoKB conservative limits
+            "interpro": {"rps": 5.0, "burst": 10},  # EBI InterPro API
+            "uniprot": {"rps": 10.0, "burst": 20},  # UniProt REST API
+            "reactome": {"rps": 10.0, "burst": 20},  # Reactome Content Service
+            "cpic": {"rps": 5.0, "burst": 10},  # CPIC PostgREST API
+            "monarch": {"rps": 5.0, "burst": 10},  # Monarch Initiative API
         }
 
     def get_limiter(self, domain: str) -> RateLimiter:
@@ -1,12 +1,121 @@
 import json
 import re
 import textwrap
+import xml.etree.ElementTree as ET
+from dataclasses import dataclass
 from typing import Any
 
 MAX_WIDTH = 72
 
+FASTA_LINE_WIDTH = 60
+
+# Longest one-line summary rendered by ``get --compact``
+SUMMARY_LINE_WIDTH = 120
+
 REMOVE_MULTI_LINES = re.compile(r"\s+")
 
+# CURIE prefix -> URL prefix used to expand identifiers in JSON-LD output
+CURIE_URL_PREFIXES = {
+    "NCBIGene": "https://identifiers.org/ncbigene:",
+    "ENSEMBL": "https://identifiers.org/ensembl:",
+    "MONDO": "http://purl.obolibrary.org/obo/MONDO_",
+    "DOID": "http://purl.obolibrary.org/obo/DOID_",
+    "HP": "http://purl.obolibrary.org/obo/HP_",
+    "OMIM": "https://omim.org/entry/",
+    "MESH": "https://identifiers.org/mesh:",
+    "DRUGBANK": "https://go.drugbank.com/drugs/",
+    "CHEBI": "http://purl.obolibrary.org/obo/CHEBI_",
+    "CHEMBL.COMPOUND": "https://www.ebi.ac.uk/chembl/compound_report_card/",
+    "PUBCHEM.COMPOUND": "https://pubchem.ncbi.nlm.nih.gov/compound/",
+    "UNII": "https://precision.fda.gov/uniisearch/srs/unii/",
+    "INCHIKEY": "https://identifiers.org/inchikey:",
+}
+
+# Namespace for record fields without a schema.org equivalent
+JSONLD_VOCAB = "https://github.com/petrsovadina/CzechMedMCP/vocab#"
+
+JSONLD_CONTEXT = {
+    "@vocab": JSONLD_VOCAB,
+    "czechmedmcp": JSONLD_VOCAB,
+    "schema": "https://schema.org/",
+    "bioschemas": "https://bioschemas.org/",
+    **CURIE_URL_PREFIXES,
+    "name": "schema:name",
+    "identifier": "schema:identifier",
+    "sameAs": {"@id": "schema:sameAs", "@type": "@id"},
+}
+
+# Entity -> record fields that map onto schema.org properties
+JSONLD_PROPERTIES = {
+    "gene": {
+        "summary": "schema:description",
+        "alias": "schema:alternateName",
+    },
+    "disease": {
+        "definition": "schema:description",
+        "synonyms": "schema:alternateName",
+    },
+    "drug": {
+        "description": "schema:description",
+        "tradename": "schema:proprietaryName",
+        "mechanism_of_action": "schema:mechanismOfAction",
+    },
+}
+
+# Entity -> (JSON-LD type, id field, CURIE prefix for bare ids)
+JSONLD_ENTITIES = {
+    "gene": ("bioschemas:Gene", "gene_id", "NCBIGene"),
+    "disease": ("schema:MedicalCondition", "disease_id", "MONDO"),
+    "drug": ("schema:Drug", "drug_id", "INCHIKEY"),
+}
+
+# Cross-reference fields that become ``sameAs`` links
+JSONLD_XREF_FIELDS = {
+    "entrezgene": "NCBIGene",
+    "drugbank_id": "DRUGBANK",
+    "chebi_id": "CHEBI",
+    "chembl_id": "CHEMBL.COMPOUND",
+    "pubchem_cid": "PUBCHEM.COMPOUND",
+    "inchikey": "INCHIKEY",
+}
+
+# Bare identifiers whose source is recognizable from their shape
+BARE_ID_PATTERNS = [
+    (re.compile(r"^DB\d{5}$"), "DRUGBANK"),
+    (re.compile(r"^CHEMBL\d+$"), "CHEMBL.COMPOUND"),
+    (re.compile(r"^ENS[A-Z]*G\d+$"), "ENSEMBL"),
+    (re.compile(r"^[A-Z]{14}-[A-Z]{10}-[A-Z]$"), "INCHIKEY"),
+]
+
+_PREFIX_BY_LOWER = {prefix.lower(): prefix for prefix in CURIE_URL_PREFIXES}
+
+
+@dataclass(frozen=True)
+class RenderContext:
+    """Output hints for the markdown renderer.
+
+    ``width`` is the column limit used when wrapping. The defaults describe
+    a pipe or file, which is what library and MCP callers get unless the
+    CLI detects a terminal. The markdown is always plain text; color only
+    applies to rich console output (see ``terminal``).
+    """
+
+    width: int = MAX_WIDTH
+
+
+_render_context = RenderContext()
+
+
+def set_render_context(context: RenderContext | None) -> None:
+    """Set the context used when none is passed (None restores defaults)."""
+    global _render_context
+    _render_context = context or RenderContext()
+
+
+def get_render_context() -> RenderContext:
+    """Return the context used when none is passed."""
+    return _render_context
+
 
 def dedupe_list_keep_order(lst: list[Any]) -> list[Any]:
     """
@@ -22,13 +131,17 @@ def dedupe_list_keep_order(lst: list[Any]) -> list[Any]:
     return data
 
 
-def to_markdown(data: str | list | dict) -> str:
+def to_markdown(
+    data: str | list | dict, context: RenderContext | None = None
+) -> str:
     """Convert a JSON string or already-parsed data (dict or list) into
     a simple Markdown representation.
 
     :param data: The input data, either as a JSON string, or a parsed list/dict.
+    :param context: Output hints; defaults to the current render context.
     :return: A string containing the generated Markdown output.
     """
+    context = context or get_render_context()
     if isinstance(data, str):
         data = json.loads(data)
 
@@ -39,10 +152,354 @@ def to_markdown(data: str | list | dict) -> str:
         data = new_data
 
     lines: list[str] = []
-    process_any(data, [], lines)
+    process_any(data, [], lines, context.width)
     return ("\n".join(lines)).strip() + "\n"
 
 
+def to_fasta(
+    header: str, sequence: str, width: int = FASTA_LINE_WIDTH
+) -> str:
+    """Render a sequence as a FASTA record with fixed-width lines.
+
+    :param header: Record header, with or without the leading ``>``.
+    :param sequence: Sequence residues; whitespace is stripped.
+    :param width: Residues per line.
+    :return: The FASTA record, terminated by a newline.
+    """
+    residues = re.sub(r"\s+", "", sequence)
+    lines = [header if header.startswith(">") else f">{header}"]
+    lines.extend(
+        residues[i : i + width] for i in range(0, len(residues), width)
+    )
+    return "\n".join(lines) + "\n"
+
+
+def to_curie(identifier: Any, default_prefix: str) -> str:
+    """Normalize an identifier to a CURIE with a known prefix.
+
+    :param identifier: A CURIE (``mondo:0007254``) or bare id (``7157``).
+    :param default_prefix: Prefix for bare ids not matched by
+        ``BARE_ID_PATTERNS``.
+    :return: The CURIE, e.g. ``MONDO:0007254`` or ``NCBIGene:7157``.
+    """
+    value = str(identifier).strip()
+    prefix, sep, local = value.partition(":")
+    if sep and prefix.lower() in _PREFIX_BY_LOWER:
+        return f"{_PREFIX_BY_LOWER[prefix.lower()]}:{local}"
+    for pattern, known_prefix in BARE_ID_PATTERNS:
+        if pattern.match(value):
+            return f"{known_prefix}:{value}"
+    return f"{default_prefix}:{value}"
+
+
+def curie_to_url(curie: str) -> str:
+    """Expand a CURIE with a known prefix to its URL."""
+    prefix, _, local = curie.partition(":")
+    base = CURIE_URL_PREFIXES.get(prefix)
+    return f"{base}{local}" if base else curie
+
+
+def to_json_ld(data: dict[str, Any], entity: str) -> dict[str, Any]:
+    """Wrap a serialized gene, disease or drug record as JSON-LD.
+
+    Adds ``@context``, ``@type``, ``@id`` as a resolvable URL,
+    ``identifier`` as a CURIE and ``sameAs`` links for known
+    cross-references. The original fields are kept: the context maps
+    those with a schema.org equivalent (``JSONLD_PROPERTIES``) onto it and
+    leaves the rest in the project's own ``JSONLD_VOCAB`` namespace.
+
+    :param data: The entity's JSON record as returned by its getter.
+    :param entity: One of ``gene``, ``disease`` or ``drug``.
+    :return: The JSON-LD document.
+    """
+    type_name, id_field, default_prefix = JSONLD_ENTITIES[entity]
+    curie = to_curie(data.get(id_field, ""), default_prefix)
+
+    same_as = []
+    for field, prefix in JSONLD_XREF_FIELDS.items():
+        if data.get(field):
+            url = curie_to_url(to_curie(data[field], prefix))
+            if url != curie_to_url(curie) and url not in same_as:
+                same_as.append(url)
+
+    document: dict[str, Any] = {
+        "@context": {**JSONLD_CONTEXT, **JSONLD_PROPERTIES[entity]},
+        "@type": type_name,
+        "@id": curie_to_url(curie),
+        "identifier": curie,
+    }
+    if data.get("name"):
+        document["name"] = data["name"]
+    if same_as:
+        document["sameAs"] = same_as
+    document.update({k: v for k, v in data.items() if k not in document})
+    return document
+
+
+def parse_record(raw: str) -> tuple[dict[str, Any] | None, str | None]:
+    """Pick the first record from a getter's JSON output.
+
+    :return: Tuple of (record or None, error message or None).
+    """
+    data = json.loads(raw)
+    if isinstance(data, list):
+        data = data[0] if data else {"error": "No record returned"}
+    if data.get("error"):
+        details = data.get("details") or data.get("suggestion")
+        error = f"{data['error']}: {details}" if details else data["error"]
+        return None, error
+    return data, None
+
+
+def json_to_json_ld(
+    raw: str, entity: str
+) -> tuple[str | None, str | None]:
+    """Convert a getter's JSON output to an indented JSON-LD document.
+
+    :return: Tuple of (JSON-LD text or None, error message or None).
+    """
+    data, error = parse_record(raw)
+    if data is None:
+        return None, error
+    return json.dumps(to_json_ld(data, entity), indent=2), None
+
+
+def summary_line(
+    label: str,
+    details: list[Any],
+    text: str | None = None,
+    width: int = SUMMARY_LINE_WIDTH,
+) -> str:
+    """Render ``label (detail, detail): text`` on a single line.
+
+    Empty details are dropped, whitespace is collapsed and lines longer
+    than ``width`` are cut with an ellipsis.
+    """
+    kept = [str(detail) for detail in details if detail]
+    line = f"{label} ({', '.join(kept)})" if kept else label
+    if text:
+        line = f"{line}: {text}"
+    line = REMOVE_MULTI_LINES.sub(" ", line).strip()
+    if len(line) > width:
+        line = line[: width - 3].rstrip() + "..."
+    return line
+
+
+def result_count_line(
+    shown: int, total: int, noun: str, post_filtered: bool = False
+) -> str:
+    """Render the ``Showing N of total`` line of a search result page.
+
+    ``total`` is the upstream API's match count. When filters were
+    applied client-side it no longer counts the filtered matches, so it
+    is labelled as the upstream total instead.
+    """
+    if post_filtered:
+        return (
+            f"Showing {shown} {noun} matching all filters on this page "
+            f"({total} upstream matches before client-side filtering)."
+        )
+    return f"Showing {shown} of {total} matching {noun}."
+
+
+def search_page_payload(
+    summary: str,
+    records: list[dict[str, Any]],
+    total: int,
+    page: int,
+    page_size: int,
+    post_filtered: bool = False,
+) -> dict[str, Any]:
+    """Build the JSON payload of a search result page.
+
+    With client-side filters the upstream count is reported as
+    ``upstream_total`` rather than ``total``, next to the number of
+    records that passed the filters.
+    """
+    payload: dict[str, Any] = {"query_summary": summary}
+    if post_filtered:
+        payload["upstream_total"] = total
+        payload["returned"] = len(records)
+    else:
+        payload["total"] = total
+    payload.update(page=page, page_size=page_size, results=records)
+    return payload
+
+
+def gene_summary_line(data: dict[str, Any]) -> str:
+    """One line for a gene, e.g. ``BRAF (Entrez 673, 7q34): B-Raf ...``."""
+    entrez = data.get("entrezgene")
+    return summary_line(
+        data.get("symbol") or data.get("gene_id", ""),
+        [f"Entrez {entrez}" if entrez else None, data.get("map_location")],
+        data.get("name") or data.get("summary"),
+    )
+
+
+def variant_summary_line(data: dict[str, Any]) -> str:
+    """One line for a variant: ID, gene, rsID and ClinVar significance."""
+    dbsnp = data.get("dbsnp") or {}
+    gene = dbsnp.get("gene") or {}
+    symbol = gene.get("symbol") if isinstance(gene, dict) else None
+    rcv = (data.get("clinvar") or {}).get("rcv") or []
+    significance = dedupe_list_keep_order([
+        record["clinical_significance"]
+        for record in (rcv if isinstance(rcv, list) else [rcv])
+        if isinstance(record, dict) and record.get("clinical_significance")
+    ])
+    return summary_line(
+        data.get("_id", ""),
+        [symbol, dbsnp.get("rsid")],
+        f"ClinVar {', '.join(significance)}" if significance else None,
+    )
+
+
+def disease_summary_line(data: dict[str, Any]) -> str:
+    """One line for a disease: name, identifier and definition."""
+    return summary_line(
+        data.get("name") or data.get("disease_id", ""),
+        [data.get("disease_id")],
+        data.get("definition"),
+    )
+
+
+def drug_summary_line(data: dict[str, Any]) -> str:
+    """One line for a drug: name, DrugBank ID and indication."""
+    return summary_line(
+        data.get("name") or data.get("drug_id", ""),
+        [data.get("drugbank_id")],
+        data.get("indication") or data.get("description"),
+    )
+
+
+def trial_summary_line(data: dict[str, Any]) -> str:
+    """One line for a trial: NCT ID, phase, status and brief title."""
+    protocol = data.get("protocolSection") or {}
+    identification = protocol.get("identificationModule") or {}
+    phases = (protocol.get("designModule") or {}).get("phases") or []
+    status = (protocol.get("statusModule") or {}).get("overallStatus")
+    return summary_line(
+        identification.get("nctId", ""),
+        ["/".join(phases), status],
+        identification.get("briefTitle"),
+    )
+
+
+SUMMARY_LINES = {
+    "gene": gene_summary_line,
+    "variant": variant_summary_line,
+    "disease": disease_summary_line,
+    "drug": drug_summary_line,
+    "trial": trial_summary_line,
+}
+
+
+def json_to_summary_line(
+    raw: str, entity: str
+) -> tuple[str | None, str | None]:
+    """Convert a getter's JSON output to a one-line summary.
+
+    :return: Tuple of (summary line or None, error message or None).
+    """
+    data, error = parse_record(raw)
+    if data is None:
+        return None, error
+    return SUMMARY_LINES[entity](data), None
+
+
+def _xml_text(parent: ET.Element, tag: str, value: Any) -> None:
+    """Append ``<tag>value</tag>`` unless the value is empty."""
+    if value is None or value == "":
+        return
+    ET.SubElement(parent, tag).text = str(value)
+
+
+def trial_to_xml(study: dict[str, Any]) -> str:
+    """Serialize a ClinicalTrials.gov study as a flat XML document.
+
+    One element per field, with repeated ``<phase>``, ``<condition>``,
+    ``<arm>``, ``<intervention>`` and ``<location>`` elements. Empty fields
+    are left out.
+
+    :param study: The study record as returned by ``trial get --json``.
+    :return: The XML document, starting with an XML declaration.
+    """
+    protocol = study.get("protocolSection") or {}
+    identification = protocol.get("identificationModule") or {}
+    status = protocol.get("statusModule") or {}
+    design = protocol.get("designModule") or {}
+    arms_module = protocol.get("armsInterventionsModule") or {}
+    sponsor = (protocol.get("sponsorCollaboratorsModule") or {}).get(
+        "leadSponsor"
+    ) or {}
+
+    root = ET.Element("trial", nct_id=identification.get("nctId", ""))
+    _xml_text(root, "brief_title", identification.get("briefTitle"))
+    _xml_text(root, "official_title", identification.get("officialTitle"))
+    _xml_text(root, "acronym", identification.get("acronym"))
+    _xml_text(root, "overall_status", status.get("overallStatus"))
+    _xml_text(root, "study_type", design.get("studyType"))
+    for phase in design.get("phases") or []:
+        _xml_text(root, "phase", phase)
+    _xml_text(
+        root, "enrollment", (design.get("enrollmentInfo") or {}).get("count")
+    )
+    for tag, field in (
+        ("start_date", "startDateStruct"),
+        ("primary_completion_date", "primaryCompletionDateStruct"),
+        ("completion_date", "completionDateStruct"),
+    ):
+        _xml_text(root, tag, (status.get(field) or {}).get("date"))
+    _xml_text(root, "lead_sponsor", sponsor.get("name"))
+
+    conditions = ET.SubElement(root, "conditions")
+    for condition in (protocol.get("conditionsModule") or {}).get(
+        "conditions"
+    ) or []:
+        _xml_text(conditions, "condition", condition)
+
+    arms = ET.SubElement(root, "arms")
+    for group in arms_module.get("armGroups") or []:
+        arm = ET.SubElement(arms, "arm")
+        _xml_text(arm, "label", group.get("label"))
+        _xml_text(arm, "type", group.get("type"))
+        _xml_text(arm, "description", group.get("description"))
+        for name in group.get("interventionNames") or []:
+            _xml_text(arm, "intervention_name", name)
+
+    interventions = ET.SubElement(root, "interventions")
+    for item in arms_module.get("interventions") or []:
+        intervention = ET.SubElement(interventions, "intervention")
+        _xml_text(intervention, "type", item.get("type"))
+        _xml_text(intervention, "name", item.get("name"))
+        _xml_text(intervention, "description", item.get("description"))
+
+    locations = ET.SubElement(root, "locations")
+    for site in (protocol.get("contactsLocationsModule") or {}).get(
+        "locations"
+    ) or []:
+        location = ET.SubElement(locations, "location")
+        for field in ("facility", "status", "city", "state", "zip", "country"):
+            _xml_text(location, field, site.get(field))
+
+    _xml_text(root, "url", study.get("URL"))
+
+    ET.indent(root)
+    body = ET.tostring(root, encoding="unicode")
+    return f'<?xml version="1.0" encoding="UTF-8"?>\n{body}\n'
+
+
+def json_to_trial_xml(raw: str) -> tuple[str | None, str | None]:
+    """Convert ``get_trial``'s JSON output to an XML document.
+
+    :return: Tuple of (XML text or None, error message or None).
+    """
+    data, error = parse_record(raw)
+    if data is None:
+        return None, error
+    return trial_to_xml(data), None
+
+
 def wrap_preserve_newlines(text: str, width: int) -> list[str]:
     """For each line in the text (split by newlines), wrap it to 'width' columns.
     Blank lines are preserved. Returns a list of wrapped lines without
@@ -78,28 +535,33 @@ def process_any(
     value: Any,
     path_keys: list[str],
     lines: list[str],
+    width: int = MAX_WIDTH,
 ) -> None:
     """Dispatch function to handle dict, list, or scalar (str/int/float/bool).
 
     :param value: The current JSON data node.
     :param path_keys: The list of keys leading to this node (for headings).
     :param lines: The running list of output Markdown lines.
+    :param width: Maximum line width for wrapping.
     """
     if isinstance(value, dict):
-        process_dict(value, path_keys, lines)
+        process_dict(value, path_keys, lines, width)
     elif isinstance(value, list):
-        process_list(value, path_keys, lines)
+        process_list(value, path_keys, lines, width)
     elif value is not None:
-        render_key_value(lines, path_keys[-1], value)
+        render_key_value(lines, path_keys[-1], value, width)
 
 
-def process_dict(dct: dict, path_keys: list[str], lines: list[str]) -> None:
+def process_dict(
+    dct: dict, path_keys: list[str], lines: list[str], width: int = MAX_WIDTH
+) -> None:
     """Handle a dictionary by printing a heading for the current path (if any),
     then processing key/value pairs in order: scalars first, then nested dicts, then lists.
 
     :param dct: The dictionary to process.
     :param path_keys: The list of keys leading to this dict (for heading).
     :param lines: The running list of output Markdown lines.
+    :param width: Maximum line width for wrapping.
     """
     if path_keys:
         level = min(len(path_keys), 5)
@@ -125,20 +587,22 @@ def process_dict(dct: dict, path_keys: list[str], lines: list[str]) -> None:
     # Process scalars first
     for key in scalar_keys:
         next_path = path_keys + [key]
-        process_any(dct[key], next_path, lines)
+        process_any(dct[key], next_path, lines, width)
 
     # Process dicts second
     for key in dict_keys:
         next_path = path_keys + [key]
-        process_any(dct[key], next_path, lines)
+        process_any(dct[key], next_path, lines, width)
 
     # Process lists last
     for key in list_keys:
         next_path = path_keys + [key]
-        process_any(dct[key], next_path, lines)
+        process_any(dct[key], next_path, lines, width)
 
 
-def process_list(lst: list, path_keys: list[str], lines: list[str]) -> None:
+def process_list(
+    lst: list, path_keys: list[str], lines: list[str], width: int = MAX_WIDTH
+) -> None:
     """If all items in the list are scalar, attempt to render them on one line
     if it fits, otherwise use bullet points. Otherwise, we recursively
     process each item.
@@ -146,29 +610,33 @@ def process_list(lst: list, path_keys: list[str], lines: list[str]) -> None:
     :param lst: The list of items to process.
     :param path_keys: The keys leading to this list.
     :param lines: The running list of Markdown lines.
+    :param width: Maximum line width for wrapping.
     """
     all_scalars = all(isinstance(i, str | int | float | bool) for i in lst)
     lst = dedupe_list_keep_order(lst)
     if path_keys and all_scalars:
         key = path_keys[-1]
-        process_scalar_list(key, lines, lst)
+        process_scalar_list(key, lines, lst, width)
     else:
         for item in lst:
-            process_any(item, path_keys, lines)
+            process_any(item, path_keys, lines, width)
 
 
-def process_scalar_list(key: str, lines: list[str], lst: list) -> None:
+def process_scalar_list(
+    key: str, lines: list[str], lst: list, width: int = MAX_WIDTH
+) -> None:
     """Print a list of scalars either on one line as "Key: item1, item2, ..."
-    if it fits within MAX_WIDTH, otherwise print a bullet list.
+    if it fits within the width, otherwise print a bullet list.
 
     :param key: The key name for this list of scalars.
     :param lines: The running list of Markdown lines.
     :param lst: The actual list of scalar items.
+    :param width: Maximum line width.
     """
     label = transform_key(key)
     items_str = ", ".join(str(item) for item in lst)
     single_line = f"{label}: {items_str}"
-    if len(single_line) <= MAX_WIDTH:
+    if len(single_line) <= width:
         append_line(lines, single_line)
     else:
         # bullet list
@@ -178,7 +646,9 @@ def process_scalar_list(key: str, lines: list[str], lst: list) -> None:
             append_line(lines, bullet)
 
 
-def render_key_value(lines: list[str], key: str, value: Any) -> None:
+def render_key_value(
+    lines: list[str], key: str, value: Any, width: int = MAX_WIDTH
+) -> None:
     """Render a single "key: value" pair. If the value is a long string,
     we do multiline wrapping with an indentation for clarity. Otherwise,
     it appears on the same line.
@@ -186,14 +656,15 @@ def render_key_value(lines: list[str], key: str, value: Any) -> None:
     :param lines: The running list of Markdown lines.
     :param key: The raw key name (untransformed).
     :param value: The value associated with this key.
+    :param width: Maximum line width for wrapping.
     """
     label = transform_key(key)
     val_str = str(value)
 
     # If the value is a fairly long string, do multiline
-    if isinstance(value, str) and len(value) > MAX_WIDTH:
+    if isinstance(value, str) and len(value) > width:
         append_line(lines, f"{label}:")
-        for wrapped in wrap_preserve_newlines(val_str, MAX_WIDTH):
+        for wrapped in wrap_preserve_newlines(val_str, width):
             append_line(lines, "  " + wrapped)
     else:
         append_line(lines, f"{label}: {val_str}")
@@ -0,0 +1,58 @@
+"""Seedable random sampling of search results.
+
+``--sample N`` on a search command fetches a window several times larger
+than N and keeps a uniform random sample of N records from it, instead
+of the first N that ``--limit`` returns. The window is the whole pool
+the sample is drawn from, so it is representative of the top-ranked
+results rather than of every match, and the search fetches
+``SAMPLE_OVERFETCH`` times more records than it prints.
+"""
+
+import random
+from typing import Any
+
+# Records fetched per sampled record
+SAMPLE_OVERFETCH = 5
+
+
+def sample_window(sample: int, max_window: int) -> int:
+    """Number of records to fetch for a sample, capped by the source."""
+    return min(sample * SAMPLE_OVERFETCH, max_window)
+
+
+def reservoir_sample(
+    rows: list[Any], n: int, seed: int | None = None
+) -> list[Any]:
+    """Pick n rows uniformly at random, keeping their original order.
+
+    Uses reservoir sampling (Algorithm R), so the same seed over the same
+    rows always returns the same sample.
+    """
+    # Sampling, not cryptography: a seedable generator is the point
+    rng = random.Random(seed)  # noqa: S311
+    reservoir: list[int] = []
+    for index in range(len(rows)):
+        if index < n:
+            reservoir.append(index)
+            continue
+        slot = rng.randint(0, index)
+        if slot < n:
+            reservoir[slot] = index
+    return [rows[index] for index in sorted(reservoir)]
+
+
+def sample_records(
+    data: Any, n: int, seed: int | None = None, key: str | None = None
+) -> Any:
+    """Sample the records of a parsed JSON search result.
+
+    ``data`` is the record list itself, or a dict holding it under
+    ``key`` next to other fields, which are kept. Errors pass through.
+    """
+    if isinstance(data, list):
+        if any(isinstance(row, dict) and "error" in row for row in data):
+            return data
+        return reservoir_sample(data, n, seed)
+    if isinstance(data, dict) and key and isinstance(data.get(key), list):
+        return {**data, key: reservoir_sample(data[key], n, seed)}
+    return data
@@ -0,0 +1,236 @@
+"""Count-only facet distributions for trials and adverse events.
+
+ClinicalTrials.gov v2 has no server-side facet endpoint, so trial facets
+are aggregated client-side over a paged query that only projects the
+faceted fields. A scan cap keeps broad queries bounded; the result
+reports both the total number of matching trials and how many were
+scanned. Adverse event facets use OpenFDA's native ``count`` queries.
+"""
+
+import json
+from collections import Counter
+from collections.abc import Callable
+from ssl import TLSVersion
+from typing import Any
+
+from . import StrEnum, http_client
+from .constants import CLINICAL_TRIALS_BASE_URL
+from .openfda.adverse_events import count_adverse_events
+from .trials.search import TrialQuery, convert_query
+
+# ClinicalTrials.gov page size limit
+TRIAL_STATS_PAGE_SIZE = 1000
+# Upper bound on trials scanned for one distribution
+TRIAL_STATS_MAX_SCANNED = 5000
+
+
+class TrialFacet(StrEnum):
+    STATUS = "status"
+    PHASE = "phase"
+    SPONSOR_TYPE = "sponsor_type"
+    COUNTRY = "country"
+
+
+class AdverseEventFacet(StrEnum):
+    REACTION = "reaction"
+    COUNTRY = "country"
+    INDICATION = "indication"
+
+
+def _status(study: dict[str, Any]) -> list[str]:
+    module = study.get("protocolSection", {}).get("statusModule", {})
+    return [module["overallStatus"]] if module.get("overallStatus") else []
+
+
+def _phase(study: dict[str, Any]) -> list[str]:
+    module = study.get("protocolSection", {}).get("designModule", {})
+    phases = module.get("phases") or []
+    return ["/".join(phases)] if phases else ["NA"]
+
+
+def _sponsor_type(study: dict[str, Any]) -> list[str]:
+    module = study.get("protocolSection", {}).get(
+        "sponsorCollaboratorsModule", {}
+    )
+    sponsor_class = module.get("leadSponsor", {}).get("class")
+    return [sponsor_class] if sponsor_class else []
+
+
+def _countries(study: dict[str, Any]) -> list[str]:
+    module = study.get("protocolSection", {}).get(
+        "contactsLocationsModule", {}
+    )
+    countries = {
+        loc["country"]
+        for loc in module.get("locations") or []
+        if isinstance(loc, dict) and loc.get("country")
+    }
+    return sorted(countries)
+
+
+# Facet -> (projected ClinicalTrials.gov field, value extractor)
+TRIAL_FACETS: dict[
+    TrialFacet, tuple[str, Callable[[dict[str, Any]], list[str]]]
+] = {
+    TrialFacet.STATUS: ("OverallStatus", _status),
+    TrialFacet.PHASE: ("Phase", _phase),
+    TrialFacet.SPONSOR_TYPE: ("LeadSponsorClass", _sponsor_type),
+    TrialFacet.COUNTRY: ("LocationCountry", _countries),
+}
+
+
+def rank_buckets(counts: Counter[str]) -> list[dict[str, Any]]:
+    """Order facet counts by count descending, then value."""
+    ranked = sorted(counts.items(), key=lambda item: (-item[1], item[0]))
+    return [{"value": value, "count": count} for value, count in ranked]
+
+
+async def trial_facet_counts(
+    query: TrialQuery,
+    facet: TrialFacet,
+    max_scanned: int = TRIAL_STATS_MAX_SCANNED,
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Count matching trials per value of a facet.
+
+    A trial contributes once to each distinct value it has, so country
+    buckets can sum to more than the number of trials.
+
+    Returns:
+        Tuple of (distribution dict or None, error message or None)
+    """
+    field, extract = TRIAL_FACETS[facet]
+    params = await convert_query(query)
+    # Search asks for CSV, which has no page token or total count
+    params["format"] = ["json"]
+    params["fields"] = [f"NCTId,{field}"]
+    params["pageSize"] = [str(min(TRIAL_STATS_PAGE_SIZE, max_scanned))]
+    params["countTotal"] = ["true"]
+    params.pop("pageToken", None)
+
+    counts: Counter[str] = Counter()
+    total: int | None = None
+    scanned = 0
+    while scanned < max_scanned:
+        response, error = await http_client.request_api(
+            url=CLINICAL_TRIALS_BASE_URL,
+            request=params,
+            method="GET",
+            tls_version=TLSVersion.TLSv1_2,
+            domain="trial",
+        )
+        if error:
+            return None, f"Error {error.code}: {error.message}"
+        response = response or {}
+        if total is None:
+            total = response.get("totalCount")
+
+        for study in response.get("studies") or []:
+            if scanned >= max_scanned:
+                break
+            scanned += 1
+            counts.update(extract(study))
+
+        token = response.get("nextPageToken")
+        if not token:
+            break
+        params["pageToken"] = [token]
+
+    return {
+        "entity": "trial",
+        "facet": facet.value,
+        "total": total if total is not None else scanned,
+        "scanned": scanned,
+        "buckets": rank_buckets(counts),
+    }, None
+
+
+async def adverse_event_facet_counts(
+    facet: AdverseEventFacet,
+    drug: str | None = None,
+    reaction: str | None = None,
+    serious: bool | None = None,
+    country: str | None = None,
+    limit: int = 25,
+    api_key: str | None = None,
+    indication: str | None = None,
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Count FAERS reports per value of a facet.
+
+    Returns:
+        Tuple of (distribution dict or None, error message or None)
+    """
+    results, error = await count_adverse_events(
+        facet.value,
+        drug=drug,
+        reaction=reaction,
+        serious=serious,
+        country=country,
+        limit=limit,
+        api_key=api_key,
+        indication=indication,
+    )
+    if error:
+        return None, error
+    buckets = [
+        {"value": bucket.get("term", "Unknown"), "count": bucket["count"]}
+        for bucket in results
+        if isinstance(bucket, dict) and bucket.get("count") is not None
+    ]
+    return {
+        "entity": "adverse-event",
+        "facet": facet.value,
+        "total": None,
+        "scanned": None,
+        "buckets": buckets,
+    }, None
+
+
+def format_facet_table(
+    distribution: dict[str, Any], summary: str | None = None
+) -> str:
+    """Render a distribution as a ranked markdown table.
+
+    The percentage column is only shown when the number of matching
+    records is known, i.e. for trial distributions.
+    """
+    entity = distribution["entity"].replace("-", " ")
+    facet = distribution["facet"].replace("_", " ")
+    lines = [f"# {entity.title()} counts by {facet}", ""]
+    if summary:
+        lines.extend([f"**Query**: {summary}", ""])
+
+    total = distribution.get("total")
+    scanned = distribution.get("scanned")
+    if total is not None:
+        line = f"**Matching**: {total:,}"
+        if scanned is not None and scanned < total:
+            line += f" (first {scanned:,} scanned)"
+        lines.extend([line, ""])
+
+    buckets = distribution["buckets"]
+    if not buckets:
+        lines.append("No matching records.")
+        return "\n".join(lines)
+
+    base = scanned or 0
+    if base:
+        lines.extend(["| Rank | Value | Count | % |", "|---|---|---|---|"])
+    else:
+        lines.extend(["| Rank | Value | Count |", "|---|---|---|"])
+    for rank, bucket in enumerate(buckets, start=1):
+        row = f"| {rank} | {bucket['value']} | {bucket['count']:,} |"
+        if base:
+            row += f" {bucket['count'] / base:.1%} |"
+        lines.append(row)
+    return "\n".join(lines)
+
+
+def format_distribution(
+    distribution: dict[str, Any],
+    output_json: bool = False,
+    summary: str | None = None,
+) -> str:
+    """Render a distribution as JSON or a markdown table."""
+    if output_json:
+        return json.dumps(distribution, indent=2)
+    return format_facet_table(distribution, summary)
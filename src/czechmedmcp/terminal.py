@@ -0,0 +1,109 @@
+"""Terminal detection and color settings for CLI output.
+
+The global ``--color {auto,always,never}`` option picks whether output
+may carry ANSI escape codes. In ``auto`` mode color is only used when
+stdout is a terminal and the ``NO_COLOR`` environment variable
+(https://no-color.org) is unset or empty, so output piped to a file or
+another program is always plain.
+
+The same detection yields a ``RenderContext`` for the markdown renderer:
+on a terminal narrower than the default wrap width, text is wrapped to
+the terminal instead. Rich output (errors and tables in a few commands)
+goes through the shared ``console``, which is rebuilt whenever the mode
+changes.
+"""
+
+import os
+import shutil
+import sys
+from typing import Any, TextIO
+
+from rich.console import Console
+
+from .core import StrEnum
+from .render import MAX_WIDTH, RenderContext, set_render_context
+
+NO_COLOR_ENV = "NO_COLOR"
+
+# Narrowest wrap width used on very small terminals
+MIN_WIDTH = 40
+
+
+class ColorMode(StrEnum):
+    AUTO = "auto"
+    ALWAYS = "always"
+    NEVER = "never"
+
+
+_color_mode = ColorMode.AUTO
+_console: Console | None = None
+
+
+def set_color_mode(mode: ColorMode | str | None) -> None:
+    """Set the global color mode (None restores ``auto``)."""
+    global _color_mode, _console
+    _color_mode = ColorMode(mode) if mode else ColorMode.AUTO
+    _console = None
+
+
+def get_color_mode() -> ColorMode:
+    """Return the global color mode."""
+    return _color_mode
+
+
+def is_tty(stream: TextIO | None = None) -> bool:
+    """Whether a stream (stdout by default) is attached to a terminal."""
+    stream = stream or sys.stdout
+    try:
+        return stream.isatty()
+    except (AttributeError, ValueError):
+        return False
+
+
+def use_color(stream: TextIO | None = None) -> bool:
+    """Whether output to a stream may contain ANSI escape codes."""
+    if _color_mode == ColorMode.ALWAYS:
+        return True
+    if _color_mode == ColorMode.NEVER:
+        return False
+    if os.environ.get(NO_COLOR_ENV):
+        return False
+    return is_tty(stream)
+
+
+def detect_render_context(stream: TextIO | None = None) -> RenderContext:
+    """Build render hints for a stream (stdout by default)."""
+    width = MAX_WIDTH
+    if is_tty(stream):
+        columns = shutil.get_terminal_size((MAX_WIDTH, 24)).columns
+        width = max(MIN_WIDTH, min(MAX_WIDTH, columns))
+    return RenderContext(width=width)
+
+
+def configure_terminal(mode: ColorMode | str | None = None) -> None:
+    """Apply a color mode and the detected render hints for stdout."""
+    set_color_mode(mode)
+    set_render_context(detect_render_context())
+
+
+def get_console() -> Console:
+    """Return a rich console honoring the current color mode."""
+    global _console
+    if _console is None:
+        color = use_color()
+        _console = Console(
+            color_system="auto" if color else None,
+            force_terminal=True if _color_mode == ColorMode.ALWAYS else None,
+            no_color=not color,
+        )
+    return _console
+
+
+class _ConsoleProxy:
+    """Module-level stand-in that forwards to the current console."""
+
+    def __getattr__(self, name: str) -> Any:
+        return getattr(get_console(), name)
+
+
+console: Any = _ConsoleProxy()
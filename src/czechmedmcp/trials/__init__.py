@@ -1,3 +1,4 @@
+from . import eligibility
 from . import getter
 from . import nci_getter
 from . import nci_search
@@ -6,6 +7,7 @@ from .search import LineOfTherapy
 
 __all__ = [
     "LineOfTherapy",
+    "eligibility",
     "getter",
     "nci_getter",
     "nci_search",
@@ -0,0 +1,162 @@
+"""Trial arms with the dosing of each arm's interventions.
+
+``protocolSection.armsInterventionsModule`` lists the arm groups and,
+separately, the interventions. The intervention description is where
+sponsors register the regimen (dose, route, schedule), so each arm is
+joined with its interventions: by the intervention's ``armGroupLabels``
+when present, else by the arm's ``interventionNames`` ("Drug: X").
+"""
+
+import json
+from ssl import TLSVersion
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from .. import http_client
+from ..constants import CLINICAL_TRIALS_BASE_URL
+
+ARM_FIELDS = "NCTId,BriefTitle,ArmsInterventionsModule"
+
+
+class ArmIntervention(BaseModel):
+    """An intervention given in an arm, with its dosing description."""
+
+    name: str
+    type: str | None = None
+    dosing: str | None = None
+    other_names: list[str] = Field(default_factory=list)
+
+
+class TrialArm(BaseModel):
+    """An arm group of a trial and the interventions it receives."""
+
+    label: str
+    type: str | None = None
+    description: str | None = None
+    interventions: list[ArmIntervention] = Field(default_factory=list)
+
+
+class TrialArms(BaseModel):
+    nct_id: str
+    title: str | None = None
+    arms: list[TrialArm] = Field(default_factory=list)
+
+
+def _intervention_key(kind: str | None, name: str | None) -> str:
+    """Key matching "Drug: Pembrolizumab" to type DRUG, name Pembrolizumab."""
+    kind = (kind or "").replace("_", " ")
+    return f"{kind}: {name or ''}".strip().lower()
+
+
+def _arm_interventions(
+    group: dict[str, Any], interventions: list[dict[str, Any]]
+) -> list[ArmIntervention]:
+    label = group.get("label")
+    names = {name.lower() for name in group.get("interventionNames") or []}
+    matched = []
+    for item in interventions:
+        labels = item.get("armGroupLabels")
+        key = _intervention_key(item.get("type"), item.get("name"))
+        if labels:
+            if label not in labels:
+                continue
+        elif key not in names:
+            continue
+        matched.append(
+            ArmIntervention(
+                name=item.get("name") or "",
+                type=item.get("type"),
+                dosing=item.get("description"),
+                other_names=item.get("otherNames") or [],
+            )
+        )
+    return matched
+
+
+def parse_trial_arms(study: dict[str, Any], nct_id: str) -> TrialArms:
+    """Build the arms of a ClinicalTrials.gov study record."""
+    protocol = study.get("protocolSection", {})
+    identification = protocol.get("identificationModule", {})
+    module = protocol.get("armsInterventionsModule", {})
+    interventions = module.get("interventions") or []
+    return TrialArms(
+        nct_id=identification.get("nctId") or nct_id,
+        title=identification.get("briefTitle"),
+        arms=[
+            TrialArm(
+                label=group.get("label") or "",
+                type=group.get("type"),
+                description=group.get("description"),
+                interventions=_arm_interventions(group, interventions),
+            )
+            for group in module.get("armGroups") or []
+        ],
+    )
+
+
+def format_trial_arms(trial: TrialArms) -> str:
+    """Format the arms and their intervention dosing as markdown."""
+    lines = [f"# Arms: {trial.nct_id}", ""]
+    if not trial.arms:
+        lines.append("No arm groups registered.")
+    for arm in trial.arms:
+        heading = f"## {arm.label}"
+        if arm.type:
+            heading += f" ({arm.type.replace('_', ' ').title()})"
+        lines.extend([heading, ""])
+        if arm.description:
+            lines.extend([arm.description, ""])
+        for intervention in arm.interventions:
+            name = intervention.name
+            if intervention.type:
+                name = f"{intervention.type.title()}: {name}"
+            lines.append(f"- **{name}**")
+            if intervention.other_names:
+                lines.append(
+                    f"  - Also known as: {', '.join(intervention.other_names)}"
+                )
+            lines.append(
+                f"  - Dosing: {intervention.dosing or 'Not registered'}"
+            )
+        if not arm.interventions:
+            lines.append("- No interventions registered for this arm.")
+        lines.append("")
+    return "\n".join(lines).rstrip() + "\n"
+
+
+async def fetch_trial_arms(
+    nct_id: str,
+) -> tuple[TrialArms | None, str | None]:
+    """Fetch a trial's arms and interventions.
+
+    Returns:
+        Tuple of (arms or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=f"{CLINICAL_TRIALS_BASE_URL}/{nct_id}",
+        request={"fields": ARM_FIELDS},
+        method="GET",
+        tls_version=TLSVersion.TLSv1_2,
+        response_model_type=None,
+        domain="clinicaltrials",
+    )
+    if error:
+        return None, f"API Error {error.code}: {error.message}"
+    if isinstance(response, dict) and "studies" in response:
+        response = (response.get("studies") or [None])[0]
+    if not isinstance(response, dict):
+        return None, f"No data found for {nct_id}"
+    return parse_trial_arms(response, nct_id), None
+
+
+async def get_trial_arms(nct_id: str, output_json: bool = False) -> str:
+    """Get a trial's arms with the dosing of each intervention."""
+    trial, error = await fetch_trial_arms(nct_id)
+    if error or trial is None:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return f"Error: {error}"
+    if output_json:
+        return json.dumps(trial.model_dump(exclude_none=True), indent=2)
+    return format_trial_arms(trial)
@@ -0,0 +1,101 @@
+"""Resumable trial search pagination through a cursor file.
+
+ClinicalTrials.gov pages through results with an opaque
+``nextPageToken``. Trial search asks for CSV, whose rows carry no token
+(it is only sent in a response header), so the token is read from a
+second, JSON request for the same page that returns only NCT IDs.
+``trial search --save-cursor FILE`` writes that token to a JSON file
+together with the query it belongs to, and
+``--resume FILE`` reads it back, so a shell loop can walk all pages
+without parsing the output. The file is removed once the last page has
+been returned, and resuming with a different query is refused, since a
+token is only valid for the search that produced it.
+"""
+
+import asyncio
+import json
+from pathlib import Path
+from ssl import TLSVersion
+from typing import Any
+
+from .. import http_client
+from ..constants import CLINICAL_TRIALS_BASE_URL
+from .search import TrialQuery, convert_query, search_trials
+
+
+def cursor_query(query: TrialQuery) -> dict[str, Any]:
+    """The query fields a cursor is tied to (everything but the token)."""
+    return query.model_dump(
+        mode="json", exclude={"next_page_hash"}, exclude_none=True
+    )
+
+
+async def fetch_next_page_token(
+    query: TrialQuery,
+) -> tuple[str | None, str | None]:
+    """Fetch the token of the page after the one ``query`` returns.
+
+    Returns:
+        Tuple of (next page token, or None on the last page, error
+        message or None)
+    """
+    params = await convert_query(query)
+    params["format"] = ["json"]
+    params["fields"] = ["NCTId"]
+    response, error = await http_client.request_api(
+        url=CLINICAL_TRIALS_BASE_URL,
+        request=params,
+        method="GET",
+        tls_version=TLSVersion.TLSv1_2,
+        domain="trial",
+    )
+    if error:
+        return None, f"Error {error.code}: {error.message}"
+    if not isinstance(response, dict):
+        return None, None
+    return response.get("nextPageToken"), None
+
+
+async def search_trials_with_cursor(
+    query: TrialQuery, explain: bool = False
+) -> tuple[str, str | None, str | None]:
+    """Run a trial search and fetch its next page token alongside.
+
+    Returns:
+        Tuple of (JSON search result, next page token or None, error
+        message of the token lookup or None)
+    """
+    result, (token, error) = await asyncio.gather(
+        search_trials(query, output_json=True, explain=explain),
+        fetch_next_page_token(query),
+    )
+    return result, token, error
+
+
+def save_cursor(path: Path, query: TrialQuery, token: str | None) -> None:
+    """Write the next page token for a query, or remove the file when done."""
+    if token is None:
+        path.unlink(missing_ok=True)
+        return
+    payload = {"query": cursor_query(query), "next_page_token": token}
+    path.write_text(json.dumps(payload, indent=2) + "\n")
+
+
+def load_cursor(path: Path, query: TrialQuery) -> str:
+    """Read the next page token saved for the same query.
+
+    Raises:
+        ValueError: If the file is unreadable or belongs to another query
+    """
+    try:
+        payload = json.loads(path.read_text())
+    except (OSError, json.JSONDecodeError) as e:
+        raise ValueError(f"Cannot read cursor file {path}: {e}") from None
+    if not isinstance(payload, dict) or not payload.get("next_page_token"):
+        raise ValueError(f"Cursor file {path} has no next page token")
+    if payload.get("query") != cursor_query(query):
+        raise ValueError(
+            f"Cursor file {path} was saved for a different query; "
+            "resume with the same search options"
+        )
+    return payload["next_page_token"]
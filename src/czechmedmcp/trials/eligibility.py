@@ -0,0 +1,380 @@
+"""Best-effort matching of a patient profile against trial eligibility.
+
+Eligibility criteria on ClinicalTrials.gov are free text, so the verdicts
+produced here are heuristics: a criterion that mentions one of the
+patient's mutations or prior therapies is reported as a match (inclusion)
+or a mismatch (exclusion); everything else is left as unknown. A mention
+negated within its clause ("no prior X", "without Y") rules the patient
+out of an inclusion criterion and leaves an exclusion criterion unknown.
+"""
+
+import json
+import logging
+import re
+from typing import Any
+
+from pydantic import BaseModel, Field, model_validator
+
+from .. import StrEnum, ensure_list
+from .getter import Module, get_trial
+
+logger = logging.getLogger(__name__)
+
+_INCLUSION_HEADER = re.compile(r"inclusion\s+criteria", re.IGNORECASE)
+_EXCLUSION_HEADER = re.compile(r"exclusion\s+criteria", re.IGNORECASE)
+_BULLET_PREFIX = re.compile(r"^\s*(?:[-*•]|\d+[.)])\s*")
+# Cues that negate a keyword later in the same clause
+_NEGATION_CUE = re.compile(
+    r"\b(?:no|not|non|without|never|absence\s+of|free\s+of|lack\s+of|"
+    r"negative\s+for)\b",
+    re.IGNORECASE,
+)
+# Clause boundaries that end the scope of a negation cue
+_CLAUSE_BOUNDARY = re.compile(r"[;.:()]|\bbut\b", re.IGNORECASE)
+_AGE_PATTERN = re.compile(
+    r"(\d+(?:\.\d+)?)\s*(year|month|week|day)", re.IGNORECASE
+)
+
+_UNIT_TO_YEARS = {
+    "year": 1.0,
+    "month": 1 / 12,
+    "week": 1 / 52,
+    "day": 1 / 365,
+}
+
+
+class Verdict(StrEnum):
+    MATCH = "match"
+    MISMATCH = "mismatch"
+    UNKNOWN = "unknown"
+
+
+class PatientSex(StrEnum):
+    FEMALE = "female"
+    MALE = "male"
+
+
+class PatientProfile(BaseModel):
+    """Patient attributes used for eligibility matching."""
+
+    age: float | None = Field(default=None, description="Age in years")
+    sex: PatientSex | None = Field(default=None, description="Patient sex")
+    mutations: list[str] = Field(
+        default_factory=list, description="Known mutations or biomarkers"
+    )
+    prior_therapies: list[str] = Field(
+        default_factory=list, description="Previously received therapies"
+    )
+
+    @model_validator(mode="before")
+    def convert_list_fields(cls, data):
+        """Convert string values to lists for list fields."""
+        if isinstance(data, dict):
+            for field_name in ["mutations", "prior_therapies"]:
+                if data.get(field_name) is not None:
+                    data[field_name] = ensure_list(
+                        data[field_name], split_strings=True
+                    )
+                else:
+                    data.pop(field_name, None)
+        return data
+
+
+class CriterionResult(BaseModel):
+    """Verdict for a single eligibility criterion."""
+
+    section: str
+    criterion: str
+    verdict: Verdict
+    reason: str = ""
+
+
+def split_eligibility_sections(text: str) -> tuple[list[str], list[str]]:
+    """Split eligibility text into inclusion and exclusion criteria.
+
+    Lines before any section header are treated as inclusion criteria.
+    """
+    inclusion: list[str] = []
+    exclusion: list[str] = []
+    current = inclusion
+
+    for raw_line in (text or "").splitlines():
+        line = raw_line.strip()
+        if not line:
+            continue
+        if _EXCLUSION_HEADER.search(line) and len(line) < 40:
+            current = exclusion
+            continue
+        if _INCLUSION_HEADER.search(line) and len(line) < 40:
+            current = inclusion
+            continue
+        criterion = _BULLET_PREFIX.sub("", line).strip()
+        if criterion:
+            current.append(criterion)
+
+    return inclusion, exclusion
+
+
+def format_structured_eligibility(
+    nct_id: str, inclusion: list[str], exclusion: list[str]
+) -> str:
+    """Render itemized inclusion and exclusion criteria as markdown."""
+    lines = [f"# Eligibility: {nct_id}"]
+    for title, criteria in (
+        ("Inclusion Criteria", inclusion),
+        ("Exclusion Criteria", exclusion),
+    ):
+        lines.extend(["", f"## {title}", ""])
+        if not criteria:
+            lines.append("None listed.")
+        for number, criterion in enumerate(criteria, start=1):
+            lines.append(f"{number}. {criterion}")
+    return "\n".join(lines)
+
+
+async def get_structured_eligibility(
+    nct_id: str, output_json: bool = False
+) -> str:
+    """Fetch a trial's eligibility criteria as itemized lists.
+
+    Args:
+        nct_id: NCT identifier (e.g., "NCT04280705")
+        output_json: Return ``{"inclusion": [...], "exclusion": [...]}``
+
+    Returns:
+        Formatted markdown or JSON string with one item per criterion
+    """
+    raw = await get_trial(nct_id, Module.PROTOCOL, output_json=True)
+    data = json.loads(raw)
+
+    if "error" in data:
+        if output_json:
+            return json.dumps(data, indent=2)
+        return f"Error: {data['error']}"
+
+    module = data.get("protocolSection", {}).get("eligibilityModule", {})
+    inclusion, exclusion = split_eligibility_sections(
+        module.get("eligibilityCriteria", "")
+    )
+    if output_json:
+        return json.dumps(
+            {"nct_id": nct_id, "inclusion": inclusion, "exclusion": exclusion},
+            indent=2,
+        )
+    return format_structured_eligibility(nct_id, inclusion, exclusion)
+
+
+def _keyword_pattern(keyword: str) -> re.Pattern[str]:
+    """Whole-word, case-insensitive pattern for a patient keyword."""
+    return re.compile(
+        rf"(?<![\w-]){re.escape(keyword.strip())}(?![\w-])", re.IGNORECASE
+    )
+
+
+def eligibility_keyword_in_text(keyword: str, text: str) -> bool:
+    """Case-insensitive whole-word check for a keyword in criterion text."""
+    if not keyword.strip():
+        return False
+    return _keyword_pattern(keyword).search(text) is not None
+
+
+def eligibility_keyword_negated(keyword: str, text: str) -> bool:
+    """Check whether every mention of a keyword is negated in its clause.
+
+    A mention is negated when a cue such as "no", "without" or "negative
+    for" precedes it in the same clause, as in "No prior osimertinib".
+    """
+    if not keyword.strip():
+        return False
+    mentions = list(_keyword_pattern(keyword).finditer(text))
+    if not mentions:
+        return False
+    for mention in mentions:
+        clause = _CLAUSE_BOUNDARY.split(text[: mention.start()])[-1]
+        if not _NEGATION_CUE.search(clause):
+            return False
+    return True
+
+
+def parse_age_years(value: str | None) -> float | None:
+    """Parse a ClinicalTrials.gov age string (e.g. '18 Years') to years."""
+    if not value:
+        return None
+    match = _AGE_PATTERN.search(value)
+    if not match:
+        return None
+    return float(match.group(1)) * _UNIT_TO_YEARS[match.group(2).lower()]
+
+
+def _check_demographics(
+    profile: PatientProfile, module: dict[str, Any]
+) -> list[CriterionResult]:
+    """Check age and sex against the structured eligibility fields."""
+    results: list[CriterionResult] = []
+
+    min_age = parse_age_years(module.get("minimumAge"))
+    max_age = parse_age_years(module.get("maximumAge"))
+    if min_age is not None or max_age is not None:
+        label = (
+            f"Age {module.get('minimumAge', 'N/A')} - "
+            f"{module.get('maximumAge', 'N/A')}"
+        )
+        if profile.age is None:
+            verdict, reason = Verdict.UNKNOWN, "patient age not provided"
+        elif (min_age is not None and profile.age < min_age) or (
+            max_age is not None and profile.age > max_age
+        ):
+            verdict, reason = Verdict.MISMATCH, f"patient age {profile.age:g}"
+        else:
+            verdict, reason = Verdict.MATCH, f"patient age {profile.age:g}"
+        results.append(
+            CriterionResult(
+                section="demographics",
+                criterion=label,
+                verdict=verdict,
+                reason=reason,
+            )
+        )
+
+    trial_sex = (module.get("sex") or "").upper()
+    if trial_sex and trial_sex != "ALL":
+        if not profile.sex:
+            verdict, reason = Verdict.UNKNOWN, "patient sex not provided"
+        elif profile.sex.value.upper() == trial_sex:
+            verdict, reason = Verdict.MATCH, f"patient sex {profile.sex}"
+        else:
+            verdict, reason = Verdict.MISMATCH, f"patient sex {profile.sex}"
+        results.append(
+            CriterionResult(
+                section="demographics",
+                criterion=f"Sex: {trial_sex}",
+                verdict=verdict,
+                reason=reason,
+            )
+        )
+
+    return results
+
+
+def _matched_keywords(
+    profile: PatientProfile, criterion: str
+) -> tuple[list[str], list[str]]:
+    """Return the patient keywords a criterion mentions and negates."""
+    keywords = profile.mutations + profile.prior_therapies
+    mentioned = [
+        k for k in keywords if eligibility_keyword_in_text(k, criterion)
+    ]
+    negated = [
+        k for k in mentioned if eligibility_keyword_negated(k, criterion)
+    ]
+    return [k for k in mentioned if k not in negated], negated
+
+
+def _criterion_result(
+    section: str, criterion: str, profile: PatientProfile
+) -> CriterionResult:
+    """Judge one free-text criterion by the patient keywords it mentions."""
+    hits, negated = _matched_keywords(profile, criterion)
+    if negated and section == "inclusion":
+        verdict = Verdict.MISMATCH
+        reason = f"rules out {', '.join(negated)}"
+    elif hits:
+        verdict = (
+            Verdict.MATCH if section == "inclusion" else Verdict.MISMATCH
+        )
+        reason = f"mentions {', '.join(hits)}"
+    elif negated:
+        verdict = Verdict.UNKNOWN
+        reason = f"negated mention of {', '.join(negated)}"
+    else:
+        verdict, reason = Verdict.UNKNOWN, ""
+    return CriterionResult(
+        section=section, criterion=criterion, verdict=verdict, reason=reason
+    )
+
+
+def evaluate_eligibility(
+    profile: PatientProfile, module: dict[str, Any]
+) -> list[CriterionResult]:
+    """Evaluate a patient profile against a trial's eligibility module."""
+    results = _check_demographics(profile, module)
+    inclusion, exclusion = split_eligibility_sections(
+        module.get("eligibilityCriteria", "")
+    )
+
+    results.extend(
+        _criterion_result("inclusion", criterion, profile)
+        for criterion in inclusion
+    )
+    results.extend(
+        _criterion_result("exclusion", criterion, profile)
+        for criterion in exclusion
+    )
+    return results
+
+
+def format_eligibility_results(
+    nct_id: str, results: list[CriterionResult]
+) -> str:
+    """Render eligibility results as a markdown table."""
+    counts = {v: 0 for v in Verdict}
+    for result in results:
+        counts[result.verdict] += 1
+
+    lines = [
+        f"# Eligibility Check: {nct_id}",
+        "",
+        "_Best-effort keyword matching; review the full criteria._",
+        "",
+        f"- **Match**: {counts[Verdict.MATCH]}",
+        f"- **Mismatch**: {counts[Verdict.MISMATCH]}",
+        f"- **Unknown**: {counts[Verdict.UNKNOWN]}",
+        "",
+        "| Section | Criterion | Verdict | Reason |",
+        "|---------|-----------|---------|--------|",
+    ]
+    for result in results:
+        criterion = result.criterion.replace("|", "\\|")
+        lines.append(
+            f"| {result.section} | {criterion} | "
+            f"{result.verdict.value} | {result.reason} |"
+        )
+    return "\n".join(lines)
+
+
+async def check_trial_eligibility(
+    nct_id: str,
+    profile: PatientProfile,
+    output_json: bool = False,
+) -> str:
+    """Fetch a trial's eligibility criteria and match a patient against it.
+
+    Args:
+        nct_id: NCT identifier (e.g., "NCT04280705")
+        profile: Patient attributes to match
+        output_json: Return JSON instead of a markdown table
+
+    Returns:
+        Formatted markdown table or JSON string with per-criterion verdicts
+    """
+    raw = await get_trial(nct_id, Module.PROTOCOL, output_json=True)
+    data = json.loads(raw)
+
+    if "error" in data:
+        if output_json:
+            return json.dumps(data, indent=2)
+        return f"Error: {data['error']}"
+
+    module = data.get("protocolSection", {}).get("eligibilityModule", {})
+    results = evaluate_eligibility(profile, module)
+
+    if output_json:
+        return json.dumps(
+            {
+                "nct_id": nct_id,
+                "profile": profile.model_dump(),
+                "criteria": [r.model_dump(mode="json") for r in results],
+            },
+            indent=2,
+        )
+    return format_eligibility_results(nct_id, results)
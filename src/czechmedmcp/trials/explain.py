@@ -0,0 +1,102 @@
+"""Explain why a trial matched an eligibility-based search.
+
+Mutation, prior-therapy and biomarker filters are sent to
+ClinicalTrials.gov as free-text searches over the eligibility criteria,
+so whether a trial is returned depends on its wording. ``--explain``
+fetches the criteria of each returned trial and quotes the criterion
+that mentions each filter keyword, using the same line splitting and
+whole-word matching as ``trial eligibility``.
+"""
+
+import json
+import textwrap
+from typing import Any
+
+from ..concurrency import gather_limited, resolve_concurrency
+from .eligibility import (
+    eligibility_keyword_in_text,
+    split_eligibility_sections,
+)
+from .getter import Module, get_trial
+from .search import TrialQuery
+
+# Parallel eligibility fetches for the displayed trials
+EXPLAIN_CONCURRENCY = 5
+
+# Maximum length of a quoted criterion
+SNIPPET_WIDTH = 160
+
+MATCH_FIELD = "Matched Eligibility"
+
+
+def explain_keywords(query: TrialQuery) -> list[str]:
+    """Keywords of the eligibility filters a match can be explained by.
+
+    Excluded mutations are left out: a trial matches them by *not*
+    mentioning the mutation, so there is no phrase to quote.
+    """
+    keywords = [
+        *(query.required_mutations or []),
+        *(query.prior_therapies or []),
+        *(query.progression_on or []),
+        *(query.biomarker_expression or {}),
+    ]
+    return [k for k in dict.fromkeys(keywords) if k.strip()]
+
+
+def find_matching_criteria(keywords: list[str], text: str) -> list[str]:
+    """Quote the first criterion mentioning each keyword.
+
+    Returns:
+        One ``keyword (section): "criterion"`` entry per matched keyword
+    """
+    inclusion, exclusion = split_eligibility_sections(text)
+    sections = [("inclusion", c) for c in inclusion] + [
+        ("exclusion", c) for c in exclusion
+    ]
+    matches: list[str] = []
+    for keyword in keywords:
+        for section, criterion in sections:
+            if eligibility_keyword_in_text(keyword, criterion):
+                snippet = textwrap.shorten(
+                    criterion, SNIPPET_WIDTH, placeholder="..."
+                )
+                matches.append(f'{keyword} ({section}): "{snippet}"')
+                break
+    return matches
+
+
+async def _eligibility_text(nct_id: str) -> str | None:
+    data = json.loads(await get_trial(nct_id, Module.PROTOCOL, True))
+    if "error" in data:
+        return None
+    module = data.get("protocolSection", {}).get("eligibilityModule", {})
+    return module.get("eligibilityCriteria", "")
+
+
+async def explain_trial_matches(
+    trials: list[dict[str, Any]],
+    keywords: list[str],
+    concurrency: int | None = None,
+) -> None:
+    """Annotate each search result with its matching eligibility criteria.
+
+    The quoted criteria are stored under ``MATCH_FIELD`` on each trial.
+    """
+    nct_ids = [trial.get("NCT Number") for trial in trials]
+    texts = await gather_limited(
+        (_eligibility_text(nct_id) for nct_id in nct_ids if nct_id),
+        resolve_concurrency(concurrency, EXPLAIN_CONCURRENCY),
+    )
+    by_id = dict(zip([n for n in nct_ids if n], texts, strict=True))
+
+    for trial, nct_id in zip(trials, nct_ids, strict=True):
+        text = by_id.get(nct_id) if nct_id else None
+        if text is None:
+            trial[MATCH_FIELD] = "Eligibility criteria unavailable"
+            continue
+        matches = find_matching_criteria(keywords, text)
+        trial[MATCH_FIELD] = matches or (
+            "No criterion mentions the search keywords; the match may "
+            "come from other wording"
+        )
@@ -5,6 +5,7 @@ from typing import Annotated, Any
 
 from .. import StrEnum, http_client, render
 from ..constants import CLINICAL_TRIALS_BASE_URL
+from .locations import annotate_location_distances
 
 logger = logging.getLogger(__name__)
 
@@ -54,8 +55,13 @@ async def get_trial(
     nct_id: str,
     module: Module = Module.PROTOCOL,
     output_json: bool = False,
+    near: tuple[float, float] | None = None,
 ) -> str:
-    """Get details of a clinical trial by module."""
+    """Get details of a clinical trial by module.
+
+    With ``near`` as (lat, lon), trial sites are annotated with their
+    distance in miles and listed nearest-first.
+    """
     fields = ",".join(modules[module])
     params = {"fields": fields}
     url = f"{CLINICAL_TRIALS_BASE_URL}/{nct_id}"
@@ -118,6 +124,9 @@ async def get_trial(
             "details": "API returned no data",
         }
 
+    if near is not None and "error" not in data_to_return:
+        annotate_location_distances(data_to_return, *near)
+
     if output_json:
         return json.dumps(data_to_return, indent=2)
     else:
@@ -0,0 +1,69 @@
+"""Distance annotation for the sites of a single trial.
+
+ClinicalTrials.gov v2 attaches a ``geoPoint`` (lat/lon) to most entries
+of ``contactsLocationsModule.locations``. Given a reference point, each
+site gets a great-circle ``distance_miles`` and the sites are reordered
+nearest-first; sites without coordinates keep their order at the end.
+"""
+
+import math
+from typing import Any
+
+EARTH_RADIUS_MILES = 3958.8
+
+
+def haversine_miles(
+    lat1: float, lon1: float, lat2: float, lon2: float
+) -> float:
+    """Great-circle distance between two points in miles."""
+    phi1, phi2 = math.radians(lat1), math.radians(lat2)
+    d_phi = math.radians(lat2 - lat1)
+    d_lambda = math.radians(lon2 - lon1)
+    a = (
+        math.sin(d_phi / 2) ** 2
+        + math.cos(phi1) * math.cos(phi2) * math.sin(d_lambda / 2) ** 2
+    )
+    return 2 * EARTH_RADIUS_MILES * math.asin(math.sqrt(a))
+
+
+def location_distance(
+    location: dict[str, Any], lat: float, lon: float
+) -> float | None:
+    """Distance from a reference point to a site, or None without geoPoint."""
+    point = location.get("geoPoint")
+    if not isinstance(point, dict):
+        return None
+    try:
+        site_lat, site_lon = float(point["lat"]), float(point["lon"])
+    except (KeyError, TypeError, ValueError):
+        return None
+    return round(haversine_miles(lat, lon, site_lat, site_lon), 1)
+
+
+def annotate_location_distances(
+    study: dict[str, Any], lat: float, lon: float
+) -> dict[str, Any]:
+    """Add ``distance_miles`` to each site and sort sites nearest-first.
+
+    The study dict is updated in place and returned; studies without a
+    locations list are returned unchanged.
+    """
+    module = study.get("protocolSection", {}).get("contactsLocationsModule")
+    if not isinstance(module, dict):
+        return study
+    locations = module.get("locations")
+    if not isinstance(locations, list):
+        return study
+
+    sites = [loc for loc in locations if isinstance(loc, dict)]
+    for site in sites:
+        site["distance_miles"] = location_distance(site, lat, lon)
+
+    module["locations"] = sorted(
+        sites,
+        key=lambda site: (
+            site["distance_miles"] is None,
+            site["distance_miles"] or 0.0,
+        ),
+    )
+    return study
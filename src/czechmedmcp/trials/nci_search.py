@@ -1,9 +1,9 @@
 """NCI Clinical Trials Search API integration for trial searches."""
 
-import asyncio
 import logging
 from typing import Any
 
+from ..concurrency import gather_limited, resolve_concurrency
 from ..constants import NCI_TRIALS_URL
 from ..diseases.search import search_diseases
 from ..integrations.cts_api import CTSAPIError, make_cts_request
@@ -12,6 +12,9 @@ from .search import TrialQuery
 
 logger = logging.getLogger(__name__)
 
+# Default parallel lookups when expanding or normalizing query terms
+TERM_LOOKUP_CONCURRENCY = 5
+
 
 async def _expand_single_disease(condition: str) -> list[str]:
     """Expand a single disease term with synonyms."""
@@ -39,14 +42,16 @@ async def _expand_single_disease(condition: str) -> list[str]:
 async def _expand_disease_terms(
     conditions: list[str],
     expand_synonyms: bool,
+    concurrency: int | None = None,
 ) -> list[str]:
     """Expand disease terms with synonyms if requested."""
     if not expand_synonyms:
         return conditions
 
     # Fetch all expansions in parallel
-    expanded = await asyncio.gather(
-        *(_expand_single_disease(c) for c in conditions)
+    expanded = await gather_limited(
+        (_expand_single_disease(c) for c in conditions),
+        resolve_concurrency(concurrency, TERM_LOOKUP_CONCURRENCY),
     )
     disease_terms = [
         term for group in expanded for term in group
@@ -85,15 +90,12 @@ async def _normalize_single_intervention(
 
 async def _normalize_interventions(
     interventions: list[str],
+    concurrency: int | None = None,
 ) -> list[str]:
     """Normalize intervention names to IDs where possible."""
-    return list(
-        await asyncio.gather(
-            *(
-                _normalize_single_intervention(i)
-                for i in interventions
-            )
-        )
+    return await gather_limited(
+        (_normalize_single_intervention(i) for i in interventions),
+        resolve_concurrency(concurrency, TERM_LOOKUP_CONCURRENCY),
     )
 
 
@@ -126,6 +128,26 @@ def _map_status_to_nci(recruiting_status: Any) -> list[str] | None:
     return status_map.get(recruiting_status.value)
 
 
+def _map_intervention_type_to_nci(intervention_type: Any) -> str | None:
+    """Map InterventionType enum to NCI intervention type values."""
+    if not intervention_type:
+        return None
+
+    type_map = {
+        "DRUG": "Drug",
+        "DEVICE": "Device",
+        "BIOLOGICAL": "Biological/Vaccine",
+        "PROCEDURE": "Procedure/Surgery",
+        "RADIATION": "Radiation",
+        "BEHAVIORAL": "Behavioral",
+        "GENETIC": "Genetic",
+        "DIETARY": "Dietary Supplement",
+        "DIAGNOSTIC_TEST": "Diagnostic Test",
+        "OTHER": "Other",
+    }
+    return type_map.get(intervention_type.value)
+
+
 def _map_sort_to_nci(sort: Any) -> str | None:
     """Map SortOrder enum to NCI sort values."""
     if not sort:
@@ -187,6 +209,12 @@ async def convert_query_to_nci(query: TrialQuery) -> dict[str, Any]:
             query.interventions
         )
 
+    nci_intervention_type = _map_intervention_type_to_nci(
+        query.intervention_type
+    )
+    if nci_intervention_type:
+        params["intervention_type"] = nci_intervention_type
+
     # NCT IDs
     if query.nct_ids:
         params["nct_ids"] = query.nct_ids
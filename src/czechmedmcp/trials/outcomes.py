@@ -0,0 +1,391 @@
+"""Structured outcome measures with posted results for a single trial.
+
+The registered outcome measures come from ``protocolSection.outcomesModule``.
+When a trial has posted results, ``resultsSection`` adds the reported
+values per arm (``outcomeMeasuresModule``), statistical analyses and the
+participant flow. Reported measures are matched to registered ones by
+type and title; reported measures that no longer match a registered
+title are listed after them.
+"""
+
+import json
+from ssl import TLSVersion
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from .. import http_client
+from ..constants import CLINICAL_TRIALS_BASE_URL
+
+OUTCOME_FIELDS = "NCTId,OutcomesModule,ResultsSection,HasResults"
+
+# protocolSection.outcomesModule key -> outcome type
+OUTCOME_TYPES = {
+    "primaryOutcomes": "PRIMARY",
+    "secondaryOutcomes": "SECONDARY",
+    "otherOutcomes": "OTHER_PRE_SPECIFIED",
+}
+
+
+class OutcomeMeasurement(BaseModel):
+    """One reported value for an arm/group."""
+
+    group: str
+    category: str | None = None
+    value: str | None = None
+    spread: str | None = None
+    lower_limit: str | None = None
+    upper_limit: str | None = None
+
+
+class OutcomeAnalysis(BaseModel):
+    """A statistical comparison reported for an outcome."""
+
+    groups: list[str] = Field(default_factory=list)
+    method: str | None = None
+    p_value: str | None = None
+    param_type: str | None = None
+    param_value: str | None = None
+    ci_lower: str | None = None
+    ci_upper: str | None = None
+
+
+class TrialOutcome(BaseModel):
+    """A registered outcome measure, with reported results if posted."""
+
+    type: str
+    measure: str
+    description: str | None = None
+    time_frame: str | None = None
+    unit: str | None = None
+    param_type: str | None = None
+    dispersion_type: str | None = None
+    participants: dict[str, str] | None = None
+    measurements: list[OutcomeMeasurement] | None = None
+    analyses: list[OutcomeAnalysis] | None = None
+
+
+class FlowMilestone(BaseModel):
+    """Participant counts per group at one milestone of a flow period."""
+
+    type: str
+    counts: dict[str, str] = Field(default_factory=dict)
+
+
+class FlowPeriod(BaseModel):
+    title: str
+    milestones: list[FlowMilestone] = Field(default_factory=list)
+
+
+class TrialOutcomes(BaseModel):
+    """Outcome measures of a trial, with posted results when requested."""
+
+    nct_id: str
+    has_results: bool = False
+    outcomes: list[TrialOutcome] = Field(default_factory=list)
+    participant_flow: list[FlowPeriod] | None = None
+
+
+def _normalize_title(title: str | None) -> str:
+    return " ".join(str(title or "").lower().split())
+
+
+def _group_titles(groups: list[dict[str, Any]]) -> dict[str, str]:
+    return {
+        group["id"]: group.get("title") or group["id"]
+        for group in groups
+        if isinstance(group, dict) and group.get("id")
+    }
+
+
+def _parse_measurements(
+    measure: dict[str, Any], groups: dict[str, str]
+) -> list[OutcomeMeasurement]:
+    measurements: list[OutcomeMeasurement] = []
+    for outcome_class in measure.get("classes") or []:
+        class_title = outcome_class.get("title")
+        for category in outcome_class.get("categories") or []:
+            label = " / ".join(
+                title
+                for title in (class_title, category.get("title"))
+                if title
+            )
+            for value in category.get("measurements") or []:
+                group_id = value.get("groupId", "")
+                measurements.append(
+                    OutcomeMeasurement(
+                        group=groups.get(group_id, group_id),
+                        category=label or None,
+                        value=value.get("value"),
+                        spread=value.get("spread"),
+                        lower_limit=value.get("lowerLimit"),
+                        upper_limit=value.get("upperLimit"),
+                    )
+                )
+    return measurements
+
+
+def _parse_analyses(
+    measure: dict[str, Any], groups: dict[str, str]
+) -> list[OutcomeAnalysis]:
+    return [
+        OutcomeAnalysis(
+            groups=[groups.get(g, g) for g in analysis.get("groupIds") or []],
+            method=analysis.get("statisticalMethod"),
+            p_value=analysis.get("pValue"),
+            param_type=analysis.get("paramType"),
+            param_value=analysis.get("paramValue"),
+            ci_lower=analysis.get("ciLowerLimit"),
+            ci_upper=analysis.get("ciUpperLimit"),
+        )
+        for analysis in measure.get("analyses") or []
+        if isinstance(analysis, dict)
+    ]
+
+
+def _participants(
+    measure: dict[str, Any], groups: dict[str, str]
+) -> dict[str, str] | None:
+    for denom in measure.get("denoms") or []:
+        counts = {
+            groups.get(count["groupId"], count["groupId"]): count["value"]
+            for count in denom.get("counts") or []
+            if count.get("groupId") and count.get("value") is not None
+        }
+        if counts:
+            return counts
+    return None
+
+
+def _with_results(
+    outcome: TrialOutcome, measure: dict[str, Any]
+) -> TrialOutcome:
+    groups = _group_titles(measure.get("groups") or [])
+    return outcome.model_copy(
+        update={
+            "unit": measure.get("unitOfMeasure"),
+            "param_type": measure.get("paramType"),
+            "dispersion_type": measure.get("dispersionType"),
+            "participants": _participants(measure, groups),
+            "measurements": _parse_measurements(measure, groups),
+            "analyses": _parse_analyses(measure, groups) or None,
+        }
+    )
+
+
+def _parse_participant_flow(
+    results: dict[str, Any],
+) -> list[FlowPeriod] | None:
+    flow = results.get("participantFlowModule")
+    if not isinstance(flow, dict):
+        return None
+    groups = _group_titles(flow.get("groups") or [])
+    periods: list[FlowPeriod] = []
+    for period in flow.get("periods") or []:
+        milestones = [
+            FlowMilestone(
+                type=milestone.get("type", ""),
+                counts={
+                    groups.get(a["groupId"], a["groupId"]): a["numSubjects"]
+                    for a in milestone.get("achievements") or []
+                    if a.get("groupId") and a.get("numSubjects") is not None
+                },
+            )
+            for milestone in period.get("milestones") or []
+        ]
+        periods.append(
+            FlowPeriod(
+                title=period.get("title") or "Overall Study",
+                milestones=milestones,
+            )
+        )
+    return periods or None
+
+
+def parse_trial_outcomes(
+    study: dict[str, Any], with_results: bool = False
+) -> TrialOutcomes:
+    """Build the outcome structure from a ClinicalTrials.gov v2 study."""
+    protocol = study.get("protocolSection", {})
+    nct_id = protocol.get("identificationModule", {}).get("nctId", "")
+    module = protocol.get("outcomesModule", {})
+
+    outcomes = [
+        TrialOutcome(
+            type=outcome_type,
+            measure=outcome.get("measure", ""),
+            description=outcome.get("description"),
+            time_frame=outcome.get("timeFrame"),
+        )
+        for key, outcome_type in OUTCOME_TYPES.items()
+        for outcome in module.get(key) or []
+        if isinstance(outcome, dict)
+    ]
+
+    results = study.get("resultsSection") or {}
+    trial = TrialOutcomes(
+        nct_id=nct_id,
+        has_results=bool(study.get("hasResults") or results),
+        outcomes=outcomes,
+    )
+    if not with_results or not results:
+        return trial
+
+    reported = (results.get("outcomeMeasuresModule") or {}).get(
+        "outcomeMeasures"
+    ) or []
+    unmatched: list[TrialOutcome] = []
+    for measure in reported:
+        key = (measure.get("type"), _normalize_title(measure.get("title")))
+        for index, outcome in enumerate(outcomes):
+            if (outcome.type, _normalize_title(outcome.measure)) == key:
+                outcomes[index] = _with_results(outcome, measure)
+                break
+        else:
+            unmatched.append(
+                _with_results(
+                    TrialOutcome(
+                        type=measure.get("type", ""),
+                        measure=measure.get("title", ""),
+                        description=measure.get("description"),
+                        time_frame=measure.get("timeFrame"),
+                    ),
+                    measure,
+                )
+            )
+
+    trial.outcomes = outcomes + unmatched
+    trial.participant_flow = _parse_participant_flow(results)
+    return trial
+
+
+def _format_value(measurement: OutcomeMeasurement) -> str:
+    value = measurement.value or "NA"
+    if measurement.spread:
+        return f"{value} ({measurement.spread})"
+    if measurement.lower_limit or measurement.upper_limit:
+        lower = measurement.lower_limit or "NA"
+        upper = measurement.upper_limit or "NA"
+        return f"{value} [{lower}, {upper}]"
+    return value
+
+
+def _format_analysis(analysis: OutcomeAnalysis) -> str:
+    parts: list[str] = []
+    if analysis.groups:
+        parts.append(" vs ".join(analysis.groups))
+    if analysis.param_type and analysis.param_value:
+        estimate = f"{analysis.param_type} {analysis.param_value}"
+        if analysis.ci_lower and analysis.ci_upper:
+            estimate += f" (CI {analysis.ci_lower} to {analysis.ci_upper})"
+        parts.append(estimate)
+    if analysis.p_value:
+        parts.append(f"p = {analysis.p_value}")
+    if analysis.method:
+        parts.append(analysis.method)
+    return "; ".join(parts)
+
+
+def _format_outcome(index: int, outcome: TrialOutcome) -> list[str]:
+    label = outcome.type.replace("_", " ").capitalize()
+    lines = [f"## {label} outcome {index}: {outcome.measure}", ""]
+    if outcome.time_frame:
+        lines.append(f"- **Time frame**: {outcome.time_frame}")
+    if outcome.description:
+        lines.append(f"- **Description**: {outcome.description}")
+    if outcome.measurements is None:
+        return lines
+
+    details = [
+        detail
+        for detail in (
+            outcome.param_type,
+            outcome.dispersion_type,
+            outcome.unit,
+        )
+        if detail
+    ]
+    lines.extend(["", f"### Results ({', '.join(details) or 'reported'})"])
+    if outcome.measurements:
+        lines.extend(["", "| Group | Participants | Category | Value |"])
+        lines.append("|---|---|---|---|")
+        participants = outcome.participants or {}
+        for measurement in outcome.measurements:
+            lines.append(
+                f"| {measurement.group} "
+                f"| {participants.get(measurement.group, '')} "
+                f"| {measurement.category or ''} "
+                f"| {_format_value(measurement)} |"
+            )
+    for analysis in outcome.analyses or []:
+        lines.append(f"- **Analysis**: {_format_analysis(analysis)}")
+    return lines
+
+
+def _format_flow(periods: list[FlowPeriod]) -> list[str]:
+    lines = ["## Participant flow"]
+    for period in periods:
+        groups: list[str] = []
+        for milestone in period.milestones:
+            groups.extend(g for g in milestone.counts if g not in groups)
+        lines.extend(["", f"### {period.title}", ""])
+        lines.append(f"| Milestone | {' | '.join(groups)} |")
+        lines.append("|---" * (len(groups) + 1) + "|")
+        for milestone in period.milestones:
+            counts = " | ".join(milestone.counts.get(g, "") for g in groups)
+            lines.append(f"| {milestone.type} | {counts} |")
+    return lines
+
+
+def format_trial_outcomes(trial: TrialOutcomes) -> str:
+    """Format trial outcomes (and any posted results) as markdown."""
+    lines = [f"# Outcomes: {trial.nct_id}", ""]
+    if not trial.has_results:
+        lines.extend(["No results have been posted for this trial.", ""])
+    if not trial.outcomes:
+        lines.append("No outcome measures registered.")
+    for index, outcome in enumerate(trial.outcomes, start=1):
+        lines.extend(_format_outcome(index, outcome))
+        lines.append("")
+    if trial.participant_flow:
+        lines.extend(_format_flow(trial.participant_flow))
+    return "\n".join(lines).rstrip() + "\n"
+
+
+async def fetch_trial_outcomes(
+    nct_id: str, with_results: bool = False
+) -> tuple[TrialOutcomes | None, str | None]:
+    """Fetch a trial's outcome measures and, optionally, posted results.
+
+    Returns:
+        Tuple of (outcomes or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=f"{CLINICAL_TRIALS_BASE_URL}/{nct_id}",
+        request={"fields": OUTCOME_FIELDS},
+        method="GET",
+        tls_version=TLSVersion.TLSv1_2,
+        response_model_type=None,
+        domain="clinicaltrials",
+    )
+    if error:
+        return None, f"API Error {error.code}: {error.message}"
+    if isinstance(response, dict) and "studies" in response:
+        response = (response.get("studies") or [None])[0]
+    if not isinstance(response, dict):
+        return None, f"No data found for {nct_id}"
+    return parse_trial_outcomes(response, with_results), None
+
+
+async def get_trial_outcomes(
+    nct_id: str, with_results: bool = False, output_json: bool = False
+) -> str:
+    """Get a trial's outcome measures, with posted results if requested."""
+    trial, error = await fetch_trial_outcomes(nct_id, with_results)
+    if error or trial is None:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return f"Error: {error}"
+    if output_json:
+        return json.dumps(trial.model_dump(exclude_none=True), indent=2)
+    return format_trial_outcomes(trial)
@@ -1,13 +1,16 @@
 import json
 import logging
+import re
+from datetime import date
 from ssl import TLSVersion
-from typing import Annotated
+from typing import Annotated, Any
 
 from pydantic import BaseModel, Field, field_validator, model_validator
 
 from .. import StrEnum, ensure_list, http_client, render
 from ..constants import CLINICAL_TRIALS_BASE_URL
 from ..integrations import BioThingsClient
+from ..utils.duration import parse_duration
 
 logger = logging.getLogger(__name__)
 
@@ -19,6 +22,7 @@ class SortOrder(StrEnum):
     START_DATE = "START_DATE"
     COMPLETION_DATE = "COMPLETION_DATE"
     SUBMITTED_DATE = "SUBMITTED_DATE"
+    STATUS = "STATUS"
 
 
 class TrialPhase(StrEnum):
@@ -63,12 +67,56 @@ class SponsorType(StrEnum):
     OTHER = "OTHER"
 
 
+class FunderType(StrEnum):
+    """ClinicalTrials.gov funder type facet of the lead sponsor."""
+
+    NIH = "nih"
+    INDUSTRY = "industry"
+    FED = "fed"
+    OTHER = "other"
+
+
 class StudyDesign(StrEnum):
     RANDOMIZED = "RANDOMIZED"
     NON_RANDOMIZED = "NON_RANDOMIZED"
     OBSERVATIONAL = "OBSERVATIONAL"
 
 
+class DesignAllocation(StrEnum):
+    RANDOMIZED = "RANDOMIZED"
+    NON_RANDOMIZED = "NON_RANDOMIZED"
+    NA = "NA"
+
+
+class DesignMasking(StrEnum):
+    NONE = "NONE"
+    SINGLE = "SINGLE"
+    DOUBLE = "DOUBLE"
+    TRIPLE = "TRIPLE"
+    QUADRUPLE = "QUADRUPLE"
+
+
+class InterventionModel(StrEnum):
+    SINGLE_GROUP = "SINGLE_GROUP"
+    PARALLEL = "PARALLEL"
+    CROSSOVER = "CROSSOVER"
+    FACTORIAL = "FACTORIAL"
+    SEQUENTIAL = "SEQUENTIAL"
+
+
+class LocationStatus(StrEnum):
+    """Recruitment status of an individual trial site."""
+
+    RECRUITING = "RECRUITING"
+    NOT_YET_RECRUITING = "NOT_YET_RECRUITING"
+    ENROLLING_BY_INVITATION = "ENROLLING_BY_INVITATION"
+    ACTIVE_NOT_RECRUITING = "ACTIVE_NOT_RECRUITING"
+    COMPLETED = "COMPLETED"
+    SUSPENDED = "SUSPENDED"
+    TERMINATED = "TERMINATED"
+    WITHDRAWN = "WITHDRAWN"
+
+
 class DateField(StrEnum):
     LAST_UPDATE = "LAST_UPDATE"
     STUDY_START = "STUDY_START"
@@ -79,6 +127,115 @@ class DateField(StrEnum):
     SUBMITTED_DATE = "SUBMITTED_DATE"
 
 
+def updated_within_range(duration: str, today: date | None = None) -> str:
+    """Build the last-update essie range for a relative duration.
+
+    Args:
+        duration: Relative duration (e.g., "90d")
+        today: Reference date, defaults to the current date
+
+    Returns:
+        ``AREA[LastUpdatePostDate]RANGE[cutoff,MAX]`` term
+    """
+    cutoff = (today or date.today()) - parse_duration(duration)
+    return f"AREA[LastUpdatePostDate]RANGE[{cutoff.isoformat()},MAX]"
+
+
+def results_within_range(duration: str, today: date | None = None) -> str:
+    """Build the results-first-posted essie range for a relative duration.
+
+    Args:
+        duration: Relative duration (e.g., "30d")
+        today: Reference date, defaults to the current date
+
+    Returns:
+        ``AREA[ResultsFirstPostDate]RANGE[cutoff,MAX]`` term
+    """
+    cutoff = (today or date.today()) - parse_duration(duration)
+    return f"AREA[ResultsFirstPostDate]RANGE[{cutoff.isoformat()},MAX]"
+
+
+# Partial NCT numbers: the NCT prefix is optional, followed by 1-8 digits
+NCT_PREFIX_PATTERN = re.compile(r"^(?:NCT)?(\d{1,8})$", re.IGNORECASE)
+
+NCT_DIGITS = 8
+
+
+def normalize_nct_prefix(value: str) -> str:
+    """Normalize a partial NCT number such as "nct0428" to "NCT0428"."""
+    match = NCT_PREFIX_PATTERN.match(value.strip())
+    if not match:
+        raise ValueError(
+            f"Invalid partial NCT number '{value}': use NCT followed by "
+            "up to 8 digits (e.g., NCT0428)"
+        )
+    return f"NCT{match.group(1)}"
+
+
+def nct_prefix_range(prefix: str) -> str:
+    """Build the essie range matching every NCT number with this prefix.
+
+    NCT numbers are NCT plus eight digits, so "NCT0428" covers
+    NCT04280000 through NCT04289999.
+    """
+    digits = normalize_nct_prefix(prefix).removeprefix("NCT")
+    low = digits.ljust(NCT_DIGITS, "0")
+    high = digits.ljust(NCT_DIGITS, "9")
+    return f"AREA[NCTId]RANGE[NCT{low},NCT{high}]"
+
+
+def eligible_age_range(
+    min_age: int | None = None, max_age: int | None = None
+) -> list[str]:
+    """Build essie ranges over a trial's own eligibility age bounds.
+
+    ``min_age`` keeps trials whose minimum eligible age is at least that
+    many years (e.g. 65 for geriatric trials); ``max_age`` keeps trials
+    whose maximum eligible age is at most that many years (e.g. 17 for
+    pediatric trials). Trials without a stated bound do not match.
+    """
+    terms = []
+    if min_age is not None:
+        terms.append(f"AREA[MinimumAge]RANGE[{min_age} years,MAX]")
+    if max_age is not None:
+        terms.append(f"AREA[MaximumAge]RANGE[MIN,{max_age} years]")
+    return terms
+
+
+def acronym_term(acronym: str) -> str:
+    """Build the essie term matching a study acronym as a phrase."""
+    phrase = acronym.replace('"', "").strip()
+    return f'AREA[Acronym]"{phrase}"'
+
+
+def collaborator_term(names: list[str]) -> str:
+    """Build the essie term matching any of the collaborators as a phrase.
+
+    ``query.lead`` only searches the lead sponsor, so organizations that
+    take part as collaborators are matched on ``CollaboratorName``.
+    """
+    phrases = (name.replace('"', "").strip() for name in names)
+    terms = [f'AREA[CollaboratorName]"{phrase}"' for phrase in phrases]
+    return terms[0] if len(terms) == 1 else f"({' OR '.join(terms)})"
+
+
+def funder_exclusion_term(funders: list[FunderType]) -> str:
+    """Build the essie term excluding trials led by the funder types.
+
+    The ``aggFilters=funderType:...`` facet can only select, so the
+    exclusion negates the ``LeadSponsorClass`` values behind each facet
+    bucket instead.
+    """
+    classes = [
+        sponsor_class
+        for funder in dict.fromkeys(funders)
+        for sponsor_class in CTGOV_FUNDER_CLASS_MAPPING[funder]
+    ]
+    if len(classes) == 1:
+        return f"NOT AREA[LeadSponsorClass]{classes[0]}"
+    return f"NOT AREA[LeadSponsorClass]({' OR '.join(classes)})"
+
+
 class PrimaryPurpose(StrEnum):
     TREATMENT = "TREATMENT"
     PREVENTION = "PREVENTION"
@@ -104,8 +261,12 @@ class LineOfTherapy(StrEnum):
     THIRD_LINE_PLUS = "3L+"
 
 
+# Sorts ClinicalTrials.gov applies server-side, so they hold across pages.
+# STATUS has no server-side equivalent: the page is fetched by relevance
+# and reordered by STATUS_SORT_PRIORITY, so it only orders within a page.
 CTGOV_SORT_MAPPING = {
     SortOrder.RELEVANCE: "@relevance",
+    SortOrder.STATUS: "@relevance",
     SortOrder.LAST_UPDATE: "LastUpdatePostDate:desc",
     SortOrder.ENROLLMENT: "EnrollmentCount:desc",
     SortOrder.START_DATE: "StudyStartDate:desc",
@@ -113,6 +274,40 @@ CTGOV_SORT_MAPPING = {
     SortOrder.SUBMITTED_DATE: "StudyFirstSubmitDate:desc",
 }
 
+# Study statuses in --sort status order, open to enrollment first. Shared
+# with the variant trial merge, which orders its union the same way.
+STATUS_SORT_PRIORITY = (
+    "RECRUITING",
+    "NOT_YET_RECRUITING",
+    "ENROLLING_BY_INVITATION",
+    "AVAILABLE",
+    "ACTIVE_NOT_RECRUITING",
+    "COMPLETED",
+    "SUSPENDED",
+    "TERMINATED",
+    "WITHDRAWN",
+)
+
+
+def trial_status_rank(trial: dict[str, Any]) -> int:
+    """Rank a trial by its study status in ``STATUS_SORT_PRIORITY``.
+
+    Statuses outside the table (e.g. unknown) rank last.
+    """
+    status = str(trial.get("Study Status") or "").strip().upper()
+    status = status.replace(" ", "_").replace(",", "")
+    if status in STATUS_SORT_PRIORITY:
+        return STATUS_SORT_PRIORITY.index(status)
+    return len(STATUS_SORT_PRIORITY)
+
+
+def sort_trials_by_status(
+    trials: list[dict[str, Any]],
+) -> list[dict[str, Any]]:
+    """Order search results by study status, keeping order within a status."""
+    return sorted(trials, key=trial_status_rank)
+
+
 CTGOV_PHASE_MAPPING = {
     TrialPhase.EARLY_PHASE1: ("EARLY_PHASE1",),
     TrialPhase.PHASE1: ("PHASE1",),
@@ -168,6 +363,21 @@ CTGOV_SPONSOR_TYPE_MAPPING = {
     SponsorType.OTHER: ("Other",),
 }
 
+# Lead sponsor classes in each bucket of the funderType facet
+CTGOV_FUNDER_CLASS_MAPPING = {
+    FunderType.NIH: ("NIH",),
+    FunderType.INDUSTRY: ("INDUSTRY",),
+    FunderType.FED: ("FED",),
+    FunderType.OTHER: (
+        "OTHER",
+        "OTHER_GOV",
+        "NETWORK",
+        "INDIV",
+        "AMBIG",
+        "UNKNOWN",
+    ),
+}
+
 CTGOV_STUDY_DESIGN_MAPPING = {
     StudyDesign.RANDOMIZED: ("Randomized",),
     StudyDesign.NON_RANDOMIZED: ("Non-Randomized",),
@@ -273,6 +483,11 @@ class TrialQuery(BaseModel):
         default=None,
         description="Lead sponsor organization names to filter by.",
     )
+    collaborators: list[str] | None = Field(
+        default=None,
+        description="Collaborator organization names to filter by "
+        "(matches trials where any of them is a collaborator).",
+    )
     recruiting_status: RecruitingStatus | None = Field(
         default=None,
         description="Study recruitment status. Use 'OPEN' for actively recruiting trials, 'CLOSED' for completed/terminated trials, or 'ANY' for all trials. Common aliases like 'recruiting', 'active', 'enrolling' map to 'OPEN'.",
@@ -285,6 +500,15 @@ class TrialQuery(BaseModel):
         default=None,
         description="Clinical trial NCT IDs",
     )
+    nct_prefix: str | None = Field(
+        default=None,
+        description="Partial NCT number; matches every trial whose NCT ID "
+        "starts with it (e.g., NCT0428)",
+    )
+    acronym: str | None = Field(
+        default=None,
+        description="Study acronym or nickname (e.g., KEYNOTE-006)",
+    )
     lat: float | None = Field(
         default=None,
         description="Latitude for location search. AI agents should geocode city/location names (e.g., 'Cleveland' → 41.4993, -81.6944) before using this parameter.",
@@ -297,6 +521,13 @@ class TrialQuery(BaseModel):
         default=None,
         description="Distance from lat/long in miles (default: 50 miles if lat/long provided but distance not specified)",
     )
+    location_status: LocationStatus | None = Field(
+        default=None,
+        description="Keep only trials with a site within the distance "
+        "of lat/long that has this recruitment status (fetches each "
+        "result's locations; markdown output notes how many trials were "
+        "dropped, JSON output stays a list of the kept trials)",
+    )
     min_date: str | None = Field(
         default=None,
         description="Minimum date for filtering",
@@ -309,6 +540,16 @@ class TrialQuery(BaseModel):
         default=None,
         description="Date field to filter on",
     )
+    updated_within: str | None = Field(
+        default=None,
+        description="Only trials updated within this duration "
+        "(e.g., 90d, 12w, 6mo, 1y)",
+    )
+    results_within: str | None = Field(
+        default=None,
+        description="Only trials whose results were first posted within "
+        "this duration (e.g., 30d, 12w, 6mo, 1y)",
+    )
     phase: TrialPhase | None = Field(
         default=None,
         description="Trial phase filter",
@@ -329,13 +570,33 @@ class TrialQuery(BaseModel):
         default=None,
         description="Type of sponsor",
     )
+    funder_exclude: list[FunderType] | None = Field(
+        default=None,
+        description="Funder types (nih, industry, fed, other) whose "
+        "trials are left out, by the lead sponsor's class.",
+    )
     study_design: StudyDesign | None = Field(
         default=None,
         description="Study design",
     )
+    allocation: DesignAllocation | None = Field(
+        default=None,
+        description="Arm allocation (randomized, non-randomized or NA)",
+    )
+    masking: DesignMasking | None = Field(
+        default=None,
+        description="Masking (blinding) level, e.g. DOUBLE for double-blind",
+    )
+    intervention_model: InterventionModel | None = Field(
+        default=None,
+        description="Intervention model (single group, parallel, "
+        "crossover, factorial or sequential)",
+    )
     sort: SortOrder | None = Field(
         default=None,
-        description="Sort order for results",
+        description="Sort order for results; STATUS reorders each page "
+        "by study status (recruiting first), the others are applied by "
+        "ClinicalTrials.gov across pages",
     )
     next_page_hash: str | None = Field(
         default=None,
@@ -380,6 +641,28 @@ class TrialQuery(BaseModel):
         ge=1,
         le=1000,
     )
+    min_enrollment: int | None = Field(
+        default=None,
+        description="Minimum (target or actual) enrollment",
+        ge=0,
+    )
+    max_enrollment: int | None = Field(
+        default=None,
+        description="Maximum (target or actual) enrollment",
+        ge=0,
+    )
+    min_age: int | None = Field(
+        default=None,
+        description="Only trials whose minimum eligible age is at least "
+        "this many years",
+        ge=0,
+    )
+    max_age: int | None = Field(
+        default=None,
+        description="Only trials whose maximum eligible age is at most "
+        "this many years",
+        ge=0,
+    )
     expand_synonyms: bool = Field(
         default=True,
         description="Expand condition searches with disease synonyms from MyDisease.info",
@@ -403,6 +686,12 @@ class TrialQuery(BaseModel):
             return alias_map.get(v_lower, v)
         return v
 
+    @field_validator("nct_prefix")
+    @classmethod
+    def check_nct_prefix(cls, v):
+        """Validate and normalize a partial NCT number."""
+        return normalize_nct_prefix(v) if v is not None else v
+
     # Field validators for list fields
     @model_validator(mode="before")
     def convert_list_fields(cls, data):
@@ -413,6 +702,8 @@ class TrialQuery(BaseModel):
                 "terms",
                 "interventions",
                 "lead_sponsor",
+                "collaborators",
+                "funder_exclude",
                 "nct_ids",
                 "prior_therapies",
                 "progression_on",
@@ -426,6 +717,56 @@ class TrialQuery(BaseModel):
                     )
         return data
 
+    @model_validator(mode="after")
+    def check_enrollment_range(self):
+        """Reject an enrollment range whose minimum exceeds its maximum."""
+        if (
+            self.min_enrollment is not None
+            and self.max_enrollment is not None
+            and self.min_enrollment > self.max_enrollment
+        ):
+            raise ValueError(
+                "min_enrollment cannot be greater than max_enrollment"
+            )
+        return self
+
+    @model_validator(mode="after")
+    def check_age_range(self):
+        """Reject an eligible-age range whose minimum exceeds its maximum."""
+        if (
+            self.min_age is not None
+            and self.max_age is not None
+            and self.min_age > self.max_age
+        ):
+            raise ValueError("min_age cannot be greater than max_age")
+        return self
+
+    @model_validator(mode="after")
+    def check_updated_within(self):
+        """Validate the duration and reject an explicit minimum date."""
+        if self.updated_within is None:
+            return self
+        parse_duration(self.updated_within)
+        if self.min_date:
+            raise ValueError(
+                "updated_within cannot be combined with min_date"
+            )
+        return self
+
+    @model_validator(mode="after")
+    def check_location_status(self):
+        """A site status is checked against the sites near lat/long."""
+        if self.location_status and (self.lat is None or self.long is None):
+            raise ValueError("location_status requires lat and long")
+        return self
+
+    @model_validator(mode="after")
+    def check_results_within(self):
+        """Validate the results-posting duration."""
+        if self.results_within is not None:
+            parse_duration(self.results_within)
+        return self
+
 
 def _inject_ids(
     params: dict[str, list[str]], ids: list[str], has_other_filters: bool
@@ -657,6 +998,55 @@ async def convert_query(query: TrialQuery) -> dict[str, list[str]]:  # noqa: C90
             f"AREA[{date_field}]RANGE[{min_val},{max_val}]",
         )
 
+    # Collaborating organizations
+    if query.collaborators:
+        has_other_filters = True
+        advanced_filters.append(collaborator_term(query.collaborators))
+
+    # Excluded funder types
+    if query.funder_exclude:
+        has_other_filters = True
+        advanced_filters.append(funder_exclusion_term(query.funder_exclude))
+
+    # Study acronym and partial NCT number
+    if query.acronym:
+        has_other_filters = True
+        advanced_filters.append(acronym_term(query.acronym))
+    if query.nct_prefix:
+        has_other_filters = True
+        advanced_filters.append(nct_prefix_range(query.nct_prefix))
+
+    # Relative last-update filter
+    if query.updated_within:
+        has_other_filters = True
+        advanced_filters.append(updated_within_range(query.updated_within))
+
+    # Relative results-posting filter
+    if query.results_within:
+        has_other_filters = True
+        advanced_filters.append(results_within_range(query.results_within))
+
+    # Enrollment filter (ClinicalTrials.gov filters on the enrollment count
+    # server-side, so no per-trial detail fetch is needed)
+    if query.min_enrollment is not None or query.max_enrollment is not None:
+        has_other_filters = True
+        min_val = (
+            query.min_enrollment if query.min_enrollment is not None else "MIN"
+        )
+        max_val = (
+            query.max_enrollment if query.max_enrollment is not None else "MAX"
+        )
+        advanced_filters.append(
+            f"AREA[EnrollmentCount]RANGE[{min_val},{max_val}]",
+        )
+
+    # Trial eligibility age bounds
+    if query.min_age is not None or query.max_age is not None:
+        has_other_filters = True
+        advanced_filters.extend(
+            eligible_age_range(query.min_age, query.max_age)
+        )
+
     # Prepare a map of "AREA[...] -> (query_value, mapping_dict)"
     advanced_map = {
         "DesignPrimaryPurpose": (
@@ -670,6 +1060,10 @@ async def convert_query(query: TrialQuery) -> dict[str, list[str]]:  # noqa: C90
         ),
         "SponsorType": (query.sponsor_type, CTGOV_SPONSOR_TYPE_MAPPING),
         "StudyDesign": (query.study_design, CTGOV_STUDY_DESIGN_MAPPING),
+        # Design enums are indexed under their API values
+        "DesignAllocation": (query.allocation, None),
+        "DesignMasking": (query.masking, None),
+        "DesignInterventionModel": (query.intervention_model, None),
         "Phase": (query.phase, CTGOV_PHASE_MAPPING),
     }
 
@@ -748,11 +1142,78 @@ async def convert_query(query: TrialQuery) -> dict[str, list[str]]:  # noqa: C90
     return params
 
 
+def trial_search_query_summary(query: TrialQuery) -> str:
+    """Render a one-line summary of the search terms and active filters."""
+    parts: list[str] = []
+    list_fields = [
+        ("Conditions", query.conditions),
+        ("Terms", query.terms),
+        ("Interventions", query.interventions),
+        ("Collaborators", query.collaborators),
+    ]
+    for label, values in list_fields:
+        if values:
+            parts.append(f"{label}: {', '.join(values)}")
+    if query.acronym:
+        parts.append(f"Acronym: {query.acronym}")
+    if query.nct_prefix:
+        parts.append(f"NCT prefix: {query.nct_prefix}")
+    if query.intervention_type:
+        mapped = CTGOV_INTERVENTION_TYPE_MAPPING[query.intervention_type]
+        parts.append(f"Intervention type: {mapped[0]}")
+    if query.funder_exclude:
+        funders = ", ".join(f.value for f in query.funder_exclude)
+        parts.append(f"Excluding funders: {funders}")
+    for label, design in [
+        ("Allocation", query.allocation),
+        ("Masking", query.masking),
+        ("Model", query.intervention_model),
+    ]:
+        if design:
+            parts.append(f"{label}: {design.value.lower().replace('_', ' ')}")
+    if query.phase:
+        parts.append(f"Phase: {query.phase.value}")
+    if query.recruiting_status:
+        parts.append(f"Status: {query.recruiting_status.value}")
+    if query.location_status:
+        status = query.location_status.value.lower().replace("_", " ")
+        parts.append(f"Nearby site status: {status}")
+    if query.min_enrollment is not None and query.max_enrollment is not None:
+        parts.append(
+            f"Enrollment: {query.min_enrollment}-{query.max_enrollment}"
+        )
+    elif query.min_enrollment is not None:
+        parts.append(f"Enrollment: >= {query.min_enrollment}")
+    elif query.max_enrollment is not None:
+        parts.append(f"Enrollment: <= {query.max_enrollment}")
+    if query.min_age is not None:
+        parts.append(f"Min eligible age: >= {query.min_age} years")
+    if query.max_age is not None:
+        parts.append(f"Max eligible age: <= {query.max_age} years")
+    if query.updated_within:
+        parts.append(f"Updated within: {query.updated_within}")
+    if query.results_within:
+        parts.append(f"Results posted within: {query.results_within}")
+    summary = " | ".join(parts) if parts else "All trials"
+    if query.sort and query.sort != SortOrder.RELEVANCE:
+        order = query.sort.value.lower().replace("_", " ")
+        if query.sort == SortOrder.STATUS:
+            order += " (within page)"
+        summary += f" | Sort: {order}"
+    return summary
+
+
 async def search_trials(
     query: TrialQuery,
     output_json: bool = False,
+    explain: bool = False,
 ) -> str:
-    """Search ClinicalTrials.gov for clinical trials."""
+    """Search ClinicalTrials.gov for clinical trials.
+
+    With ``explain``, each result is annotated with the eligibility
+    criteria that mention the query's mutation, therapy and biomarker
+    keywords (one extra request per result).
+    """
     params = await convert_query(query)
 
     # Log filter mode if NCT IDs are present
@@ -764,13 +1225,24 @@ async def search_trials(
             query.terms,
             query.interventions,
             query.lead_sponsor,
+            query.collaborators,
+            query.funder_exclude,
             query.lat is not None and query.long is not None,
             query.date_field and (query.min_date or query.max_date),
+            query.updated_within,
+            query.results_within,
+            query.min_age is not None,
+            query.max_age is not None,
+            query.acronym,
+            query.nct_prefix,
             query.primary_purpose,
             query.study_type,
             query.intervention_type,
             query.sponsor_type,
             query.study_design,
+            query.allocation,
+            query.masking,
+            query.intervention_model,
             query.phase,
             query.age_group and query.age_group != AgeGroup.ALL,
             query.recruiting_status not in (None, RecruitingStatus.OPEN),
@@ -799,8 +1271,29 @@ async def search_trials(
     )
 
     data = response
+    site_counts: dict[str, int] = {}
     if error:
         data = {"error": f"Error {error.code}: {error.message}"}
+    elif isinstance(data, list):
+        # Import here to avoid circular imports
+        from .explain import explain_keywords, explain_trial_matches
+        from .site_status import filter_trials_by_site_status
+
+        if query.location_status:
+            data, site_counts = await filter_trials_by_site_status(
+                data, query
+            )
+        if query.sort == SortOrder.STATUS:
+            data = sort_trials_by_status(data)
+        if explain:
+            await explain_trial_matches(data, explain_keywords(query))
+
+    if site_counts and not output_json:
+        # JSON stays a list of trials; only markdown carries the counts
+        from .site_status import site_status_note
+
+        note = site_status_note(site_counts)
+        return f"{note}\n\n{render.to_markdown(data)}" if data else note
 
     if data and not output_json:
         return render.to_markdown(data)
@@ -850,6 +1343,12 @@ async def _trial_searcher(
         float | None,
         "Distance from lat/long in miles (default: 50 miles if lat/long provided but distance not specified)",
     ] = None,
+    location_status: Annotated[
+        LocationStatus | str | None,
+        "Keep only trials with a site within the distance of lat/long "
+        "that has this status (e.g., RECRUITING); fetches each result's "
+        "locations",
+    ] = None,
     min_date: Annotated[
         str | None, "Minimum date for filtering (YYYY-MM-DD)"
     ] = None,
@@ -871,7 +1370,23 @@ async def _trial_searcher(
         SponsorType | str | None, "Type of sponsor"
     ] = None,
     study_design: Annotated[StudyDesign | str | None, "Study design"] = None,
-    sort: Annotated[SortOrder | str | None, "Sort order for results"] = None,
+    allocation: Annotated[
+        DesignAllocation | str | None,
+        "Arm allocation (RANDOMIZED, NON_RANDOMIZED, NA)",
+    ] = None,
+    masking: Annotated[
+        DesignMasking | str | None,
+        "Masking level (NONE, SINGLE, DOUBLE, TRIPLE, QUADRUPLE)",
+    ] = None,
+    intervention_model: Annotated[
+        InterventionModel | str | None,
+        "Intervention model (SINGLE_GROUP, PARALLEL, CROSSOVER, FACTORIAL, "
+        "SEQUENTIAL)",
+    ] = None,
+    sort: Annotated[
+        SortOrder | str | None,
+        "Sort order for results (STATUS: recruiting first, within a page)",
+    ] = None,
     next_page_hash: Annotated[
         str | None, "Token to retrieve the next page of results"
     ] = None,
@@ -931,6 +1446,7 @@ async def _trial_searcher(
     - lat: Latitude for location search
     - long: Longitude for location search
     - distance: Distance from lat/long in miles
+    - location_status: Required status of a site near lat/long (e.g., RECRUITING)
     - min_date: Minimum date for filtering (YYYY-MM-DD)
     - max_date: Maximum date for filtering (YYYY-MM-DD)
     - date_field: Date field to filter on
@@ -940,7 +1456,10 @@ async def _trial_searcher(
     - intervention_type: Type of intervention
     - sponsor_type: Type of sponsor
     - study_design: Study design
-    - sort: Sort order for results
+    - allocation: Arm allocation (RANDOMIZED, NON_RANDOMIZED, NA)
+    - masking: Masking level (NONE, SINGLE, DOUBLE, TRIPLE, QUADRUPLE)
+    - intervention_model: Intervention model (e.g., PARALLEL, CROSSOVER)
+    - sort: Sort order for results; STATUS orders each page recruiting first
     - next_page_hash: Token to retrieve the next page of results
     - prior_therapies: Prior therapies to search for in eligibility criteria - list or comma-separated string
     - progression_on: Therapies the patient has progressed on - list or comma-separated string
@@ -968,6 +1487,7 @@ async def _trial_searcher(
         lat=lat,
         long=long,
         distance=distance,
+        location_status=location_status,
         min_date=min_date,
         max_date=max_date,
         date_field=date_field,
@@ -977,6 +1497,9 @@ async def _trial_searcher(
         intervention_type=intervention_type,
         sponsor_type=sponsor_type,
         study_design=study_design,
+        allocation=allocation,
+        masking=masking,
+        intervention_model=intervention_model,
         sort=sort,
         next_page_hash=next_page_hash,
         prior_therapies=ensure_list(prior_therapies, split_strings=True),
@@ -0,0 +1,137 @@
+"""Filter trials by the recruitment status of their nearby sites.
+
+ClinicalTrials.gov filters a trial's overall status and, separately,
+whether any of its sites lies within ``distance`` of a point, but not
+whether a nearby site is itself recruiting: a trial that recruits in
+Boston still matches a search around Denver where its only site has
+completed. ``--location-status`` fetches the locations of each returned
+trial, one detail request per trial, and keeps a trial only when a site
+within the distance has the requested status. Trials whose locations
+cannot be fetched are dropped rather than guessed at, and the counts of
+both kinds of dropped trial are returned so markdown output can say how
+many of the page's trials were filtered out and why (JSON output stays a
+plain list of the kept trials).
+"""
+
+import json
+from typing import Any
+
+from ..concurrency import gather_limited, resolve_concurrency
+from .getter import Module, get_trial
+from .locations import location_distance
+from .search import LocationStatus, TrialQuery
+
+# Parallel location fetches for the returned trials
+SITE_STATUS_CONCURRENCY = 5
+
+# Search radius in miles when the query leaves distance unset
+DEFAULT_SITE_DISTANCE = 50
+
+MATCH_FIELD = "Matching Sites"
+
+
+def matching_sites(
+    locations: list[dict[str, Any]],
+    status: LocationStatus,
+    lat: float,
+    lon: float,
+    distance: float,
+) -> list[str]:
+    """Describe the sites within ``distance`` miles that have ``status``.
+
+    Sites without coordinates cannot be placed and never match.
+    """
+    sites: list[str] = []
+    for location in locations:
+        if not isinstance(location, dict):
+            continue
+        if str(location.get("status", "")).upper() != status.value:
+            continue
+        miles = location_distance(location, lat, lon)
+        if miles is None or miles > distance:
+            continue
+        place = ", ".join(
+            str(location[key])
+            for key in ("facility", "city", "state", "country")
+            if location.get(key)
+        )
+        sites.append(f"{place or 'Unnamed site'} ({miles} mi)")
+    return sites
+
+
+async def _trial_locations(nct_id: str) -> list[dict[str, Any]] | None:
+    data = json.loads(await get_trial(nct_id, Module.LOCATIONS, True))
+    if "error" in data:
+        return None
+    module = data.get("protocolSection", {}).get(
+        "contactsLocationsModule", {}
+    )
+    return module.get("locations") or []
+
+
+async def filter_trials_by_site_status(
+    trials: list[dict[str, Any]],
+    query: TrialQuery,
+    concurrency: int | None = None,
+) -> tuple[list[dict[str, Any]], dict[str, int]]:
+    """Keep the trials with a nearby site in ``query.location_status``.
+
+    The matching sites are stored under ``MATCH_FIELD`` on each kept
+    trial. Without a location status or reference point the trials are
+    returned unchanged with empty counts.
+
+    Returns:
+        The kept trials and the counts of the page's trials that were
+        ``checked``, ``kept``, dropped as ``unmatched`` (no nearby site
+        with the status) or dropped as ``fetch_errors`` (locations could
+        not be fetched).
+    """
+    status = query.location_status
+    if status is None or query.lat is None or query.long is None:
+        return trials, {}
+
+    distance = query.distance or DEFAULT_SITE_DISTANCE
+    nct_ids = [trial.get("NCT Number") for trial in trials]
+    fetched = await gather_limited(
+        (_trial_locations(nct_id) for nct_id in nct_ids if nct_id),
+        resolve_concurrency(concurrency, SITE_STATUS_CONCURRENCY),
+    )
+    by_id = dict(zip([n for n in nct_ids if n], fetched, strict=True))
+
+    kept: list[dict[str, Any]] = []
+    unmatched = fetch_errors = 0
+    for trial, nct_id in zip(trials, nct_ids, strict=True):
+        locations = by_id.get(nct_id) if nct_id else None
+        if locations is None:
+            fetch_errors += 1
+            continue
+        sites = matching_sites(
+            locations, status, query.lat, query.long, distance
+        )
+        if not sites:
+            unmatched += 1
+            continue
+        trial[MATCH_FIELD] = sites
+        kept.append(trial)
+    counts = {
+        "checked": len(trials),
+        "kept": len(kept),
+        "unmatched": unmatched,
+        "fetch_errors": fetch_errors,
+    }
+    return kept, counts
+
+
+def site_status_note(counts: dict[str, int]) -> str:
+    """Summarize how many of the page's trials the filter dropped."""
+    note = (
+        f"Nearby site status filter kept {counts['kept']} of "
+        f"{counts['checked']} trials on this page: "
+        f"{counts['unmatched']} had no matching site nearby"
+    )
+    if counts["fetch_errors"]:
+        note += (
+            f", {counts['fetch_errors']} could not be checked because "
+            "their locations failed to load"
+        )
+    return note + "."
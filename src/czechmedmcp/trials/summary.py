@@ -0,0 +1,95 @@
+"""Minimal status card for a single trial.
+
+``trial get --summary-only`` asks ClinicalTrials.gov for just the fields
+needed for a status check (title, overall status, phases and conditions)
+instead of whole protocol modules, keeping the payload small for
+dashboards that poll many trials.
+"""
+
+import json
+from ssl import TLSVersion
+from typing import Any
+
+from pydantic import BaseModel, Field
+
+from .. import http_client
+from ..constants import CLINICAL_TRIALS_BASE_URL
+
+SUMMARY_FIELDS = "NCTId,BriefTitle,OverallStatus,Phase,Condition"
+
+
+class TrialSummary(BaseModel):
+    """Title, status, phases and conditions of a trial."""
+
+    nct_id: str
+    title: str | None = None
+    status: str | None = None
+    phases: list[str] = Field(default_factory=list)
+    conditions: list[str] = Field(default_factory=list)
+    url: str
+
+
+def parse_trial_summary(study: dict[str, Any], nct_id: str) -> TrialSummary:
+    """Build a trial summary from a ClinicalTrials.gov study record."""
+    protocol = study.get("protocolSection", {})
+    identification = protocol.get("identificationModule", {})
+    nct_id = identification.get("nctId") or nct_id
+    return TrialSummary(
+        nct_id=nct_id,
+        title=identification.get("briefTitle"),
+        status=protocol.get("statusModule", {}).get("overallStatus"),
+        phases=protocol.get("designModule", {}).get("phases") or [],
+        conditions=protocol.get("conditionsModule", {}).get("conditions")
+        or [],
+        url=f"https://clinicaltrials.gov/study/{nct_id}",
+    )
+
+
+def format_trial_summary(summary: TrialSummary) -> str:
+    """Format a trial summary as a compact markdown block."""
+    lines = [
+        f"# {summary.nct_id}: {summary.title or 'Untitled'}",
+        "",
+        f"- **Status**: {summary.status or 'Unknown'}",
+        f"- **Phases**: {', '.join(summary.phases) or 'N/A'}",
+        f"- **Conditions**: {', '.join(summary.conditions) or 'N/A'}",
+        f"- **URL**: {summary.url}",
+    ]
+    return "\n".join(lines) + "\n"
+
+
+async def fetch_trial_summary(
+    nct_id: str,
+) -> tuple[TrialSummary | None, str | None]:
+    """Fetch the minimal status fields of a trial.
+
+    Returns:
+        Tuple of (summary or None, error message or None)
+    """
+    response, error = await http_client.request_api(
+        url=f"{CLINICAL_TRIALS_BASE_URL}/{nct_id}",
+        request={"fields": SUMMARY_FIELDS},
+        method="GET",
+        tls_version=TLSVersion.TLSv1_2,
+        response_model_type=None,
+        domain="clinicaltrials",
+    )
+    if error:
+        return None, f"API Error {error.code}: {error.message}"
+    if isinstance(response, dict) and "studies" in response:
+        response = (response.get("studies") or [None])[0]
+    if not isinstance(response, dict):
+        return None, f"No data found for {nct_id}"
+    return parse_trial_summary(response, nct_id), None
+
+
+async def get_trial_summary(nct_id: str, output_json: bool = False) -> str:
+    """Get a trial's title, status, phases and conditions."""
+    summary, error = await fetch_trial_summary(nct_id)
+    if error or summary is None:
+        if output_json:
+            return json.dumps({"error": error}, indent=2)
+        return f"Error: {error}"
+    if output_json:
+        return json.dumps(summary.model_dump(), indent=2)
+    return format_trial_summary(summary)
@@ -0,0 +1,37 @@
+"""Parsing of short human-readable durations such as ``30d`` or ``12h``."""
+
+import re
+from datetime import timedelta
+
+_DURATION_PATTERN = re.compile(
+    r"^\s*(\d+)\s*(mo|[smhdwy])\s*$", re.IGNORECASE
+)
+
+_UNIT_SECONDS = {
+    "s": 1,
+    "m": 60,
+    "h": 3600,
+    "d": 86400,
+    "w": 7 * 86400,
+    "mo": 30 * 86400,
+    "y": 365 * 86400,
+}
+
+
+def parse_duration(value: str) -> timedelta:
+    """Parse a duration such as ``45m``, ``12h``, ``30d``, ``6mo`` or ``1y``.
+
+    ``m`` is minutes and ``mo`` months, which count as 30 days; years
+    count as 365 days.
+
+    Raises:
+        ValueError: If the value is not a positive integer followed by a unit
+    """
+    match = _DURATION_PATTERN.match(value or "")
+    if not match or int(match.group(1)) == 0:
+        raise ValueError(
+            f"Invalid duration '{value}'. Use a positive number followed "
+            "by s, m (minutes), h, d, w, mo (months) or y (e.g., 30d)."
+        )
+    amount, unit = int(match.group(1)), match.group(2).lower()
+    return timedelta(seconds=amount * _UNIT_SECONDS[unit])
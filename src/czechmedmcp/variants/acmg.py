@@ -0,0 +1,220 @@
+"""ACMG/AMP criterion hints computed from a variant's annotations.
+
+A handful of the ACMG/AMP 2015 criteria can be read straight off the
+annotations MyVariant.info already returns: population frequency from
+gnomAD (BA1, BS1, PM2), in-silico predictors (PP3, BP4, using the ClinGen
+SVI calibrated REVEL thresholds with CADD as a fallback), ClinVar's
+aggregate significance (PP5, BP6) and whether the residue is a recurrent
+cBioPortal hotspot (PM1). Each criterion is reported with the evidence
+it was judged on. These are hints for a curator, not a classification:
+the frequency cut-offs are generic rather than gene-specific, and
+segregation, functional and phenotype evidence is never considered.
+"""
+
+from typing import Any
+
+from pydantic import BaseModel
+
+from .. import ensure_list
+from .clinvar import extract_clinvar_submissions
+from .search import variant_protein_positions
+
+ACMG_ADVISORY = (
+    "Advisory only: automated hints for individual ACMG/AMP criteria, "
+    "not a variant classification. Review the evidence before use."
+)
+
+# gnomAD allele frequency cut-offs (generic, not disease-specific)
+BA1_FREQUENCY = 0.05
+BS1_FREQUENCY = 0.01
+PM2_FREQUENCY = 0.0001
+
+# ClinGen SVI calibrated REVEL thresholds (supporting strength)
+REVEL_PATHOGENIC = 0.644
+REVEL_BENIGN = 0.290
+
+# ClinGen SVI calibrated CADD phred thresholds (supporting strength)
+CADD_PATHOGENIC = 25.3
+CADD_BENIGN = 22.7
+
+
+class AcmgCriterion(BaseModel):
+    """One ACMG/AMP criterion and whether the annotations suggest it."""
+
+    code: str
+    met: bool | None = None
+    evidence: str
+
+
+def _numbers(value: Any) -> list[float]:
+    numbers = []
+    for item in ensure_list(value):
+        try:
+            numbers.append(float(item))
+        except (TypeError, ValueError):
+            continue
+    return numbers
+
+
+def population_frequency(variant: dict[str, Any]) -> float | None:
+    """Highest gnomAD exome/genome allele frequency, None when absent."""
+    frequencies = []
+    for source in ("gnomad_exome", "gnomad_genome"):
+        af = ((variant.get(source) or {}).get("af") or {}).get("af")
+        frequencies.extend(_numbers(af))
+    return max(frequencies) if frequencies else None
+
+
+def _revel(variant: dict[str, Any]) -> float | None:
+    revel = (variant.get("dbnsfp") or {}).get("revel") or {}
+    scores = _numbers(revel.get("score") if isinstance(revel, dict) else None)
+    return max(scores) if scores else None
+
+
+def _cadd(variant: dict[str, Any]) -> float | None:
+    scores = _numbers((variant.get("cadd") or {}).get("phred"))
+    return max(scores) if scores else None
+
+
+def _frequency_criteria(af: float | None) -> list[AcmgCriterion]:
+    if af is None:
+        observed = "absent from gnomAD"
+    else:
+        observed = f"gnomAD AF {af:.6g}"
+    return [
+        AcmgCriterion(
+            code="BA1",
+            met=af is not None and af > BA1_FREQUENCY,
+            evidence=f"{observed} (stand-alone benign > {BA1_FREQUENCY})",
+        ),
+        AcmgCriterion(
+            code="BS1",
+            met=af is not None and BS1_FREQUENCY < af <= BA1_FREQUENCY,
+            evidence=f"{observed} (strong benign > {BS1_FREQUENCY})",
+        ),
+        AcmgCriterion(
+            code="PM2",
+            met=af is None or af < PM2_FREQUENCY,
+            evidence=f"{observed} (rare < {PM2_FREQUENCY})",
+        ),
+    ]
+
+
+def _predictor_criteria(variant: dict[str, Any]) -> list[AcmgCriterion]:
+    revel, cadd = _revel(variant), _cadd(variant)
+    if revel is not None:
+        observed = f"REVEL {revel:.3f}"
+        pathogenic = revel >= REVEL_PATHOGENIC
+        benign = revel <= REVEL_BENIGN
+        cut_offs = (f">= {REVEL_PATHOGENIC}", f"<= {REVEL_BENIGN}")
+    elif cadd is not None:
+        observed = f"CADD {cadd:.1f}"
+        pathogenic = cadd >= CADD_PATHOGENIC
+        benign = cadd <= CADD_BENIGN
+        cut_offs = (f">= {CADD_PATHOGENIC}", f"<= {CADD_BENIGN}")
+    else:
+        missing = "no REVEL or CADD score"
+        return [
+            AcmgCriterion(code="PP3", evidence=missing),
+            AcmgCriterion(code="BP4", evidence=missing),
+        ]
+    return [
+        AcmgCriterion(
+            code="PP3", met=pathogenic, evidence=f"{observed} ({cut_offs[0]})"
+        ),
+        AcmgCriterion(
+            code="BP4", met=benign, evidence=f"{observed} ({cut_offs[1]})"
+        ),
+    ]
+
+
+def _hotspot_criterion(
+    variant: dict[str, Any], hotspot_positions: set[int] | None
+) -> AcmgCriterion:
+    if hotspot_positions is None:
+        return AcmgCriterion(
+            code="PM1", evidence="cBioPortal hotspot data unavailable"
+        )
+    residues = sorted(variant_protein_positions(variant) & hotspot_positions)
+    if residues:
+        joined = ", ".join(str(r) for r in residues)
+        evidence = f"residue {joined} is a recurrent cBioPortal hotspot"
+    else:
+        evidence = "not at a recurrent cBioPortal hotspot residue"
+    return AcmgCriterion(code="PM1", met=bool(residues), evidence=evidence)
+
+
+def _clinvar_criteria(variant: dict[str, Any]) -> list[AcmgCriterion]:
+    significances = sorted({
+        str(record["clinical_significance"]).lower()
+        for record in extract_clinvar_submissions(variant)
+        if record.get("clinical_significance")
+    })
+    if not significances:
+        missing = "no ClinVar interpretation"
+        return [
+            AcmgCriterion(code="PP5", evidence=missing),
+            AcmgCriterion(code="BP6", evidence=missing),
+        ]
+    # "Conflicting interpretations of pathogenicity", or records that
+    # disagree with each other, support neither direction
+    pathogenic = any(s.endswith("pathogenic") for s in significances)
+    benign = any(s.endswith("benign") for s in significances)
+    observed = "ClinVar: " + "; ".join(significances)
+    note = " (criterion retired by ClinGen SVI)"
+    return [
+        AcmgCriterion(
+            code="PP5",
+            met=pathogenic and not benign,
+            evidence=observed + note,
+        ),
+        AcmgCriterion(
+            code="BP6",
+            met=benign and not pathogenic,
+            evidence=observed + note,
+        ),
+    ]
+
+
+def assess_acmg(
+    variant: dict[str, Any], hotspot_positions: set[int] | None = None
+) -> list[AcmgCriterion]:
+    """Judge the annotation-derived ACMG/AMP criteria for a variant.
+
+    ``met`` is None when the annotations needed for a criterion are missing.
+    """
+    return [
+        *_frequency_criteria(population_frequency(variant)),
+        _hotspot_criterion(variant, hotspot_positions),
+        *_predictor_criteria(variant),
+        *_clinvar_criteria(variant),
+    ]
+
+
+def acmg_record(criteria: list[AcmgCriterion]) -> dict[str, Any]:
+    """JSON form of the criterion hints, with the advisory notice."""
+    return {
+        "advisory": ACMG_ADVISORY,
+        "criteria": {c.code: c.model_dump(exclude={"code"}) for c in criteria},
+    }
+
+
+def format_acmg(variant_id: str, criteria: list[AcmgCriterion]) -> str:
+    """Format the criterion hints as a markdown section."""
+    lines = [
+        f"## ACMG Criteria Hints: {variant_id}",
+        "",
+        f"_{ACMG_ADVISORY}_",
+        "",
+        "| Criterion | Suggested | Evidence |",
+        "|-----------|-----------|----------|",
+    ]
+    for criterion in criteria:
+        if criterion.met is None:
+            suggested = "Not assessed"
+        else:
+            suggested = "Yes" if criterion.met else "No"
+        lines.append(
+            f"| {criterion.code} | {suggested} | {criterion.evidence} |"
+        )
+    return "\n".join(lines)
@@ -0,0 +1,90 @@
+"""Per-record ClinVar interpretations for a variant.
+
+MyVariant.info embeds ClinVar at the RCV level: one record per
+(variant, condition) pair, carrying that record's aggregate significance,
+review status, last evaluation date and submitter count. Listing the
+records side by side exposes conflicting interpretations that the
+variant-level significance summary hides.
+"""
+
+from typing import Any
+
+from .. import ensure_list
+
+
+def _condition_name(conditions: Any) -> str | None:
+    """Join the condition names of an RCV record."""
+    names = [
+        c["name"]
+        for c in ensure_list(conditions)
+        if isinstance(c, dict) and c.get("name")
+    ]
+    return "; ".join(names) or None
+
+
+def extract_clinvar_submissions(
+    variant: dict[str, Any],
+) -> list[dict[str, Any]]:
+    """Return the ClinVar RCV records of a variant, newest evaluation first."""
+    clinvar = variant.get("clinvar")
+    if not isinstance(clinvar, dict):
+        return []
+
+    submissions = []
+    for rcv in ensure_list(clinvar.get("rcv")):
+        if not isinstance(rcv, dict):
+            continue
+        record = {
+            "accession": rcv.get("accession"),
+            "clinical_significance": rcv.get("clinical_significance"),
+            "review_status": rcv.get("review_status"),
+            "condition": _condition_name(rcv.get("conditions")),
+            "last_evaluated": rcv.get("last_evaluated"),
+            "origin": rcv.get("origin"),
+            "number_submitters": rcv.get("number_submitters"),
+        }
+        submissions.append({k: v for k, v in record.items() if v is not None})
+
+    submissions.sort(key=lambda s: s.get("last_evaluated") or "", reverse=True)
+    return submissions
+
+
+def conflicting_significances(submissions: list[dict[str, Any]]) -> list[str]:
+    """Return the distinct significances when records disagree, else []."""
+    significances = sorted({
+        str(s["clinical_significance"]).lower()
+        for s in submissions
+        if s.get("clinical_significance")
+    })
+    return significances if len(significances) > 1 else []
+
+
+def format_clinvar_submissions(
+    variant_id: str, submissions: list[dict[str, Any]]
+) -> str:
+    """Format ClinVar records as a markdown section."""
+    lines = [f"## ClinVar Submissions: {variant_id}", ""]
+    if not submissions:
+        lines.append("No ClinVar records for this variant.")
+        return "\n".join(lines)
+
+    conflicts = conflicting_significances(submissions)
+    if conflicts:
+        lines.append(f"**Conflicting interpretations**: {', '.join(conflicts)}")
+        lines.append("")
+
+    lines.append(
+        "| Accession | Significance | Review status | Condition "
+        "| Last evaluated | Submitters |"
+    )
+    lines.append("|---|---|---|---|---|---|")
+    for s in submissions:
+        lines.append(
+            f"| {s.get('accession', '-')} "
+            f"| {s.get('clinical_significance', '-')} "
+            f"| {s.get('review_status', '-')} "
+            f"| {s.get('condition', '-')} "
+            f"| {s.get('last_evaluated', '-')} "
+            f"| {s.get('number_submitters', '-')} |"
+        )
+    return "\n".join(lines)
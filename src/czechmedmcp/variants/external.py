@@ -1,6 +1,5 @@
 """External data sources for enhanced variant annotations."""
 
-import asyncio
 import json
 import logging
 import re
@@ -10,6 +9,7 @@ from urllib.parse import quote
 from pydantic import BaseModel, Field
 
 from .. import http_client
+from ..concurrency import gather_limited, resolve_concurrency
 
 # Import CBioPortalVariantData from the new module
 from .cbio_external_client import CBioPortalVariantData
@@ -489,6 +489,7 @@ class ExternalVariantAggregator:
         include_cbioportal: bool = True,
         include_oncokb: bool = True,
         variant_data: dict[str, Any] | None = None,
+        concurrency: int | None = None,
     ) -> EnhancedVariantAnnotation:
         """Fetch and aggregate variant annotations from external sources.
 
@@ -499,6 +500,7 @@ class ExternalVariantAggregator:
             include_cbioportal: Whether to include cBioPortal data
             include_oncokb: Whether to include OncoKB data
             variant_data: Optional variant data from MyVariant.info to extract gene/protein info
+            concurrency: Max sources queried at once (default: all)
         """
         logger.info(
             f"get_enhanced_annotations called for {variant_id}, include_cbioportal={include_cbioportal}"
@@ -561,7 +563,11 @@ class ExternalVariantAggregator:
             )
 
         # Run all queries in parallel
-        results = await asyncio.gather(*tasks, return_exceptions=True)
+        results = await gather_limited(
+            tasks,
+            resolve_concurrency(concurrency, None),
+            return_exceptions=True,
+        )
 
         # Build the enhanced annotation
         annotation = EnhancedVariantAnnotation(variant_id=variant_id)
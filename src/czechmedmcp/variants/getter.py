@@ -6,15 +6,39 @@ import logging
 from typing import Annotated
 
 from .. import ensure_list, http_client, render
-from ..constants import DEFAULT_ASSEMBLY, MYVARIANT_GET_URL
+from ..concurrency import gather_limited, resolve_concurrency
+from ..constants import (
+    ASSEMBLY_ALIASES,
+    ASSEMBLY_LABELS,
+    DEFAULT_ASSEMBLY,
+    MYVARIANT_GET_URL,
+)
 from ..oncokb_helper import get_oncokb_annotation_for_variant
+from .acmg import acmg_record, assess_acmg, format_acmg
+from .clinvar import extract_clinvar_submissions, format_clinvar_submissions
 from .external import ExternalVariantAggregator, format_enhanced_annotations
 from .filters import filter_variants
 from .formatter import consolidate_multi_allelic_variants
+from .gnomad import extract_gnomad_populations, format_gnomad_populations
 from .links import inject_links
+from .liftover import format_liftover, liftover_variant, variant_coordinates
+from .literature import (
+    fetch_variant_literature,
+    format_variant_literature,
+    literature_terms,
+)
+from .search import _get_hotspot_positions
+from .transcripts import (
+    format_selected_transcript,
+    format_transcript_error,
+    select_transcript,
+)
 
 logger = logging.getLogger(__name__)
 
+# Parallel literature searches for a multi-record variant
+LITERATURE_CONCURRENCY = 3
+
 
 def _format_error_response(
     error: http_client.RequestError, variant_id: str
@@ -40,12 +64,34 @@ def _format_error_response(
     return [{"error": error_msg}]
 
 
+def normalize_assembly(assembly: str) -> str:
+    """Map a genome build name (GRCh37/GRCh38/hg19/hg38) to MyVariant's form.
+
+    Raises:
+        ValueError: If the build is not supported
+    """
+    normalized = ASSEMBLY_ALIASES.get(assembly.strip().lower())
+    if normalized is None:
+        raise ValueError(
+            f"Invalid assembly '{assembly}'. "
+            "Must be one of: GRCh37, GRCh38, hg19, hg38."
+        )
+    return normalized
+
+
 async def get_variant(  # noqa: C901
     variant_id: str,
     output_json: bool = False,
     include_external: bool = False,
     assembly: str = DEFAULT_ASSEMBLY,
     extensive: bool = False,
+    clinvar_submissions: bool = False,
+    transcript: str | None = None,
+    show_transcript: bool = False,
+    liftover: str | None = None,
+    literature_limit: int | None = None,
+    acmg: bool = False,
+    gnomad_detail: bool = False,
 ) -> str:
     """
     Get variant details from MyVariant.info using the variant identifier.
@@ -58,8 +104,23 @@ async def get_variant(  # noqa: C901
         variant_id: Variant identifier (HGVS or rsID)
         output_json: Return JSON format if True, else Markdown
         include_external: Include external annotations (TCGA, 1000 Genomes, cBioPortal)
-        assembly: Genome assembly (hg19 or hg38), defaults to hg19
+        assembly: Genome assembly (hg19/GRCh37 or hg38/GRCh38), defaults to hg38
         extensive: Show full details (default: compact format for all variants)
+        clinvar_submissions: List each ClinVar record's significance, review
+            status, condition and last evaluated date
+        transcript: Ensembl or RefSeq transcript whose HGVS and consequence
+            are reported
+        show_transcript: Report a transcript's HGVS and consequence even
+            without ``transcript``, picking ClinVar's preferred (MANE)
+            transcript
+        liftover: Second assembly (GRCh37/GRCh38) whose coordinates are
+            reported next to the primary ones
+        literature_limit: Number of articles citing the variant to embed
+            (PubTator3 search on its gene and rsID); None skips the search
+        acmg: Add advisory ACMG/AMP criterion hints derived from population
+            frequency, REVEL/CADD, ClinVar and cBioPortal hotspots
+        gnomad_detail: Break the gnomAD exome/genome allele frequency down
+            by population, with allele count, allele number and homozygotes
 
     Returns:
         Formatted variant data as JSON or Markdown string
@@ -67,6 +128,19 @@ async def get_variant(  # noqa: C901
     If output_json is True, the result is returned as a formatted JSON string;
     otherwise, it is rendered as Markdown.
     """
+    assembly = normalize_assembly(assembly)
+    if liftover is not None:
+        liftover = normalize_assembly(liftover)
+        if liftover == assembly:
+            data_to_return = [
+                {
+                    "error": f"Liftover target {ASSEMBLY_LABELS[liftover]} "
+                    "is the same as the primary assembly"
+                }
+            ]
+            if output_json:
+                return json.dumps(data_to_return, indent=2)
+            return render.to_markdown(data_to_return)
     response, error = await http_client.request_api(
         url=f"{MYVARIANT_GET_URL}/{variant_id}",
         request={"fields": "all", "assembly": assembly},
@@ -88,6 +162,43 @@ async def get_variant(  # noqa: C901
     # Inject database links into the variant data
     data_to_return = inject_links(data_to_return)
     data_to_return = filter_variants(data_to_return)
+    for variant_data in data_to_return:
+        variant_data["assembly"] = ASSEMBLY_LABELS[assembly]
+
+    # Select the transcript reported for each variant
+    transcript_sections: list[str] = []
+    if transcript or show_transcript:
+        for variant_data in data_to_return:
+            record_id = variant_data.get("_id", variant_id)
+            selected, transcript_error = select_transcript(
+                variant_data, transcript
+            )
+            if transcript_error:
+                variant_data["selected_transcript"] = {
+                    "error": transcript_error
+                }
+                transcript_sections.append(
+                    format_transcript_error(record_id, transcript_error)
+                )
+            elif selected:
+                variant_data["selected_transcript"] = selected
+                transcript_sections.append(
+                    format_selected_transcript(record_id, selected)
+                )
+
+    # Resolve each variant in the liftover assembly
+    liftover_sections: list[str] = []
+    if liftover is not None:
+        for variant_data in data_to_return:
+            source = variant_coordinates(variant_data, assembly)
+            target, note = await liftover_variant(variant_data, liftover)
+            variant_data["liftover"] = target or {
+                "assembly": ASSEMBLY_LABELS[liftover],
+                "error": note,
+            }
+            liftover_sections.append(
+                format_liftover(source, target, liftover, note)
+            )
 
     # Collect OncoKB annotations separately for markdown appendage
     oncokb_annotations: list[str] = []
@@ -134,6 +245,77 @@ async def get_variant(  # noqa: C901
                     if oncokb_formatted:
                         oncokb_annotations.append(oncokb_formatted)
 
+    # Collect per-record ClinVar interpretations separately for markdown
+    clinvar_sections: list[str] = []
+    if clinvar_submissions:
+        for variant_data in data_to_return:
+            submissions = extract_clinvar_submissions(variant_data)
+            variant_data["clinvar_submissions"] = submissions
+            clinvar_sections.append(
+                format_clinvar_submissions(
+                    variant_data.get("_id", variant_id), submissions
+                )
+            )
+
+    # Break gnomAD frequencies down by population
+    gnomad_sections: list[str] = []
+    if gnomad_detail:
+        for variant_data in data_to_return:
+            populations = extract_gnomad_populations(variant_data)
+            variant_data["gnomad_populations"] = populations
+            gnomad_sections.append(
+                format_gnomad_populations(
+                    variant_data.get("_id", variant_id), populations
+                )
+            )
+
+    # Embed the top articles citing each variant, one search per distinct
+    # gene and variant term
+    literature_sections: list[str] = []
+    if literature_limit is not None:
+        keys = list(
+            dict.fromkeys(
+                literature_terms(variant_data, variant_id)
+                for variant_data in data_to_return
+            )
+        )
+        found = await gather_limited(
+            (
+                fetch_variant_literature(gene, term, literature_limit)
+                for gene, term in keys
+            ),
+            resolve_concurrency(None, LITERATURE_CONCURRENCY),
+        )
+        searched = dict(zip(keys, found, strict=True))
+        for (gene, term), (articles, note) in searched.items():
+            label = f"{term} ({gene})" if gene else term
+            literature_sections.append(
+                format_variant_literature(label, articles, note)
+            )
+        for variant_data in data_to_return:
+            articles, note = searched[
+                literature_terms(variant_data, variant_id)
+            ]
+            variant_data["literature"] = (
+                articles if articles is not None else {"error": note}
+            )
+
+    # Derive advisory ACMG/AMP criterion hints for each variant
+    acmg_sections: list[str] = []
+    if acmg:
+        hotspots: dict[str, set[int] | None] = {}
+        for variant_data in data_to_return:
+            gene, _ = literature_terms(variant_data, variant_id)
+            if gene and gene not in hotspots:
+                hotspots[gene] = await _get_hotspot_positions(gene)
+            criteria = assess_acmg(
+                variant_data, hotspots.get(gene) if gene else None
+            )
+            variant_data["acmg"] = acmg_record(criteria)
+            acmg_sections.append(
+                format_acmg(variant_data.get("_id", variant_id), criteria)
+            )
+
     if output_json:
         return json.dumps(data_to_return, indent=2)
     else:
@@ -143,11 +325,31 @@ async def get_variant(  # noqa: C901
                 f"Applying compact format for {len(data_to_return)} variant(s)"
             )
             consolidated = consolidate_multi_allelic_variants(data_to_return)
+            if isinstance(consolidated.get("summary"), dict):
+                consolidated["summary"]["assembly"] = ASSEMBLY_LABELS[assembly]
             base_markdown = render.to_markdown(consolidated)
         else:
             # Render full markdown
             base_markdown = render.to_markdown(data_to_return)
 
+        if transcript_sections:
+            base_markdown += "\n\n" + "\n\n".join(transcript_sections)
+
+        if liftover_sections:
+            base_markdown += "\n\n" + "\n\n".join(liftover_sections)
+
+        if clinvar_sections:
+            base_markdown += "\n\n" + "\n\n".join(clinvar_sections)
+
+        if gnomad_sections:
+            base_markdown += "\n\n" + "\n\n".join(gnomad_sections)
+
+        if literature_sections:
+            base_markdown += "\n\n" + "\n\n".join(literature_sections)
+
+        if acmg_sections:
+            base_markdown += "\n\n" + "\n\n".join(acmg_sections)
+
         if oncokb_annotations:
             # Append OncoKB annotations as separate markdown sections
             return base_markdown + "\n" + "\n".join(oncokb_annotations)
@@ -166,7 +368,7 @@ async def _variant_details(
     ] = True,
     assembly: Annotated[
         str,
-        "Genome assembly (hg19 or hg38). Default: hg19",
+        "Genome assembly (hg19 or hg38). Default: hg38",
     ] = DEFAULT_ASSEMBLY,
     extensive: Annotated[
         bool,
@@ -180,7 +382,7 @@ async def _variant_details(
     - call_benefit: Define and summarize why this function is being called and the intended benefit
     - variant_id: A variant identifier ("chr7:g.140453136A>T")
     - include_external: Include annotations from TCGA, 1000 Genomes, cBioPortal, and Mastermind
-    - assembly: Genome assembly (hg19 or hg38). Default: hg19
+    - assembly: Genome assembly (hg19 or hg38). Default: hg38
     - extensive: Show full details (default: compact format optimized for LLM consumption)
 
     Process: Queries the MyVariant.info GET endpoint, optionally fetching
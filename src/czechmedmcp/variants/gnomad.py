@@ -0,0 +1,112 @@
+"""Per-population gnomAD allele frequencies for a variant.
+
+MyVariant.info embeds gnomAD exome and genome data with one key per
+population inside each metric, e.g. ``gnomad_exome.af.af_nfe`` and
+``gnomad_exome.hom.hom_nfe``. The variant card only shows the overall
+allele frequency; this breaks it down by population, with the allele
+count, allele number and homozygote count behind each frequency.
+"""
+
+from typing import Any
+
+from .. import ensure_list
+
+# gnomAD datasets embedded by MyVariant.info and their labels
+GNOMAD_DATASETS = (("gnomad_exome", "exome"), ("gnomad_genome", "genome"))
+
+# gnomAD population codes and their names
+GNOMAD_POPULATIONS = (
+    ("afr", "African/African American"),
+    ("amr", "Latino/Admixed American"),
+    ("asj", "Ashkenazi Jewish"),
+    ("eas", "East Asian"),
+    ("fin", "Finnish"),
+    ("nfe", "Non-Finnish European"),
+    ("sas", "South Asian"),
+    ("oth", "Other"),
+)
+
+
+def _metric(data: dict[str, Any], metric: str, population: str | None):
+    """Read one metric, e.g. ``ac.ac_afr``; the first value if a list."""
+    values = data.get(metric)
+    if not isinstance(values, dict):
+        return None
+    key = f"{metric}_{population}" if population else metric
+    items = ensure_list(values.get(key))
+    return items[0] if items else None
+
+
+def _population_record(
+    data: dict[str, Any], population: str | None, name: str
+) -> dict[str, Any]:
+    return {
+        "population": population or "all",
+        "name": name,
+        "af": _metric(data, "af", population),
+        "ac": _metric(data, "ac", population),
+        "an": _metric(data, "an", population),
+        "hom": _metric(data, "hom", population),
+    }
+
+
+def extract_gnomad_populations(
+    variant: dict[str, Any],
+) -> dict[str, list[dict[str, Any]]]:
+    """Return per-population records for each gnomAD dataset present.
+
+    Each dataset lists an ``all`` record followed by one record per
+    population; missing metrics are None.
+    """
+    datasets: dict[str, list[dict[str, Any]]] = {}
+    for key, label in GNOMAD_DATASETS:
+        data = variant.get(key)
+        if not isinstance(data, dict):
+            continue
+        records = [_population_record(data, None, "All")]
+        records.extend(
+            _population_record(data, code, name)
+            for code, name in GNOMAD_POPULATIONS
+        )
+        datasets[label] = records
+    return datasets
+
+
+def _cell(value: Any, frequency: bool = False) -> str:
+    if value is None:
+        return "-"
+    if frequency:
+        try:
+            return f"{float(value):.4g}"
+        except (TypeError, ValueError):
+            return str(value)
+    return str(value)
+
+
+def format_gnomad_populations(
+    variant_id: str, datasets: dict[str, list[dict[str, Any]]]
+) -> str:
+    """Format per-population gnomAD records as a markdown section."""
+    lines = [f"## gnomAD Populations: {variant_id}"]
+    if not datasets:
+        lines.extend(["", "No gnomAD data for this variant."])
+        return "\n".join(lines)
+
+    for label, records in datasets.items():
+        lines.extend([
+            "",
+            f"### gnomAD {label}",
+            "",
+            "| Population | Allele frequency | Allele count "
+            "| Allele number | Homozygotes |",
+            "|---|---|---|---|---|",
+        ])
+        for r in records:
+            lines.append(
+                f"| {r['name']} ({r['population']}) "
+                f"| {_cell(r['af'], frequency=True)} "
+                f"| {_cell(r['ac'])} "
+                f"| {_cell(r['an'])} "
+                f"| {_cell(r['hom'])} |"
+            )
+    return "\n".join(lines)
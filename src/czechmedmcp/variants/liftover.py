@@ -0,0 +1,138 @@
+"""Cross-assembly coordinates for a variant (GRCh37 <-> GRCh38).
+
+MyVariant.info serves separate records per genome build, keyed by the
+build's HGVS ID. The ClinVar and dbNSFP sections of a record also carry
+the position in the other build, so a lifted-over SNV ID can be built
+from them and resolved in the target assembly. Variants without such a
+position are looked up in the target assembly by rsID instead, matching
+on the alternate allele.
+"""
+
+from typing import Any
+
+from .. import ensure_list, http_client
+from ..constants import ASSEMBLY_LABELS, MYVARIANT_GET_URL
+
+# Record sections that carry positions in both genome builds
+CROSS_ASSEMBLY_SOURCES = ("clinvar", "dbnsfp")
+
+LIFTOVER_FIELDS = "_id,chrom,vcf,hg19,hg38,dbsnp.rsid"
+
+
+def variant_coordinates(
+    variant: dict[str, Any], assembly: str
+) -> dict[str, Any]:
+    """Return the variant's ID and position in the given assembly."""
+    vcf = variant.get("vcf") or {}
+    span = variant.get(assembly) or {}
+    start = span.get("start") or vcf.get("position")
+    record = {
+        "assembly": ASSEMBLY_LABELS[assembly],
+        "id": variant.get("_id"),
+        "chrom": variant.get("chrom"),
+        "start": int(start) if start else None,
+        "end": span.get("end") or (int(start) if start else None),
+        "ref": vcf.get("ref"),
+        "alt": vcf.get("alt"),
+    }
+    return {k: v for k, v in record.items() if v is not None}
+
+
+def target_position(variant: dict[str, Any], target: str) -> int | None:
+    """Return the variant's start in the target assembly, if recorded."""
+    for source in CROSS_ASSEMBLY_SOURCES:
+        for section in ensure_list(variant.get(source)):
+            if not isinstance(section, dict):
+                continue
+            span = section.get(target)
+            if isinstance(span, dict) and span.get("start"):
+                return int(span["start"])
+    return None
+
+
+def lifted_snv_id(variant: dict[str, Any], target: str) -> str | None:
+    """Build the HGVS ID an SNV has in the target assembly."""
+    vcf = variant.get("vcf") or {}
+    ref, alt = vcf.get("ref") or "", vcf.get("alt") or ""
+    position = target_position(variant, target)
+    if not variant.get("chrom") or position is None:
+        return None
+    if len(ref) != 1 or len(alt) != 1:
+        return None
+    return f"chr{variant['chrom']}:g.{position}{ref}>{alt}"
+
+
+async def _fetch(
+    variant_id: str, assembly: str
+) -> list[dict[str, Any]] | None:
+    response, error = await http_client.request_api(
+        url=f"{MYVARIANT_GET_URL}/{variant_id}",
+        request={"fields": LIFTOVER_FIELDS, "assembly": assembly},
+        method="GET",
+        domain="myvariant",
+    )
+    if error or not response:
+        return None
+    return [hit for hit in ensure_list(response) if isinstance(hit, dict)]
+
+
+async def liftover_variant(
+    variant: dict[str, Any], target: str
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Resolve a variant in the target assembly.
+
+    :param variant: MyVariant.info record in the source assembly
+    :param target: Target assembly in MyVariant's form (hg19 or hg38)
+    :return: Tuple of (target coordinates or None, note or None)
+    """
+    label = ASSEMBLY_LABELS[target]
+    lifted_id = lifted_snv_id(variant, target)
+    if lifted_id:
+        hits = await _fetch(lifted_id, target)
+        if hits:
+            return variant_coordinates(hits[0], target), None
+
+    rsid = (variant.get("dbsnp") or {}).get("rsid")
+    if rsid:
+        alt = (variant.get("vcf") or {}).get("alt")
+        for hit in await _fetch(rsid, target) or []:
+            if (hit.get("vcf") or {}).get("alt") == alt:
+                return variant_coordinates(hit, target), None
+
+    return None, f"Variant could not be located in {label}"
+
+
+def format_liftover(
+    source: dict[str, Any],
+    target: dict[str, Any] | None,
+    target_assembly: str,
+    note: str | None = None,
+) -> str:
+    """Format source and target coordinates side by side."""
+    target_label = ASSEMBLY_LABELS[target_assembly]
+    lines = [f"## Liftover: {source['assembly']} -> {target_label}", ""]
+    if target is None:
+        lines.append(f"**Note**: {note or 'No coordinates available'}")
+        return "\n".join(lines)
+
+    def position(coords: dict[str, Any]) -> str:
+        start, end = coords.get("start"), coords.get("end")
+        if start is None:
+            return ""
+        return str(start) if end in (None, start) else f"{start}-{end}"
+
+    def alleles(coords: dict[str, Any]) -> str:
+        if not coords.get("ref") and not coords.get("alt"):
+            return ""
+        return f"{coords.get('ref', '')}>{coords.get('alt', '')}"
+
+    lines.append(f"| | {source['assembly']} | {target_label} |")
+    lines.append("|---|---|---|")
+    for label, value in (
+        ("ID", lambda c: c.get("id", "")),
+        ("Chromosome", lambda c: c.get("chrom", "")),
+        ("Position", position),
+        ("Ref>Alt", alleles),
+    ):
+        lines.append(f"| {label} | {value(source)} | {value(target)} |")
+    return "\n".join(lines)
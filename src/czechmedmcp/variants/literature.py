@@ -0,0 +1,85 @@
+"""Literature citing a variant, for the ``variant get`` card.
+
+The articles are found the same way as ``article search --gene X
+--variant Y``: PubTator3 resolves the gene and the variant (rsID, or the
+queried identifier when the record has none) to entities and returns
+the articles annotated with both, most relevant first. Abstracts are
+not fetched, since the card only lists the article references.
+"""
+
+from typing import Any
+
+from .. import ensure_list
+from ..articles.search import PubmedRequest, fetch_article_page
+
+DEFAULT_LITERATURE_LIMIT = 5
+MAX_LITERATURE_LIMIT = 20
+
+ARTICLE_FIELDS = ("pmid", "title", "journal", "date", "pubmed_url")
+
+
+def literature_terms(
+    variant: dict[str, Any], variant_id: str
+) -> tuple[str | None, str]:
+    """Return the gene symbol and variant term to search articles with."""
+    gene = None
+    dbsnp = variant.get("dbsnp") or {}
+    for gene_info in ensure_list(dbsnp.get("gene")):
+        if isinstance(gene_info, dict) and gene_info.get("symbol"):
+            gene = gene_info["symbol"]
+            break
+    if gene is None:
+        names = ensure_list((variant.get("dbnsfp") or {}).get("genename"))
+        gene = names[0] if names else None
+    return gene, dbsnp.get("rsid") or variant_id
+
+
+async def fetch_variant_literature(
+    gene: str | None, term: str, limit: int = DEFAULT_LITERATURE_LIMIT
+) -> tuple[list[dict[str, Any]] | None, str | None]:
+    """Fetch the top articles mentioning a variant (and its gene).
+
+    Returns:
+        Tuple of (article references or None, error message or None)
+    """
+    request = PubmedRequest(genes=[gene] if gene else [], variants=[term])
+    results, error = await fetch_article_page(
+        request, limit=min(limit, MAX_LITERATURE_LIMIT), abstracts=False
+    )
+    if error or results is None:
+        return None, error
+    articles = []
+    for result in results:
+        if not result.pmid:
+            continue
+        record = result.model_dump(mode="json", exclude_none=True)
+        articles.append(
+            {key: record[key] for key in ARTICLE_FIELDS if key in record}
+        )
+    return articles, None
+
+
+def format_variant_literature(
+    label: str, articles: list[dict[str, Any]] | None, note: str | None = None
+) -> str:
+    """Format a variant's articles as a markdown section."""
+    lines = [f"## Literature: {label}", ""]
+    if articles is None:
+        lines.append(f"Literature unavailable: {note}")
+        return "\n".join(lines)
+    if not articles:
+        lines.append("No articles found in PubTator3.")
+        return "\n".join(lines)
+
+    for article in articles:
+        source = ", ".join(
+            part
+            for part in (article.get("journal"), article.get("date"))
+            if part
+        )
+        line = (
+            f"- [PMID {article['pmid']}]({article.get('pubmed_url')}) "
+            f"{article.get('title') or 'Untitled'}"
+        )
+        lines.append(f"{line} ({source})" if source else line)
+    return "\n".join(lines)
@@ -1,11 +1,17 @@
 import json
 import logging
+import re
 from typing import Annotated, Any
 
-from pydantic import BaseModel, Field, model_validator
+from pydantic import BaseModel, Field, field_validator, model_validator
 
 from .. import StrEnum, ensure_list, http_client, render
-from ..constants import MYVARIANT_QUERY_URL, SYSTEM_PAGE_SIZE
+from ..constants import (
+    ASSEMBLY_LABELS,
+    DEFAULT_ASSEMBLY,
+    MYVARIANT_QUERY_URL,
+    SYSTEM_PAGE_SIZE,
+)
 from .filters import filter_variants
 from .links import inject_links
 
@@ -20,6 +26,218 @@ class ClinicalSignificance(StrEnum):
     BENIGN = "benign"
 
 
+class AlleleOrigin(StrEnum):
+    GERMLINE = "germline"
+    SOMATIC = "somatic"
+    BOTH = "both"
+
+
+# Sequence Ontology consequence terms used in SnpEff annotations
+SO_CONSEQUENCES = (
+    "transcript_ablation",
+    "splice_acceptor_variant",
+    "splice_donor_variant",
+    "stop_gained",
+    "frameshift_variant",
+    "stop_lost",
+    "start_lost",
+    "transcript_amplification",
+    "inframe_insertion",
+    "inframe_deletion",
+    "missense_variant",
+    "protein_altering_variant",
+    "splice_region_variant",
+    "incomplete_terminal_codon_variant",
+    "start_retained_variant",
+    "stop_retained_variant",
+    "synonymous_variant",
+    "coding_sequence_variant",
+    "mature_miRNA_variant",
+    "5_prime_UTR_variant",
+    "3_prime_UTR_variant",
+    "non_coding_transcript_exon_variant",
+    "intron_variant",
+    "NMD_transcript_variant",
+    "non_coding_transcript_variant",
+    "upstream_gene_variant",
+    "downstream_gene_variant",
+    "TFBS_ablation",
+    "TFBS_amplification",
+    "TF_binding_site_variant",
+    "regulatory_region_ablation",
+    "regulatory_region_amplification",
+    "regulatory_region_variant",
+    "intergenic_region",
+)
+
+_SO_BY_LOWER = {term.lower(): term for term in SO_CONSEQUENCES}
+
+
+def normalize_consequence(term: str) -> str:
+    """Map a consequence to its SO term (``missense`` -> ``missense_variant``).
+
+    Matching ignores case and accepts spaces or hyphens for underscores
+    and a missing ``_variant`` suffix.
+
+    Raises:
+        ValueError: If the term is not a known consequence
+    """
+    key = term.strip().lower().replace("-", "_").replace(" ", "_")
+    for candidate in (key, f"{key}_variant"):
+        if candidate in _SO_BY_LOWER:
+            return _SO_BY_LOWER[candidate]
+    raise ValueError(
+        f"Unknown consequence '{term}'. Valid terms: "
+        + ", ".join(SO_CONSEQUENCES)
+    )
+
+
+def normalize_consequences(terms: list[str]) -> list[str]:
+    """Normalize consequence terms, dropping duplicates but keeping order."""
+    normalized: list[str] = []
+    for term in terms:
+        so_term = normalize_consequence(term)
+        if so_term not in normalized:
+            normalized.append(so_term)
+    return normalized
+
+
+class SnpEffImpact(StrEnum):
+    """SnpEff putative impact classes, most to least severe."""
+
+    HIGH = "HIGH"
+    MODERATE = "MODERATE"
+    LOW = "LOW"
+    MODIFIER = "MODIFIER"
+
+
+def normalize_impacts(values: list[str]) -> list[SnpEffImpact]:
+    """Normalize SnpEff impacts, dropping duplicates but keeping order.
+
+    Raises:
+        ValueError: If a value is not one of the four SnpEff impacts
+    """
+    impacts: list[SnpEffImpact] = []
+    for value in values:
+        try:
+            impact = SnpEffImpact(str(value).strip().upper())
+        except ValueError:
+            allowed = ", ".join(i.value for i in SnpEffImpact)
+            raise ValueError(
+                f"Invalid SnpEff impact '{value}'. Expected one of: {allowed}"
+            ) from None
+        if impact not in impacts:
+            impacts.append(impact)
+    return impacts
+
+
+# Coding position: CDS number, 5'UTR (-) or 3'UTR (*), with an optional
+# intronic offset (c.4375+1, c.-14-2)
+_HGVSC_POSITION = r"[-*]?\d+(?:[+-]\d+)?"
+
+# Coding HGVS change after the c. prefix: a substitution, deletion,
+# duplication, insertion, indel, inversion or unchanged position
+HGVSC_CHANGE_PATTERN = re.compile(
+    rf"^{_HGVSC_POSITION}(?:_{_HGVSC_POSITION})?"
+    r"(?:[ACGT]>[ACGT]|delins[ACGT]+|del[ACGT]*|dup[ACGT]*|ins[ACGT]+"
+    r"|inv|=)$",
+    re.IGNORECASE,
+)
+
+
+def normalize_hgvsc(value: str) -> str:
+    """Normalize coding HGVS to the ``c.`` form indexed by MyVariant.info.
+
+    A transcript prefix (``NM_004333.4:c.1799T>A``) is dropped and a
+    missing or upper-case ``c.`` prefix is added (``1799T>A`` ->
+    ``c.1799T>A``).
+
+    Raises:
+        ValueError: If the value is not a valid coding HGVS change (for
+            example protein notation such as V600E)
+    """
+    change = value.strip().rsplit(":", 1)[-1].strip()
+    if change[:2].lower() == "c.":
+        change = change[2:]
+    if not HGVSC_CHANGE_PATTERN.match(change):
+        raise ValueError(
+            f"Invalid coding HGVS '{value}'. Expected cDNA notation "
+            "such as c.1799T>A"
+        )
+    return f"c.{change}"
+
+
+# ClinVar condition identifiers: MedGen concept (C0025202, CN517202) or
+# OMIM phenotype (155600), as indexed under clinvar.rcv.conditions
+MEDGEN_ID_PATTERN = re.compile(r"^(?:MEDGEN:)?(C\d{7}|CN\d+)$", re.IGNORECASE)
+OMIM_ID_PATTERN = re.compile(r"^(?:(?:OMIM|MIM):)?(\d{6})$", re.IGNORECASE)
+
+
+def normalize_clinvar_condition(value: str) -> str:
+    """Normalize a ClinVar condition ID to ``MedGen:C...`` or ``OMIM:...``.
+
+    Raises:
+        ValueError: If the value is not a MedGen or OMIM identifier
+    """
+    text = value.strip()
+    if match := MEDGEN_ID_PATTERN.match(text):
+        return f"MedGen:{match.group(1).upper()}"
+    if match := OMIM_ID_PATTERN.match(text):
+        return f"OMIM:{match.group(1)}"
+    raise ValueError(
+        f"Invalid ClinVar condition ID '{value}'. Expected a MedGen "
+        "concept (e.g. C0025202) or OMIM number (e.g. OMIM:155600)"
+    )
+
+
+def clinvar_condition_term(condition: str) -> str:
+    """Build the MyVariant.info term matching a normalized condition ID."""
+    source, identifier = condition.split(":", 1)
+    field = "medgen" if source == "MedGen" else "omim"
+    return f'clinvar.rcv.conditions.identifiers.{field}:"{identifier}"'
+
+
+class CivicEvidenceLevel(StrEnum):
+    """CIViC evidence levels, from validated (A) to inferential (E)."""
+
+    A = "A"
+    B = "B"
+    C = "C"
+    D = "D"
+    E = "E"
+
+
+# CIViC evidence embedded by MyVariant.info, one record per evidence item
+CIVIC_THERAPY_FIELD = "civic.molecularProfiles.evidenceItems.therapies.name"
+CIVIC_LEVEL_FIELD = "civic.molecularProfiles.evidenceItems.evidenceLevel"
+
+
+def civic_levels_at_least(level: CivicEvidenceLevel) -> list[str]:
+    """Evidence levels as strong as ``level`` (B -> A, B)."""
+    levels = list(CivicEvidenceLevel)
+    return [lvl.value for lvl in levels[: levels.index(level) + 1]]
+
+
+def civic_query_parts(
+    therapies: list[str], level: CivicEvidenceLevel | None
+) -> list[str]:
+    """Build the MyVariant.info terms for the CIViC therapy filters.
+
+    Both are matched against the variant's CIViC evidence items as a
+    whole, so a therapy and a level may come from different items.
+    """
+    parts: list[str] = []
+    if therapies:
+        names = " OR ".join(
+            f'{CIVIC_THERAPY_FIELD}:"{name}"' for name in therapies
+        )
+        parts.append(f"({names})" if len(therapies) > 1 else names)
+    if level is not None:
+        levels = " OR ".join(civic_levels_at_least(level))
+        parts.append(f"{CIVIC_LEVEL_FIELD}:({levels})")
+    return parts
+
+
 class PolyPhenPrediction(StrEnum):
     PROBABLY_DAMAGING = "D"
     POSSIBLY_DAMAGING = "P"
@@ -71,8 +289,21 @@ MYVARIANT_FIELDS = [
     "dbsnp.rsid",
     "exac.af",
     "gnomad_exome.af.af",
+    "snpeff.ann.effect",
+    "snpeff.ann.putative_impact",
 ]
 
+# Over-fetch factor for --hotspot, which drops non-hotspot variants after
+# the MyVariant.info search; the window always starts at the first hit so
+# pages are taken from the filtered variants
+HOTSPOT_OVERFETCH = 5
+
+# Largest window MyVariant.info returns in one request
+HOTSPOT_FETCH_CAP = 1000
+
+# Residue number in a protein change such as p.V600E or p.Val600Glu
+PROTEIN_POSITION_PATTERN = re.compile(r"^(?:p\.)?[A-Za-z*]+(\d+)")
+
 
 class VariantQuery(BaseModel):
     """Search parameters for querying variant data from MyVariant.info."""
@@ -101,6 +332,15 @@ class VariantQuery(BaseModel):
         default=None,
         description="ClinVar clinical significance",
     )
+    origin: AlleleOrigin = Field(
+        default=AlleleOrigin.BOTH,
+        description="ClinVar allele origin (germline, somatic, or both)",
+    )
+    clinvar_condition: str | None = Field(
+        default=None,
+        description="ClinVar condition as a MedGen (C0025202) or OMIM "
+        "(OMIM:155600) identifier",
+    )
     max_frequency: float | None = Field(
         default=None,
         description="Maximum population allele frequency threshold",
@@ -121,6 +361,24 @@ class VariantQuery(BaseModel):
         default=None,
         description="SIFT prediction",
     )
+    consequences: list[str] = Field(
+        default_factory=list,
+        description="SnpEff consequences (Sequence Ontology terms, OR-ed)",
+    )
+    impacts: list[SnpEffImpact] = Field(
+        default_factory=list,
+        description="SnpEff putative impacts (HIGH, MODERATE, LOW, "
+        "MODIFIER; OR-ed)",
+    )
+    therapies: list[str] = Field(
+        default_factory=list,
+        description="CIViC therapy names (e.g., vemurafenib), OR-ed",
+    )
+    therapy_evidence: CivicEvidenceLevel | None = Field(
+        default=None,
+        description="Minimum CIViC evidence level (A strongest, E "
+        "weakest) of the variant's evidence items",
+    )
     sources: list[VariantSources] = Field(
         description="Include only specific data sources",
         default_factory=list,
@@ -133,10 +391,60 @@ class VariantQuery(BaseModel):
         default=0,
         description="Result offset for pagination",
     )
+    assembly: str = Field(
+        default=DEFAULT_ASSEMBLY,
+        description="Genome build for coordinates (GRCh37/hg19 or GRCh38/hg38)",
+    )
+    hotspot: bool = Field(
+        default=False,
+        description="Only variants at recurrent cancer hotspot residues "
+        "of the gene in cBioPortal (best-effort; requires gene)",
+    )
+
+    @field_validator("assembly", mode="before")
+    @classmethod
+    def normalize_assembly(cls, v):
+        """Accept GRCh37/GRCh38 as well as hg19/hg38."""
+        from .getter import normalize_assembly
+
+        return normalize_assembly(v) if isinstance(v, str) else v
+
+    @field_validator("hgvsc", mode="before")
+    @classmethod
+    def normalize_hgvsc_notation(cls, v):
+        """Accept cDNA changes with or without the ``c.`` prefix."""
+        return normalize_hgvsc(v) if isinstance(v, str) else v
+
+    @field_validator("clinvar_condition", mode="before")
+    @classmethod
+    def normalize_condition_id(cls, v):
+        """Accept MedGen and OMIM IDs with or without a prefix."""
+        return normalize_clinvar_condition(v) if isinstance(v, str) else v
+
+    @field_validator("consequences", mode="before")
+    @classmethod
+    def normalize_consequence_terms(cls, v):
+        """Accept a single term or list and map each to its SO term."""
+        return normalize_consequences(ensure_list(v, split_strings=True))
+
+    @field_validator("impacts", mode="before")
+    @classmethod
+    def normalize_impact_values(cls, v):
+        """Accept a single impact or list, in any case."""
+        return normalize_impacts(ensure_list(v, split_strings=True))
+
+    @field_validator("therapies", mode="before")
+    @classmethod
+    def normalize_therapy_names(cls, v):
+        """Accept a single name or list, dropping blanks and duplicates."""
+        names = [str(name).strip() for name in ensure_list(v)]
+        return list(dict.fromkeys(name for name in names if name))
 
     @model_validator(mode="after")
     def validate_query_params(self) -> "VariantQuery":
-        if not self.model_dump(exclude_none=True, exclude_defaults=True):
+        if not self.model_dump(
+            exclude_none=True, exclude_defaults=True, exclude={"assembly"}
+        ):
             raise ValueError(
                 "At least one search parameter is required"
             )
@@ -151,6 +459,11 @@ class VariantQuery(BaseModel):
             self.cadd is not None,
             self.polyphen is not None,
             self.sift is not None,
+            bool(self.consequences),
+            bool(self.impacts),
+            self.clinvar_condition is not None,
+            bool(self.therapies),
+            self.therapy_evidence is not None,
         ])
         if (
             self.gene
@@ -162,10 +475,13 @@ class VariantQuery(BaseModel):
                 "may return too many results and timeout. "
                 "Please specify at least one of: hgvsp "
                 "(e.g., p.V600E), hgvsc, rsid, region, "
-                "frequency_max, or cadd_score_min to narrow "
-                "the search."
+                "frequency_max, cadd_score_min, consequence, impact, "
+                "therapy or therapy_evidence to narrow the search."
             )
 
+        if self.hotspot and not self.gene:
+            raise ValueError("Hotspot filtering requires a gene")
+
         return self
 
 
@@ -192,6 +508,12 @@ def build_query_string(query: VariantQuery) -> str:
         ("dbnsfp.hgvsc", query.hgvsc, None, True),
         ("dbsnp.rsid", query.rsid, None, True),
         ("clinvar.rcv.clinical_significance", query.significance, None, True),
+        (
+            "clinvar.rcv.origin",
+            query.origin if query.origin != AlleleOrigin.BOTH else None,
+            None,
+            True,
+        ),
         ("gnomad_exome.af.af", query.max_frequency, "<=", False),
         ("gnomad_exome.af.af", query.min_frequency, ">=", False),
         ("cadd.phred", query.cadd, ">=", False),
@@ -204,9 +526,72 @@ def build_query_string(query: VariantQuery) -> str:
         if part is not None:
             query_parts.append(part)
 
+    if query.clinvar_condition:
+        query_parts.append(clinvar_condition_term(query.clinvar_condition))
+
+    if query.consequences:
+        effects = " OR ".join(
+            f'snpeff.ann.effect:"{term}"' for term in query.consequences
+        )
+        query_parts.append(
+            f"({effects})" if len(query.consequences) > 1 else effects
+        )
+
+    if query.impacts:
+        impacts = " OR ".join(
+            f'snpeff.ann.putative_impact:"{impact.value}"'
+            for impact in query.impacts
+        )
+        query_parts.append(
+            f"({impacts})" if len(query.impacts) > 1 else impacts
+        )
+
+    query_parts.extend(
+        civic_query_parts(query.therapies, query.therapy_evidence)
+    )
+
     return " AND ".join(query_parts) if query_parts else "*"
 
 
+def variant_search_query_summary(query: VariantQuery) -> str:
+    """Render a one-line summary of the search terms and active filters."""
+    parts: list[str] = []
+    for label, value in [
+        ("Gene", query.gene),
+        ("HGVSp", query.hgvsp),
+        ("HGVSc", query.hgvsc),
+        ("rsID", query.rsid),
+        ("Region", query.region),
+        ("Significance", query.significance),
+    ]:
+        if value:
+            parts.append(f"{label}: {value!s}")
+    if query.origin != AlleleOrigin.BOTH:
+        parts.append(f"Origin: {query.origin.value}")
+    if query.clinvar_condition:
+        parts.append(f"ClinVar condition: {query.clinvar_condition}")
+    if query.min_frequency is not None:
+        parts.append(f"Min frequency: {query.min_frequency}")
+    if query.max_frequency is not None:
+        parts.append(f"Max frequency: {query.max_frequency}")
+    if query.cadd is not None:
+        parts.append(f"Min CADD: {query.cadd}")
+    if query.consequences:
+        parts.append(f"Consequence: {' OR '.join(query.consequences)}")
+    if query.impacts:
+        impacts = " OR ".join(impact.value for impact in query.impacts)
+        parts.append(f"Impact: {impacts}")
+    if query.therapies:
+        parts.append(f"CIViC therapy: {' OR '.join(query.therapies)}")
+    if query.therapy_evidence:
+        levels = civic_levels_at_least(query.therapy_evidence)
+        parts.append(f"CIViC evidence level: {'/'.join(levels)}")
+    if query.hotspot:
+        parts.append("Hotspots only (cBioPortal)")
+    parts.append(f"Assembly: {ASSEMBLY_LABELS[query.assembly]}")
+    return " | ".join(parts)
+
+
 async def convert_query(query: VariantQuery) -> dict[str, Any]:
     """Convert a VariantQuery to parameters for the MyVariant.info API."""
     fields = MYVARIANT_FIELDS[:] + [f"{s}.*" for s in query.sources]
@@ -224,6 +609,7 @@ async def convert_query(query: VariantQuery) -> dict[str, Any]:
         "size": query.size,
         "from": query.offset,
         "fields": ",".join(fields),
+        "assembly": query.assembly,
     }
 
 
@@ -244,6 +630,49 @@ async def _get_cbioportal_summary(gene: str) -> str | None:
     return None
 
 
+async def _get_hotspot_positions(gene: str) -> set[int] | None:
+    """Residues of the gene's recurrent cBioPortal hotspots.
+
+    Returns None when cBioPortal has no summary for the gene.
+    """
+    try:
+        from .cbioportal_search import CBioPortalSearchClient
+
+        summary = await CBioPortalSearchClient().get_gene_search_summary(
+            gene
+        )
+    except Exception as e:
+        logger.warning(f"Failed to get cBioPortal hotspots: {e}")
+        return None
+    if summary is None:
+        return None
+    return {
+        hotspot.position for hotspot in summary.hotspots if hotspot.position
+    }
+
+
+def variant_protein_positions(variant: dict[str, Any]) -> set[int]:
+    """Protein residue numbers from a variant's dbNSFP HGVSp annotations."""
+    hgvsp = (variant.get("dbnsfp") or {}).get("hgvsp")
+    positions = set()
+    for change in ensure_list(hgvsp):
+        match = PROTEIN_POSITION_PATTERN.match(str(change))
+        if match:
+            positions.add(int(match.group(1)))
+    return positions
+
+
+def filter_hotspot_variants(
+    variants: list[dict[str, Any]], positions: set[int]
+) -> list[dict[str, Any]]:
+    """Keep variants that change one of the hotspot residues."""
+    return [
+        variant
+        for variant in variants
+        if variant_protein_positions(variant) & positions
+    ]
+
+
 async def _get_oncokb_summary(gene: str) -> str | None:
     """Fetch OncoKB summary for a gene."""
     try:
@@ -288,6 +717,12 @@ async def search_variants(
 ) -> str:
     """Search variants using the MyVariant.info API with optional cBioPortal and OncoKB summaries."""
     params = await convert_query(query)
+    if query.hotspot:
+        params["from"] = 0
+        params["size"] = min(
+            (query.offset + query.size) * HOTSPOT_OVERFETCH,
+            HOTSPOT_FETCH_CAP,
+        )
 
     response, error = await http_client.request_api(
         url=MYVARIANT_QUERY_URL,
@@ -308,6 +743,22 @@ async def search_variants(
     else:
         data = inject_links(data)
         data = filter_variants(data)
+        for variant in data:
+            variant["assembly"] = ASSEMBLY_LABELS[query.assembly]
+
+    if query.hotspot and query.gene and not error:
+        positions = await _get_hotspot_positions(query.gene)
+        if positions is None:
+            data = [
+                {
+                    "error": "cBioPortal hotspot data is unavailable for "
+                    f"{query.gene}"
+                }
+            ]
+        else:
+            data = filter_hotspot_variants(data, positions)[
+                query.offset : query.offset + query.size
+            ]
 
     # Get enrichment summaries if searching by gene
     cbioportal_summary = (
@@ -356,10 +807,31 @@ async def _variant_searcher(
         PolyPhenPrediction | str | None, "PolyPhen-2 prediction"
     ] = None,
     sift: Annotated[SiftPrediction | str | None, "SIFT prediction"] = None,
+    consequence: Annotated[
+        list[str] | str | None,
+        "SO consequence terms (e.g., missense_variant), OR-ed together",
+    ] = None,
+    impact: Annotated[
+        list[str] | str | None,
+        "SnpEff impacts (HIGH, MODERATE, LOW, MODIFIER), OR-ed together",
+    ] = None,
+    therapy: Annotated[
+        list[str] | str | None,
+        "CIViC therapy names (e.g., vemurafenib), OR-ed together",
+    ] = None,
+    therapy_evidence: Annotated[
+        CivicEvidenceLevel | str | None,
+        "Minimum CIViC evidence level, A (validated) to E (inferential)",
+    ] = None,
     sources: Annotated[
         list[VariantSources] | list[str] | str | None,
         "Include only specific data sources (list or comma-separated string)",
     ] = None,
+    hotspot: Annotated[
+        bool,
+        "Only variants at recurrent cBioPortal cancer hotspot residues "
+        "(requires gene)",
+    ] = False,
     size: Annotated[int, "Number of results to return"] = SYSTEM_PAGE_SIZE,
     offset: Annotated[int, "Result offset for pagination"] = 0,
 ) -> str:
@@ -379,7 +851,12 @@ async def _variant_searcher(
     - cadd: Minimum CADD phred score
     - polyphen: PolyPhen-2 prediction
     - sift: SIFT prediction
+    - consequence: SO consequence terms (e.g., missense_variant), OR-ed together
+    - impact: SnpEff impacts (HIGH, MODERATE, LOW, MODIFIER), OR-ed together
+    - therapy: CIViC therapy names (e.g., vemurafenib), OR-ed together
+    - therapy_evidence: Minimum CIViC evidence level, A (validated) to E (inferential)
     - sources: Include only specific data sources (list or comma-separated string)
+    - hotspot: Only variants at recurrent cBioPortal cancer hotspot residues (requires gene)
     - size: Number of results to return (default: 10)
     - offset: Result offset for pagination (default: 0)
 
@@ -399,7 +876,12 @@ async def _variant_searcher(
         cadd=cadd,
         polyphen=polyphen,
         sift=sift,
+        consequences=ensure_list(consequence, split_strings=True),
+        impacts=ensure_list(impact, split_strings=True),
+        therapies=ensure_list(therapy, split_strings=True),
+        therapy_evidence=therapy_evidence,
         sources=ensure_list(sources, split_strings=True),
+        hotspot=hotspot,
         size=size,
         offset=offset,
     )
@@ -0,0 +1,156 @@
+"""Per-transcript HGVS and consequence selection for a variant.
+
+MyVariant.info embeds SnpEff annotations with one entry per affected
+transcript (``snpeff.ann``). A transcript can be requested explicitly by
+Ensembl or RefSeq accession, with or without version. Otherwise the
+transcript ClinVar uses in its preferred variant name is chosen, since
+ClinVar names variants on the MANE Select transcript where one exists,
+falling back to SnpEff's first (most severe) protein-coding annotation.
+"""
+
+import re
+from typing import Any
+
+from .. import ensure_list
+
+# RefSeq accession leading a ClinVar preferred name, e.g. NM_004333.6(BRAF)
+CLINVAR_NAME_TRANSCRIPT = re.compile(r"^(N[MR]_\d+(?:\.\d+)?)\(")
+
+SELECTED_BY_REQUEST = "requested"
+SELECTED_BY_CLINVAR = "ClinVar preferred (MANE Select where available)"
+SELECTED_BY_SNPEFF = "most severe SnpEff annotation"
+
+
+def _accession(transcript: str) -> str:
+    """Return the unversioned, upper-cased accession of a transcript."""
+    return transcript.strip().split(".")[0].upper()
+
+
+def extract_transcript_annotations(
+    variant: dict[str, Any],
+) -> list[dict[str, Any]]:
+    """Return the variant's SnpEff annotations, one per transcript."""
+    snpeff = variant.get("snpeff")
+    if not isinstance(snpeff, dict):
+        return []
+
+    annotations: list[dict[str, Any]] = []
+    seen: set[str] = set()
+    for ann in ensure_list(snpeff.get("ann")):
+        if not isinstance(ann, dict) or not ann.get("feature_id"):
+            continue
+        if ann["feature_id"] in seen:
+            continue
+        seen.add(ann["feature_id"])
+        record = {
+            "transcript": ann["feature_id"],
+            "gene": ann.get("gene_name") or ann.get("genename"),
+            "hgvs_c": ann.get("hgvs_c"),
+            "hgvs_p": ann.get("hgvs_p"),
+            "consequence": ann.get("effect"),
+            "impact": ann.get("putative_impact"),
+            "biotype": ann.get("transcript_biotype"),
+        }
+        annotations.append({k: v for k, v in record.items() if v})
+    return annotations
+
+
+def clinvar_preferred_transcript(variant: dict[str, Any]) -> str | None:
+    """Return the RefSeq transcript of ClinVar's preferred variant name."""
+    clinvar = variant.get("clinvar")
+    if not isinstance(clinvar, dict):
+        return None
+    for rcv in ensure_list(clinvar.get("rcv")):
+        if not isinstance(rcv, dict):
+            continue
+        match = CLINVAR_NAME_TRANSCRIPT.match(str(rcv.get("preferred_name")))
+        if match:
+            return match.group(1)
+    return None
+
+
+def _find(
+    annotations: list[dict[str, Any]], transcript: str
+) -> dict[str, Any] | None:
+    """Find an annotation by exact or unversioned accession."""
+    wanted = transcript.strip().upper()
+    for ann in annotations:
+        if ann["transcript"].upper() == wanted:
+            return ann
+    for ann in annotations:
+        if _accession(ann["transcript"]) == _accession(wanted):
+            return ann
+    return None
+
+
+def select_transcript(
+    variant: dict[str, Any], transcript: str | None = None
+) -> tuple[dict[str, Any] | None, str | None]:
+    """Pick the transcript annotation reported for a variant.
+
+    Returns:
+        Tuple of (annotation with a ``selection`` reason or None, error
+        message or None). An error is only returned when a requested
+        transcript is not annotated.
+    """
+    annotations = extract_transcript_annotations(variant)
+
+    if transcript:
+        found = _find(annotations, transcript)
+        if found is None:
+            available = ", ".join(a["transcript"] for a in annotations)
+            return None, (
+                f"Transcript '{transcript}' not found for "
+                f"{variant.get('_id', 'this variant')}. "
+                + (
+                    f"Available transcripts: {available}"
+                    if available
+                    else "No per-transcript annotations are available."
+                )
+            )
+        return {**found, "selection": SELECTED_BY_REQUEST}, None
+
+    if not annotations:
+        return None, None
+
+    preferred = clinvar_preferred_transcript(variant)
+    found = _find(annotations, preferred) if preferred else None
+    if found is not None:
+        return {**found, "selection": SELECTED_BY_CLINVAR}, None
+
+    coding = [a for a in annotations if a.get("biotype") == "protein_coding"]
+    fallback = (coding or annotations)[0]
+    return {**fallback, "selection": SELECTED_BY_SNPEFF}, None
+
+
+def format_transcript_error(variant_id: str, message: str) -> str:
+    """Format a failed transcript selection as a markdown section."""
+    return "\n".join(
+        [
+            "## Transcript",
+            "",
+            f"- **Variant**: {variant_id}",
+            f"- **Error**: {message}",
+        ]
+    )
+
+
+def format_selected_transcript(
+    variant_id: str, annotation: dict[str, Any]
+) -> str:
+    """Format the selected transcript as a markdown section."""
+    title = annotation["transcript"]
+    if annotation.get("gene"):
+        title += f" ({annotation['gene']})"
+    lines = [f"## Transcript: {title}", ""]
+    lines.append(f"- **Variant**: {variant_id}")
+    lines.append(f"- **Selection**: {annotation['selection']}")
+    for label, key in (
+        ("HGVS c.", "hgvs_c"),
+        ("HGVS p.", "hgvs_p"),
+        ("Consequence", "consequence"),
+        ("Impact", "impact"),
+    ):
+        if annotation.get(key):
+            lines.append(f"- **{label}**: {annotation[key]}")
+    return "\n".join(lines)
@@ -0,0 +1,137 @@
+"""Variant-to-trial matching against ClinicalTrials.gov.
+
+Matching is best-effort: trials rarely cite variants consistently, so the
+same mutation may appear as a protein change (``BRAF V600E``), a dbSNP
+identifier or an HGVS string. Querying several representations and
+merging the results on NCT ID increases recall.
+"""
+
+import json
+import logging
+from typing import Any
+
+from .. import render
+from ..concurrency import gather_limited, resolve_concurrency
+from ..trials.search import (
+    RecruitingStatus,
+    TrialQuery,
+    search_trials,
+    trial_status_rank,
+)
+
+logger = logging.getLogger(__name__)
+
+# Upper bound on ClinicalTrials.gov calls per variant lookup
+MAX_VARIANT_TRIAL_QUERIES = 3
+
+
+def variant_trial_mutation_query(gene: str, change: str) -> str:
+    """Build the normalized ``gene change`` query (e.g. ``BRAF V600E``)."""
+    change = change.strip()
+    if change.lower().startswith("p."):
+        change = change[2:]
+    return f"{gene.strip().upper()} {change}"
+
+
+def variant_trial_representations(
+    gene: str,
+    change: str,
+    rsid: str | None = None,
+    hgvs: str | None = None,
+) -> list[str]:
+    """List the distinct query strings for a variant, capped in number."""
+    candidates = [variant_trial_mutation_query(gene, change), rsid, hgvs]
+    representations: list[str] = []
+    for candidate in candidates:
+        if candidate and candidate.strip() not in representations:
+            representations.append(candidate.strip())
+    return representations[:MAX_VARIANT_TRIAL_QUERIES]
+
+
+def merge_variant_trials(
+    result_sets: list[tuple[str, list[dict[str, Any]]]],
+) -> list[dict[str, Any]]:
+    """Union trial result sets, deduplicating by NCT ID.
+
+    Each merged trial records which representations matched it under
+    ``Matched Queries``. The merged set is sorted by recruiting status
+    priority; ties keep first-seen order.
+    """
+    merged: dict[str, dict[str, Any]] = {}
+    for representation, trials in result_sets:
+        for trial in trials:
+            nct_id = trial.get("NCT Number")
+            if not nct_id:
+                continue
+            if nct_id not in merged:
+                merged[nct_id] = {**trial, "Matched Queries": []}
+            merged[nct_id]["Matched Queries"].append(representation)
+
+    return sorted(merged.values(), key=trial_status_rank)
+
+
+async def search_variant_trials(
+    gene: str,
+    change: str,
+    rsid: str | None = None,
+    hgvs: str | None = None,
+    recruiting_status: RecruitingStatus | None = None,
+    page_size: int = 10,
+    output_json: bool = False,
+    concurrency: int | None = None,
+) -> str:
+    """Find trials mentioning a variant under any of its representations.
+
+    Args:
+        gene: Gene symbol (e.g., "BRAF")
+        change: Protein change (e.g., "V600E" or "p.V600E")
+        rsid: Optional dbSNP rsID to also query
+        hgvs: Optional HGVS notation to also query
+        recruiting_status: Recruiting status filter (default: OPEN)
+        page_size: Results requested per representation
+        output_json: Return JSON instead of formatted markdown
+        concurrency: Max representations queried at once (default: all)
+
+    Returns:
+        Markdown or JSON with the merged, deduplicated trial list
+    """
+    representations = variant_trial_representations(gene, change, rsid, hgvs)
+    raw_results = await gather_limited(
+        (
+            search_trials(
+                TrialQuery(
+                    terms=[representation],
+                    recruiting_status=recruiting_status,
+                    page_size=page_size,
+                ),
+                output_json=True,
+            )
+            for representation in representations
+        ),
+        resolve_concurrency(concurrency, MAX_VARIANT_TRIAL_QUERIES),
+    )
+
+    result_sets: list[tuple[str, list[dict[str, Any]]]] = []
+    for representation, raw in zip(representations, raw_results, strict=True):
+        try:
+            data = json.loads(raw)
+        except json.JSONDecodeError:
+            logger.warning(f"Unparseable trial results for {representation}")
+            continue
+        if isinstance(data, list):
+            result_sets.append((representation, data))
+        else:
+            logger.warning(
+                f"Trial search for {representation} failed: "
+                f"{data.get('error') if isinstance(data, dict) else data}"
+            )
+
+    trials = merge_variant_trials(result_sets)
+
+    if output_json:
+        return json.dumps(trials, indent=2)
+    if not trials:
+        return (
+            f"No trials found for {variant_trial_mutation_query(gene, change)}."
+        )
+    return render.to_markdown(trials)
@@ -0,0 +1,101 @@
+"""Tests for article citation links from iCite."""
+
+import json
+
+import pytest
+
+from czechmedmcp.articles import citations
+from czechmedmcp.articles.citations import (
+    CITATION_LIST_LIMIT,
+    fetch_article_with_citations,
+    fetch_citation_links,
+    format_citation_links,
+)
+
+ICITE = {
+    "data": [
+        {
+            "pmid": 30000000,
+            "references": [20000001, "20000002"],
+            "cited_by": None,
+        }
+    ]
+}
+
+
+def _fake_icite(monkeypatch, response, error=None):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        calls.append(request)
+        return response, error
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+@pytest.mark.asyncio
+async def test_fetch_citation_links(monkeypatch):
+    calls = _fake_icite(monkeypatch, ICITE)
+
+    links, error = await fetch_citation_links(30000000)
+
+    assert error is None
+    assert calls[0]["pmids"] == "30000000"
+    assert links == {"references": [20000001, 20000002], "cited_by": []}
+
+
+@pytest.mark.asyncio
+async def test_fetch_citation_links_missing_record(monkeypatch):
+    _fake_icite(monkeypatch, {"data": []})
+
+    links, error = await fetch_citation_links(1)
+
+    assert links is None
+    assert error == "No iCite record found for PMID 1"
+
+
+def test_format_citation_links():
+    text = format_citation_links(
+        {"references": [20000001], "cited_by": []}, cited_by=True
+    )
+
+    assert "## References (1)" in text
+    assert (
+        "- [PMID 20000001](https://pubmed.ncbi.nlm.nih.gov/20000001/)" in text
+    )
+    assert "## Cited By (0)" in text
+    assert "None found in iCite." in text
+
+    text = format_citation_links(
+        {"references": [], "cited_by": list(range(CITATION_LIST_LIMIT + 5))},
+        references=False,
+    )
+    assert "## References" not in text
+    assert "... and 5 more" in text
+
+
+@pytest.mark.asyncio
+async def test_fetch_article_with_citations(monkeypatch):
+    async def fake_fetch_articles(pmids, **kwargs):
+        return json.dumps([{"pmid": pmids[0], "title": "An article"}])
+
+    async def fake_links(pmid):
+        return {"references": [20000001], "cited_by": [40000001]}, None
+
+    monkeypatch.setattr(
+        citations.fetch, "fetch_articles", fake_fetch_articles
+    )
+    monkeypatch.setattr(citations, "fetch_citation_links", fake_links)
+
+    result = json.loads(
+        await fetch_article_with_citations(
+            30000000, output_json=True, cited_by=False
+        )
+    )
+
+    assert result == [
+        {"pmid": 30000000, "title": "An article", "references": [20000001]}
+    ]
@@ -0,0 +1,91 @@
+"""Tests for date-range filtering of article search."""
+
+import json
+from datetime import date
+
+import pytest
+
+from czechmedmcp.articles.dates import ArticleDateField, pubmed_date_query
+from czechmedmcp.articles.search import (
+    PubmedRequest,
+    ResultItem,
+    SearchResponse,
+    article_search_query_summary,
+    search_articles,
+)
+
+
+def test_pubmed_date_query_per_field():
+    start, end = date(2020, 1, 1), date(2020, 12, 31)
+
+    assert pubmed_date_query(ArticleDateField.PUB, start, end) == (
+        "2020/01/01:2020/12/31[dp]"
+    )
+    assert pubmed_date_query(ArticleDateField.ENTRY, start) == (
+        "2020/01/01:3000/12/31[edat]"
+    )
+    assert pubmed_date_query(ArticleDateField.REVISED, date_to=end) == (
+        "1800/01/01:2020/12/31[lr]"
+    )
+
+
+def test_article_search_query_summary_dates():
+    query = PubmedRequest(
+        genes=["BRAF"], date_from="2020-01-01", date_to="2020-12-31"
+    )
+    assert article_search_query_summary(query) == (
+        "Genes: BRAF | Publication date: 2020-01-01 to 2020-12-31"
+    )
+
+    query = PubmedRequest(
+        genes=["BRAF"], date_from="2024-06-01", date_field="entry"
+    )
+    assert article_search_query_summary(query) == (
+        "Genes: BRAF | Entry date: >= 2024-06-01"
+    )
+
+    with pytest.raises(ValueError, match="date_from must not be after"):
+        PubmedRequest(date_from="2021-01-01", date_to="2020-01-01")
+    with pytest.raises(ValueError, match="MeSH or dates"):
+        PubmedRequest(preprint_only=True, date_to="2020-01-01")
+
+
+@pytest.mark.asyncio
+async def test_search_articles_filters_by_date(monkeypatch):
+    pubtator = SearchResponse(
+        results=[
+            ResultItem(pmid=1, title="Entered too early"),
+            ResultItem(pmid=2, title="Entered in range"),
+        ],
+        page_size=10,
+        current=1,
+        count=2,
+        total_pages=1,
+    )
+    calls = []
+
+    async def fake_request_api(url, request, **kwargs):
+        calls.append((url, request))
+        if url.endswith("esearch.fcgi"):
+            return {"esearchresult": {"idlist": ["2"]}}, None
+        return pubtator, None
+
+    async def no_abstracts(pmids, full=False):
+        return None, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    monkeypatch.setattr(
+        "czechmedmcp.articles.search.call_pubtator_api", no_abstracts
+    )
+
+    query = PubmedRequest(
+        keywords=["braf"], date_from="2024-01-01", date_field="entry"
+    )
+    data = json.loads(await search_articles(query, output_json=True))
+
+    assert [item["pmid"] for item in data] == [2]
+    assert calls[1][1]["term"] == (
+        "(1[uid] OR 2[uid]) AND 2024/01/01:3000/12/31[edat]"
+    )
@@ -1,5 +1,7 @@
 import json
 
+import pytest
+
 from czechmedmcp.articles.fetch import fetch_articles
 
 pmids = [39293516, 34397683, 37296959]
@@ -27,3 +29,45 @@ async def test_fetch_abstracts(anyio_backend):
         assert len(item["title"]) > 10
         assert len(item["abstract"]) > 100
         assert "full_text" not in item
+
+
+PUBTATOR = {
+    "PubTator3": [
+        {
+            "pmid": 34397683,
+            "pmcid": "PMC8361234",
+            "journal": "Nature",
+            "authors": ["Doe J"],
+            "passages": [
+                {"infons": {"type": "title"}, "text": "A title"},
+                {"infons": {"type": "abstract"}, "text": "An abstract."},
+                {
+                    "infons": {"section_type": "INTRO", "type": "paragraph"},
+                    "text": "Body text.",
+                },
+            ],
+        }
+    ]
+}
+
+
+@pytest.mark.asyncio
+async def test_fetch_articles_abstract_only(monkeypatch):
+    calls = []
+
+    async def fake_request_api(url, request, **kwargs):
+        calls.append(request)
+        return kwargs["response_model_type"](**PUBTATOR), None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    result = await fetch_articles(
+        [34397683], full=True, output_json=True, abstract_only=True
+    )
+
+    assert calls[0]["full"] == "false"
+    assert json.loads(result) == [
+        {"pmid": 34397683, "title": "A title", "abstract": "An abstract."}
+    ]
@@ -0,0 +1,129 @@
+"""Tests for MeSH descriptor filtering of article search."""
+
+import json
+
+import pytest
+
+from czechmedmcp.articles.mesh import (
+    filter_pmids_by_mesh,
+    mesh_query,
+    split_mesh_terms,
+)
+from czechmedmcp.articles.search import (
+    PubmedRequest,
+    ResultItem,
+    SearchResponse,
+    article_search_query_summary,
+    search_articles,
+)
+
+MESH_SEARCH = {"esearchresult": {"idlist": ["68008545"]}}
+MESH_SUMMARY = {
+    "result": {
+        "uids": ["68008545"],
+        "68008545": {"ds_meshui": "D008545", "ds_meshterms": ["Melanoma"]},
+    }
+}
+
+
+def _fake_eutils(monkeypatch, pubmed_ids, pubtator=None):
+    calls = []
+
+    async def fake_request_api(url, request, **kwargs):
+        calls.append((url, request))
+        if url.endswith("esummary.fcgi"):
+            return MESH_SUMMARY, None
+        if url.endswith("esearch.fcgi"):
+            if request["db"] == "mesh":
+                return MESH_SEARCH, None
+            return {"esearchresult": {"idlist": pubmed_ids}}, None
+        return pubtator, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+def test_split_mesh_terms():
+    assert split_mesh_terms("Melanoma") == ["Melanoma"]
+    assert split_mesh_terms(" d008545 | Neoplasms ") == [
+        "D008545",
+        "Neoplasms",
+    ]
+    with pytest.raises(ValueError, match="Invalid MeSH ID 'D123'"):
+        split_mesh_terms("D123")
+    with pytest.raises(ValueError):
+        PubmedRequest(keywords=["x"], mesh=["|"])
+
+
+def test_article_search_query_summary_mesh():
+    query = PubmedRequest(genes=["BRAF"], mesh=["Melanoma|D009369", "Humans"])
+
+    assert article_search_query_summary(query) == (
+        "Genes: BRAF | MeSH: (Melanoma OR D009369) AND Humans"
+    )
+
+
+@pytest.mark.asyncio
+async def test_mesh_query_resolves_ids(monkeypatch):
+    _fake_eutils(monkeypatch, [])
+
+    query, error = await mesh_query(["D008545|Neoplasms", "Humans"])
+
+    assert error is None
+    assert query == (
+        '("Melanoma"[MeSH Terms] OR "Neoplasms"[MeSH Terms]) AND '
+        '("Humans"[MeSH Terms])'
+    )
+
+
+@pytest.mark.asyncio
+async def test_mesh_query_unknown_id(monkeypatch):
+    _fake_eutils(monkeypatch, [])
+
+    query, error = await mesh_query(["D000001"])
+
+    assert query is None
+    assert error == "Unknown MeSH ID: D000001"
+
+
+@pytest.mark.asyncio
+async def test_filter_pmids_by_mesh(monkeypatch):
+    calls = _fake_eutils(monkeypatch, ["2"])
+
+    matching, error = await filter_pmids_by_mesh([1, 2], ["Melanoma"])
+
+    assert error is None
+    assert matching == {2}
+    term = calls[0][1]["term"]
+    assert term == '(1[uid] OR 2[uid]) AND ("Melanoma"[MeSH Terms])'
+
+
+@pytest.mark.asyncio
+async def test_search_articles_filters_by_mesh(monkeypatch):
+    pubtator = SearchResponse(
+        results=[
+            ResultItem(pmid=1, title="Not indexed"),
+            ResultItem(pmid=2, title="Indexed with Melanoma"),
+        ],
+        page_size=10,
+        current=1,
+        count=2,
+        total_pages=1,
+    )
+    calls = _fake_eutils(monkeypatch, ["2"], pubtator)
+
+    async def no_abstracts(pmids, full=False):
+        return None, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.articles.search.call_pubtator_api", no_abstracts
+    )
+
+    query = PubmedRequest(keywords=["braf"], mesh=["Melanoma"])
+    data = json.loads(await search_articles(query, output_json=True))
+
+    assert [item["pmid"] for item in data] == [2]
+    # Over-fetches to make up for the filtered-out articles
+    assert calls[0][1].size == 50
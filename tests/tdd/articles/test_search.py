@@ -1,3 +1,4 @@
+import asyncio
 import json
 from unittest.mock import patch
 
@@ -7,7 +8,12 @@ from czechmedmcp.articles.search import (
     PubmedRequest,
     ResultItem,
     SearchResponse,
+    article_search_query_summary,
+    article_fetch_size,
+    article_page_error,
+    author_query,
     convert_request,
+    fetch_article_page,
     search_articles,
 )
 
@@ -218,3 +224,201 @@ async def test_search_network_error(anyio_backend):
         assert len(data) == 1
         assert "error" in data[0]
         assert "Error 599: Network connectivity error" in data[0]["error"]
+
+
+@pytest.mark.asyncio
+async def test_search_has_fulltext_keeps_pmc_articles(anyio_backend):
+    """Test that --has-fulltext drops articles without a PMCID."""
+    mock_response = SearchResponse(
+        results=[
+            ResultItem(pmid=1, pmcid="PMC1000001", title="In PMC"),
+            ResultItem(pmid=2, title="PubMed only"),
+            ResultItem(pmid=3, pmcid="PMC1000003", title="Also in PMC"),
+        ],
+        page_size=10,
+        current=1,
+        count=3,
+        total_pages=1,
+    )
+
+    with (
+        patch("czechmedmcp.http_client.request_api") as mock_request,
+        patch("czechmedmcp.articles.search.call_pubtator_api") as mock_fetch,
+    ):
+        mock_request.return_value = (mock_response, None)
+        mock_fetch.return_value = (None, None)
+
+        query = PubmedRequest(keywords=["melanoma"], has_fulltext=True)
+        data = json.loads(await search_articles(query, output_json=True))
+
+        assert [item["pmid"] for item in data] == [1, 3]
+        # Over-fetches to make up for the filtered-out articles
+        pubtator_request = mock_request.call_args.kwargs["request"]
+        assert pubtator_request.size == 30
+
+
+def test_article_search_query_summary():
+    """Test that the query summary reports the full-text filter."""
+    query = PubmedRequest(genes=["BRAF"], has_fulltext=True)
+    assert (
+        article_search_query_summary(query) == "Genes: BRAF | Full text in PMC"
+    )
+    assert article_search_query_summary(PubmedRequest()) == "All articles"
+
+
+def test_preprint_only_request():
+    """Test the preprint-only summary and its PubMed filter conflicts."""
+    query = PubmedRequest(keywords=["CRISPR"], preprint_only=True)
+    assert article_search_query_summary(query) == (
+        "Keywords: CRISPR | Preprints only"
+    )
+    with pytest.raises(ValueError, match="Preprint-only"):
+        PubmedRequest(
+            keywords=["CRISPR"], preprint_only=True, authors=["Doudna"]
+        )
+
+
+def test_author_query_last_name_by_default():
+    """Test that authors are searched by last name unless exact is set."""
+    assert author_query(["Smith", "Lee K"]) == "smith[au] AND lee[au]"
+    assert author_query(["Smith, J. A."], exact=True) == '"smith ja"[au]'
+    assert (
+        author_query(["van der Berg H"], exact=True)
+        == '"van der berg h"[au]'
+    )
+
+
+def test_article_fetch_size_is_capped():
+    """Test that filters over-fetch once and reject pages past the cap."""
+    query = PubmedRequest(keywords=["x"])
+    assert article_fetch_size(query, page=2, limit=10) == 20
+    query = PubmedRequest(
+        keywords=["x"],
+        has_fulltext=True,
+        authors=["Smith"],
+        mesh=["Melanoma"],
+        date_from="2020-01-01",
+    )
+    assert article_fetch_size(query, page=1, limit=10) == 50
+    assert article_page_error(query, page=20, limit=10) is None
+    assert "enough for 20 pages of 10" in article_page_error(
+        query, page=21, limit=10
+    )
+    assert article_page_error(PubmedRequest(keywords=["x"]), 500, 10) is None
+    assert article_fetch_size(query, page=1, limit=500) == 1000
+    assert article_page_error(query, page=1, limit=500) is None
+
+
+def test_author_exact_requires_initials():
+    """Test that exact author matching rejects bare last names."""
+    with pytest.raises(ValueError, match="'Last FM' names"):
+        PubmedRequest(keywords=["x"], authors=["Smith"], author_exact=True)
+
+
+@pytest.mark.asyncio
+async def test_search_authors_are_and_combined(monkeypatch):
+    """Test that every --author must be on the article, checked by PubMed."""
+    pubtator = SearchResponse(
+        results=[
+            ResultItem(pmid=1, title="Both", authors=["Smith JA", "Lee K"]),
+            ResultItem(pmid=2, title="Smith only", authors=["Smith JB"]),
+        ],
+        page_size=10,
+        current=1,
+        count=2,
+        total_pages=1,
+    )
+    calls = []
+
+    async def fake_request_api(url, request, **kwargs):
+        calls.append((url, request))
+        if url.endswith("esearch.fcgi"):
+            return {"esearchresult": {"idlist": ["1"]}}, None
+        return pubtator, None
+
+    async def no_abstracts(pmids, full=False):
+        return None, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    monkeypatch.setattr(
+        "czechmedmcp.articles.search.call_pubtator_api", no_abstracts
+    )
+
+    query = PubmedRequest(keywords=["melanoma"], authors=["Smith", "Lee"])
+    data = json.loads(await search_articles(query, output_json=True))
+
+    assert [item["pmid"] for item in data] == [1]
+    assert "Smith" not in calls[0][1].text
+    assert calls[1][1]["term"] == (
+        "(1[uid] OR 2[uid]) AND smith[au] AND lee[au]"
+    )
+
+
+def test_article_search_query_summary_authors():
+    """Test that the query summary reports the author matching mode."""
+    query = PubmedRequest(genes=["BRAF"], authors=["Smith", "Lee"])
+    assert article_search_query_summary(query) == (
+        "Genes: BRAF | Authors: Smith AND Lee (last name)"
+    )
+    query = PubmedRequest(
+        genes=["BRAF"], authors=["Smith JA"], author_exact=True
+    )
+    assert article_search_query_summary(query).endswith(
+        "Authors: Smith JA (exact)"
+    )
+
+
+@pytest.mark.asyncio
+async def test_fetch_article_page_reports_errors(monkeypatch):
+    """Test that search errors are returned and abstracts can be skipped."""
+    pubtator = SearchResponse(
+        results=[ResultItem(pmid=1, title="An article")],
+        page_size=10,
+        current=1,
+        count=1,
+        total_pages=1,
+    )
+    outcomes = [asyncio.TimeoutError(), (pubtator, None)]
+
+    async def fake_request_api(url, request, **kwargs):
+        outcome = outcomes.pop(0)
+        if isinstance(outcome, Exception):
+            raise outcome
+        return outcome
+
+    async def no_abstract_fetch(pmids, full=False):
+        raise AssertionError("abstracts were fetched")
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    monkeypatch.setattr(
+        "czechmedmcp.articles.search.call_pubtator_api", no_abstract_fetch
+    )
+
+    query = PubmedRequest(keywords=["melanoma"])
+    results, error = await fetch_article_page(query, abstracts=False)
+    assert results is None
+    assert error.startswith("PubTator3 search timed out")
+
+    results, error = await fetch_article_page(query, abstracts=False)
+    assert error is None
+    assert [result.pmid for result in results] == [1]
+    assert results[0].source == "PubMed"
+
+
+@pytest.mark.asyncio
+async def test_search_articles_timeout_lists_no_articles(monkeypatch):
+    """Test that a timed-out search still returns an empty result list."""
+
+    async def fake_request_api(url, request, **kwargs):
+        raise asyncio.TimeoutError()
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    query = PubmedRequest(keywords=["melanoma"])
+    assert json.loads(await search_articles(query, output_json=True)) == []
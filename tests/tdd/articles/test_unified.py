@@ -174,6 +174,27 @@ class TestUnifiedSearch:
             assert len(articles) == 2
             assert all(a["publication_state"] == "preprint" for a in articles)
 
+    @pytest.mark.asyncio
+    async def test_search_articles_unified_preprint_only_request(self):
+        """Test that a preprint-only request never searches PubMed."""
+        request = PubmedRequest(keywords=["cancer"], preprint_only=True)
+        preprint = {"title": "A preprint", "publication_state": "preprint"}
+
+        with (
+            patch("czechmedmcp.articles.unified.search_articles") as mock_pubmed,
+            patch(
+                "czechmedmcp.articles.unified.search_preprints"
+            ) as mock_preprints,
+        ):
+            mock_preprints.return_value = json.dumps([preprint])
+
+            result = await search_articles_unified(
+                request, include_pubmed=True, output_json=True
+            )
+
+            mock_pubmed.assert_not_called()
+            assert json.loads(result) == [preprint]
+
     @pytest.mark.asyncio
     async def test_search_articles_unified_error_handling(self):
         """Test error handling when one source fails."""
@@ -9,7 +9,11 @@ from starlette.responses import PlainTextResponse
 from starlette.routing import Route
 from starlette.testclient import TestClient
 
-from czechmedmcp.auth import BearerTokenMiddleware, validate_auth_token
+from czechmedmcp.auth import (
+    BearerTokenMiddleware,
+    is_loopback_host,
+    validate_auth_token,
+)
 
 
 class TestValidateAuthToken:
@@ -73,6 +77,44 @@ class TestValidateAuthToken:
             result = validate_auth_token()
             assert result == token_32
 
+    def test_validate_token_from_biomcp_http_token(self):
+        """Test that BIOMCP_HTTP_TOKEN is read and preferred."""
+        preferred = "l" * 32
+        fallback = "m" * 32
+        env = {"BIOMCP_HTTP_TOKEN": preferred, "MCP_AUTH_TOKEN": fallback}
+        with patch.dict(os.environ, env, clear=True):
+            assert validate_auth_token() == preferred
+
+    def test_validate_explicit_token_overrides_env(self):
+        """Test that an --auth-token value takes precedence over env vars."""
+        explicit = "n" * 32
+        with patch.dict(
+            os.environ, {"BIOMCP_HTTP_TOKEN": "o" * 32}, clear=True
+        ):
+            assert validate_auth_token(explicit) == explicit
+
+    def test_validate_explicit_token_too_short(self):
+        """Test that a short --auth-token names the option in the error."""
+        with (
+            patch.dict(os.environ, {}, clear=True),
+            pytest.raises(ValueError, match="--auth-token must be at least"),
+        ):
+            validate_auth_token("short")
+
+
+class TestIsLoopbackHost:
+    """Tests for is_loopback_host function."""
+
+    def test_loopback_hosts(self):
+        """Test that local-only bind addresses are recognised."""
+        for host in ("127.0.0.1", "localhost", "::1", "[::1]", "127.0.1.1"):
+            assert is_loopback_host(host), host
+
+    def test_non_loopback_hosts(self):
+        """Test that public and wildcard bind addresses are not loopback."""
+        for host in ("0.0.0.0", "::", "192.168.1.10", "example.org"):
+            assert not is_loopback_host(host), host
+
 
 class TestBearerTokenMiddleware:
     """Tests for BearerTokenMiddleware class."""
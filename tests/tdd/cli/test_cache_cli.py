@@ -0,0 +1,97 @@
+"""Tests for cache management CLI commands."""
+
+import time
+
+from typer.testing import CliRunner
+
+from czechmedmcp import http_client
+from czechmedmcp.cli.main import app
+
+runner = CliRunner()
+
+
+class FakeCache:
+    """Minimal stand-in for a diskcache.Cache with tagged entries."""
+
+    def __init__(self, entries):
+        self.entries = dict(entries)
+
+    def __len__(self):
+        return len(self.entries)
+
+    def volume(self):
+        return 2048
+
+    def iterkeys(self):
+        return iter(list(self.entries))
+
+    def get(self, key, default=None, tag=False):
+        value, entry_tag = self.entries[key]
+        return (value, entry_tag) if tag else value
+
+    def delete(self, key):
+        return self.entries.pop(key, None) is not None
+
+    def clear(self):
+        count = len(self.entries)
+        self.entries.clear()
+        return count
+
+
+def _install(monkeypatch, cache):
+    monkeypatch.setattr(http_client, "get_cache", lambda: cache)
+    monkeypatch.setattr(http_client, "get_cache_path", lambda: "/tmp/cache")
+
+
+def test_cache_stats(monkeypatch):
+    _install(monkeypatch, FakeCache({"a": ("x", 0), "b": ("y", 0)}))
+
+    result = runner.invoke(app, ["cache", "stats"])
+
+    assert result.exit_code == 0
+    assert "Entries: 2" in result.stdout
+    assert "Size: 2.0 KB" in result.stdout
+
+
+def test_cache_path(monkeypatch):
+    _install(monkeypatch, FakeCache({}))
+
+    result = runner.invoke(app, ["cache", "path"])
+
+    assert result.exit_code == 0
+    assert "/tmp/cache" in result.stdout
+
+
+def test_cache_clear_all(monkeypatch):
+    cache = FakeCache({"a": ("x", time.time()), "b": ("y", 0)})
+    _install(monkeypatch, cache)
+
+    result = runner.invoke(app, ["cache", "clear"])
+
+    assert result.exit_code == 0
+    assert "Removed 2" in result.stdout
+    assert len(cache) == 0
+
+
+def test_cache_clear_older_than(monkeypatch):
+    now = time.time()
+    cache = FakeCache({
+        "fresh": ("x", now - 60),
+        "stale": ("y", now - 3 * 86400),
+        "untagged": ("z", None),
+    })
+    _install(monkeypatch, cache)
+
+    result = runner.invoke(app, ["cache", "clear", "--older-than", "1d"])
+
+    assert result.exit_code == 0
+    assert "Removed 2" in result.stdout
+    assert list(cache.entries) == ["fresh"]
+
+
+def test_cache_clear_rejects_bad_duration(monkeypatch):
+    _install(monkeypatch, FakeCache({}))
+
+    result = runner.invoke(app, ["cache", "clear", "--older-than", "soon"])
+
+    assert result.exit_code == 1
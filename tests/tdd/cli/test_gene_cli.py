@@ -574,3 +574,40 @@ class TestGeneCliIntegration:
         assert "search" in result.stdout.lower()
         assert "page" in result.stdout.lower()
         assert "json" in result.stdout.lower()
+
+
+class TestGeneEnrichCommand:
+    """Test cases for the 'gene enrich' command."""
+
+    def test_gene_list_and_pathway_are_exclusive(self):
+        """A gene list and --pathway cannot be combined or both omitted."""
+        both = runner.invoke(
+            app, ["gene", "enrich", "TP53", "--pathway", "R-HSA-5673001"]
+        )
+        neither = runner.invoke(app, ["gene", "enrich"])
+
+        assert both.exit_code == 1
+        assert neither.exit_code == 1
+
+    def test_pathway_genes_are_enriched(self):
+        """--pathway expands to the pathway's genes."""
+        with (
+            patch(
+                "czechmedmcp.cli.genes.fetch_pathway_genes",
+                new_callable=AsyncMock,
+                return_value=(["BRAF", "KRAS"], None),
+            ),
+            patch(
+                "czechmedmcp.cli.genes.enrichment_report",
+                new_callable=AsyncMock,
+                return_value="# Enrichment",
+            ) as mock_report,
+        ):
+            result = runner.invoke(
+                app, ["gene", "enrich", "--pathway", "r-hsa-5673001"]
+            )
+
+        assert result.exit_code == 0
+        args = mock_report.call_args.args
+        assert args[0] == ["BRAF", "KRAS"]
+        assert args[2] == "Reactome pathway R-HSA-5673001 (2 genes)"
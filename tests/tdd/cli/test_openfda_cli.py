@@ -0,0 +1,26 @@
+"""Tests for OpenFDA CLI output."""
+
+import json
+from unittest.mock import patch
+
+from typer.testing import CliRunner
+
+from czechmedmcp.cli.main import app
+
+runner = CliRunner()
+
+
+@patch("czechmedmcp.cli.openfda.get_adverse_event")
+def test_adverse_event_json_is_printed_verbatim(mock_get):
+    """JSON output must not be wrapped or read as rich markup."""
+    narrative = "Patient took [drug] " + "and developed a rash " * 10
+    mock_get.return_value = json.dumps(
+        {"report_id": "10003304", "narrative": narrative}
+    )
+
+    result = runner.invoke(
+        app, ["openfda", "adverse", "get", "10003304", "--json"]
+    )
+
+    assert result.exit_code == 0
+    assert json.loads(result.stdout)["narrative"] == narrative
@@ -0,0 +1,36 @@
+"""Tests for HTTP server option handling."""
+
+import pytest
+import typer
+
+from czechmedmcp.cli.server import normalize_cors_origins
+
+
+def test_cors_origins_default_to_any_origin():
+    """Test that no --cors-origin keeps the permissive default."""
+    assert normalize_cors_origins(None) == ["*"]
+    assert normalize_cors_origins([]) == ["*"]
+
+
+def test_cors_origins_are_normalized():
+    """Test that origins are trimmed, de-duplicated and kept in order."""
+    origins = normalize_cors_origins(
+        [
+            "http://localhost:3000/",
+            "https://app.example.org",
+            "http://localhost:3000",
+        ]
+    )
+    assert origins == ["http://localhost:3000", "https://app.example.org"]
+
+
+def test_cors_wildcard_wins():
+    """Test that '*' among explicit origins allows any origin."""
+    assert normalize_cors_origins(["http://localhost:3000", "*"]) == ["*"]
+
+
+def test_cors_origin_rejects_invalid_values():
+    """Test that values that are not bare origins are rejected."""
+    for value in ("localhost:3000", "http://example.org/app", "ftp://x.org"):
+        with pytest.raises(typer.BadParameter):
+            normalize_cors_origins([value])
@@ -0,0 +1,46 @@
+"""Tests for the trial CLI option checks."""
+
+from typer.testing import CliRunner
+
+from czechmedmcp.cli.main import app
+from czechmedmcp.cli.trials import trial_get_mode_error
+from czechmedmcp.trials.getter import Module
+
+runner = CliRunner()
+
+
+def test_trial_get_mode_error():
+    assert trial_get_mode_error([], "nci", Module.PROTOCOL, True) is None
+    assert (
+        trial_get_mode_error(
+            ["--compact"], "clinicaltrials", Module.PROTOCOL, False
+        )
+        is None
+    )
+    assert trial_get_mode_error(
+        ["--compact", "--summary-only"],
+        "clinicaltrials",
+        Module.PROTOCOL,
+        False,
+    ) == ("--compact cannot be combined with --summary-only")
+    assert trial_get_mode_error(
+        ["--summary-only"], "nci", Module.PROTOCOL, False
+    ) == ("--summary-only requires --source clinicaltrials")
+    assert trial_get_mode_error(
+        ["--near-lat/--near-lon"], "clinicaltrials", Module.PROTOCOL, True
+    ) == ("--near-lat/--near-lon requires the Locations or All module")
+    assert trial_get_mode_error(
+        ["--format"], "clinicaltrials", Module.OUTCOMES, True
+    ) == ("--format cannot be combined with --json")
+
+
+def test_trial_get_rejects_combined_modes():
+    result = runner.invoke(
+        app, ["trial", "get", "NCT04280705", "--compact", "--summary-only"]
+    )
+
+    assert result.exit_code == 1
+    assert (
+        "Error: --summary-only cannot be combined with --compact"
+        in result.output
+    )
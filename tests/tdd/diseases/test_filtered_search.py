@@ -0,0 +1,242 @@
+"""Unit tests for filtered disease search."""
+
+import json
+
+import pytest
+
+from czechmedmcp.diseases.filtered_search import (
+    DiseaseSearchFilters,
+    format_query_summary,
+    is_rare_disease,
+    matches_filters,
+    search_diseases_filtered,
+)
+
+RARE_SUBSET_HIT = {
+    "_id": "MONDO:0010526",
+    "mondo": {"label": "Fabry disease", "subset": ["gard_rare"]},
+}
+ORPHANET_HIT = {
+    "_id": "MONDO:0007739",
+    "mondo": {"label": "Huntington disease", "xrefs": {"orphanet": "399"}},
+}
+COMMON_HIT = {
+    "_id": "MONDO:0005148",
+    "mondo": {"label": "type 2 diabetes mellitus", "subset": ["clingen"]},
+}
+
+
+class TestRareDiseaseFilter:
+    """Test rare-disease designation and filter wiring."""
+
+    def test_is_rare_disease(self):
+        assert is_rare_disease(RARE_SUBSET_HIT)
+        assert is_rare_disease(ORPHANET_HIT)
+        assert not is_rare_disease(COMMON_HIT)
+        assert not is_rare_disease({"_id": "MONDO:1"})
+
+    def test_rare_only_filter(self):
+        filters = DiseaseSearchFilters(rare_only=True)
+        assert not filters.is_empty()
+        assert matches_filters(ORPHANET_HIT, filters)
+        assert not matches_filters(COMMON_HIT, filters)
+        assert matches_filters(COMMON_HIT, DiseaseSearchFilters())
+
+    def test_query_summary(self):
+        assert format_query_summary(
+            "chorea", DiseaseSearchFilters(rare_only=True)
+        ) == ("Query: chorea | Rare only (best-effort)")
+        assert format_query_summary("chorea", DiseaseSearchFilters()) == (
+            "Query: chorea"
+        )
+
+    @pytest.mark.asyncio
+    async def test_search_applies_rare_filter(self, monkeypatch):
+        async def mock_request_api(url, request, method, domain):
+            assert domain == "mydisease"
+            assert "mondo.subset" in request["fields"]
+            return (
+                {"total": 2, "hits": [ORPHANET_HIT, COMMON_HIT]},
+                None,
+            )
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_diseases_filtered(
+            "disease",
+            filters=DiseaseSearchFilters(rare_only=True),
+            output_json=True,
+        )
+        data = json.loads(result)
+
+        assert data["query_summary"].endswith("Rare only (best-effort)")
+        assert [r["disease_id"] for r in data["results"]] == [
+            "MONDO:0007739"
+        ]
+        assert data["results"][0]["rare"] is True
+        assert data["upstream_total"] == 2
+        assert data["returned"] == 1
+        assert "total" not in data
+
+
+class TestGeneFilter:
+    """Test the Monarch-backed gene-to-disease filter."""
+
+    def test_query_summary_includes_gene(self):
+        filters = DiseaseSearchFilters(gene="BRAF")
+        assert not filters.is_empty()
+        assert format_query_summary(None, filters) == "Gene: BRAF"
+        assert format_query_summary("melanoma", filters) == (
+            "Query: melanoma | Gene: BRAF"
+        )
+
+    def test_gene_rejects_rare_only(self):
+        with pytest.raises(ValueError, match="rare_only"):
+            DiseaseSearchFilters(gene="BRAF", rare_only=True)
+
+    @pytest.mark.asyncio
+    async def test_search_by_gene_uses_monarch(self, monkeypatch):
+        calls = []
+
+        async def mock_request_api(url, request, method, domain):
+            assert domain == "monarch"
+            calls.append((url, request))
+            if url.endswith("/search"):
+                return (
+                    {
+                        "items": [
+                            {
+                                "id": "MGI:88190",
+                                "name": "Braf",
+                                "in_taxon": "NCBITaxon:10090",
+                            },
+                            {
+                                "id": "HGNC:1097",
+                                "name": "BRAF",
+                                "in_taxon": "NCBITaxon:9606",
+                            },
+                        ]
+                    },
+                    None,
+                )
+            return (
+                {
+                    "total": 42,
+                    "items": [
+                        {
+                            "object": "MONDO:0007893",
+                            "object_label": "Noonan syndrome 7",
+                            "predicate": "biolink:causes",
+                            "primary_knowledge_source": "infores:omim",
+                        },
+                        {
+                            "object": "MONDO:0005105",
+                            "object_label": "melanoma",
+                            "predicate": "biolink:contributes_to",
+                        },
+                    ],
+                },
+                None,
+            )
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_diseases_filtered(
+            None,
+            filters=DiseaseSearchFilters(gene="BRAF"),
+            page=2,
+            page_size=2,
+            output_json=True,
+        )
+        data = json.loads(result)
+
+        association_request = calls[1][1]
+        assert association_request["subject"] == "HGNC:1097"
+        assert association_request["offset"] == 2
+        assert association_request["limit"] == 2
+        assert data["total"] == 42
+        assert data["query_summary"] == "Gene: BRAF"
+        assert data["results"][0] == {
+            "disease_id": "MONDO:0007893",
+            "name": "Noonan syndrome 7",
+            "association": "causes",
+            "source": "infores:omim",
+        }
+
+    @pytest.mark.asyncio
+    async def test_search_by_gene_and_name_counts_matches(self, monkeypatch):
+        calls = []
+
+        async def mock_request_api(url, request, method, domain):
+            calls.append(request)
+            if url.endswith("/search"):
+                return (
+                    {
+                        "items": [
+                            {
+                                "id": "HGNC:1097",
+                                "name": "BRAF",
+                                "in_taxon": "NCBITaxon:9606",
+                            }
+                        ]
+                    },
+                    None,
+                )
+            return (
+                {
+                    "total": 3,
+                    "items": [
+                        {
+                            "object": "MONDO:0005105",
+                            "object_label": "melanoma",
+                        },
+                        {
+                            "object": "MONDO:0007893",
+                            "object_label": "Noonan syndrome 7",
+                        },
+                        {
+                            "object": "MONDO:0005012",
+                            "object_label": "cutaneous melanoma",
+                        },
+                    ],
+                },
+                None,
+            )
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_diseases_filtered(
+            "melanoma",
+            filters=DiseaseSearchFilters(gene="BRAF"),
+            page=2,
+            page_size=1,
+            output_json=True,
+        )
+        data = json.loads(result)
+
+        assert (calls[1]["offset"], calls[1]["limit"]) == (0, 500)
+        assert len(calls) == 2
+        assert data["total"] == 2
+        assert [r["disease_id"] for r in data["results"]] == [
+            "MONDO:0005012"
+        ]
+
+    @pytest.mark.asyncio
+    async def test_search_by_unknown_gene(self, monkeypatch):
+        async def mock_request_api(url, request, method, domain):
+            return {"items": []}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_diseases_filtered(
+            None, filters=DiseaseSearchFilters(gene="NOTAGENE")
+        )
+        assert result == "No human gene found in Monarch for 'NOTAGENE'"
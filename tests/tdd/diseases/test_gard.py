@@ -0,0 +1,102 @@
+"""Tests for the GARD rare-disease linkage of a disease."""
+
+import json
+
+import pytest
+
+from czechmedmcp.diseases.gard import (
+    format_disease_gard,
+    gard_records,
+    normalize_gard_id,
+)
+from czechmedmcp.diseases.getter import get_disease
+
+
+def test_normalize_gard_id():
+    assert normalize_gard_id("GARD:0006233") == "GARD:0006233"
+    assert normalize_gard_id("6233") == "GARD:0006233"
+    assert normalize_gard_id(6233) == "GARD:0006233"
+    assert normalize_gard_id("gard:6233") == "GARD:0006233"
+    assert normalize_gard_id("not-an-id") is None
+
+
+def test_gard_records_deduplicates_across_blocks():
+    records = gard_records(
+        {"gard": ["0006233", "GARD:0006233"]}, {"gard": 6233}, None
+    )
+
+    assert records == [
+        {
+            "gard_id": "GARD:0006233",
+            "url": "https://rarediseases.info.nih.gov/diseases/6233",
+        }
+    ]
+    assert gard_records({"mesh": "D003550"}) == []
+
+
+def test_format_disease_gard():
+    text = format_disease_gard(gard_records({"gard": "6233"}))
+
+    assert text.startswith("## GARD")
+    assert (
+        "- **GARD:0006233**: https://rarediseases.info.nih.gov/diseases/6233"
+        in text
+    )
+    assert "unavailable: no GARD" in format_disease_gard(
+        [], "no GARD cross-reference for this disease"
+    )
+
+
+def _fake_mydisease(monkeypatch, disease):
+    async def fake_request_api(url, request, method, domain):
+        return disease, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+
+@pytest.mark.asyncio
+async def test_get_disease_with_gard(monkeypatch):
+    _fake_mydisease(
+        monkeypatch,
+        {
+            "_id": "MONDO:0009061",
+            "name": "cystic fibrosis",
+            "mondo": {
+                "mondo": "MONDO:0009061",
+                "xrefs": {"gard": "GARD:0006233"},
+            },
+        },
+    )
+
+    data = json.loads(
+        await get_disease("MONDO:0009061", output_json=True, gard=True)
+    )
+    assert data["gard"][0]["gard_id"] == "GARD:0006233"
+    assert "gard_error" not in data
+
+    text = await get_disease("MONDO:0009061", gard=True)
+    assert "## GARD" in text
+    assert "rarediseases.info.nih.gov/diseases/6233" in text
+
+    data = json.loads(await get_disease("MONDO:0009061", output_json=True))
+    assert "gard" not in data
+
+
+@pytest.mark.asyncio
+async def test_get_disease_without_gard_entry(monkeypatch):
+    _fake_mydisease(
+        monkeypatch,
+        {
+            "_id": "MONDO:0005105",
+            "name": "melanoma",
+            "mondo": {"mondo": "MONDO:0005105"},
+        },
+    )
+
+    data = json.loads(
+        await get_disease("MONDO:0005105", output_json=True, gard=True)
+    )
+    assert data["gard"] == []
+    assert data["gard_error"] == "no GARD cross-reference for this disease"
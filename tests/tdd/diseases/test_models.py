@@ -0,0 +1,116 @@
+"""Tests for Monarch animal models of a disease."""
+
+import pytest
+
+from czechmedmcp.diseases.monarch import (
+    DISEASE_PHENOTYPE_CATEGORY,
+    MODEL_CATEGORY,
+    fetch_disease_models,
+    format_disease_models,
+    parse_genotype_label,
+)
+
+MODELS = {
+    "items": [
+        {
+            "subject": "MGI:3800042",
+            "subject_label": "Pax3<Sp>/Pax3<+> [C57BL/6J]",
+            "subject_taxon_label": "Mus musculus",
+            "object": "MONDO:0010937",
+        },
+        {
+            "subject": "ZFIN:ZDB-FISH-150901-1",
+            "subject_label": "mitfa<w2/w2>",
+            "subject_taxon_label": "Danio rerio",
+            "object": "MONDO:0010937",
+        },
+    ]
+}
+
+PHENOTYPES = {
+    "MONDO:0010937": ["Heterochromia iridis", "Hearing impairment"],
+    "MGI:3800042": ["heterochromia iridis", "white spotting"],
+    "ZFIN:ZDB-FISH-150901-1": ["melanocyte absent"],
+}
+
+
+def _fake_monarch(monkeypatch, models):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        calls.append(request)
+        if request["category"] == MODEL_CATEGORY:
+            return models, None
+        subject = request["subject"]
+        if request["category"] == DISEASE_PHENOTYPE_CATEGORY:
+            assert subject == "MONDO:0010937"
+        return {
+            "items": [
+                {"object_label": label} for label in PHENOTYPES[subject]
+            ]
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+def test_parse_genotype_label():
+    assert parse_genotype_label("Pax3<Sp>/Pax3<+> [C57BL/6J]") == (
+        "Pax3",
+        "Pax3<Sp>",
+    )
+    assert parse_genotype_label("mitfa<w2/w2>") == ("mitfa", "mitfa<w2/w2>")
+    assert parse_genotype_label("wild type") == (None, None)
+
+
+@pytest.mark.asyncio
+async def test_fetch_disease_models(monkeypatch):
+    _fake_monarch(monkeypatch, MODELS)
+
+    models, error = await fetch_disease_models("MONDO:0010937")
+
+    assert error is None
+    mouse, fish = models
+    assert mouse["organism"] == "Mus musculus"
+    assert mouse["gene"] == "Pax3"
+    assert mouse["allele"] == "Pax3<Sp>"
+    assert mouse["phenotypes"] == ["heterochromia iridis", "white spotting"]
+    assert mouse["shared_phenotypes"] == ["heterochromia iridis"]
+    assert mouse["url"] == "https://monarchinitiative.org/MGI:3800042"
+    assert fish["organism"] == "Danio rerio"
+    assert fish["shared_phenotypes"] == []
+
+
+@pytest.mark.asyncio
+async def test_no_models_is_not_an_error(monkeypatch):
+    calls = _fake_monarch(monkeypatch, {"items": [], "total": 0})
+
+    models, error = await fetch_disease_models("MONDO:0000001")
+
+    assert models == []
+    assert error is None
+    assert len(calls) == 1
+    assert "No animal models found" in format_disease_models(models)
+
+
+def test_format_disease_models():
+    text = format_disease_models(
+        [
+            {
+                "organism": "Mus musculus",
+                "gene": "Pax3",
+                "allele": "Pax3<Sp>",
+                "genotype": "Pax3<Sp>/Pax3<+>",
+                "phenotypes": ["heterochromia iridis", "white spotting"],
+                "shared_phenotypes": ["heterochromia iridis"],
+                "url": "https://monarchinitiative.org/MGI:3800042",
+            }
+        ]
+    )
+
+    assert "### Pax3<Sp> (Mus musculus)" in text
+    assert "- **Shared with disease**: heterochromia iridis" in text
+    assert "- **Monarch**: https://monarchinitiative.org/MGI:3800042" in text
+    assert "unavailable: boom" in format_disease_models([], "boom")
@@ -0,0 +1,120 @@
+"""Tests for drugs indicated for a disease."""
+
+import json
+
+import pytest
+
+from czechmedmcp.diseases.getter import get_disease
+from czechmedmcp.diseases.treatments import (
+    fetch_disease_treatments,
+    format_disease_treatments,
+    indication_query,
+)
+
+HITS = {
+    "hits": [
+        {
+            "name": "DACARBAZINE",
+            "chembl": {
+                "pref_name": "DACARBAZINE",
+                "drug_indications": [
+                    {"mesh_id": "D008545", "max_phase_for_ind": 4},
+                    {"mesh_id": "D006689", "max_phase_for_ind": 4},
+                ],
+            },
+        },
+        {
+            "chembl": {
+                "pref_name": "RELATLIMAB",
+                "drug_indications": {
+                    "mesh_id": "D008545",
+                    "max_phase_for_ind": "3.0",
+                },
+            },
+        },
+        {
+            "chembl": {
+                "pref_name": "ALDESLEUKIN",
+                "drug_indications": [
+                    {"mesh_id": "D002292", "max_phase_for_ind": 4},
+                    {"mesh_id": "D008545", "max_phase_for_ind": 2},
+                ],
+            },
+        },
+    ]
+}
+
+
+def _fake_mychem(monkeypatch, disease=None):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        if domain == "mychem":
+            calls.append(request)
+            return HITS, None
+        return disease, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+def test_indication_query():
+    assert indication_query("melanoma", "D008545") == (
+        "chembl.drug_indications.mesh_id:D008545"
+    )
+    assert indication_query("melanoma") == (
+        'chembl.drug_indications.mesh_heading:"melanoma"'
+    )
+
+
+@pytest.mark.asyncio
+async def test_treatments_ranked_by_phase_for_this_disease(monkeypatch):
+    calls = _fake_mychem(monkeypatch)
+
+    treatments, error = await fetch_disease_treatments("melanoma", "D008545")
+
+    assert error is None
+    assert calls[0]["q"] == "chembl.drug_indications.mesh_id:D008545"
+    # Aldesleukin's approval is for another indication
+    assert [(t["drug"], t["evidence_level"]) for t in treatments] == [
+        ("Dacarbazine", "Approved"),
+        ("Relatlimab", "Phase 3"),
+        ("Aldesleukin", "Phase 2"),
+    ]
+    assert {t["source"] for t in treatments} == {"ChEMBL"}
+
+
+def test_format_disease_treatments():
+    record = {
+        "drug": "Dacarbazine",
+        "evidence_level": "Approved",
+        "source": "ChEMBL",
+    }
+    text = format_disease_treatments([record])
+
+    assert "| 1 | Dacarbazine | Approved | ChEMBL |" in text
+    assert "No drugs" in format_disease_treatments([])
+
+
+@pytest.mark.asyncio
+async def test_get_disease_with_treatments(monkeypatch):
+    calls = _fake_mychem(
+        monkeypatch,
+        {
+            "_id": "MONDO:0005105",
+            "name": "melanoma",
+            "mondo": {"mondo": "MONDO:0005105", "xrefs": {"mesh": "D008545"}},
+        },
+    )
+
+    data = json.loads(
+        await get_disease("MONDO:0005105", output_json=True, treatments=True)
+    )
+    assert data["treatments"][0]["drug"] == "Dacarbazine"
+    assert calls[0]["q"].endswith("mesh_id:D008545")
+
+    text = await get_disease("MONDO:0005105", treatments=True)
+    assert "## Treatments" in text
+    assert "| 2 | Relatlimab | Phase 3 | ChEMBL |" in text
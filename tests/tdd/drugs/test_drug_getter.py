@@ -23,6 +23,7 @@ class TestDrugGetter:
                 "indication": "Treatment of chronic myeloid leukemia...",
                 "mechanism_of_action": "Inhibits BCR-ABL tyrosine kinase...",
                 "products": {"name": ["Gleevec", "Glivec"]},
+                "groups": ["approved", "investigational"],
             },
             "chembl": {
                 "molecule_chembl_id": "CHEMBL941",
@@ -78,6 +79,7 @@ class TestDrugGetter:
 
         assert "## Drug: Imatinib" in result
         assert "DrugBank ID**: DB00619" in result
+        assert "Approval Status**: approved, investigational" in result
 
     @pytest.mark.asyncio
     async def test_get_drug_json_output(self, monkeypatch, mock_drug_response):
@@ -95,6 +97,7 @@ class TestDrugGetter:
         assert data["drug_id"] == "CHEMBL941"
         assert data["name"] == "Imatinib"
         assert data["drugbank_id"] == "DB00619"
+        assert data["groups"] == ["approved", "investigational"]
         assert (
             data["_links"]["DrugBank"]
             == "https://www.drugbank.ca/drugs/DB00619"
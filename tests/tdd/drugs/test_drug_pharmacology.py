@@ -0,0 +1,88 @@
+"""Tests for the pharmacology (PK/PD) section of drug get."""
+
+import json
+
+import pytest
+
+from czechmedmcp.drugs.getter import get_drug
+from czechmedmcp.drugs.pharmacology import (
+    DrugPharmacology,
+    format_pharmacology,
+    parse_pharmacology,
+)
+
+WARFARIN = {
+    "_id": "PJVWKTKQMONHTI-UHFFFAOYSA-N",
+    "drugbank": {
+        "id": "DB00682",
+        "name": "Warfarin",
+        "pharmacology": {
+            "absorption": "Warfarin is essentially completely absorbed.",
+            "half_life": "  Approximately 1 week.  ",
+            "metabolism": ["Hepatic via CYP2C9.", "Minor CYP1A2 and CYP3A4."],
+            "route_of_elimination": "Urine (92%), as metabolites.",
+            "toxicity": "Bleeding.",
+        },
+    },
+}
+
+
+def _fake_mychem(monkeypatch, response):
+    async def fake_request_api(url, request, method, domain):
+        return response, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+
+def test_parse_pharmacology():
+    pharmacology = parse_pharmacology(WARFARIN["drugbank"]["pharmacology"])
+
+    assert pharmacology.half_life == "Approximately 1 week."
+    assert pharmacology.metabolism == (
+        "Hepatic via CYP2C9. Minor CYP1A2 and CYP3A4."
+    )
+    assert pharmacology.clearance is None
+    assert parse_pharmacology(None) == DrugPharmacology()
+
+
+def test_format_pharmacology():
+    text = format_pharmacology(
+        parse_pharmacology(WARFARIN["drugbank"]["pharmacology"])
+    )
+
+    assert text.startswith("### Pharmacology")
+    assert "- **Half-life**: Approximately 1 week." in text
+    assert "- **Route of elimination**: Urine (92%), as metabolites." in text
+    assert "Clearance" not in text
+    assert "No pharmacokinetic data" in format_pharmacology(DrugPharmacology())
+
+
+@pytest.mark.asyncio
+async def test_get_drug_pharmacology_json(monkeypatch):
+    _fake_mychem(monkeypatch, WARFARIN)
+
+    result = json.loads(
+        await get_drug("DB00682", output_json=True, include_pharmacology=True)
+    )
+
+    assert result["pharmacology"] == {
+        "absorption": "Warfarin is essentially completely absorbed.",
+        "half_life": "Approximately 1 week.",
+        "metabolism": "Hepatic via CYP2C9. Minor CYP1A2 and CYP3A4.",
+        "route_of_elimination": "Urine (92%), as metabolites.",
+    }
+
+    result = json.loads(await get_drug("DB00682", output_json=True))
+    assert "pharmacology" not in result
+
+
+@pytest.mark.asyncio
+async def test_get_drug_pharmacology_markdown(monkeypatch):
+    _fake_mychem(monkeypatch, WARFARIN)
+
+    text = await get_drug("DB00682", include_pharmacology=True)
+
+    assert "## Drug: Warfarin" in text
+    assert "\n\n### Pharmacology\n- **Absorption**:" in text
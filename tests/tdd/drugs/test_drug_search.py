@@ -0,0 +1,383 @@
+"""Unit tests for filtered drug search."""
+
+import json
+
+import pytest
+
+from czechmedmcp.drugs.search import (
+    ApprovalStatus,
+    DrugSearchFilters,
+    DrugSort,
+    MoaClass,
+    TargetAction,
+    build_mychem_query,
+    format_query_summary,
+    matches_filters,
+    search_drugs,
+    sort_fetch_size,
+    sort_page_error,
+    sort_records,
+    sort_window_note,
+)
+
+
+class TestDrugSearchFilters:
+    """Test filter translation and summaries."""
+
+    def test_query_without_filters(self):
+        """Plain queries pass through unchanged."""
+        assert build_mychem_query("imatinib", DrugSearchFilters()) == (
+            "imatinib"
+        )
+
+    def test_approval_status_translates_to_drugbank_groups(self):
+        """Approval status becomes a drugbank.groups clause."""
+        filters = DrugSearchFilters(approval_status=ApprovalStatus.WITHDRAWN)
+        assert build_mychem_query("rofecoxib", filters) == (
+            "rofecoxib AND drugbank.groups:withdrawn"
+        )
+
+    def test_query_summary_includes_approval_status(self):
+        """The summary reflects the active approval filter."""
+        filters = DrugSearchFilters(approval_status="investigational")
+        summary = format_query_summary("kinase", filters)
+        assert summary == "Query: kinase | Approval status: investigational"
+
+    def test_post_filter_uses_drugbank_groups(self):
+        """Hits without the requested group are dropped."""
+        filters = DrugSearchFilters(approval_status=ApprovalStatus.WITHDRAWN)
+        withdrawn = {"drugbank": {"groups": ["approved", "withdrawn"]}}
+        approved = {"drugbank": {"groups": "approved"}}
+        chembl_only = {"chembl": {"pref_name": "X"}}
+        assert matches_filters(withdrawn, filters)
+        assert not matches_filters(approved, filters)
+        assert not matches_filters(chembl_only, filters)
+
+    @pytest.mark.asyncio
+    async def test_search_drugs_sends_filter(self, monkeypatch):
+        """The filter is pushed down and echoed in JSON output."""
+        captured = {}
+
+        async def mock_request_api(url, request, method, domain):
+            captured.update(request)
+            return (
+                {
+                    "total": 2,
+                    "hits": [
+                        {
+                            "_id": "DB00533",
+                            "drugbank": {
+                                "id": "DB00533",
+                                "name": "Rofecoxib",
+                                "groups": ["investigational", "withdrawn"],
+                            },
+                        },
+                        {
+                            "_id": "DB00482",
+                            "drugbank": {
+                                "id": "DB00482",
+                                "name": "Celecoxib",
+                                "groups": ["approved"],
+                            },
+                        },
+                    ],
+                },
+                None,
+            )
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_drugs(
+            "coxib",
+            DrugSearchFilters(approval_status=ApprovalStatus.WITHDRAWN),
+            output_json=True,
+        )
+        data = json.loads(result)
+
+        assert captured["q"] == "coxib AND drugbank.groups:withdrawn"
+        assert data["query_summary"] == (
+            "Query: coxib | Approval status: withdrawn"
+        )
+        assert [r["name"] for r in data["results"]] == ["Rofecoxib"]
+
+
+class TestDrugTargetFilters:
+    """Test filtering drugs by target gene and action."""
+
+    ERLOTINIB = {
+        "drugbank": {
+            "targets": [
+                {"gene_name": "EGFR", "actions": ["Inhibitor"]},
+                {"gene_name": "NR1I2", "actions": "agonist"},
+            ]
+        }
+    }
+
+    def test_target_action_translates_to_drugbank_targets(self):
+        """Target and action become drugbank.targets clauses."""
+        filters = DrugSearchFilters(
+            target="egfr", target_action=TargetAction.INHIBITOR
+        )
+        assert build_mychem_query("", filters) == (
+            "drugbank.targets.gene_name:EGFR AND "
+            'drugbank.targets.actions:"inhibitor"'
+        )
+        assert format_query_summary("", filters) == (
+            "Query:  | Target: EGFR | Target action: inhibitor"
+        )
+
+    def test_action_must_apply_to_requested_target(self):
+        """An action on another target of the drug does not match."""
+        assert matches_filters(
+            self.ERLOTINIB,
+            DrugSearchFilters(target="EGFR", target_action="inhibitor"),
+        )
+        assert not matches_filters(
+            self.ERLOTINIB,
+            DrugSearchFilters(target="EGFR", target_action="agonist"),
+        )
+        assert matches_filters(
+            self.ERLOTINIB, DrugSearchFilters(target_action="agonist")
+        )
+        assert not matches_filters({}, DrugSearchFilters(target="EGFR"))
+
+    @pytest.mark.asyncio
+    async def test_search_drugs_requests_target_fields(self, monkeypatch):
+        """Target fields are fetched so hits can be post-filtered."""
+        captured = {}
+
+        async def mock_request_api(url, request, method, domain):
+            captured.update(request)
+            return {"total": 1, "hits": [self.ERLOTINIB]}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_drugs(
+            "erlotinib",
+            DrugSearchFilters(target="EGFR", target_action="inhibitor"),
+            output_json=True,
+        )
+
+        assert "drugbank.targets.actions" in captured["fields"]
+        assert len(json.loads(result)["results"]) == 1
+
+
+class TestDrugStableOrder:
+    """Test the deterministic tie-break of equal relevance scores."""
+
+    @pytest.mark.asyncio
+    async def test_relevance_ties_are_ordered_by_id(self, monkeypatch):
+        """Hits with equal scores are listed by MyChem ID."""
+        hits = [
+            {"_id": "B", "_score": 2.0, "drugbank": {"groups": ["approved"]}},
+            {"_id": "A", "_score": 2.0, "drugbank": {"groups": ["approved"]}},
+            {"_id": "C", "_score": 3.0, "drugbank": {"groups": ["approved"]}},
+        ]
+
+        async def mock_request_api(url, request, method, domain):
+            return {"total": 3, "hits": list(hits)}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+        filters = DrugSearchFilters(approval_status=ApprovalStatus.APPROVED)
+
+        data = json.loads(await search_drugs("x", filters, output_json=True))
+        assert [r["drug_id"] for r in data["results"]] == ["C", "A", "B"]
+
+        data = json.loads(
+            await search_drugs(
+                "x", filters, output_json=True, stable_sort=False
+            )
+        )
+        assert [r["drug_id"] for r in data["results"]] == ["B", "A", "C"]
+
+
+class TestDrugSort:
+    """Test client-side ordering of drug search results."""
+
+    RECORDS = [
+        {"drug_id": "D3", "name": "imatinib", "max_phase": 4},
+        {"drug_id": "D1", "name": "Bosutinib", "first_approval": 2012},
+        {"drug_id": "D2", "max_phase": 2, "first_approval": 2001},
+        {"drug_id": "D4", "name": "Dasatinib", "max_phase": 4},
+    ]
+
+    def test_name_sort_is_case_insensitive_missing_last(self):
+        """Names sort ascending ignoring case; unnamed records go last."""
+        ordered = sort_records(self.RECORDS, DrugSort.NAME)
+        assert [r["drug_id"] for r in ordered] == ["D1", "D4", "D3", "D2"]
+
+    def test_max_phase_sort_breaks_ties_by_name(self):
+        """Equal phases fall back to name; missing phase goes last."""
+        ordered = sort_records(self.RECORDS, DrugSort.MAX_PHASE)
+        assert [r["drug_id"] for r in ordered] == ["D4", "D3", "D2", "D1"]
+
+    def test_approval_date_sort_descending_missing_last(self):
+        """Newest approval first; records without a year keep name order."""
+        ordered = sort_records(self.RECORDS, DrugSort.APPROVAL_DATE)
+        assert [r["drug_id"] for r in ordered] == ["D1", "D2", "D4", "D3"]
+
+    def test_query_summary_includes_sort(self):
+        """The summary reflects the sort order."""
+        filters = DrugSearchFilters(sort="max-phase")
+        assert format_query_summary("kinase", filters) == (
+            "Query: kinase | Sort: max-phase"
+        )
+        assert not filters.is_empty()
+
+    def test_sort_fetch_size(self):
+        """Sorting fetches a window that covers the requested page."""
+        assert sort_fetch_size(1, 10) == 100
+        assert sort_fetch_size(20, 10) == 200
+        assert sort_fetch_size(50, 100) == 1000
+
+    def test_sort_page_error(self):
+        """Sorted pages past the single-request window are rejected."""
+        assert sort_page_error(10, 100) is None
+        error = sort_page_error(11, 100)
+        assert "enough for 10 pages of 100" in error
+        assert "page 11 is beyond them" in error
+
+    @pytest.mark.asyncio
+    async def test_search_drugs_rejects_sorted_page_past_window(
+        self, monkeypatch
+    ):
+        """A sorted page past the window errors without a request."""
+
+        async def mock_request_api(url, request, method, domain):
+            raise AssertionError("no request expected")
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_drugs(
+            "kinase",
+            DrugSearchFilters(sort=DrugSort.NAME),
+            page=101,
+            page_size=10,
+            output_json=True,
+        )
+        assert "page 101 is beyond them" in json.loads(result)["error"]
+
+    @pytest.mark.asyncio
+    async def test_search_drugs_sorts_before_paging(self, monkeypatch):
+        """The window is fetched from offset 0 and sorted, then paged."""
+        captured = {}
+
+        async def mock_request_api(url, request, method, domain):
+            captured.update(request)
+            return (
+                {
+                    "total": 3,
+                    "hits": [
+                        {
+                            "_id": "A",
+                            "name": "alpha",
+                            "chembl": {"max_phase": 1},
+                        },
+                        {
+                            "_id": "B",
+                            "name": "beta",
+                            "chembl": {"max_phase": 4},
+                        },
+                        {"_id": "C", "name": "gamma"},
+                    ],
+                },
+                None,
+            )
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_drugs(
+            "kinase",
+            DrugSearchFilters(sort=DrugSort.MAX_PHASE),
+            page=2,
+            page_size=1,
+            output_json=True,
+        )
+        data = json.loads(result)
+
+        assert captured["from"] == 0
+        assert captured["size"] == 100
+        assert [r["drug_id"] for r in data["results"]] == ["A"]
+        assert "sort_note" not in data
+
+    def test_sort_window_note(self):
+        """A sort over part of the matches says how many were ranked."""
+        assert sort_window_note(DrugSort.NAME, 80, 100) is None
+        note = sort_window_note(DrugSort.MAX_PHASE, 2500, 100)
+        assert note.startswith("Sorted by max-phase within the top 100")
+        assert "of 2500 matches" in note
+
+
+class TestDrugMoaClassFilter:
+    """Test the controlled mechanism-of-action class filter."""
+
+    IMATINIB = {
+        "_id": "BFSMGDJOXZAERB-UHFFFAOYSA-N",
+        "name": "imatinib",
+        "drugcentral": {
+            "pharmacology_class": {
+                "fda_epc": [
+                    {"code": "N0000175605", "description": "Kinase Inhibitor"}
+                ]
+            }
+        },
+    }
+
+    def test_moa_class_translates_to_epc_terms(self):
+        """The class becomes an OR of exact FDA EPC terms."""
+        filters = DrugSearchFilters(moa_class="immune checkpoint inhibitor")
+        query = build_mychem_query("", filters)
+
+        assert query.startswith(
+            "(drugcentral.pharmacology_class.fda_epc.description:"
+            '"Programmed Death Receptor-1 Blocking Antibody" OR '
+        )
+        assert query.count(" OR ") == 2
+        assert format_query_summary("", filters) == (
+            "Query:  | MoA class: immune checkpoint inhibitor"
+        )
+
+    def test_moa_class_post_filter(self):
+        """Only hits annotated with a mapped EPC term match."""
+        filters = DrugSearchFilters(moa_class=MoaClass.KINASE_INHIBITOR)
+
+        assert matches_filters(self.IMATINIB, filters)
+        assert not matches_filters(
+            self.IMATINIB, DrugSearchFilters(moa_class="statin")
+        )
+        assert not matches_filters({"_id": "x"}, filters)
+
+    @pytest.mark.asyncio
+    async def test_search_drugs_requests_epc_field(self, monkeypatch):
+        """The EPC field is fetched and reported on each record."""
+        captured = {}
+
+        async def mock_request_api(url, request, method, domain):
+            captured.update(request)
+            return {"total": 1, "hits": [self.IMATINIB]}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = json.loads(
+            await search_drugs(
+                "",
+                DrugSearchFilters(moa_class="kinase inhibitor"),
+                output_json=True,
+            )
+        )
+
+        assert "fda_epc.description" in captured["fields"]
+        assert result["results"][0]["moa_classes"] == ["Kinase Inhibitor"]
+        assert "MoA class: kinase inhibitor" in result["query_summary"]
@@ -0,0 +1,91 @@
+"""Tests for listing a drug's molecular targets."""
+
+import json
+
+import pytest
+
+from czechmedmcp.drugs.targets import get_drug_targets, parse_drug_targets
+
+IMATINIB = {
+    "_id": "KTUFNOKKBVMGRW-UHFFFAOYSA-N",
+    "drugbank": {
+        "id": "DB00619",
+        "name": "Imatinib",
+        "targets": [
+            {
+                "gene_name": "ABL1",
+                "uniprot": "P00519",
+                "name": "Tyrosine-protein kinase ABL1",
+                "actions": ["Inhibitor"],
+            },
+            {
+                "gene_name": "KIT",
+                "uniprot": "P10721",
+                "name": "Mast/stem cell growth factor receptor Kit",
+                "actions": "inhibitor",
+            },
+            {
+                "gene_name": "PDGFRA",
+                "uniprot": "P16234",
+                "name": "Platelet-derived growth factor receptor alpha",
+            },
+        ],
+    },
+}
+
+
+def _fake_mychem(monkeypatch, response):
+    async def fake_request_api(url, request, method, domain):
+        return response, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+
+def test_parse_drug_targets():
+    targets = parse_drug_targets(IMATINIB["drugbank"]["targets"])
+
+    assert [t.gene for t in targets] == ["ABL1", "KIT", "PDGFRA"]
+    assert targets[0].action == "inhibitor"
+    assert targets[1].action == "inhibitor"
+    assert targets[2].action is None
+
+
+@pytest.mark.asyncio
+async def test_get_drug_targets_table(monkeypatch):
+    _fake_mychem(monkeypatch, IMATINIB)
+
+    text = await get_drug_targets("DB00619", limit=1, offset=1)
+
+    assert "# Drug Targets: Imatinib" in text
+    assert "Showing 2-2 of 3 targets." in text
+    assert (
+        "| KIT | P10721 | Mast/stem cell growth factor receptor Kit | "
+        "inhibitor |"
+    ) in text
+    assert "ABL1" not in text
+
+
+@pytest.mark.asyncio
+async def test_get_drug_targets_json(monkeypatch):
+    _fake_mychem(monkeypatch, IMATINIB)
+
+    targets = json.loads(await get_drug_targets("DB00619", output_json=True))
+
+    assert targets[0] == {
+        "gene": "ABL1",
+        "uniprot": "P00519",
+        "name": "Tyrosine-protein kinase ABL1",
+        "action": "inhibitor",
+    }
+    assert len(targets) == 3
+
+
+@pytest.mark.asyncio
+async def test_drug_without_targets(monkeypatch):
+    _fake_mychem(monkeypatch, {"_id": "x", "drugbank": {"id": "DB00001"}})
+
+    text = await get_drug_targets("DB00001")
+
+    assert "No molecular targets found." in text
@@ -0,0 +1 @@
+"""Tests for gene information tools."""
@@ -0,0 +1,125 @@
+"""Tests for Open Targets gene-disease association scores."""
+
+import json
+
+import pytest
+
+from czechmedmcp.genes import get_gene
+from czechmedmcp.genes.associations import (
+    fetch_disease_associations,
+    format_associations,
+    parse_associations,
+)
+
+ROWS = [
+    {
+        "disease": {
+            "id": "EFO_0003060",
+            "name": "non-small cell lung carcinoma",
+            "dbXRefs": ["MONDO:0005233", "DOID:3908"],
+        },
+        "score": 0.62,
+        "datatypeScores": [
+            {"id": "literature", "score": 0.4},
+            {"id": "known_drug", "score": 0.98},
+            {"id": "animal_model", "score": 0},
+        ],
+    },
+    {
+        "disease": {"id": "MONDO_0005105", "name": "melanoma"},
+        "score": 0.81,
+        "datatypeScores": [{"id": "somatic_mutation", "score": 0.9}],
+    },
+]
+
+OPENTARGETS = {
+    "data": {"target": {"associatedDiseases": {"count": 2, "rows": ROWS}}}
+}
+
+
+def test_parse_associations_sorts_by_score():
+    associations = parse_associations(ROWS)
+
+    assert [a.disease for a in associations] == [
+        "melanoma",
+        "non-small cell lung carcinoma",
+    ]
+    assert associations[0].mondo_id == "MONDO:0005105"
+    assert associations[1].mondo_id == "MONDO:0005233"
+    assert associations[1].datatypes == {"known_drug": 0.98, "literature": 0.4}
+
+
+def test_format_associations():
+    text = format_associations(parse_associations(ROWS))
+
+    assert "## Disease Associations (Open Targets)" in text
+    assert (
+        "| melanoma | MONDO:0005105 | 0.810 | somatic_mutation (0.90) |"
+    ) in text
+    assert "No associated diseases" in format_associations([])
+
+
+@pytest.mark.asyncio
+async def test_fetch_disease_associations(monkeypatch):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        calls.append(request)
+        return OPENTARGETS, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    associations, error = await fetch_disease_associations(
+        "ENSG00000146648", 500
+    )
+
+    assert error is None
+    assert calls[0]["variables"] == {
+        "ensemblId": "ENSG00000146648",
+        "size": 100,
+    }
+    assert len(associations) == 2
+
+
+@pytest.mark.asyncio
+async def test_fetch_disease_associations_graphql_error(monkeypatch):
+    async def fake_request_api(url, request, method, domain):
+        return {"errors": [{"message": "bad id"}]}, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    associations, error = await fetch_disease_associations("X", 10)
+
+    assert associations is None
+    assert error == "Open Targets query failed: bad id"
+
+
+@pytest.mark.asyncio
+async def test_get_gene_with_associations_json(monkeypatch):
+    async def fake_request_api(url, request, method, domain):
+        if domain == "opentargets":
+            return OPENTARGETS, None
+        return {
+            "_id": "1956",
+            "symbol": "EGFR",
+            "ensembl": {"gene": "ENSG00000146648"},
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    data = json.loads(
+        await get_gene("1956", output_json=True, associations_limit=1)
+    )
+
+    assert data["associations"][0] == {
+        "disease": "melanoma",
+        "mondo_id": "MONDO:0005105",
+        "score": 0.81,
+        "datatypes": {"somatic_mutation": 0.9},
+    }
@@ -0,0 +1,157 @@
+"""Tests for the CIViC evidence of a gene's variants."""
+
+import json
+
+import pytest
+
+from czechmedmcp.genes.civic import (
+    CIVIC_PAGE_SIZE,
+    CIVIC_MAX_PAGES,
+    civic_gene_query,
+    extract_evidence_items,
+    get_gene_civic,
+)
+
+
+def _item(eid, level, therapies=(), status="ACCEPTED"):
+    return {
+        "id": eid,
+        "name": f"EID{eid}",
+        "status": status,
+        "evidenceLevel": level,
+        "evidenceType": "PREDICTIVE",
+        "evidenceDirection": "SUPPORTS",
+        "significance": "SENSITIVITYRESPONSE",
+        "disease": {"name": "Melanoma"},
+        "therapies": [{"name": name} for name in therapies],
+    }
+
+
+V600E = {
+    "_id": "chr7:g.140453136A>T",
+    "civic": {
+        "name": "V600E",
+        "molecularProfiles": [
+            {
+                "name": "BRAF V600E",
+                "evidenceItems": [
+                    _item(3017, "B", ["Dabrafenib", "Trametinib"]),
+                    _item(95, "A", ["Vemurafenib"]),
+                    _item(7, "C", status="REJECTED"),
+                ],
+            }
+        ],
+    },
+}
+V600K = {
+    "_id": "chr7:g.140453136_140453137delinsTT",
+    "civic": {
+        "name": "V600K",
+        "molecularProfiles": {
+            "name": "BRAF V600K",
+            "evidenceItems": _item(1409, "C", ["Trametinib"]),
+        },
+    },
+}
+
+
+def test_civic_gene_query():
+    assert civic_gene_query("BRAF") == (
+        '(civic.entrezName:"BRAF" OR dbnsfp.genename:"BRAF") '
+        "AND _exists_:civic"
+    )
+
+
+def test_extract_evidence_items():
+    records = extract_evidence_items(V600E)
+
+    assert [r["evidence_id"] for r in records] == ["EID3017", "EID95"]
+    assert records[0] == {
+        "evidence_id": "EID3017",
+        "molecular_profile": "BRAF V600E",
+        "disease": "Melanoma",
+        "therapies": "Dabrafenib, Trametinib",
+        "evidence_level": "B",
+        "evidence_type": "PREDICTIVE",
+        "evidence_direction": "SUPPORTS",
+        "significance": "SENSITIVITYRESPONSE",
+    }
+    assert extract_evidence_items(V600K)[0]["therapies"] == "Trametinib"
+    assert extract_evidence_items({"_id": "x"}) == []
+
+
+@pytest.mark.asyncio
+async def test_get_gene_civic_pages_strongest_first(monkeypatch):
+    requests = []
+
+    async def fake_request_api(url, request, method, domain):
+        requests.append(request)
+        return {"total": 3, "hits": [V600K, V600E, V600E]}, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    data = json.loads(await get_gene_civic("braf", output_json=True))
+    assert [r["evidence_id"] for r in data["evidence"]] == [
+        "EID95",
+        "EID3017",
+        "EID1409",
+    ]
+    assert data["total"] == 3
+    assert data["unread_variants"] == 0
+    assert requests[0]["q"].startswith('(civic.entrezName:"BRAF"')
+
+    text = await get_gene_civic("BRAF", limit=1, offset=1)
+    assert text.startswith("# CIViC Evidence: BRAF")
+    assert "Showing 2-2 of 3 evidence items." in text
+    assert (
+        "| EID3017 | BRAF V600E | Melanoma | Dabrafenib, Trametinib | B |"
+        in text
+    )
+
+
+@pytest.mark.asyncio
+async def test_get_gene_civic_reads_every_page(monkeypatch):
+    starts = []
+
+    async def fake_request_api(url, request, method, domain):
+        starts.append(request["from"])
+        hits = [V600E] if request["from"] == 0 else [V600K]
+        return {"total": CIVIC_PAGE_SIZE + 1, "hits": hits}, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    data = json.loads(await get_gene_civic("BRAF", output_json=True))
+    assert sorted(starts) == [0, CIVIC_PAGE_SIZE]
+    assert [r["evidence_id"] for r in data["evidence"]] == [
+        "EID95",
+        "EID3017",
+        "EID1409",
+    ]
+
+
+@pytest.mark.asyncio
+async def test_get_gene_civic_reports_unread_variants(monkeypatch):
+    requests = []
+
+    async def fake_request_api(url, request, method, domain):
+        requests.append(request)
+        total = CIVIC_PAGE_SIZE * CIVIC_MAX_PAGES + 5
+        return {"total": total, "hits": [V600E]}, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    data = json.loads(await get_gene_civic("BRAF", output_json=True))
+    assert len(requests) == CIVIC_MAX_PAGES
+    assert data["unread_variants"] == 5
+    assert [r["evidence_id"] for r in data["evidence"]] == [
+        "EID95",
+        "EID3017",
+    ]
+    text = await get_gene_civic("BRAF")
+    assert "5 CIViC variants" in text
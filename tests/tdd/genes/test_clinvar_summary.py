@@ -0,0 +1,92 @@
+"""Tests for the ClinVar variant burden of a gene."""
+
+import json
+
+import pytest
+
+from czechmedmcp.genes import get_gene
+from czechmedmcp.genes.clinvar_summary import (
+    clinvar_count_query,
+    fetch_clinvar_summary,
+    format_clinvar_summary,
+)
+
+TOTALS = {
+    None: 120,
+    "pathogenic": 40,
+    "likely pathogenic": 12,
+    "uncertain significance": 50,
+    "likely benign": 9,
+    "benign": 7,
+    "conflicting interpretations of pathogenicity": 3,
+}
+
+
+def _fake_myvariant(monkeypatch):
+    queries = []
+
+    async def fake_request_api(url, request, method, domain):
+        if domain == "myvariant":
+            queries.append(request["q"])
+            significance = None
+            if " AND " in request["q"]:
+                significance = request["q"].split(":")[-1].strip('"')
+            return {"total": TOTALS[significance], "hits": []}, None
+        return {"_id": "672", "symbol": "BRCA1"}, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return queries
+
+
+def test_clinvar_count_query():
+    assert clinvar_count_query("BRCA1") == 'clinvar.gene.symbol:"BRCA1"'
+    assert clinvar_count_query("BRCA1", "benign") == (
+        'clinvar.gene.symbol:"BRCA1" AND '
+        'clinvar.rcv.clinical_significance:"benign"'
+    )
+
+
+@pytest.mark.asyncio
+async def test_fetch_clinvar_summary_counts_each_category(monkeypatch):
+    queries = _fake_myvariant(monkeypatch)
+
+    summary, error = await fetch_clinvar_summary("BRCA1")
+
+    assert error is None
+    assert len(queries) == 7
+    assert summary["total"] == 120
+    assert summary["counts"]["pathogenic"] == 40
+    assert list(summary["counts"])[-1] == (
+        "conflicting interpretations of pathogenicity"
+    )
+
+
+def test_format_clinvar_summary():
+    summary = {
+        "gene": "BRCA1",
+        "total": 52,
+        "counts": {"pathogenic": 40, "likely pathogenic": 12},
+    }
+    text = format_clinvar_summary(summary)
+
+    assert "| Likely pathogenic | 12 |" in text
+    assert "| **Total** | 52 |" in text
+    assert "No ClinVar variants for XYZ." in format_clinvar_summary(
+        {"gene": "XYZ", "total": 0, "counts": {}}
+    )
+
+
+@pytest.mark.asyncio
+async def test_get_gene_with_clinvar_summary(monkeypatch):
+    _fake_myvariant(monkeypatch)
+
+    data = json.loads(
+        await get_gene("672", output_json=True, include_clinvar_summary=True)
+    )
+    assert data["clinvar_summary"]["counts"]["benign"] == 7
+
+    text = await get_gene("672", include_clinvar_summary=True)
+    assert "## ClinVar Variant Summary" in text
+    assert "| Uncertain significance | 50 |" in text
@@ -0,0 +1,105 @@
+"""Tests for GTEx tissue expression of a gene."""
+
+import json
+
+import pytest
+
+from czechmedmcp.genes import get_gene
+from czechmedmcp.genes.expression import (
+    TissueExpression,
+    fetch_tissue_expression,
+    format_expression,
+)
+
+REFERENCE = {"data": [{"gencodeId": "ENSG00000157764.13", "symbol": "BRAF"}]}
+
+MEDIANS = {
+    "data": [
+        {"tissueSiteDetailId": "Whole_Blood", "median": 2.4, "unit": "TPM"},
+        {
+            "tissueSiteDetailId": "Brain_Cerebellum",
+            "median": 31.7,
+            "unit": "TPM",
+        },
+        {"tissueSiteDetailId": "Testis", "median": 18.2, "unit": "TPM"},
+    ]
+}
+
+
+def _fake_gtex(monkeypatch, reference=REFERENCE, medians=MEDIANS):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        if domain == "gtex":
+            calls.append((url.split("/api/v2/")[1], request))
+            if url.endswith("reference/gene"):
+                return reference, None
+            return medians, None
+        return {
+            "_id": "673",
+            "symbol": "BRAF",
+            "ensembl": {"gene": "ENSG00000157764"},
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+@pytest.mark.asyncio
+async def test_fetch_tissue_expression_ranks_tissues(monkeypatch):
+    calls = _fake_gtex(monkeypatch)
+
+    records, error = await fetch_tissue_expression("ENSG00000157764")
+
+    assert error is None
+    assert calls[1] == (
+        "expression/medianGeneExpression",
+        {"gencodeId": "ENSG00000157764.13", "datasetId": "gtex_v8"},
+    )
+    assert [r.tissue for r in records] == [
+        "Brain Cerebellum",
+        "Testis",
+        "Whole Blood",
+    ]
+
+
+@pytest.mark.asyncio
+async def test_gene_outside_gtex_has_no_data(monkeypatch):
+    calls = _fake_gtex(monkeypatch, reference={"data": []})
+
+    records, error = await fetch_tissue_expression("ENSG00000000000")
+
+    assert (records, error) == ([], None)
+    assert len(calls) == 1
+    assert "No GTEx expression data" in format_expression([])
+
+
+def test_format_expression_marks_low_expression():
+    text = format_expression([
+        TissueExpression(tissue="Testis", level=0.4),
+        TissueExpression(tissue="Liver", level=0.1),
+    ])
+
+    assert "Low expression: no tissue reaches 1 TPM." in text
+    assert "| 1 | Testis | 0.4 TPM |" in text
+
+
+@pytest.mark.asyncio
+async def test_get_gene_with_expression(monkeypatch):
+    _fake_gtex(monkeypatch)
+
+    data = json.loads(
+        await get_gene("673", output_json=True, include_expression=True)
+    )
+    assert data["expression"][0] == {
+        "tissue": "Brain Cerebellum",
+        "level": 31.7,
+        "unit": "TPM",
+    }
+
+    text = await get_gene("673", include_expression=True)
+    assert "## Tissue Expression (GTEx)" in text
+    assert "| 1 | Brain Cerebellum | 31.7 TPM |" in text
+    assert "Low expression" not in text
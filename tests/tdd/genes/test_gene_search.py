@@ -0,0 +1,561 @@
+"""Unit tests for filtered gene search."""
+
+import json
+
+import pytest
+
+from czechmedmcp.genes.associations import MAX_ASSOCIATIONS
+from czechmedmcp.genes.search import (
+    MYGENE_MAX_SIZE,
+    GeneMatch,
+    GeneSearchFilters,
+    GoMode,
+    format_query_summary,
+    go_query_clause,
+    matches_filters,
+    mygene_query_term,
+    normalize_go_id,
+    normalize_pathway,
+    pathway_query_clause,
+    resolve_pathways,
+    search_genes,
+)
+
+
+class TestGeneSummaryFilters:
+    """Test summary-based post-filtering."""
+
+    def test_has_summary_drops_empty_summaries(self):
+        filters = GeneSearchFilters(has_summary=True)
+        assert matches_filters({"summary": "Tumor suppressor."}, filters)
+        assert not matches_filters({"summary": "  "}, filters)
+        assert not matches_filters({"symbol": "LOC1"}, filters)
+
+    def test_min_summary_length(self):
+        filters = GeneSearchFilters(min_summary_length=20)
+        assert not matches_filters({"summary": "Short stub."}, filters)
+        assert matches_filters({"summary": "x" * 20}, filters)
+
+    def test_no_filters_keep_everything(self):
+        filters = GeneSearchFilters()
+        assert filters.is_empty()
+        assert matches_filters({}, filters)
+
+    def test_query_summary(self):
+        assert format_query_summary(
+            "kinase", GeneSearchFilters(has_summary=True)
+        ) == ("Query: kinase | Has summary")
+        assert format_query_summary(
+            "kinase", GeneSearchFilters(min_summary_length=50)
+        ) == ("Query: kinase | Min summary length: 50")
+
+    @pytest.mark.asyncio
+    async def test_search_genes_single_request(self, monkeypatch):
+        """Filtering uses the search response without extra lookups."""
+        calls = []
+
+        async def mock_request_api(url, request, method, domain):
+            calls.append(request)
+            return (
+                {
+                    "total": 2,
+                    "hits": [
+                        {"_id": "7157", "symbol": "TP53", "summary": "Long"},
+                        {"_id": "1", "symbol": "LOC1"},
+                    ],
+                },
+                None,
+            )
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_genes(
+            "p53", GeneSearchFilters(has_summary=True), output_json=True
+        )
+        data = json.loads(result)
+
+        assert len(calls) == 1
+        assert "summary" in calls[0]["fields"]
+        assert [r["symbol"] for r in data["results"]] == ["TP53"]
+
+
+class TestGeneMatch:
+    """Test query-term construction for --symbol-only and --name-only."""
+
+    def test_auto_passes_query_through(self):
+        assert mygene_query_term("TP53") == "TP53"
+        assert mygene_query_term("tumor protein") == "tumor protein"
+
+    def test_symbol_only_builds_symbol_term(self):
+        assert mygene_query_term("CAT", GeneMatch.SYMBOL) == "symbol:CAT"
+        assert (
+            mygene_query_term(" HLA-A ", GeneMatch.SYMBOL)
+            == "symbol:HLA\\-A"
+        )
+
+    def test_symbol_only_rejects_multiple_words(self):
+        with pytest.raises(ValueError, match="single gene symbol"):
+            mygene_query_term("tumor protein", GeneMatch.SYMBOL)
+
+    def test_name_only_builds_name_term(self):
+        assert (
+            mygene_query_term("tumor protein p53", GeneMatch.NAME)
+            == "name:(tumor protein p53)"
+        )
+        assert mygene_query_term("CAT", GeneMatch.NAME) == "name:(CAT)"
+
+    def test_query_summary_shows_match_mode(self):
+        assert format_query_summary(
+            "CAT", GeneSearchFilters(), GeneMatch.SYMBOL
+        ) == ("Query: CAT | Match: symbol only")
+
+    @pytest.mark.asyncio
+    async def test_search_genes_sends_symbol_term(self, monkeypatch):
+        calls = []
+
+        async def mock_request_api(url, request, method, domain):
+            calls.append(request)
+            return {"total": 0, "hits": []}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        await search_genes("CAT", match=GeneMatch.SYMBOL)
+        assert calls[0]["q"] == "symbol:CAT"
+
+        result = await search_genes(
+            "two words", match=GeneMatch.SYMBOL, output_json=True
+        )
+        assert "single gene symbol" in json.loads(result)["error"]
+        assert len(calls) == 1
+
+
+class TestGeneStructureFilter:
+    """Test the PDB structure filter."""
+
+    def test_query_summary(self):
+        assert format_query_summary(
+            "kinase", GeneSearchFilters(has_structure=True)
+        ) == ("Query: kinase | Has PDB structure")
+
+    @pytest.mark.asyncio
+    async def test_search_genes_adds_structure_clause(self, monkeypatch):
+        requests = []
+
+        async def mock_request_api(url, request, method, domain):
+            requests.append((domain, request))
+            return (
+                {"total": 12, "hits": [{"_id": "673", "symbol": "BRAF"}]},
+                None,
+            )
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = json.loads(
+            await search_genes(
+                "kinase",
+                filters=GeneSearchFilters(has_structure=True),
+                page=2,
+                page_size=1,
+                output_json=True,
+            )
+        )
+
+        assert [domain for domain, _ in requests] == ["mygene"]
+        request = requests[0][1]
+        assert request["q"] == "(kinase) AND _exists_:pdb"
+        assert request["size"] == 1
+        assert request["from"] == 1
+        assert [r["symbol"] for r in result["results"]] == ["BRAF"]
+        assert result["total"] == 12
+        assert result["query_summary"] == "Query: kinase | Has PDB structure"
+
+
+class TestGeneStableOrder:
+    """Test the deterministic tie-break of equal relevance scores."""
+
+    HITS = [
+        {"_id": "7157", "_score": 5.0, "entrezgene": 7157, "symbol": "TP53"},
+        {"_id": "673", "_score": 9.0, "entrezgene": 673, "symbol": "BRAF"},
+        {"_id": "1956", "_score": 5.0, "entrezgene": 1956, "symbol": "EGFR"},
+    ]
+
+    @pytest.mark.asyncio
+    async def test_ties_are_ordered_by_entrez_id(self, monkeypatch):
+        async def mock_request_api(url, request, method, domain):
+            return {"total": 3, "hits": list(self.HITS)}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = json.loads(
+            await search_genes(
+                "kinase", match=GeneMatch.NAME, output_json=True
+            )
+        )
+        assert [r["symbol"] for r in result["results"]] == [
+            "BRAF",
+            "EGFR",
+            "TP53",
+        ]
+
+        result = json.loads(
+            await search_genes(
+                "kinase",
+                match=GeneMatch.NAME,
+                output_json=True,
+                stable_sort=False,
+            )
+        )
+        assert [r["symbol"] for r in result["results"]] == [
+            "TP53",
+            "BRAF",
+            "EGFR",
+        ]
+
+
+class TestGeneGoFilter:
+    """Test GO term filters built into the MyGene.info query."""
+
+    APOPTOSIS = (
+        '(go.BP.id:"GO:0006915" OR go.MF.id:"GO:0006915" '
+        'OR go.CC.id:"GO:0006915")'
+    )
+    MITOCHONDRION = (
+        '(go.BP.id:"GO:0005739" OR go.MF.id:"GO:0005739" '
+        'OR go.CC.id:"GO:0005739")'
+    )
+
+    def test_normalize_go_id(self):
+        assert normalize_go_id("GO:0006915") == "GO:0006915"
+        assert normalize_go_id(" go_0006915 ") == "GO:0006915"
+        assert normalize_go_id("0006915") == "GO:0006915"
+        with pytest.raises(ValueError, match="Invalid GO ID"):
+            normalize_go_id("GO:123")
+        with pytest.raises(ValueError, match="Invalid GO ID"):
+            GeneSearchFilters(go_ids=["apoptosis"])
+
+    def test_single_term_ignores_mode(self):
+        assert go_query_clause(["GO:0006915"]) == self.APOPTOSIS
+        assert go_query_clause(["GO:0006915"], GoMode.OR) == self.APOPTOSIS
+
+    def test_and_mode(self):
+        assert go_query_clause(["GO:0006915", "GO:0005739"]) == (
+            f"({self.APOPTOSIS} AND {self.MITOCHONDRION})"
+        )
+
+    def test_or_mode(self):
+        clause = go_query_clause(["GO:0006915", "GO:0005739"], GoMode.OR)
+        assert clause == f"({self.APOPTOSIS} OR {self.MITOCHONDRION})"
+
+    def test_query_summary(self):
+        filters = GeneSearchFilters(
+            go_ids=["GO:0006915", "0005739"], go_mode=GoMode.OR
+        )
+        assert not filters.is_empty()
+        assert format_query_summary("kinase", filters) == (
+            "Query: kinase | GO: GO:0006915 OR GO:0005739"
+        )
+
+    @pytest.mark.asyncio
+    async def test_search_genes_adds_go_clause(self, monkeypatch):
+        requests = []
+
+        async def mock_request_api(url, request, method, domain):
+            requests.append(request)
+            return {"total": 0, "hits": []}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        await search_genes(
+            "kinase",
+            filters=GeneSearchFilters(go_ids=["GO:0006915", "GO:0005739"]),
+            output_json=True,
+        )
+        assert requests[0]["q"] == (
+            f"(kinase) AND ({self.APOPTOSIS} AND {self.MITOCHONDRION})"
+        )
+
+
+class TestGeneDiseaseFilter:
+    """Test the Open Targets disease association filter."""
+
+    TARGETS = {
+        "disease": {
+            "id": "MONDO_0005105",
+            "name": "melanoma",
+            "associatedTargets": {
+                "rows": [
+                    {
+                        "target": {
+                            "id": "ENSG00000157764",
+                            "approvedSymbol": "BRAF",
+                        },
+                        "score": 0.82,
+                    },
+                    {
+                        "target": {
+                            "id": "ENSG00000133703",
+                            "approvedSymbol": "KRAS",
+                        },
+                        "score": 0.31,
+                    },
+                    {
+                        "target": {
+                            "id": "ENSG00000147889",
+                            "approvedSymbol": "CDKN2A",
+                        },
+                        "score": 0.9,
+                    },
+                ]
+            },
+        }
+    }
+
+    def test_query_summary(self):
+        filters = GeneSearchFilters(disease="melanoma", min_score=0.5)
+        assert not filters.is_empty()
+        assert format_query_summary("*", filters) == (
+            "Query: * | Associated with: melanoma | "
+            "Min association score: 0.5"
+        )
+
+    def test_min_score_requires_disease(self):
+        with pytest.raises(ValueError, match="requires a disease"):
+            GeneSearchFilters(min_score=0.5)
+
+    @pytest.mark.asyncio
+    async def test_search_genes_restricts_to_associated_genes(
+        self, monkeypatch
+    ):
+        requests = []
+
+        async def mock_request_api(url, request, method, domain):
+            requests.append((domain, request))
+            if domain == "opentargets":
+                if "search(" in request["query"]:
+                    hits = [{"id": "MONDO_0005105", "name": "melanoma"}]
+                    return {"data": {"search": {"hits": hits}}}, None
+                return {"data": self.TARGETS}, None
+            return {
+                "total": 2,
+                "hits": [
+                    {
+                        "_id": "673",
+                        "symbol": "BRAF",
+                        "ensembl": {"gene": "ENSG00000157764"},
+                    },
+                    {
+                        "_id": "1029",
+                        "symbol": "CDKN2A",
+                        "ensembl": [{"gene": "ENSG00000147889"}],
+                    },
+                ],
+            }, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_genes(
+            "*",
+            filters=GeneSearchFilters(disease="melanoma", min_score=0.5),
+            output_json=True,
+        )
+
+        lookup, targets, mygene = requests
+        assert lookup[1]["variables"] == {"name": "melanoma"}
+        assert targets[1]["variables"]["efoId"] == "MONDO_0005105"
+        # KRAS falls below the minimum score
+        assert mygene[1]["q"] == (
+            "(*) AND ensembl.gene:(ENSG00000147889 OR ENSG00000157764)"
+        )
+        assert mygene[1]["size"] == MYGENE_MAX_SIZE
+        assert mygene[1]["from"] == 0
+        records = json.loads(result)["results"]
+        assert [(r["symbol"], r["association_score"]) for r in records] == [
+            ("CDKN2A", 0.9),
+            ("BRAF", 0.82),
+        ]
+
+        # Later pages continue the ranking over all associated genes
+        requests.clear()
+        result = await search_genes(
+            "*",
+            filters=GeneSearchFilters(disease="melanoma", min_score=0.5),
+            page=2,
+            page_size=1,
+            output_json=True,
+        )
+
+        payload = json.loads(result)
+        assert requests[-1][1]["from"] == 0
+        assert payload["total"] == 2
+        assert [r["symbol"] for r in payload["results"]] == ["BRAF"]
+
+    @pytest.mark.asyncio
+    async def test_capped_associations_report_upstream_total(
+        self, monkeypatch
+    ):
+        rows = [
+            {
+                "target": {"id": f"ENSG{i}", "approvedSymbol": f"G{i}"},
+                "score": 0.9 - i / 1000,
+            }
+            for i in range(MAX_ASSOCIATIONS)
+        ]
+        targets = {"disease": {"associatedTargets": {"count": 250}}}
+        targets["disease"]["associatedTargets"]["rows"] = rows
+
+        async def mock_request_api(url, request, method, domain):
+            if domain == "opentargets":
+                return {"data": targets}, None
+            hit = {"_id": "1", "symbol": "G0", "ensembl": {"gene": "ENSG0"}}
+            return {"total": 1, "hits": [hit]}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        filters = GeneSearchFilters(disease="MONDO:0005105")
+        payload = json.loads(
+            await search_genes("*", filters=filters, output_json=True)
+        )
+        assert payload["upstream_total"] == 250
+        assert payload["returned"] == 1
+        assert "Top 100 of 250 associated genes" in payload["query_summary"]
+
+        text = await search_genes("*", filters=filters)
+        assert "250 upstream matches" in text
+
+    @pytest.mark.asyncio
+    async def test_disease_id_skips_name_lookup(self, monkeypatch):
+        requests = []
+
+        async def mock_request_api(url, request, method, domain):
+            requests.append(request)
+            return {"data": {"disease": None}}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_genes(
+            "kinase", filters=GeneSearchFilters(disease="MONDO:0000001")
+        )
+
+        assert requests[0]["variables"]["efoId"] == "MONDO_0000001"
+        assert result == (
+            "Error: Disease MONDO_0000001 not found in Open Targets"
+        )
+
+
+class TestGenePathwayFilter:
+    """Test the Reactome pathway filter."""
+
+    APOPTOSIS = 'pathway.reactome.id:"R-HSA-109581"'
+    AUTOPHAGY = 'pathway.reactome.id:"R-HSA-9612973"'
+
+    def test_normalize_pathway(self):
+        assert normalize_pathway(" r-hsa-109581 ") == "R-HSA-109581"
+        assert normalize_pathway("R-MMU-109581.2") == "R-MMU-109581.2"
+        assert normalize_pathway(" Apoptosis ") == "Apoptosis"
+        filters = GeneSearchFilters(pathways=["r-hsa-109581", " "])
+        assert filters.pathways == ["R-HSA-109581"]
+        assert not filters.is_empty()
+
+    def test_pathway_query_clause(self):
+        assert pathway_query_clause(["R-HSA-109581"]) == self.APOPTOSIS
+        assert pathway_query_clause(["R-HSA-109581", "R-HSA-9612973"]) == (
+            f"({self.APOPTOSIS} OR {self.AUTOPHAGY})"
+        )
+
+    def test_query_summary(self):
+        filters = GeneSearchFilters(pathways=["R-HSA-109581", "autophagy"])
+        assert format_query_summary("*", filters) == (
+            "Query: * | Pathway: R-HSA-109581 OR autophagy"
+        )
+        resolved = {
+            "R-HSA-109581": {"id": "R-HSA-109581", "name": None},
+            "autophagy": {"id": "R-HSA-9612973", "name": "Autophagy"},
+        }
+        assert format_query_summary("*", filters, pathways=resolved) == (
+            "Query: * | Pathway: R-HSA-109581 OR Autophagy (R-HSA-9612973)"
+        )
+
+    @pytest.mark.asyncio
+    async def test_search_genes_resolves_names(self, monkeypatch):
+        requests = []
+
+        async def mock_request_api(url, request, method, domain):
+            requests.append((domain, request))
+            if domain == "reactome":
+                entry = {"stId": "R-HSA-9612973", "name": "Autophagy"}
+                return {"results": [{"entries": [entry]}]}, None
+            return {"total": 0, "hits": []}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_genes(
+            "*",
+            filters=GeneSearchFilters(pathways=["R-HSA-109581", "autophagy"]),
+            output_json=True,
+        )
+
+        # Only the name needs a Reactome lookup
+        lookup, mygene = requests
+        assert lookup[1]["query"] == "autophagy"
+        assert lookup[1]["species"] == "Homo sapiens"
+        assert mygene[1]["q"] == (
+            f"(*) AND ({self.APOPTOSIS} OR {self.AUTOPHAGY})"
+        )
+        assert json.loads(result)["query_summary"] == (
+            "Query: * | Pathway: R-HSA-109581 OR Autophagy (R-HSA-9612973)"
+        )
+
+    @pytest.mark.asyncio
+    async def test_resolve_pathways_keeps_input_order(self, monkeypatch):
+        names = {"apoptosis": "R-HSA-109581", "autophagy": "R-HSA-9612973"}
+
+        async def mock_request_api(url, request, method, domain):
+            query = request["query"]
+            entry = {"stId": names[query], "name": query.title()}
+            return {"results": [{"entries": [entry]}]}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        resolved, error = await resolve_pathways(["autophagy", "apoptosis"])
+        assert error is None
+        assert list(resolved) == ["autophagy", "apoptosis"]
+        assert resolved["apoptosis"] == {
+            "id": "R-HSA-109581",
+            "name": "Apoptosis",
+        }
+
+    @pytest.mark.asyncio
+    async def test_unknown_pathway_name_is_an_error(self, monkeypatch):
+        async def mock_request_api(url, request, method, domain):
+            return {"results": []}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+
+        result = await search_genes(
+            "*", filters=GeneSearchFilters(pathways=["no such pathway"])
+        )
+        assert result == (
+            "Error: No Reactome pathway found for 'no such pathway'"
+        )
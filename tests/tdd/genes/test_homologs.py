@@ -0,0 +1,118 @@
+"""Tests for Open Targets paralogs and orthologs of a gene."""
+
+import json
+
+import pytest
+
+from czechmedmcp.genes import get_gene
+from czechmedmcp.genes.homologs import (
+    fetch_homologs,
+    format_homologs,
+    split_homologs,
+)
+
+HOMOLOGUES = [
+    {
+        "speciesName": "Mouse",
+        "homologyType": "ortholog_one2one",
+        "targetGeneId": "ENSMUSG00000041147",
+        "targetGeneSymbol": "Brca2",
+        "queryPercentageIdentity": 58.9,
+        "isHighConfidence": "1",
+    },
+    {
+        "speciesName": "Human",
+        "homologyType": "other_paralog",
+        "targetGeneId": "ENSG00000083093",
+        "targetGeneSymbol": "PALB2",
+        "queryPercentageIdentity": 4.2,
+        "isHighConfidence": None,
+    },
+    {
+        "speciesName": "Chimpanzee",
+        "homologyType": "ortholog_one2one",
+        "targetGeneId": "ENSPTRG00000005766",
+        "targetGeneSymbol": "",
+        "queryPercentageIdentity": 98.6,
+        "isHighConfidence": "1",
+    },
+]
+
+
+def _fake_opentargets(monkeypatch, homologues=HOMOLOGUES):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        if domain == "opentargets":
+            calls.append(request["variables"])
+            return {"data": {"target": {"homologues": homologues}}}, None
+        return {
+            "_id": "675",
+            "symbol": "BRCA2",
+            "ensembl": {"gene": "ENSG00000139618"},
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+@pytest.mark.asyncio
+async def test_fetch_homologs_splits_by_type(monkeypatch):
+    calls = _fake_opentargets(monkeypatch)
+
+    homologs, error = await fetch_homologs("ENSG00000139618")
+    paralogs, orthologs = split_homologs(homologs)
+
+    assert error is None
+    assert calls == [{"ensemblId": "ENSG00000139618"}]
+    assert [h.symbol for h in paralogs] == ["PALB2"]
+    assert paralogs[0].high_confidence is None
+    # Most identical first; a blank symbol falls back to the gene ID
+    assert [h.gene_id for h in orthologs] == [
+        "ENSPTRG00000005766",
+        "ENSMUSG00000041147",
+    ]
+    assert orthologs[0].symbol is None
+
+
+def test_format_homologs_without_results():
+    assert format_homologs("Orthologs", []) == (
+        "## Orthologs\n\nNo orthologs found."
+    )
+
+
+@pytest.mark.asyncio
+async def test_get_gene_with_paralogs_and_orthologs(monkeypatch):
+    _fake_opentargets(monkeypatch)
+
+    data = json.loads(
+        await get_gene("675", output_json=True, include_orthologs=True)
+    )
+    assert "paralogs" not in data
+    assert data["orthologs"][1]["species"] == "Mouse"
+
+    text = await get_gene("675", include_paralogs=True, include_orthologs=True)
+    assert "## Paralogs" in text
+    assert "| PALB2 | Human | other paralog | 4.2% |" in text
+    chimp = "| ENSPTRG00000005766 | Chimpanzee | ortholog one2one | 98.6% |"
+    assert chimp in text
+
+
+@pytest.mark.asyncio
+async def test_get_gene_paralogs_and_orthologs_share_one_lookup(monkeypatch):
+    calls = _fake_opentargets(monkeypatch)
+
+    data = json.loads(
+        await get_gene(
+            "675",
+            output_json=True,
+            include_paralogs=True,
+            include_orthologs=True,
+        )
+    )
+
+    assert calls == [{"ensemblId": "ENSG00000139618"}]
+    assert list(data)[-2:] == ["paralogs", "orthologs"]
+    assert [h["symbol"] for h in data["paralogs"]] == ["PALB2"]
@@ -2,12 +2,21 @@
 Unit tests for OpenFDA adverse events integration.
 """
 
+import json
 from unittest.mock import patch
 
 import pytest
 
 from czechmedmcp.openfda.adverse_events import (
+    AdministrationRoute,
+    PatientAgeGroup,
+    age_group_query,
+    age_group_range,
+    count_adverse_events,
     get_adverse_event,
+    parse_route,
+    route_code_label,
+    route_query,
     search_adverse_events,
 )
 
@@ -204,3 +213,400 @@ async def test_get_adverse_event_not_found():
 
         assert "NOTFOUND123" in result
         assert "not found" in result
+
+
+NARRATIVE_RESPONSE = {
+    "results": [
+        {
+            "safetyreportid": "87654321",
+            "patient": {
+                "reaction": [{"reactionmeddrapt": "RASH"}],
+                "summary": {
+                    "narrativeincludeclinical": (
+                        "Patient developed a rash two days after starting"
+                        " therapy."
+                    )
+                },
+            },
+        }
+    ]
+}
+
+
+@pytest.mark.asyncio
+async def test_get_adverse_event_narrative_only():
+    """Test narrative-only rendering of a report."""
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (NARRATIVE_RESPONSE, None)
+
+        result = await get_adverse_event("87654321", narrative_only=True)
+
+        assert "Event Narrative" in result
+        assert "rash two days after" in result
+        assert "Adverse Reactions" not in result
+
+
+@pytest.mark.asyncio
+async def test_get_adverse_event_json_includes_narrative():
+    """Test JSON output exposes the narrative field."""
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (NARRATIVE_RESPONSE, None)
+
+        result = await get_adverse_event("87654321", output_json=True)
+        data = json.loads(result)
+
+        assert data["narrative"].startswith("Patient developed a rash")
+        assert data["safetyreportid"] == "87654321"
+
+
+@pytest.mark.asyncio
+async def test_search_adverse_events_by_country():
+    """Test that the reporter country filter reaches the query."""
+    mock_response = {
+        "meta": {"results": {"total": 1}},
+        "results": [
+            {
+                "safetyreportid": "1",
+                "patient": {"reaction": [{"reactionmeddrapt": "NAUSEA"}]},
+            }
+        ],
+    }
+
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (mock_response, None)
+
+        result = await search_adverse_events(drug="imatinib", country="de")
+
+        params = mock_request.call_args[0][1]
+        assert 'primarysource.reportercountry:"DE"' in params["search"]
+        assert "**Reporter Country**: DE" in result
+
+
+@pytest.mark.asyncio
+async def test_search_adverse_events_rejects_bad_country():
+    """Test that malformed country codes are rejected before any request."""
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        result = await search_adverse_events(drug="imatinib", country="USA")
+
+        assert "Invalid country code 'USA'" in result
+        mock_request.assert_not_called()
+
+
+@pytest.mark.asyncio
+async def test_search_adverse_events_count_by_country():
+    """Test country distribution via the count aggregation."""
+    mock_response = {
+        "results": [
+            {"term": "US", "count": 1200},
+            {"term": "JP", "count": 45},
+        ]
+    }
+
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (mock_response, None)
+
+        result = await search_adverse_events(
+            drug="imatinib", count_by="country"
+        )
+
+        params = mock_request.call_args[0][1]
+        assert params["count"] == "primarysource.reportercountry.exact"
+        assert "skip" not in params
+        assert "### Reports by Country" in result
+        assert "| US | 1,200 |" in result
+
+
+@pytest.mark.asyncio
+async def test_search_adverse_events_by_indication():
+    """Test that the drug indication filter reaches the query and summary."""
+    mock_response = {
+        "meta": {"results": {"total": 1}},
+        "results": [
+            {
+                "safetyreportid": "1",
+                "patient": {"reaction": [{"reactionmeddrapt": "NAUSEA"}]},
+            }
+        ],
+    }
+
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (mock_response, None)
+
+        result = await search_adverse_events(
+            drug="metformin", indication="type 2 diabetes mellitus"
+        )
+
+        params = mock_request.call_args[0][1]
+        assert params["search"].endswith(
+            'AND patient.drug.drugindication:"type 2 diabetes mellitus"'
+        )
+        assert "**Indication**: type 2 diabetes mellitus" in result
+
+
+@pytest.mark.asyncio
+async def test_search_adverse_events_count_by_indication():
+    """Test indication distribution via the count aggregation."""
+    mock_response = {"results": [{"term": "HYPERTENSION", "count": 310}]}
+
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (mock_response, None)
+
+        result = await search_adverse_events(
+            drug="lisinopril", count_by="indication"
+        )
+
+        params = mock_request.call_args[0][1]
+        assert params["count"] == "patient.drug.drugindication.exact"
+        assert "### Reports by Indication" in result
+        assert "| HYPERTENSION | 310 |" in result
+
+
+def test_age_group_range():
+    """Test the mapping of age buckets to onset ages in years."""
+    assert age_group_range(PatientAgeGroup.NEONATE) is None
+    assert age_group_range(PatientAgeGroup.INFANT) == (0, 1)
+    assert age_group_range(PatientAgeGroup.CHILD) == (2, 11)
+    assert age_group_range(PatientAgeGroup.ADOLESCENT) == (12, 17)
+    assert age_group_range(PatientAgeGroup.ADULT) == (18, 64)
+    assert age_group_range(PatientAgeGroup.ELDERLY) == (65, None)
+
+
+def test_age_group_query():
+    """Test that buckets match the age group code or onset age."""
+    assert age_group_query(PatientAgeGroup.NEONATE) == (
+        'patient.patientagegroup:"1"'
+    )
+    assert age_group_query(PatientAgeGroup.ELDERLY) == (
+        '(patient.patientagegroup:"6" OR '
+        '(patient.patientonsetageunit:"801" AND '
+        "patient.patientonsetage:[65 TO *]))"
+    )
+
+
+@pytest.mark.asyncio
+async def test_search_adverse_events_by_age_group():
+    """Test that the age group reaches the query and summary."""
+    mock_response = {
+        "meta": {"results": {"total": 1}},
+        "results": [
+            {
+                "safetyreportid": "1",
+                "patient": {"reaction": [{"reactionmeddrapt": "RASH"}]},
+            }
+        ],
+    }
+
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (mock_response, None)
+
+        result = await search_adverse_events(
+            drug="amoxicillin", age_group=PatientAgeGroup.CHILD
+        )
+
+        params = mock_request.call_args[0][1]
+        assert params["search"].endswith("patient.patientonsetage:[2 TO 11]))")
+        assert "**Age Group**: child (2-11 years)" in result
+
+
+
+def test_parse_route():
+    """Test route names and abbreviations resolve to routes."""
+    assert parse_route("Oral") == AdministrationRoute.ORAL
+    assert parse_route("iv") == AdministrationRoute.INTRAVENOUS
+    assert parse_route(" SC ") == AdministrationRoute.SUBCUTANEOUS
+    assert parse_route("inhaled") == AdministrationRoute.INHALATION
+    with pytest.raises(ValueError, match="Unknown route 'nasogastric'"):
+        parse_route("nasogastric")
+
+
+def test_route_query():
+    """Test that routes map to FAERS administration route codes."""
+    assert route_query(AdministrationRoute.ORAL) == (
+        'patient.drug.drugadministrationroute:"048"'
+    )
+    assert route_query(AdministrationRoute.INTRAVENOUS) == (
+        '(patient.drug.drugadministrationroute:"040" OR '
+        'patient.drug.drugadministrationroute:"041" OR '
+        'patient.drug.drugadministrationroute:"042")'
+    )
+
+
+def test_route_code_label():
+    """Test that count buckets show the route name with its code."""
+    assert route_code_label("048") == "oral (048)"
+    assert route_code_label("041") == "intravenous drip (041)"
+    assert route_code_label("042") == "intravenous (042)"
+    assert route_code_label("999") == "999"
+
+
+@pytest.mark.asyncio
+async def test_search_adverse_events_by_route():
+    """Test that the route reaches the query and summary."""
+    mock_response = {
+        "meta": {"results": {"total": 1}},
+        "results": [
+            {
+                "safetyreportid": "1",
+                "patient": {"reaction": [{"reactionmeddrapt": "NAUSEA"}]},
+            }
+        ],
+    }
+
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (mock_response, None)
+
+        result = await search_adverse_events(
+            drug="methotrexate", route=AdministrationRoute.ORAL
+        )
+
+        params = mock_request.call_args[0][1]
+        assert params["search"].endswith(
+            'AND patient.drug.drugadministrationroute:"048"'
+        )
+        assert "**Route**: oral" in result
+
+
+@pytest.mark.asyncio
+async def test_search_adverse_events_count_by_route():
+    """Test route distribution via the count aggregation."""
+    mock_response = {
+        "results": [
+            {"term": "058", "count": 820},
+            {"term": "042", "count": 75},
+        ]
+    }
+
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (mock_response, None)
+
+        result = await search_adverse_events(
+            drug="methotrexate", count_by="route"
+        )
+
+        params = mock_request.call_args[0][1]
+        assert params["count"] == "patient.drug.drugadministrationroute"
+        assert "### Reports by Route" in result
+        assert "| subcutaneous (058) | 820 |" in result
+        assert "| intravenous (042) | 75 |" in result
+
+
+@pytest.mark.asyncio
+async def test_count_adverse_events_applies_all_filters():
+    """The shared count helper filters like the report search does."""
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (
+            {"results": [{"term": "048", "count": 12}]},
+            None,
+        )
+
+        buckets, error = await count_adverse_events(
+            "route",
+            drug="methotrexate",
+            indication="psoriasis",
+            age_group=PatientAgeGroup.ELDERLY,
+            route=AdministrationRoute.ORAL,
+        )
+
+    search = mock_request.call_args[0][1]["search"]
+    assert error is None
+    assert age_group_query(PatientAgeGroup.ELDERLY) in search
+    assert route_query(AdministrationRoute.ORAL) in search
+    assert 'patient.drug.drugindication:"psoriasis"' in search
+    assert buckets == [{"term": "oral (048)", "count": 12}]
+
+
+TIMELINE_RESPONSE = {
+    "results": [
+        {
+            "safetyreportid": "55555555",
+            "receiptdate": "20240320",
+            "receivedate": "20240401",
+            "patient": {
+                "drug": [
+                    {
+                        "medicinalproduct": "DRUG A",
+                        "drugstartdate": "20240110",
+                        "drugenddate": "20240205",
+                    },
+                    {"medicinalproduct": "DRUG B", "drugstartdate": "202312"},
+                    {"medicinalproduct": "DRUG C"},
+                ],
+                "reaction": [
+                    {
+                        "reactionmeddrapt": "HEPATOTOXICITY",
+                        "reactionstartdate": "20240201",
+                    }
+                ],
+            },
+        }
+    ]
+}
+
+
+@pytest.mark.asyncio
+async def test_get_adverse_event_timeline_json():
+    """Test the timeline dates and the intervals between them."""
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (TIMELINE_RESPONSE, None)
+
+        result = await get_adverse_event(
+            "55555555", output_json=True, timeline=True
+        )
+        timeline = json.loads(result)["timeline"]
+
+        # A partial date is the earliest start but yields no intervals
+        assert timeline["drug_start"] == "2023-12"
+        assert timeline["event_onset"] == "2024-02-01"
+        assert timeline["report_received"] == "2024-03-20"
+        assert timeline["intervals"] == {
+            "time_to_onset_days": None,
+            "onset_to_report_days": 48,
+            "start_to_report_days": None,
+        }
+        assert [d["drug"] for d in timeline["drugs"]] == ["DRUG A", "DRUG B"]
+
+
+@pytest.mark.asyncio
+async def test_get_adverse_event_timeline_markdown():
+    """Test the timeline section renders the temporal sequence."""
+    report = TIMELINE_RESPONSE["results"][0]
+    patient = {**report["patient"], "drug": report["patient"]["drug"][:1]}
+    response = {"results": [{**report, "patient": patient}]}
+    with patch(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request"
+    ) as mock_request:
+        mock_request.return_value = (response, None)
+
+        result = await get_adverse_event("55555555", timeline=True)
+
+        assert "### Timeline" in result
+        assert (
+            "Drug start (2024-01-10) → Event onset (2024-02-01) → "
+            "Report received (2024-03-20)"
+        ) in result
+        assert "- **Time to onset**: 22 days" in result
+        assert "- **DRUG A**: 2024-01-10 to 2024-02-05" in result
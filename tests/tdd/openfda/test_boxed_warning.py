@@ -0,0 +1,84 @@
+"""Tests for the boxed warning of a drug's FDA label."""
+
+import json
+from unittest.mock import patch
+
+import pytest
+
+from czechmedmcp.drugs.getter import get_drug
+from czechmedmcp.openfda.boxed_warning import (
+    format_boxed_warning,
+    get_boxed_warning,
+)
+
+LABEL = {
+    "set_id": "abc-123",
+    "openfda": {"brand_name": ["Avandia"]},
+    "boxed_warning": [
+        "WARNING: CONGESTIVE HEART FAILURE\n  Thiazolidinediones cause or "
+        "exacerbate congestive heart failure."
+    ],
+}
+
+REQUEST = "czechmedmcp.openfda.boxed_warning.make_openfda_request"
+
+
+class TestBoxedWarning:
+    """Test boxed warning retrieval and formatting."""
+
+    @pytest.mark.asyncio
+    async def test_get_boxed_warning(self):
+        with patch(REQUEST) as mock_request:
+            mock_request.return_value = ({"results": [LABEL]}, None)
+            warning, error = await get_boxed_warning("rosiglitazone")
+
+        assert error is None
+        assert warning["has_boxed_warning"] is True
+        assert warning["text"].startswith("WARNING: CONGESTIVE HEART FAILURE")
+        assert "  " not in warning["text"]
+        assert warning["brand_name"] == "Avandia"
+        params = mock_request.call_args[0][1]
+        assert "_exists_:boxed_warning" in params["search"]
+        assert 'openfda.generic_name:"rosiglitazone"' in params["search"]
+
+    @pytest.mark.asyncio
+    async def test_no_matching_label_means_no_boxed_warning(self):
+        with patch(REQUEST) as mock_request:
+            mock_request.return_value = (None, "No matches found!")
+            warning, error = await get_boxed_warning("ibuprofen")
+
+        assert error is None
+        assert warning == {"has_boxed_warning": False, "text": None}
+        assert format_boxed_warning(warning) == (
+            "### Boxed Warning\n\nNo boxed warning."
+        )
+
+    @pytest.mark.asyncio
+    async def test_get_boxed_warning_error(self):
+        with patch(REQUEST) as mock_request:
+            mock_request.return_value = (None, "API rate limit exceeded")
+            warning, error = await get_boxed_warning("rosiglitazone")
+
+        assert warning is None
+        assert "rate limit" in error
+
+    @pytest.mark.asyncio
+    async def test_get_drug_with_boxed_warning(self, monkeypatch):
+        async def mock_request_api(url, request, method, domain):
+            return {"_id": "CHEMBL121", "name": "Rosiglitazone"}, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", mock_request_api
+        )
+        with patch(REQUEST) as mock_request:
+            mock_request.return_value = ({"results": [LABEL]}, None)
+            data = json.loads(
+                await get_drug(
+                    "CHEMBL121", output_json=True, include_boxed_warning=True
+                )
+            )
+            text = await get_drug("CHEMBL121", include_boxed_warning=True)
+
+        assert data["boxed_warning"]["has_boxed_warning"] is True
+        assert text.startswith("### ⚠️ BOXED WARNING")
+        assert "_From Avandia (set ID abc-123)_" in text
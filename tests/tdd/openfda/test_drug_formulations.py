@@ -0,0 +1,136 @@
+"""Tests for marketed drug formulations from the NDC Directory."""
+
+from unittest.mock import patch
+
+import pytest
+
+from czechmedmcp.openfda.drug_formulations import (
+    format_formulations,
+    get_drug_formulations,
+    group_formulations,
+)
+from czechmedmcp.openfda.exceptions import (
+    OpenFDARateLimitError,
+    OpenFDATimeoutError,
+)
+
+NDC_PRODUCTS = [
+    {
+        "product_ndc": "0078-0401",
+        "brand_name": "Gleevec",
+        "labeler_name": "Novartis",
+        "dosage_form": "TABLET",
+        "route": ["ORAL"],
+        "active_ingredients": [
+            {"name": "IMATINIB MESYLATE", "strength": "100 mg/1"}
+        ],
+    },
+    {
+        "product_ndc": "0093-7629",
+        "brand_name": "Imatinib Mesylate",
+        "labeler_name": "Teva",
+        "dosage_form": "TABLET",
+        "route": ["ORAL"],
+        "active_ingredients": [
+            {"name": "IMATINIB MESYLATE", "strength": "100 mg/1"}
+        ],
+    },
+    {
+        "product_ndc": "0078-0438",
+        "brand_name": "Gleevec",
+        "labeler_name": "Novartis",
+        "dosage_form": "TABLET",
+        "route": ["ORAL"],
+        "active_ingredients": [
+            {"name": "IMATINIB MESYLATE", "strength": "400 mg/1"}
+        ],
+    },
+    {
+        "product_ndc": "9999-0001",
+        "brand_name": "Imkeldi",
+        "labeler_name": "Shorla",
+        "dosage_form": "SOLUTION",
+        "route": ["ORAL"],
+        "active_ingredients": [
+            {"name": "IMATINIB MESYLATE", "strength": "80 mg/mL"}
+        ],
+    },
+]
+
+
+class TestDrugFormulations:
+    """Test formulation grouping and retrieval."""
+
+    def test_group_formulations_merges_products(self):
+        formulations = group_formulations(NDC_PRODUCTS)
+
+        assert len(formulations) == 3
+        tablet_100 = formulations[1]
+        assert tablet_100["dosage_form"] == "TABLET"
+        assert tablet_100["strength"] == "IMATINIB MESYLATE 100 mg/1"
+        assert tablet_100["brand_names"] == ["Gleevec", "Imatinib Mesylate"]
+        assert tablet_100["labelers"] == ["Novartis", "Teva"]
+        assert tablet_100["product_count"] == 2
+
+    def test_group_formulations_route_filter(self):
+        products = [
+            *NDC_PRODUCTS,
+            {
+                "brand_name": "Injectable",
+                "dosage_form": "INJECTION",
+                "route": ["INTRAVENOUS"],
+            },
+        ]
+        assert len(group_formulations(products)) == 4
+        oral = group_formulations(products, route="oral")
+        assert {f["route"] for f in oral} == {"ORAL"}
+        assert group_formulations(products, route="TOPICAL") == []
+
+    @pytest.mark.asyncio
+    async def test_get_drug_formulations(self):
+        with patch(
+            "czechmedmcp.openfda.drug_formulations.make_openfda_request"
+        ) as mock_request:
+            mock_request.return_value = ({"results": NDC_PRODUCTS}, None)
+            formulations, error = await get_drug_formulations("imatinib")
+
+        assert error is None
+        assert len(formulations) == 3
+        params = mock_request.call_args[0][1]
+        assert 'generic_name:"imatinib"' in params["search"]
+
+    @pytest.mark.asyncio
+    async def test_get_drug_formulations_error(self):
+        with patch(
+            "czechmedmcp.openfda.drug_formulations.make_openfda_request"
+        ) as mock_request:
+            mock_request.return_value = (None, "API rate limit exceeded")
+            formulations, error = await get_drug_formulations("imatinib")
+
+        assert formulations is None
+        assert "rate limit" in error
+
+    @pytest.mark.asyncio
+    async def test_get_drug_formulations_raised_errors(self):
+        for exc, message in (
+            (OpenFDARateLimitError(), "rate limit exceeded"),
+            (OpenFDATimeoutError(), "timed out"),
+        ):
+            with patch(
+                "czechmedmcp.openfda.drug_formulations.make_openfda_request"
+            ) as mock_request:
+                mock_request.side_effect = exc
+                formulations, error = await get_drug_formulations("imatinib")
+
+            assert formulations is None
+            assert message in error
+
+    def test_format_formulations(self):
+        markdown = format_formulations(
+            group_formulations(NDC_PRODUCTS), route="oral"
+        )
+        assert markdown.startswith("### Formulations (ORAL)")
+        assert "| ORAL | SOLUTION | IMATINIB MESYLATE 80 mg/mL |" in markdown
+        assert "FDA Data Notice" in markdown
+
+        assert "No marketed formulations" in format_formulations([])
@@ -0,0 +1,226 @@
+"""Tests for Reactome pathway retrieval."""
+
+import json
+
+import pytest
+
+from czechmedmcp.pathways.getter import (
+    get_pathway,
+    is_reactome_id,
+    parent_pathways,
+    parse_reaction,
+    pathway_diagram_url,
+)
+
+PATHWAY = {
+    "stId": "R-HSA-5673001",
+    "displayName": "RAF/MAP kinase cascade",
+    "speciesName": "Homo sapiens",
+    "summation": [{"text": "The RAS-RAF-MEK-ERK pathway..."}],
+    "hasEvent": [
+        {
+            "stId": "R-HSA-5672965",
+            "displayName": "RAS GEFs promote RAS nucleotide exchange",
+            "schemaClass": "Reaction",
+        },
+        {
+            "stId": "R-HSA-5674135",
+            "displayName": "MAP2K and MAPK activation",
+            "schemaClass": "Pathway",
+        },
+    ],
+}
+
+REACTION = {
+    "stId": "R-HSA-5672965",
+    "displayName": "RAS GEFs promote RAS nucleotide exchange",
+    "input": [
+        {"displayName": "RAS:GDP [plasma membrane]"},
+        {"displayName": "GTP [cytosol]"},
+    ],
+    "output": [
+        {"displayName": "RAS:GTP [plasma membrane]"},
+        {"displayName": "GDP [cytosol]"},
+        12345,
+    ],
+    "catalystActivity": [
+        {"physicalEntity": {"displayName": "RAS GEFs [plasma membrane]"}}
+    ],
+}
+
+
+SIGNALING = {
+    "stId": "R-HSA-162582",
+    "displayName": "Signal Transduction",
+    "schemaClass": "TopLevelPathway",
+}
+
+MAPK = {
+    "stId": "R-HSA-5683057",
+    "displayName": "MAPK family signaling cascades",
+    "schemaClass": "Pathway",
+}
+
+ANCESTORS = [
+    [
+        {"stId": "R-HSA-5673001", "displayName": "RAF/MAP kinase cascade"},
+        {"stId": "R-HSA-5684996", "displayName": "MAPK1/MAPK3 signaling"},
+        MAPK,
+        SIGNALING,
+    ],
+    [
+        {"stId": "R-HSA-5673001", "displayName": "RAF/MAP kinase cascade"},
+        {"stId": "R-HSA-5684996", "displayName": "MAPK1/MAPK3 signaling"},
+        {"stId": "R-HSA-9006925", "displayName": "Intracellular signaling"},
+        SIGNALING,
+    ],
+]
+
+
+def _mock_reactome(calls):
+    async def mock_request_api(url, request, method, domain):
+        calls.append(url)
+        if url.endswith("/ancestors"):
+            return ANCESTORS, None
+        identifier = url.rsplit("/", 1)[-1]
+        return {"R-HSA-5673001": PATHWAY, "R-HSA-5672965": REACTION}[
+            identifier
+        ], None
+
+    return mock_request_api
+
+
+def test_is_reactome_id():
+    assert is_reactome_id("R-HSA-5673001")
+    assert is_reactome_id("r-hsa-5673001")
+    assert not is_reactome_id("hsa04010")
+
+
+def test_parse_reaction_collects_participants():
+    reaction = parse_reaction(REACTION)
+
+    assert reaction.inputs == ["RAS:GDP [plasma membrane]", "GTP [cytosol]"]
+    assert reaction.outputs == ["RAS:GTP [plasma membrane]", "GDP [cytosol]"]
+    assert reaction.catalysts == ["RAS GEFs [plasma membrane]"]
+
+
+@pytest.mark.asyncio
+async def test_get_pathway_without_diagram_skips_reaction_lookups(
+    monkeypatch,
+):
+    calls = []
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", _mock_reactome(calls)
+    )
+
+    result = await get_pathway("R-HSA-5673001")
+
+    assert len(calls) == 1
+    assert "## Events" in result
+    assert "## Diagram" not in result
+
+
+@pytest.mark.asyncio
+async def test_get_pathway_diagram_json(monkeypatch):
+    calls = []
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", _mock_reactome(calls)
+    )
+
+    data = json.loads(
+        await get_pathway(
+            "R-HSA-5673001", include_diagram=True, output_json=True
+        )
+    )
+
+    # Only the Reaction is detailed; the sub-pathway is skipped
+    assert len(calls) == 2
+    diagram = data["diagram"]
+    assert diagram["diagram_url"] == pathway_diagram_url("R-HSA-5673001")
+    assert [r["st_id"] for r in diagram["reactions"]] == ["R-HSA-5672965"]
+    assert diagram["reactions"][0]["catalysts"] == [
+        "RAS GEFs [plasma membrane]"
+    ]
+
+
+@pytest.mark.asyncio
+async def test_get_pathway_diagram_markdown(monkeypatch):
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", _mock_reactome([])
+    )
+
+    result = await get_pathway("R-HSA-5673001", include_diagram=True)
+
+    assert "## Diagram" in result
+    assert "exporter/diagram/R-HSA-5673001.png" in result
+    assert "  - Catalysts: RAS GEFs [plasma membrane]" in result
+
+
+def test_parent_pathways_takes_entry_next_to_event():
+    parents = parent_pathways("R-HSA-5673001", ANCESTORS)
+
+    # Both chains share the same direct parent
+    assert parents == [
+        {
+            "id": "R-HSA-5684996",
+            "name": "MAPK1/MAPK3 signaling",
+            "path": [
+                "Signal Transduction",
+                "MAPK family signaling cascades",
+                "MAPK1/MAPK3 signaling",
+            ],
+        }
+    ]
+    # Chains listed from the top-level pathway down are handled too
+    assert parent_pathways("R-HSA-5683057", [[SIGNALING, MAPK]]) == [
+        {
+            "id": "R-HSA-162582",
+            "name": "Signal Transduction",
+            "path": ["Signal Transduction"],
+        }
+    ]
+    assert parent_pathways("R-HSA-162582", [[SIGNALING]]) == []
+
+
+@pytest.mark.asyncio
+async def test_get_pathway_hierarchy_json(monkeypatch):
+    calls = []
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", _mock_reactome(calls)
+    )
+
+    data = json.loads(
+        await get_pathway(
+            "R-HSA-5673001", output_json=True, parents=True, children=True
+        )
+    )
+
+    assert calls[-1].endswith("/data/event/R-HSA-5673001/ancestors")
+    assert [p["id"] for p in data["parents"]] == ["R-HSA-5684996"]
+    assert data["parents_error"] is None
+    # Only the sub-pathway event is a child; the reaction is not
+    assert data["children"] == [
+        {"id": "R-HSA-5674135", "name": "MAP2K and MAPK activation"}
+    ]
+
+
+@pytest.mark.asyncio
+async def test_get_pathway_hierarchy_markdown(monkeypatch):
+    calls = []
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", _mock_reactome(calls)
+    )
+
+    result = await get_pathway("R-HSA-5673001", children=True)
+
+    assert len(calls) == 1
+    assert "## Parent Pathways" not in result
+    assert "## Child Pathways" in result
+    assert "- MAP2K and MAPK activation (R-HSA-5674135)" in result
+
+    result = await get_pathway("R-HSA-5673001", parents=True)
+    assert "- MAPK1/MAPK3 signaling (R-HSA-5684996)" in result
+    assert (
+        "  - Path: Signal Transduction > MAPK family signaling cascades > "
+        "MAPK1/MAPK3 signaling"
+    ) in result
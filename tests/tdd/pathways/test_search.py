@@ -0,0 +1,86 @@
+"""Tests for Reactome pathway search."""
+
+import json
+
+import pytest
+
+from czechmedmcp.pathways.search import (
+    PathwaySearchFilters,
+    normalize_species,
+    pathway_search,
+    pathway_search_params,
+    search_query_summary,
+)
+
+RESULTS = {
+    "results": [
+        {
+            "typeName": "Pathway",
+            "entries": [
+                {
+                    "stId": "R-MMU-5683057",
+                    "name": '<span class="highlighting" >MAPK</span> '
+                    "family signaling cascades",
+                    "species": ["Mus musculus"],
+                },
+                {"name": "no identifier"},
+            ],
+        }
+    ]
+}
+
+
+def test_normalize_species_accepts_names_and_taxon_ids():
+    assert normalize_species("Mouse") == "Mus musculus"
+    assert normalize_species("10090") == "Mus musculus"
+    assert normalize_species("NCBITaxon:7955") == "Danio rerio"
+    assert normalize_species("drosophila melanogaster") == (
+        "Drosophila melanogaster"
+    )
+    with pytest.raises(ValueError, match="Unknown species"):
+        normalize_species("unicorn")
+
+
+def test_query_constrained_to_species():
+    human = PathwaySearchFilters(query="MAPK")
+    mouse = PathwaySearchFilters(query="MAPK", species="mouse", limit=5)
+
+    assert pathway_search_params(human)["species"] == "Homo sapiens"
+    assert pathway_search_params(mouse) == {
+        "query": "MAPK",
+        "species": "Mus musculus",
+        "types": "Pathway",
+        "cluster": "true",
+        "rows": 5,
+    }
+    assert search_query_summary(mouse) == (
+        "Query: MAPK | Species: Mus musculus (taxon 10090)"
+    )
+
+
+@pytest.mark.asyncio
+async def test_pathway_search_strips_highlighting(monkeypatch):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        calls.append((url, request))
+        return RESULTS, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    filters = PathwaySearchFilters(query="MAPK", species="10090")
+
+    data = json.loads(await pathway_search(filters, output_json=True))
+
+    assert calls[0][0].endswith("/search/query")
+    assert data["pathways"] == [
+        {
+            "id": "R-MMU-5683057",
+            "name": "MAPK family signaling cascades",
+            "species": "Mus musculus",
+            "url": "https://reactome.org/PathwayBrowser/#/R-MMU-5683057",
+        }
+    ]
+    text = await pathway_search(filters)
+    assert "- **MAPK family signaling cascades** (R-MMU-5683057)" in text
@@ -0,0 +1,94 @@
+"""Tests for CPIC pharmacogenomic recommendation search."""
+
+import json
+
+import pytest
+
+from czechmedmcp.pgx.search import (
+    MetabolizerPhenotype,
+    PgxSearchFilters,
+    matches_phenotype,
+    pgx_search,
+    recommendation_params,
+    search_query_summary,
+)
+
+POOR = {
+    "drugname": "codeine",
+    "guidelinename": "CYP2D6, OPRM1, COMT and Opioids",
+    "guidelineurl": "https://cpicpgx.org/guidelines/guideline-for-codeine",
+    "phenotypes": {"CYP2D6": "Poor Metabolizer"},
+    "drugrecommendation": "Avoid codeine use because of lack of efficacy.",
+    "classification": "Strong",
+}
+ULTRARAPID = {
+    **POOR,
+    "phenotypes": {"CYP2D6": "Ultrarapid Metabolizer"},
+    "drugrecommendation": "Avoid codeine use because of potential for "
+    "serious toxicity.",
+}
+
+
+def test_filters_need_gene_or_drug():
+    with pytest.raises(ValueError, match="specify a gene or a drug"):
+        PgxSearchFilters(phenotype="poor")
+    filters = PgxSearchFilters(gene=" cyp2d6 ", drug="Codeine")
+    assert (filters.gene, filters.drug) == ("CYP2D6", "codeine")
+
+
+def test_recommendation_params():
+    params = recommendation_params(
+        PgxSearchFilters(gene="CYP2D6", phenotype="poor")
+    )
+    assert params["phenotypes->>CYP2D6"] == "eq.Poor Metabolizer"
+    assert "drugname" not in params
+
+    params = recommendation_params(PgxSearchFilters(drug="codeine"))
+    assert params["drugname"] == "eq.codeine"
+    assert not any(key.startswith("phenotypes") for key in params)
+
+    params = recommendation_params(PgxSearchFilters(gene="CYP2C19"))
+    assert params["phenotypes->>CYP2C19"] == "not.is.null"
+
+
+def test_matches_phenotype():
+    filters = PgxSearchFilters(drug="codeine", phenotype="ultrarapid")
+    assert matches_phenotype(ULTRARAPID, filters)
+    assert not matches_phenotype(POOR, filters)
+    assert matches_phenotype(POOR, PgxSearchFilters(drug="codeine"))
+
+    filters = PgxSearchFilters(gene="CYP2C19", phenotype="poor")
+    assert not matches_phenotype(POOR, filters)
+
+
+def test_search_query_summary():
+    filters = PgxSearchFilters(
+        drug="codeine", phenotype=MetabolizerPhenotype.ULTRARAPID
+    )
+    assert search_query_summary(filters) == (
+        "Drug: codeine | Phenotype: Ultrarapid Metabolizer"
+    )
+
+
+@pytest.mark.asyncio
+async def test_pgx_search_filters_by_phenotype(monkeypatch):
+    requests = []
+
+    async def fake_request_api(url, request, method, domain):
+        requests.append(request)
+        return [POOR, ULTRARAPID], None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    filters = PgxSearchFilters(drug="codeine", phenotype="ultrarapid")
+    data = json.loads(await pgx_search(filters, output_json=True))
+    assert requests[0]["drugname"] == "eq.codeine"
+    assert [r["phenotypes"] for r in data["results"]] == [
+        {"CYP2D6": "Ultrarapid Metabolizer"}
+    ]
+
+    text = await pgx_search(filters)
+    assert "## codeine (CYP2D6: Ultrarapid Metabolizer)" in text
+    assert "- **Strength**: Strong" in text
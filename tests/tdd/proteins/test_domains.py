@@ -0,0 +1,126 @@
+"""Tests for protein domain retrieval."""
+
+import json
+
+import pytest
+
+from czechmedmcp.proteins.domains import (
+    get_protein_domains,
+    is_uniprot_accession,
+    parse_interpro_domains,
+)
+
+INTERPRO_RESPONSE = {
+    "count": 3,
+    "results": [
+        {
+            "metadata": {
+                "accession": "PF07714",
+                "name": "Protein tyrosine and serine/threonine kinase",
+                "source_database": "pfam",
+                "type": "domain",
+            },
+            "proteins": [
+                {
+                    "accession": "p15056",
+                    "entry_protein_locations": [
+                        {"fragments": [{"start": 457, "end": 712}]}
+                    ],
+                }
+            ],
+        },
+        {
+            "metadata": {
+                "accession": "IPR003116",
+                "name": "Raf-like Ras-binding domain",
+                "source_database": "interpro",
+                "type": "domain",
+            },
+            "proteins": [
+                {
+                    "accession": "p15056",
+                    "entry_protein_locations": [
+                        {"fragments": [{"start": 155, "end": 227}]}
+                    ],
+                }
+            ],
+        },
+        {
+            "metadata": {
+                "accession": "IPR000719",
+                "name": "Protein kinase family",
+                "source_database": "interpro",
+                "type": "family",
+            },
+            "proteins": [
+                {
+                    "accession": "p15056",
+                    "entry_protein_locations": [
+                        {"fragments": [{"start": 1, "end": 766}]}
+                    ],
+                }
+            ],
+        },
+    ],
+}
+
+
+def test_is_uniprot_accession():
+    assert is_uniprot_accession("P15056")
+    assert is_uniprot_accession("a0a024r161")
+    assert not is_uniprot_accession("BRAF")
+
+
+def test_parse_interpro_domains_keeps_domains_sorted_by_position():
+    domains = parse_interpro_domains(INTERPRO_RESPONSE)
+
+    assert [(d.database, d.start, d.end) for d in domains] == [
+        ("interpro", 155, 227),
+        ("pfam", 457, 712),
+    ]
+    assert domains[0].name == "Raf-like Ras-binding domain"
+
+
+@pytest.mark.asyncio
+async def test_get_protein_domains_json_paginates(monkeypatch):
+    requested = {}
+
+    async def mock_request_api(url, request, method, domain):
+        requested["url"] = url
+        return INTERPRO_RESPONSE, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", mock_request_api
+    )
+
+    result = await get_protein_domains(
+        "p15056", limit=1, offset=1, output_json=True
+    )
+    data = json.loads(result)
+
+    assert requested["url"].endswith("/protein/uniprot/P15056/")
+    assert data == [
+        {
+            "name": "Protein tyrosine and serine/threonine kinase",
+            "database": "pfam",
+            "accession": "PF07714",
+            "start": 457,
+            "end": 712,
+        }
+    ]
+
+
+@pytest.mark.asyncio
+async def test_get_protein_domains_markdown(monkeypatch):
+    async def mock_request_api(url, request, method, domain):
+        return INTERPRO_RESPONSE, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", mock_request_api
+    )
+
+    result = await get_protein_domains("P15056")
+
+    assert "# Protein Domains: P15056" in result
+    assert "Showing 1-2 of 2 domains." in result
+    assert "| Raf-like Ras-binding domain | interpro |" in result
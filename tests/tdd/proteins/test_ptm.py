@@ -0,0 +1,87 @@
+"""Tests for post-translational modification sites from UniProt."""
+
+import json
+
+import pytest
+
+from czechmedmcp.proteins.getter import get_protein
+from czechmedmcp.proteins.ptm import format_protein_ptms, parse_protein_ptms
+
+FEATURES = [
+    {
+        "type": "Modified residue",
+        "description": "Phosphoserine; by CDK1 and CDK2",
+        "location": {"start": {"value": 15}, "end": {"value": 15}},
+    },
+    {
+        "type": "Modified residue",
+        "description": "N6-acetyllysine; alternate",
+        "location": {"start": {"value": 120}, "end": {"value": 120}},
+    },
+    {
+        "type": "Glycosylation",
+        "description": "N-linked (GlcNAc...) asparagine",
+        "location": {"start": {"value": 88}, "end": {"value": 88}},
+    },
+    {
+        "type": "Cross-link",
+        "description": "Glycyl lysine isopeptide (Lys-Gly) (interchain "
+        "with G-Cter in ubiquitin)",
+        "location": {"start": {"value": 370}, "end": {"value": 370}},
+    },
+    {"type": "Natural variant", "description": "in LFS; dbSNP:rs28934578"},
+]
+
+ENTRY = {
+    "entryType": "UniProtKB reviewed (Swiss-Prot)",
+    "primaryAccession": "P04637",
+    "uniProtkbId": "P53_HUMAN",
+    "features": FEATURES,
+}
+
+
+def test_parse_protein_ptms():
+    ptms = parse_protein_ptms(FEATURES)
+
+    assert [p.position for p in ptms] == ["15", "120", "88", "370"]
+    assert ptms[0].model_dump() == {
+        "position": "15",
+        "modification": "Phosphoserine",
+        "enzyme": "CDK1 and CDK2",
+    }
+    assert ptms[1].enzyme is None
+    assert ptms[3].modification.startswith("Glycyl lysine isopeptide")
+
+
+def test_format_protein_ptms():
+    text = format_protein_ptms(parse_protein_ptms(FEATURES))
+
+    assert "## Post-Translational Modifications (4)" in text
+    assert "| 15 | Phosphoserine | CDK1 and CDK2 |" in text
+    assert "| 120 | N6-acetyllysine |  |" in text
+    assert "No modification sites" in format_protein_ptms([])
+
+
+@pytest.mark.asyncio
+async def test_get_protein_with_ptm(monkeypatch):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        calls.append(request)
+        return ENTRY, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    data = json.loads(await get_protein("P04637", output_json=True, ptm=True))
+    assert "ft_mod_res" in calls[0]["fields"]
+    assert data["ptms"][0]["modification"] == "Phosphoserine"
+    assert "variants" not in data
+
+    text = await get_protein("P04637", ptm=True)
+    assert "## Post-Translational Modifications (4)" in text
+
+    data = json.loads(await get_protein("P04637", output_json=True))
+    assert "ft_mod_res" not in calls[2]["fields"]
+    assert "ptms" not in data
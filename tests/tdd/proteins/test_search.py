@@ -0,0 +1,103 @@
+"""Tests for UniProt protein search and organism filtering."""
+
+import json
+
+import pytest
+
+from czechmedmcp.proteins.search import (
+    build_protein_query,
+    protein_search_query_summary,
+    resolve_organism,
+    search_proteins,
+)
+
+
+def test_default_query_is_human():
+    assert build_protein_query(gene="braf") == (
+        "gene_exact:BRAF AND organism_id:9606 AND reviewed:true"
+    )
+
+
+def test_organism_alias_overrides_human_default():
+    assert build_protein_query("kinase", organism="Mouse") == (
+        "(kinase) AND organism_id:10090 AND reviewed:true"
+    )
+    assert "organism_id:10116" in build_protein_query("p53", organism="rat")
+    assert "organism_id:559292" in build_protein_query(
+        gene="CDC28", organism="yeast"
+    )
+
+
+def test_organism_accepts_taxonomy_id():
+    assert resolve_organism(" 7955 ") == 7955
+    assert "organism_id:7955" in build_protein_query(
+        gene="tp53", organism="7955"
+    )
+
+
+def test_all_species_drops_organism_term():
+    assert build_protein_query(gene="TP53", all_species=True) == (
+        "gene_exact:TP53 AND reviewed:true"
+    )
+
+
+def test_invalid_organism_and_conflicts_are_rejected():
+    with pytest.raises(ValueError, match="Unknown organism 'dragon'"):
+        build_protein_query(gene="TP53", organism="dragon")
+    with pytest.raises(ValueError, match="--all-species"):
+        build_protein_query(gene="TP53", organism="mouse", all_species=True)
+    with pytest.raises(ValueError, match="search query or --gene"):
+        build_protein_query(organism="mouse")
+
+
+def test_query_summary_reflects_organism():
+    assert protein_search_query_summary(gene="trp53", organism="mouse") == (
+        "Gene: TRP53 | Organism: mouse (10090)"
+    )
+    assert protein_search_query_summary("kinase") == (
+        "Query: kinase | Organism: human (9606)"
+    )
+    assert protein_search_query_summary(gene="TP53", all_species=True) == (
+        "Gene: TP53 | Organism: all species"
+    )
+
+
+@pytest.mark.asyncio
+async def test_search_proteins_sends_organism_term(monkeypatch):
+    captured = {}
+
+    async def fake_request_api(url, request, method, domain):
+        captured.update(request)
+        return {
+            "results": [
+                {
+                    "entryType": "UniProtKB reviewed (Swiss-Prot)",
+                    "primaryAccession": "P02340",
+                    "uniProtkbId": "P53_MOUSE",
+                    "genes": [{"geneName": {"value": "Tp53"}}],
+                    "organism": {
+                        "scientificName": "Mus musculus",
+                        "taxonId": 10090,
+                    },
+                }
+            ]
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    result = await search_proteins(
+        gene="Tp53", organism="mouse", output_json=True
+    )
+
+    assert "organism_id:10090" in captured["query"]
+    assert "sequence" not in captured["fields"]
+    entries = json.loads(result)
+    assert entries[0]["accession"] == "P02340"
+    assert entries[0]["taxon_id"] == 10090
+
+
+@pytest.mark.asyncio
+async def test_search_proteins_reports_invalid_organism():
+    result = await search_proteins(gene="TP53", organism="dragon")
+    assert result.startswith("Error: Unknown organism 'dragon'")
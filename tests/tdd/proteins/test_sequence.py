@@ -0,0 +1,129 @@
+"""Tests for UniProt protein records and FASTA output."""
+
+import json
+
+import pytest
+
+from czechmedmcp.proteins.getter import (
+    get_gene_protein_fasta,
+    get_protein,
+    get_protein_fasta,
+)
+from czechmedmcp.proteins.uniprot import (
+    parse_uniprot_entry,
+    uniprot_fasta_header,
+)
+
+BRAF_ENTRY = {
+    "entryType": "UniProtKB reviewed (Swiss-Prot)",
+    "primaryAccession": "P15056",
+    "uniProtkbId": "BRAF_HUMAN",
+    "proteinDescription": {
+        "recommendedName": {
+            "fullName": {"value": "Serine/threonine-protein kinase B-raf"}
+        }
+    },
+    "genes": [{"geneName": {"value": "BRAF"}}],
+    "organism": {"scientificName": "Homo sapiens", "taxonId": 9606},
+    "sequence": {"value": "MAALSGGGGG" * 7, "length": 70},
+}
+
+
+def test_parse_uniprot_entry():
+    entry = parse_uniprot_entry(BRAF_ENTRY)
+
+    assert entry.accession == "P15056"
+    assert entry.reviewed
+    assert entry.gene_symbol == "BRAF"
+    assert entry.length == 70
+
+
+def test_fasta_header_is_uniprot_style():
+    header = uniprot_fasta_header(parse_uniprot_entry(BRAF_ENTRY))
+
+    assert header == (
+        ">sp|P15056|BRAF_HUMAN Serine/threonine-protein kinase B-raf "
+        "OS=Homo sapiens OX=9606 GN=BRAF"
+    )
+
+
+@pytest.mark.asyncio
+async def test_get_protein_fasta_wraps_sequence(monkeypatch):
+    async def mock_request_api(url, request, method, domain):
+        assert url.endswith("/P15056.json")
+        return BRAF_ENTRY, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", mock_request_api
+    )
+
+    fasta, error = await get_protein_fasta("p15056")
+
+    assert error is None
+    lines = fasta.splitlines()
+    assert lines[0].startswith(">sp|P15056|BRAF_HUMAN")
+    assert [len(line) for line in lines[1:]] == [60, 10]
+
+
+@pytest.mark.asyncio
+async def test_get_gene_protein_fasta_searches_reviewed_human(monkeypatch):
+    async def mock_request_api(url, request, method, domain):
+        assert "gene_exact:BRAF" in request["query"]
+        assert "organism_id:9606" in request["query"]
+        return {"results": [BRAF_ENTRY]}, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", mock_request_api
+    )
+
+    fasta, error = await get_gene_protein_fasta("braf")
+
+    assert error is None
+    assert fasta.startswith(">sp|P15056|BRAF_HUMAN")
+
+
+@pytest.mark.asyncio
+async def test_gene_fasta_errors_without_protein(monkeypatch):
+    async def mock_request_api(url, request, method, domain):
+        return {"results": []}, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", mock_request_api
+    )
+
+    fasta, error = await get_gene_protein_fasta("MIR21")
+
+    assert fasta is None
+    assert "No reviewed UniProt protein found for gene MIR21" in error
+
+
+@pytest.mark.asyncio
+async def test_protein_fasta_errors_without_sequence(monkeypatch):
+    entry = {k: v for k, v in BRAF_ENTRY.items() if k != "sequence"}
+
+    async def mock_request_api(url, request, method, domain):
+        return entry, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", mock_request_api
+    )
+
+    fasta, error = await get_protein_fasta("P15056")
+
+    assert fasta is None
+    assert error == "No sequence available for P15056"
+
+
+@pytest.mark.asyncio
+async def test_get_protein_json_omits_sequence(monkeypatch):
+    async def mock_request_api(url, request, method, domain):
+        return BRAF_ENTRY, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", mock_request_api
+    )
+
+    data = json.loads(await get_protein("P15056", output_json=True))
+
+    assert data["entry_name"] == "BRAF_HUMAN"
+    assert "sequence" not in data
@@ -0,0 +1,93 @@
+"""Tests for disease-associated protein variants from UniProt."""
+
+import json
+
+import pytest
+
+from czechmedmcp.proteins.getter import get_protein
+from czechmedmcp.proteins.variants import (
+    VARIANT_FIELD,
+    format_protein_variants,
+    parse_protein_variants,
+)
+
+FEATURES = [
+    {
+        "type": "Natural variant",
+        "featureId": "VAR_018629",
+        "description": "in CFC1 and MM; somatic mutation; "
+        "dbSNP:rs113488022",
+        "location": {"start": {"value": 600}, "end": {"value": 600}},
+        "alternativeSequence": {
+            "originalSequence": "V",
+            "alternativeSequences": ["E"],
+        },
+        "featureCrossReferences": [
+            {"database": "dbSNP", "id": "rs113488022"}
+        ],
+    },
+    {
+        "type": "Natural variant",
+        "description": "in dbSNP:rs1234",
+        "location": {"start": {"value": 10}, "end": {"value": 10}},
+        "alternativeSequence": {
+            "originalSequence": "A",
+            "alternativeSequences": ["T"],
+        },
+    },
+    {
+        "type": "Natural variant",
+        "description": "in NS7; dbSNP:rs180177040",
+        "location": {"start": {"value": 485}, "end": {"value": 486}},
+        "alternativeSequence": {"originalSequence": "NV"},
+    },
+    {"type": "Domain", "description": "Protein kinase"},
+]
+
+ENTRY = {
+    "entryType": "UniProtKB reviewed (Swiss-Prot)",
+    "primaryAccession": "P15056",
+    "uniProtkbId": "BRAF_HUMAN",
+    "features": FEATURES,
+}
+
+
+def test_parse_protein_variants():
+    variants = parse_protein_variants(FEATURES)
+
+    assert [v.change for v in variants] == ["V600E", "NV485-486del"]
+    assert variants[0].disease == "CFC1 and MM"
+    assert variants[0].dbsnp == "rs113488022"
+    assert variants[1].position == "485-486"
+    assert variants[1].dbsnp == "rs180177040"
+
+
+def test_format_protein_variants():
+    text = format_protein_variants(parse_protein_variants(FEATURES))
+
+    assert "## Disease-Associated Variants (2)" in text
+    assert "| 600 | V600E | CFC1 and MM | rs113488022 |" in text
+    assert "No disease-associated" in format_protein_variants([])
+
+
+@pytest.mark.asyncio
+async def test_get_protein_with_variants(monkeypatch):
+    calls = []
+
+    async def fake_request_api(url, request, method, domain):
+        calls.append(request)
+        return ENTRY, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    data = json.loads(
+        await get_protein("P15056", output_json=True, variants=True)
+    )
+    assert VARIANT_FIELD in calls[0]["fields"]
+    assert data["variants"][0]["change"] == "V600E"
+
+    data = json.loads(await get_protein("P15056", output_json=True))
+    assert VARIANT_FIELD not in calls[1]["fields"]
+    assert "variants" not in data
@@ -9,6 +9,7 @@ import pytest
 from czechmedmcp.articles.fetch import (
     Article,
     FetchArticlesResponse,
+    FullTextFormat,
     Passage,
     PassageInfo,
     _article_details,
@@ -191,6 +192,34 @@ class TestArticleModel:
         ])
         assert a.full_text == ""
 
+    def _structured_article(self):
+        return self._article([
+            _make_passage("INTRO", "Introduction", "title_1"),
+            _make_passage("INTRO", "Intro text", "paragraph"),
+            _make_passage("RESULTS", "Results text", "paragraph"),
+            _make_passage("FIG", "Figure 1. Survival.", "fig_caption"),
+        ])
+
+    def test_full_text_sections(self):
+        a = self._structured_article()
+        assert a.render_full_text(FullTextFormat.SECTIONS) == [
+            {"section": "Introduction", "text": "Introduction\n\nIntro text"},
+            {"section": "Results", "text": "Results text"},
+            {"section": "Figures", "text": "Figure 1. Survival."},
+        ]
+
+    def test_full_text_markdown(self):
+        a = self._structured_article()
+        assert a.render_full_text(FullTextFormat.MARKDOWN) == (
+            "## Introduction\n\nIntro text\n\n"
+            "## Results\n\nResults text\n\n"
+            "## Figures\n\n*Figure 1. Survival.*"
+        )
+
+    def test_full_text_plain_is_default_shape(self):
+        a = self._structured_article()
+        assert a.render_full_text(FullTextFormat.PLAIN) == a.full_text
+
     def test_pubmed_url(self):
         a = self._article([_make_passage("title", "T")])
         assert a.pubmed_url == (
@@ -569,6 +598,27 @@ class TestFetchArticles:
         assert "full_text" in data[0]
         assert data[0]["full_text"] == "Introduction text"
 
+    @patch(
+        "czechmedmcp.articles.fetch.call_pubtator_api",
+    )
+    async def test_full_text_sections_format_json(
+        self, mock_api
+    ):
+        mock_api.return_value = (
+            _pubtator_response(),
+            None,
+        )
+        result = await fetch_articles(
+            [12345],
+            full=True,
+            output_json=True,
+            fulltext_format=FullTextFormat.SECTIONS,
+        )
+        data = json.loads(result)
+        assert data[0]["full_text"] == [
+            {"section": "Introduction", "text": "Introduction text"}
+        ]
+
     @patch(
         "czechmedmcp.articles.fetch.call_pubtator_api",
     )
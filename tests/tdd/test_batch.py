@@ -0,0 +1,146 @@
+"""Tests for batch fetching."""
+
+import asyncio
+import json
+
+import pytest
+
+from czechmedmcp import batch
+from czechmedmcp.batch import (
+    BatchEntity,
+    BatchError,
+    format_batch,
+    format_batch_record,
+    run_batch,
+    stream_batch,
+)
+
+
+async def _fake_gene(identifier: str) -> str:
+    """Gene getter stand-in: 'NOTAGENE' is not found."""
+    if identifier == "NOTAGENE":
+        return json.dumps({"error": f"Gene '{identifier}' not found"})
+    return json.dumps({"symbol": identifier, "name": f"{identifier} gene"})
+
+
+@pytest.fixture
+def fake_gene(monkeypatch):
+    monkeypatch.setitem(batch.BATCH_FETCHERS, BatchEntity.GENE, _fake_gene)
+
+
+@pytest.mark.asyncio
+async def test_batch_fails_fast_by_default(fake_gene):
+    with pytest.raises(BatchError) as exc_info:
+        await run_batch(BatchEntity.GENE, ["TP53", "NOTAGENE", "BRAF"])
+
+    assert exc_info.value.identifier == "NOTAGENE"
+    assert "not found" in exc_info.value.message
+
+
+@pytest.mark.asyncio
+async def test_batch_continue_on_error_collects_failures(fake_gene):
+    results, errors = await run_batch(
+        BatchEntity.GENE,
+        ["TP53", "NOTAGENE", "BRAF"],
+        continue_on_error=True,
+    )
+
+    assert [r["id"] for r in results] == ["TP53", "BRAF"]
+    assert errors == [{"id": "NOTAGENE", "error": "Gene 'NOTAGENE' not found"}]
+
+
+@pytest.mark.asyncio
+async def test_batch_continue_on_error_output(fake_gene):
+    results, errors = await run_batch(
+        BatchEntity.GENE, ["TP53", "NOTAGENE"], continue_on_error=True
+    )
+
+    markdown = format_batch(BatchEntity.GENE, results, errors)
+    assert markdown.startswith("# Gene TP53")
+    assert "# Batch errors (1)" in markdown
+    assert "- **NOTAGENE**: Gene 'NOTAGENE' not found" in markdown
+
+    data = json.loads(
+        format_batch(BatchEntity.GENE, results, errors, output_json=True)
+    )
+    assert data["results"] == [{"symbol": "TP53", "name": "TP53 gene"}]
+    assert data["errors"][0]["id"] == "NOTAGENE"
+
+
+def test_format_batch_json_without_error_reporting():
+    results = [{"id": "TP53", "data": {"symbol": "TP53"}}]
+    data = json.loads(format_batch(BatchEntity.GENE, results, output_json=True))
+    assert data == [{"symbol": "TP53"}]
+
+
+async def _slow_gene(identifier: str) -> str:
+    """Gene getter stand-in whose first identifiers resolve last."""
+    await asyncio.sleep({"TP53": 0.03, "BRAF": 0.02}.get(identifier, 0))
+    return await _fake_gene(identifier)
+
+
+@pytest.fixture
+def slow_gene(monkeypatch):
+    monkeypatch.setitem(batch.BATCH_FETCHERS, BatchEntity.GENE, _slow_gene)
+
+
+async def _collect(**kwargs) -> list[dict]:
+    return [record async for record in stream_batch(**kwargs)]
+
+
+@pytest.mark.asyncio
+async def test_stream_batch_yields_in_completion_order(slow_gene):
+    records = await _collect(
+        entity=BatchEntity.GENE, identifiers=["TP53", "BRAF", "EGFR"]
+    )
+    assert [r["id"] for r in records] == ["EGFR", "BRAF", "TP53"]
+
+
+@pytest.mark.asyncio
+async def test_stream_batch_ordered_keeps_input_order(slow_gene):
+    records = await _collect(
+        entity=BatchEntity.GENE,
+        identifiers=["TP53", "BRAF", "EGFR"],
+        ordered=True,
+    )
+    assert [r["id"] for r in records] == ["TP53", "BRAF", "EGFR"]
+    assert records[0]["data"]["symbol"] == "TP53"
+
+
+@pytest.mark.asyncio
+async def test_stream_batch_fails_fast_by_default(slow_gene):
+    seen = []
+    with pytest.raises(BatchError) as exc_info:
+        async for record in stream_batch(
+            BatchEntity.GENE, ["TP53", "NOTAGENE", "EGFR"], ordered=True
+        ):
+            seen.append(record["id"])
+
+    assert seen == ["TP53"]
+    assert exc_info.value.identifier == "NOTAGENE"
+
+
+@pytest.mark.asyncio
+async def test_stream_batch_continue_on_error(slow_gene):
+    records = await _collect(
+        entity=BatchEntity.GENE,
+        identifiers=["TP53", "NOTAGENE"],
+        ordered=True,
+        continue_on_error=True,
+    )
+    assert records[1] == {
+        "id": "NOTAGENE",
+        "error": "Gene 'NOTAGENE' not found",
+    }
+
+
+def test_format_batch_record():
+    record = {"id": "TP53", "data": {"symbol": "TP53"}}
+    line = format_batch_record(BatchEntity.GENE, record, output_json=True)
+    assert "\n" not in line
+    assert json.loads(line) == record
+
+    error = {"id": "X", "error": "not found"}
+    assert format_batch_record(BatchEntity.GENE, error) == (
+        "# Gene X\n\nError: not found"
+    )
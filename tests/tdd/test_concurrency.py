@@ -0,0 +1,99 @@
+"""Tests for the global fan-out concurrency cap."""
+
+import asyncio
+
+import pytest
+
+from czechmedmcp import concurrency
+from czechmedmcp.concurrency import (
+    CONCURRENCY_ENV,
+    gather_limited,
+    get_concurrency,
+    resolve_concurrency,
+    set_concurrency,
+)
+from czechmedmcp.dry_run import DryRunRequest
+
+
+@pytest.fixture(autouse=True)
+def reset_concurrency(monkeypatch):
+    monkeypatch.delenv(CONCURRENCY_ENV, raising=False)
+    monkeypatch.setattr(concurrency, "_concurrency_override", None)
+
+
+def test_resolve_concurrency_defaults_to_operation_limit():
+    assert get_concurrency() is None
+    assert resolve_concurrency(None, 8) == 8
+    assert resolve_concurrency(None, None) is None
+
+
+def test_global_cap_overrides_operation_default():
+    set_concurrency(1)
+
+    assert resolve_concurrency(None, 8) == 1
+    assert resolve_concurrency(None, None) == 1
+    assert resolve_concurrency(3, 8) == 3
+
+
+def test_env_var_sets_global_cap(monkeypatch):
+    monkeypatch.setenv(CONCURRENCY_ENV, "2")
+    assert get_concurrency() == 2
+
+    monkeypatch.setenv(CONCURRENCY_ENV, "auto")
+    assert get_concurrency() is None
+
+    monkeypatch.setenv(CONCURRENCY_ENV, "lots")
+    assert get_concurrency() is None
+
+
+def test_set_concurrency_rejects_zero():
+    with pytest.raises(ValueError):
+        set_concurrency(0)
+
+
+@pytest.mark.asyncio
+async def test_gather_limited_bounds_in_flight_and_keeps_order():
+    in_flight = 0
+    peak = 0
+
+    async def work(i):
+        nonlocal in_flight, peak
+        in_flight += 1
+        peak = max(peak, in_flight)
+        await asyncio.sleep(0)
+        in_flight -= 1
+        return i
+
+    results = await gather_limited((work(i) for i in range(6)), 2)
+
+    assert results == list(range(6))
+    assert peak == 2
+
+
+@pytest.mark.asyncio
+async def test_gather_limited_returns_exceptions():
+    async def fail():
+        raise RuntimeError("boom")
+
+    async def ok():
+        return "ok"
+
+    results = await gather_limited(
+        [ok(), fail()], 1, return_exceptions=True
+    )
+
+    assert results[0] == "ok"
+    assert isinstance(results[1], RuntimeError)
+
+
+@pytest.mark.asyncio
+async def test_gather_limited_raises_dry_run_requests():
+    async def dry():
+        raise DryRunRequest("GET", "https://example.org", {})
+
+    async def ok():
+        return "ok"
+
+    for limit in (1, None):
+        with pytest.raises(DryRunRequest):
+            await gather_limited([ok(), dry()], limit, return_exceptions=True)
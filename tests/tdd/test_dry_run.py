@@ -0,0 +1,91 @@
+"""Tests for dry-run mode."""
+
+import json
+
+import pytest
+
+from czechmedmcp import http_client
+from czechmedmcp.dry_run import DryRunRequest, is_dry_run, set_dry_run
+
+
+@pytest.fixture
+def dry_run():
+    """Enable dry-run mode for one test."""
+    set_dry_run(True)
+    yield
+    set_dry_run(None)
+
+
+def test_dry_run_follows_environment(monkeypatch):
+    """Test that BIOMCP_DRY_RUN enables dry-run unless overridden."""
+    monkeypatch.setenv("BIOMCP_DRY_RUN", "true")
+    assert is_dry_run()
+
+    set_dry_run(False)
+    try:
+        assert not is_dry_run()
+    finally:
+        set_dry_run(None)
+
+    monkeypatch.delenv("BIOMCP_DRY_RUN")
+    assert not is_dry_run()
+
+
+@pytest.mark.asyncio
+async def test_request_api_raises_instead_of_sending(dry_run, monkeypatch):
+    """Test that no HTTP call is made and the resolved request is kept."""
+
+    async def fail_call_http(*args, **kwargs):
+        raise AssertionError("HTTP request sent during dry run")
+
+    monkeypatch.setattr(http_client, "call_http", fail_call_http)
+
+    with pytest.raises(DryRunRequest) as exc_info:
+        await http_client.request_api(
+            url="https://clinicaltrials.gov/api/v2/studies",
+            request={"query.cond": "melanoma", "pageSize": 10},
+            method="GET",
+            domain="clinicaltrials",
+        )
+
+    request = exc_info.value
+    assert request.method == "GET"
+    assert request.domain == "clinicaltrials"
+    assert request.params == {"query.cond": "melanoma", "pageSize": 10}
+
+
+@pytest.mark.asyncio
+async def test_dry_run_bypasses_broad_exception_handlers(dry_run):
+    """Test that search code catching Exception cannot swallow a dry run."""
+
+    async def search():
+        try:
+            await http_client.request_api(
+                url="https://example.org/api", request={}, domain="test"
+            )
+        except Exception:
+            return "error"
+        return "sent"
+
+    with pytest.raises(DryRunRequest):
+        await search()
+
+
+def test_dry_run_request_rendering_redacts_secrets():
+    """Test text and JSON rendering, with credentials masked."""
+    request = DryRunRequest(
+        "GET",
+        "https://api.fda.gov/drug/event.json",
+        {"search": 'patient.drug.medicinalproduct:"aspirin"', "api_key": "s3"},
+        "openfda",
+    )
+
+    text = request.render()
+    assert text.startswith("Dry run - no request sent.")
+    assert "GET https://api.fda.gov/drug/event.json" in text
+    assert "s3" not in text
+
+    data = json.loads(request.render(output_json=True))
+    assert data["dry_run"] is True
+    assert data["params"]["api_key"] == "***"
+    assert data["params"]["search"] == 'patient.drug.medicinalproduct:"aspirin"'
@@ -10,6 +10,13 @@ import pytest
 
 from czechmedmcp.enrichr import EnrichrClient, get_database_name
 from czechmedmcp.enrichr.databases import ENRICHR_DATABASES
+from czechmedmcp.enrichr.report import (
+    enrich_markdown,
+    enrichment_report,
+    gene_list_source,
+    pathway_source,
+)
+from czechmedmcp.pathways import fetch_pathway_genes
 
 
 @pytest.fixture
@@ -325,3 +332,55 @@ class TestEnrichOneStep:
         )
 
         assert results is None
+
+
+class TestEnrichmentReport:
+    """Test the standalone enrichment report."""
+
+    @pytest.mark.asyncio
+    async def test_pathway_source_in_markdown_header(self, monkeypatch):
+        """A pathway's genes are enriched and the header names the pathway."""
+        calls = []
+
+        async def fake_request_api(url, request, method, domain, **kwargs):
+            calls.append(domain)
+            if domain == "reactome":
+                return [
+                    {"geneName": ["BRAF"]},
+                    {"geneName": ["KRAS", "KRAS2"]},
+                    {"geneName": ["BRAF"]},
+                    {"displayName": "ChEBI:15996 GTP"},
+                ], None
+            if url.endswith("/addList"):
+                return {"userListId": 1}, None
+            return {
+                "KEGG_2021_Human": [
+                    [1, "MAPK signaling", 1e-6, 5.0, 80.0, "BRAF;KRAS", 1e-5]
+                ]
+            }, None
+
+        monkeypatch.setattr(
+            "czechmedmcp.http_client.request_api", fake_request_api
+        )
+
+        genes, error = await fetch_pathway_genes("r-hsa-5673001")
+        assert error is None
+        assert genes == ["BRAF", "KRAS"]
+
+        text = await enrichment_report(
+            genes, "pathway", pathway_source("R-HSA-5673001", genes)
+        )
+        assert text.startswith(
+            "# Enrichment: Reactome pathway R-HSA-5673001 (2 genes)"
+        )
+        assert "| 1 | MAPK signaling | 1e-05 | BRAF, KRAS |" in text
+
+    def test_enrich_markdown_without_terms(self):
+        text = enrich_markdown(
+            gene_list_source(["TP53"]), "KEGG_2021_Human", []
+        )
+        assert text == (
+            "# Enrichment: Gene list (1 gene)\n\n"
+            "_Database: KEGG_2021_Human_\n\n"
+            "No significant enrichment terms found."
+        )
@@ -0,0 +1,92 @@
+"""Tests for enumerating the CLI help examples."""
+
+from types import SimpleNamespace
+
+from czechmedmcp.cli.pipe import pipe_command
+from czechmedmcp.examples import (
+    CliCommand,
+    collect_commands,
+    collect_examples,
+    format_example_list,
+    format_example_script,
+    parse_examples,
+)
+
+GENE_DOC = """
+Search genes.
+
+Examples:
+    # Search by symbol
+    czechmedmcp gene search TP53
+
+    czechmedmcp gene search kinase --page 2
+    By Entrez ID: czechmedmcp gene get 7157
+
+Notes:
+    czechmedmcp gene search is not an example here
+"""
+
+
+def test_parse_examples():
+    assert parse_examples(GENE_DOC) == [
+        ("Search by symbol", "czechmedmcp gene search TP53"),
+        (None, "czechmedmcp gene search kinase --page 2"),
+        ("By Entrez ID", "czechmedmcp gene get 7157"),
+    ]
+    assert parse_examples("Get a gene.") == []
+
+
+def test_parse_examples_keeps_pipelines():
+    (example,) = parse_examples(pipe_command.__doc__)[1:]
+    assert example == (
+        None,
+        "cat ncts.txt | czechmedmcp pipe 'trial get {} --json'",
+    )
+
+
+def _command(name, doc, hidden=False):
+    def callback():
+        pass
+
+    callback.__doc__ = doc
+    return SimpleNamespace(name=name, callback=callback, hidden=hidden)
+
+
+def test_collect_commands_and_examples():
+    gene_app = SimpleNamespace(
+        registered_commands=[
+            _command("search", GENE_DOC),
+            _command("secret", GENE_DOC, hidden=True),
+        ]
+    )
+    app = SimpleNamespace(
+        registered_commands=[_command(None, "Show the version.")],
+        registered_groups=[
+            SimpleNamespace(name="gene", typer_instance=gene_app, hidden=False)
+        ],
+    )
+
+    commands = collect_commands(app)
+    assert [command.path for command in commands] == [
+        "callback",
+        "gene search",
+    ]
+
+    examples = collect_examples(commands, "gene")
+    assert len(examples) == 3
+    assert collect_examples(commands, "trial") == []
+    assert format_example_list(examples).splitlines()[0] == (
+        "czechmedmcp gene search TP53"
+    )
+
+    script = format_example_script(examples)
+    assert script.startswith("#!/bin/sh\n# czechmedmcp CLI examples\n")
+    assert "# --- czechmedmcp gene search ---\n# Search by symbol\n" in script
+    assert "# By Entrez ID\nczechmedmcp gene get 7157" in script
+
+
+def test_collect_examples_uses_command_path():
+    commands = [CliCommand("gene", "search", GENE_DOC)]
+    (first, *_) = collect_examples(commands)
+    assert first.command == "gene search"
+    assert first.entity == "gene"
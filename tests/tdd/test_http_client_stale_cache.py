@@ -0,0 +1,104 @@
+"""Tests for refetching stale cached responses."""
+
+import pytest
+from pydantic import BaseModel
+
+from czechmedmcp import http_client
+from czechmedmcp.http_client import (
+    RequestError,
+    is_stale_cache_hit,
+    request_api,
+)
+
+
+class Record(BaseModel):
+    id: str
+    name: str
+
+
+class FakeCache:
+    def __init__(self, entries):
+        self.entries = dict(entries)
+
+    def get(self, key, default=None):
+        return self.entries.get(key, default)
+
+    def set(self, key, value, expire=None, tag=None):
+        self.entries[key] = value
+
+    def delete(self, key):
+        return self.entries.pop(key, None) is not None
+
+
+@pytest.fixture
+def cache(monkeypatch):
+    fake = FakeCache({})
+    monkeypatch.setattr(http_client, "get_cache", lambda: fake)
+    monkeypatch.setattr(
+        http_client, "_handle_offline_mode", lambda *args: None
+    )
+    return fake
+
+
+def _key(url):
+    return http_client.generate_cache_key("GET", url, {})
+
+
+def _fake_http(monkeypatch, content):
+    calls = []
+
+    async def fake_call_http(method, url, params, **kwargs):
+        calls.append(url)
+        return 200, content
+
+    monkeypatch.setattr(http_client, "call_http", fake_call_http)
+    return calls
+
+
+def test_is_stale_cache_hit():
+    assert is_stale_cache_hit(None, RequestError(code=500, message="bad"))
+    assert is_stale_cache_hit(None, None)
+    assert not is_stale_cache_hit({}, None)
+    assert not is_stale_cache_hit([], None)
+    assert not is_stale_cache_hit({"hits": []}, None)
+    assert not is_stale_cache_hit(Record(id="1", name="x"), None)
+
+
+@pytest.mark.asyncio
+async def test_fresh_cache_hit_is_served(monkeypatch, cache):
+    url = "https://example.org/record/1"
+    cache.entries[_key(url)] = '{"id": "1", "name": "cached"}'
+    calls = _fake_http(monkeypatch, '{"id": "1", "name": "fresh"}')
+
+    record, error = await request_api(url, {}, Record)
+
+    assert error is None
+    assert record.name == "cached"
+    assert calls == []
+
+
+@pytest.mark.asyncio
+async def test_schema_drift_refetches_and_updates_cache(monkeypatch, cache):
+    url = "https://example.org/record/1"
+    cache.entries[_key(url)] = '{"identifier": "1"}'
+    calls = _fake_http(monkeypatch, '{"id": "1", "name": "fresh"}')
+
+    record, error = await request_api(url, {}, Record)
+
+    assert error is None
+    assert record.name == "fresh"
+    assert calls == [url]
+    assert cache.entries[_key(url)] == '{"id": "1", "name": "fresh"}'
+
+
+@pytest.mark.asyncio
+async def test_empty_cached_result_is_served(monkeypatch, cache):
+    url = "https://example.org/search"
+    cache.entries[_key(url)] = "[]"
+    calls = _fake_http(monkeypatch, '[{"id": "2", "name": "new"}]')
+
+    data, error = await request_api(url, {})
+
+    assert error is None
+    assert data == []
+    assert calls == []
@@ -9,6 +9,10 @@ from czechmedmcp.diseases.search import search_diseases
 from czechmedmcp.integrations.cts_api import CTSAPIError, make_cts_request
 from czechmedmcp.interventions import search_interventions
 from czechmedmcp.organizations import get_organization, search_organizations
+from czechmedmcp.organizations.search import (
+    format_organization_results,
+    organization_search_query_summary,
+)
 from czechmedmcp.trials.nci_getter import get_trial_nci
 from czechmedmcp.trials.nci_search import (
     convert_query_to_nci,
@@ -70,6 +74,52 @@ class TestOrganizationsModule:
             assert len(result["organizations"]) == 1
             assert result["organizations"][0]["name"] == "Test Cancer Center"
 
+    @pytest.mark.asyncio
+    async def test_search_organizations_has_trials(self):
+        """Test filtering organizations to trial sites."""
+
+        async def fake_request(url, params=None, api_key=None):
+            if url.endswith("/organizations"):
+                return {
+                    "data": [
+                        {"id": "ORG001", "name": "Active Center"},
+                        {"id": "ORG002", "name": "Idle Clinic"},
+                        {"id": "ORG003", "name": "Flaky Hospital"},
+                    ],
+                    "total": 40,
+                }
+            if params["sites.org_name"] == "Flaky Hospital":
+                raise CTSAPIError("NCI API timed out")
+            active = params["sites.org_name"] == "Active Center"
+            return {"data": [], "total": 3 if active else 0}
+
+        with patch(
+            "czechmedmcp.organizations.search.make_cts_request",
+            side_effect=fake_request,
+        ):
+            result = await search_organizations(
+                city="Boston", api_key="test-key", has_trials=True
+            )
+
+        assert "total" not in result
+        assert result["upstream_total"] == 40
+        assert result["returned"] == 1
+        assert result["organizations"][0]["id"] == "ORG001"
+        assert result["trial_lookup_errors"] == [
+            {"name": "Flaky Hospital", "error": "NCI API timed out"}
+        ]
+        text = format_organization_results(result)
+        assert "(40 upstream matches before client-side filtering)" in text
+        assert "- Flaky Hospital: NCI API timed out" in text
+
+    def test_organization_search_query_summary(self):
+        """Test the one-line organization filter summary."""
+        summary = organization_search_query_summary(
+            city="Boston", state="MA", has_trials=True
+        )
+
+        assert summary == "Location: Boston, MA | Has trials: yes"
+
     @pytest.mark.asyncio
     async def test_get_organization(self):
         """Test getting specific organization."""
@@ -0,0 +1,106 @@
+"""Tests for running one command per stdin identifier."""
+
+import logging
+
+import pytest
+
+from czechmedmcp import pipe
+from czechmedmcp.concurrency import set_concurrency
+from czechmedmcp.dry_run import set_dry_run
+from czechmedmcp.pipe import (
+    expand_template,
+    format_pipe_output,
+    global_options,
+    parse_template,
+    read_values,
+    run_pipe,
+)
+from czechmedmcp.terminal import set_color_mode
+
+
+def test_parse_template_requires_placeholder():
+    assert parse_template("variant get '{}' --json") == [
+        "variant",
+        "get",
+        "{}",
+        "--json",
+    ]
+    with pytest.raises(ValueError, match="placeholder"):
+        parse_template("gene get TP53")
+    with pytest.raises(ValueError, match="another pipe"):
+        parse_template("pipe 'gene get {}'")
+    with pytest.raises(ValueError, match="Invalid command template"):
+        parse_template("gene get '{}")
+
+
+def test_expand_template_keeps_value_as_one_argument():
+    args = parse_template("article search --keyword {} --gene BRAF")
+    assert expand_template(args, "lung cancer") == [
+        "article",
+        "search",
+        "--keyword",
+        "lung cancer",
+        "--gene",
+        "BRAF",
+    ]
+    assert expand_template(["trial", "get", "NCT{}"], "04280705") == [
+        "trial",
+        "get",
+        "NCT04280705",
+    ]
+
+
+def test_read_values_skips_blank_lines():
+    assert read_values(["TP53\n", "\n", "  BRAF  \n"]) == ["TP53", "BRAF"]
+
+
+@pytest.mark.asyncio
+async def test_run_pipe_continues_on_errors(monkeypatch):
+    calls = []
+
+    async def fake_execute(args):
+        calls.append(args)
+        if args[-1] == "NOTAGENE":
+            return 1, "", "Error: Gene 'NOTAGENE' not found\n"
+        return 0, f"# {args[-1]}\n", ""
+
+    monkeypatch.setattr(pipe, "execute", fake_execute)
+    results = await run_pipe("gene get {}", ["TP53", "NOTAGENE", "BRAF"])
+
+    assert calls == [
+        ["gene", "get", "TP53"],
+        ["gene", "get", "NOTAGENE"],
+        ["gene", "get", "BRAF"],
+    ]
+    assert [result.ok for result in results] == [True, False, True]
+    assert results[1].error == "Gene 'NOTAGENE' not found"
+    assert format_pipe_output(results, "\n---\n") == "# TP53\n---\n# BRAF"
+
+
+def test_global_options_are_passed_on(monkeypatch):
+    monkeypatch.delenv("BIOMCP_CONCURRENCY", raising=False)
+    monkeypatch.delenv("BIOMCP_DRY_RUN", raising=False)
+    logger = logging.getLogger("czechmedmcp")
+    level = logger.level
+    try:
+        logger.setLevel(logging.WARNING)
+        set_color_mode("never")
+        assert global_options() == ["--color", "never"]
+
+        logger.setLevel(logging.INFO)
+        set_concurrency(2)
+        set_dry_run(True)
+        set_color_mode("always")
+        assert global_options() == [
+            "--verbose",
+            "--concurrency",
+            "2",
+            "--dry-run",
+            "--color",
+            "always",
+        ]
+    finally:
+        logger.setLevel(level)
+        set_concurrency(None)
+        set_dry_run(None)
+        set_color_mode(None)
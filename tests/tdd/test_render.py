@@ -1,3 +1,6 @@
+import json
+from xml.etree import ElementTree
+
 from czechmedmcp import render
 
 
@@ -202,3 +205,212 @@ def test_render_key_value_long():
     # first line "brief summary:"
     assert lines[0] == "Brief Summary:"
     assert lines[1].startswith("  hello hello")
+
+
+def test_to_fasta_wraps_lines():
+    fasta = render.to_fasta("sp|P1|X_HUMAN", "AC GT\nACGT", width=3)
+    assert fasta == ">sp|P1|X_HUMAN\nACG\nTAC\nGT\n"
+
+
+def test_to_curie_normalizes_prefixes():
+    assert render.to_curie("7157", "NCBIGene") == "NCBIGene:7157"
+    assert render.to_curie("mondo:0007254", "MONDO") == "MONDO:0007254"
+    assert render.to_curie("DB00619", "INCHIKEY") == "DRUGBANK:DB00619"
+    assert render.to_curie("CHEMBL941", "INCHIKEY") == (
+        "CHEMBL.COMPOUND:CHEMBL941"
+    )
+    assert render.curie_to_url("MONDO:0007254") == (
+        "http://purl.obolibrary.org/obo/MONDO_0007254"
+    )
+    assert render.curie_to_url("FOO:1") == "FOO:1"
+
+
+def test_to_json_ld_gene():
+    document = render.to_json_ld(
+        {
+            "gene_id": "7157",
+            "symbol": "TP53",
+            "name": "tumor protein p53",
+            "summary": "This gene encodes a tumor suppressor protein.",
+        },
+        "gene",
+    )
+    context = document["@context"]
+    assert document["@type"] == "bioschemas:Gene"
+    assert context["@vocab"] == render.JSONLD_VOCAB
+    assert context["name"] == "schema:name"
+    assert context["summary"] == "schema:description"
+    assert "symbol" not in context
+    assert document["@id"] == "https://identifiers.org/ncbigene:7157"
+    assert document["identifier"] == "NCBIGene:7157"
+    assert document["name"] == "tumor protein p53"
+    assert document["symbol"] == "TP53"
+
+
+def test_to_json_ld_drug_same_as():
+    document = render.to_json_ld(
+        {
+            "drug_id": "KTUFNOKKBVMGRW-UHFFFAOYSA-N",
+            "name": "imatinib",
+            "drugbank_id": "DB00619",
+            "chebi_id": "CHEBI:45783",
+            "inchikey": "KTUFNOKKBVMGRW-UHFFFAOYSA-N",
+        },
+        "drug",
+    )
+    assert document["@type"] == "schema:Drug"
+    assert document["identifier"] == "INCHIKEY:KTUFNOKKBVMGRW-UHFFFAOYSA-N"
+    assert document["sameAs"] == [
+        "https://go.drugbank.com/drugs/DB00619",
+        "http://purl.obolibrary.org/obo/CHEBI_45783",
+    ]
+
+
+def test_json_to_json_ld_reports_errors():
+    document, error = render.json_to_json_ld(
+        '{"error": "Disease \'x\' not found"}', "disease"
+    )
+    assert document is None
+    assert error == "Disease 'x' not found"
+
+    document, error = render.json_to_json_ld(
+        '{"disease_id": "MONDO:0005105", "name": "melanoma"}', "disease"
+    )
+    assert error is None
+    assert '"@type": "schema:MedicalCondition"' in document
+
+
+def test_gene_summary_line():
+    line = render.gene_summary_line(
+        {
+            "gene_id": "673",
+            "symbol": "BRAF",
+            "entrezgene": 673,
+            "map_location": "7q34",
+            "name": "B-Raf proto-oncogene, serine/threonine kinase",
+        }
+    )
+    assert line == (
+        "BRAF (Entrez 673, 7q34): "
+        "B-Raf proto-oncogene, serine/threonine kinase"
+    )
+    assert render.gene_summary_line({"gene_id": "1", "symbol": "X"}) == "X"
+
+
+def test_summary_line_is_one_truncated_line():
+    line = render.summary_line("melanoma", ["MONDO:0005105"], "word\n" * 60)
+    assert "\n" not in line
+    assert len(line) == render.SUMMARY_LINE_WIDTH
+    assert line.endswith("...")
+
+
+def test_variant_and_trial_summary_lines():
+    variant = {
+        "_id": "chr7:g.140453136A>T",
+        "dbsnp": {"rsid": "rs113488022", "gene": {"symbol": "BRAF"}},
+        "clinvar": {
+            "rcv": [
+                {"clinical_significance": "Pathogenic"},
+                {"clinical_significance": "Pathogenic"},
+                {"clinical_significance": "Likely pathogenic"},
+            ]
+        },
+    }
+    assert render.variant_summary_line(variant) == (
+        "chr7:g.140453136A>T (BRAF, rs113488022): "
+        "ClinVar Pathogenic, Likely pathogenic"
+    )
+
+    trial = {
+        "protocolSection": {
+            "identificationModule": {
+                "nctId": "NCT04280705",
+                "briefTitle": "Adaptive COVID-19 Treatment Trial (ACTT)",
+            },
+            "statusModule": {"overallStatus": "COMPLETED"},
+            "designModule": {"phases": ["PHASE3"]},
+        }
+    }
+    assert render.trial_summary_line(trial) == (
+        "NCT04280705 (PHASE3, COMPLETED): "
+        "Adaptive COVID-19 Treatment Trial (ACTT)"
+    )
+
+
+def test_json_to_summary_line():
+    line, error = render.json_to_summary_line(
+        '[{"drug_id": "KTUFNOKKBVMGRW-UHFFFAOYSA-N", "name": "imatinib", '
+        '"drugbank_id": "DB00619", "indication": "Treatment of CML"}]',
+        "drug",
+    )
+    assert error is None
+    assert line == "imatinib (DB00619): Treatment of CML"
+
+    line, error = render.json_to_summary_line(
+        '{"error": "Gene \'X\' not found"}', "gene"
+    )
+    assert line is None
+    assert error == "Gene 'X' not found"
+
+
+def test_trial_to_xml(data_dir):
+    raw = (data_dir / "ct_gov/trials_NCT04280705.json").read_text()
+    study = json.loads(raw)["studies"][0]
+    root = ElementTree.fromstring(render.trial_to_xml(study))
+
+    assert root.tag == "trial"
+    assert root.get("nct_id") == "NCT04280705"
+    assert root.findtext("study_type") == "INTERVENTIONAL"
+    assert root.findtext("overall_status") == "COMPLETED"
+    assert [p.text for p in root.findall("phase")] == ["PHASE3"]
+    assert root.findtext("conditions/condition") == "COVID-19"
+    names = [n.text for n in root.findall("interventions/intervention/name")]
+    assert names == ["Placebo", "Remdesivir"]
+    assert root.find("arms/arm/label") is not None
+    assert len(root.findall("locations/location")) > 1
+
+
+def test_json_to_trial_xml_escapes_and_reports_errors():
+    document, error = render.json_to_trial_xml(
+        json.dumps(
+            {
+                "protocolSection": {
+                    "identificationModule": {
+                        "nctId": "NCT00000001",
+                        "briefTitle": "Drug A <10 mg> & placebo",
+                    }
+                },
+                "URL": "https://clinicaltrials.gov/study/NCT00000001",
+            }
+        )
+    )
+    assert error is None
+    assert document.startswith('<?xml version="1.0" encoding="UTF-8"?>')
+    assert "Drug A &lt;10 mg&gt; &amp; placebo" in document
+    root = ElementTree.fromstring(document)
+    assert root.findtext("brief_title") == "Drug A <10 mg> & placebo"
+    assert root.find("enrollment") is None
+    assert list(root.find("locations")) == []
+
+    document, error = render.json_to_trial_xml(
+        '{"error": "API Error 404", "details": "Not found"}'
+    )
+    assert document is None
+    assert error == "API Error 404: Not found"
+
+
+def test_result_count_labels_upstream_total_when_filtered():
+    assert render.result_count_line(3, 40, "genes") == (
+        "Showing 3 of 40 matching genes."
+    )
+    line = render.result_count_line(3, 40, "genes", post_filtered=True)
+    assert line.startswith("Showing 3 genes matching all filters")
+    assert "(40 upstream matches before client-side filtering)" in line
+
+    payload = render.search_page_payload("q", [{}], 40, 1, 10)
+    assert payload["total"] == 40
+    payload = render.search_page_payload(
+        "q", [{}], 40, 1, 10, post_filtered=True
+    )
+    assert (payload["upstream_total"], payload["returned"]) == (40, 1)
+    assert "total" not in payload
@@ -0,0 +1,43 @@
+"""Tests for seedable sampling of search results."""
+
+from czechmedmcp.sampling import (
+    reservoir_sample,
+    sample_records,
+    sample_window,
+)
+
+
+def test_sample_window_is_capped_by_source():
+    assert sample_window(4, 100) == 20
+    assert sample_window(50, 100) == 100
+
+
+def test_reservoir_sample_is_seedable_and_ordered():
+    rows = list(range(100))
+
+    first = reservoir_sample(rows, 10, seed=42)
+
+    assert first == reservoir_sample(rows, 10, seed=42)
+    assert first == sorted(first)
+    assert len(set(first)) == 10
+    assert first != rows[:10]
+
+
+def test_reservoir_sample_of_short_list_keeps_everything():
+    assert reservoir_sample(["a", "b"], 5, seed=1) == ["a", "b"]
+
+
+def test_sample_records_in_dict_keeps_other_fields():
+    data = {"studies": list(range(30)), "nextPageToken": "abc"}
+
+    sampled = sample_records(data, 3, seed=7, key="studies")
+
+    assert len(sampled["studies"]) == 3
+    assert sampled["nextPageToken"] == "abc"
+
+
+def test_sample_records_passes_errors_through():
+    errors = [{"error": "Error 500: upstream failure"}]
+
+    assert sample_records(errors, 3, seed=7) == errors
+    assert sample_records({"error": "x"}, 3, key="studies") == {"error": "x"}
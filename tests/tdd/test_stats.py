@@ -0,0 +1,189 @@
+"""Tests for count-only facet distributions."""
+
+import json
+
+import pytest
+
+from czechmedmcp.stats import (
+    AdverseEventFacet,
+    TrialFacet,
+    adverse_event_facet_counts,
+    format_distribution,
+    format_facet_table,
+    trial_facet_counts,
+)
+from czechmedmcp.trials.search import RecruitingStatus, TrialQuery
+
+
+def _study(status, phases=None, sponsor=None, countries=()):
+    return {
+        "protocolSection": {
+            "statusModule": {"overallStatus": status},
+            "designModule": {"phases": phases or []},
+            "sponsorCollaboratorsModule": {"leadSponsor": {"class": sponsor}},
+            "contactsLocationsModule": {
+                "locations": [{"country": c} for c in countries]
+            },
+        }
+    }
+
+
+PAGES = [
+    {
+        "totalCount": 3,
+        "studies": [
+            _study("RECRUITING", ["PHASE2"], "INDUSTRY", ["US", "US"]),
+            _study("COMPLETED", ["PHASE1", "PHASE2"], "OTHER", ["FR"]),
+        ],
+        "nextPageToken": "page2",
+    },
+    {
+        "studies": [
+            _study("RECRUITING", None, "INDUSTRY", ["US", "FR"]),
+        ],
+    },
+]
+
+
+@pytest.fixture
+def ctgov(monkeypatch):
+    calls = []
+
+    async def fake_request_api(url, request, **kwargs):
+        calls.append(dict(request))
+        if request.get("format") != ["json"]:
+            # What a CSV page parses to: rows, no token or total
+            return [{"NCT Number": "NCT00000001"}], None
+        return PAGES[len(calls) - 1], None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+@pytest.mark.asyncio
+async def test_trial_counts_by_status_follow_pages(ctgov):
+    query = TrialQuery(
+        conditions=["melanoma"],
+        recruiting_status=RecruitingStatus.ANY,
+        expand_synonyms=False,
+    )
+    result, error = await trial_facet_counts(query, TrialFacet.STATUS)
+
+    assert error is None
+    assert result["total"] == 3
+    assert result["scanned"] == 3
+    assert result["buckets"] == [
+        {"value": "RECRUITING", "count": 2},
+        {"value": "COMPLETED", "count": 1},
+    ]
+    assert ctgov[0]["format"] == ["json"]
+    assert ctgov[0]["fields"] == ["NCTId,OverallStatus"]
+    assert ctgov[0]["countTotal"] == ["true"]
+    assert ctgov[1]["pageToken"] == ["page2"]
+
+
+@pytest.mark.asyncio
+async def test_trial_counts_by_phase_and_country(ctgov):
+    query = TrialQuery(conditions=["melanoma"], expand_synonyms=False)
+    phases, _ = await trial_facet_counts(query, TrialFacet.PHASE)
+    assert [b["value"] for b in phases["buckets"]] == [
+        "NA",
+        "PHASE1/PHASE2",
+        "PHASE2",
+    ]
+
+    ctgov.clear()
+    countries, _ = await trial_facet_counts(query, TrialFacet.COUNTRY)
+    # Each trial counts once per distinct country
+    assert countries["buckets"] == [
+        {"value": "FR", "count": 2},
+        {"value": "US", "count": 2},
+    ]
+
+
+@pytest.mark.asyncio
+async def test_trial_counts_stop_at_scan_cap(ctgov):
+    query = TrialQuery(conditions=["melanoma"], expand_synonyms=False)
+    result, _ = await trial_facet_counts(
+        query, TrialFacet.SPONSOR_TYPE, max_scanned=2
+    )
+
+    assert len(ctgov) == 1
+    assert ctgov[0]["pageSize"] == ["2"]
+    assert result["total"] == 3
+    assert result["scanned"] == 2
+    assert "(first 2 scanned)" in format_facet_table(result)
+
+
+@pytest.mark.asyncio
+async def test_trial_counts_report_api_error(monkeypatch):
+    class Error:
+        code = 500
+        message = "boom"
+
+    async def fake_request_api(url, request, **kwargs):
+        return None, Error()
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    result, error = await trial_facet_counts(
+        TrialQuery(conditions=["melanoma"], expand_synonyms=False),
+        TrialFacet.STATUS,
+    )
+    assert result is None
+    assert error == "Error 500: boom"
+
+
+@pytest.mark.asyncio
+async def test_adverse_event_counts_reuse_count_query(monkeypatch):
+    captured = {}
+
+    async def fake_request(endpoint, params, domain, api_key=None):
+        captured.update(params)
+        return {
+            "results": [
+                {"term": "NAUSEA", "count": 120},
+                {"term": "RASH", "count": 45},
+            ]
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.openfda.adverse_events.make_openfda_request",
+        fake_request,
+    )
+    result, error = await adverse_event_facet_counts(
+        AdverseEventFacet.REACTION, drug="imatinib", limit=10
+    )
+
+    assert error is None
+    assert captured["count"] == "patient.reaction.reactionmeddrapt.exact"
+    assert captured["limit"] == 10
+    assert result["buckets"][0] == {"value": "NAUSEA", "count": 120}
+    table = format_facet_table(result)
+    assert "| Rank | Value | Count |" in table
+    assert "%" not in table
+
+
+def test_format_facet_table_ranks_with_percentages():
+    distribution = {
+        "entity": "trial",
+        "facet": "sponsor_type",
+        "total": 4,
+        "scanned": 4,
+        "buckets": [
+            {"value": "INDUSTRY", "count": 3},
+            {"value": "OTHER", "count": 1},
+        ],
+    }
+    table = format_facet_table(distribution, "Conditions: melanoma")
+
+    assert table.startswith("# Trial counts by sponsor type")
+    assert "**Query**: Conditions: melanoma" in table
+    assert "| 1 | INDUSTRY | 3 | 75.0% |" in table
+    assert "| 2 | OTHER | 1 | 25.0% |" in table
+    assert json.loads(format_distribution(distribution, True)) == (
+        distribution
+    )
@@ -0,0 +1,76 @@
+"""Tests for terminal detection and the global color mode."""
+
+import io
+
+import pytest
+
+from czechmedmcp import render, terminal
+from czechmedmcp.render import MAX_WIDTH, RenderContext
+from czechmedmcp.terminal import (
+    NO_COLOR_ENV,
+    ColorMode,
+    configure_terminal,
+    detect_render_context,
+    set_color_mode,
+    use_color,
+)
+
+
+class FakeTTY(io.StringIO):
+    def isatty(self):
+        return True
+
+
+@pytest.fixture(autouse=True)
+def reset_terminal(monkeypatch):
+    monkeypatch.delenv(NO_COLOR_ENV, raising=False)
+    monkeypatch.setattr(terminal, "_color_mode", ColorMode.AUTO)
+    monkeypatch.setattr(terminal, "_console", None)
+    monkeypatch.setattr(render, "_render_context", RenderContext())
+
+
+def test_auto_mode_only_colors_terminals():
+    assert use_color(FakeTTY()) is True
+    assert use_color(io.StringIO()) is False
+
+
+def test_no_color_env_disables_auto_color(monkeypatch):
+    monkeypatch.setenv(NO_COLOR_ENV, "1")
+    assert use_color(FakeTTY()) is False
+
+
+def test_explicit_modes_override_detection(monkeypatch):
+    monkeypatch.setenv(NO_COLOR_ENV, "1")
+    set_color_mode("always")
+    assert use_color(io.StringIO()) is True
+
+    set_color_mode(ColorMode.NEVER)
+    assert use_color(FakeTTY()) is False
+
+
+def test_pipe_gets_default_render_context():
+    context = detect_render_context(io.StringIO())
+    assert context == RenderContext(width=MAX_WIDTH)
+
+
+def test_narrow_terminal_narrows_wrapping(monkeypatch):
+    monkeypatch.setattr(
+        terminal.shutil,
+        "get_terminal_size",
+        lambda fallback: terminal.os.terminal_size((50, 24)),
+    )
+    context = detect_render_context(FakeTTY())
+    assert context.width == 50
+
+    data = {"summary": "word " * 25}
+    narrow = render.to_markdown(data, context).splitlines()
+    default = render.to_markdown(data).splitlines()
+    assert len(narrow) > len(default)
+
+
+def test_configure_terminal_sets_default_render_context(monkeypatch):
+    monkeypatch.setattr(terminal.sys, "stdout", io.StringIO())
+    configure_terminal("never")
+
+    assert terminal.get_color_mode() == ColorMode.NEVER
+    assert render.get_render_context() == RenderContext()
@@ -0,0 +1,88 @@
+"""Tests for trial arms with intervention dosing."""
+
+import json
+
+import pytest
+
+from czechmedmcp.trials.arms import (
+    format_trial_arms,
+    get_trial_arms,
+    parse_trial_arms,
+)
+
+STUDY = {
+    "protocolSection": {
+        "identificationModule": {
+            "nctId": "NCT00000002",
+            "briefTitle": "Drug A Versus Placebo",
+        },
+        "armsInterventionsModule": {
+            "armGroups": [
+                {
+                    "label": "Drug A",
+                    "type": "EXPERIMENTAL",
+                    "description": "Drug A plus standard care",
+                    "interventionNames": ["Drug: Drug A"],
+                },
+                {
+                    "label": "Placebo",
+                    "type": "PLACEBO_COMPARATOR",
+                    "interventionNames": ["Drug: Placebo"],
+                },
+            ],
+            "interventions": [
+                {
+                    "type": "DRUG",
+                    "name": "Drug A",
+                    "description": "200 mg IV every 3 weeks",
+                    "armGroupLabels": ["Drug A"],
+                    "otherNames": ["ABC-123"],
+                },
+                {"type": "DRUG", "name": "Placebo"},
+            ],
+        },
+    }
+}
+
+
+def test_interventions_attach_to_arms():
+    trial = parse_trial_arms(STUDY, "NCT00000002")
+    drug_arm, placebo_arm = trial.arms
+
+    assert drug_arm.interventions[0].dosing == "200 mg IV every 3 weeks"
+    assert drug_arm.interventions[0].other_names == ["ABC-123"]
+    # No armGroupLabels: matched through the arm's interventionNames
+    assert [i.name for i in placebo_arm.interventions] == ["Placebo"]
+
+
+def test_format_renders_dosing_per_arm():
+    text = format_trial_arms(parse_trial_arms(STUDY, "NCT00000002"))
+
+    assert "## Drug A (Experimental)" in text
+    assert "  - Dosing: 200 mg IV every 3 weeks" in text
+    assert "## Placebo (Placebo Comparator)" in text
+    assert "  - Dosing: Not registered" in text
+
+
+@pytest.mark.asyncio
+async def test_get_trial_arms_json(monkeypatch):
+    captured = {}
+
+    async def fake_request_api(url, request, **kwargs):
+        captured["url"] = url
+        captured.update(request)
+        return STUDY, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    result = json.loads(await get_trial_arms("NCT00000002", output_json=True))
+
+    assert captured["url"].endswith("/NCT00000002")
+    assert "ArmsInterventionsModule" in captured["fields"]
+    assert result["arms"][0]["interventions"][0] == {
+        "name": "Drug A",
+        "type": "DRUG",
+        "dosing": "200 mg IV every 3 weeks",
+        "other_names": ["ABC-123"],
+    }
@@ -0,0 +1,101 @@
+"""Tests for resumable trial search pagination."""
+
+import json
+
+import pytest
+
+from czechmedmcp.trials.cursor import (
+    load_cursor,
+    save_cursor,
+    search_trials_with_cursor,
+)
+from czechmedmcp.trials.search import RecruitingStatus, TrialQuery
+
+
+@pytest.mark.asyncio
+async def test_search_trials_with_cursor_reads_token(monkeypatch):
+    formats = []
+
+    async def fake_request_api(url, request, **kwargs):
+        formats.append(request.get("format"))
+        if request.get("format") == ["csv"]:
+            # The parsed CSV page carries no token
+            return [{"NCT Number": "NCT00000001"}], None
+        return {
+            "studies": [{"protocolSection": {}}],
+            "nextPageToken": "NF0g5JGBlPMuwQY",
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    query = TrialQuery(conditions=["melanoma"], expand_synonyms=False)
+
+    result, token, error = await search_trials_with_cursor(query)
+
+    assert json.loads(result) == [{"NCT Number": "NCT00000001"}]
+    assert token == "NF0g5JGBlPMuwQY"
+    assert error is None
+    assert sorted(formats) == [["csv"], ["json"]]
+
+
+@pytest.mark.asyncio
+async def test_search_trials_with_cursor_last_page(monkeypatch):
+    async def fake_request_api(url, request, **kwargs):
+        if request.get("format") == ["csv"]:
+            return [{"NCT Number": "NCT00000001"}], None
+        return {"studies": [{"protocolSection": {}}]}, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+
+    _, token, error = await search_trials_with_cursor(
+        TrialQuery(conditions=["melanoma"], expand_synonyms=False)
+    )
+    assert token is None
+    assert error is None
+
+
+def test_save_resume_round_trip(tmp_path):
+    path = tmp_path / "cursor.json"
+    query = TrialQuery(
+        conditions=["melanoma"], recruiting_status=RecruitingStatus.OPEN
+    )
+
+    save_cursor(path, query, "NF0g5JGBlPMuwQY")
+
+    saved = json.loads(path.read_text())
+    assert saved["next_page_token"] == "NF0g5JGBlPMuwQY"
+    assert saved["query"]["conditions"] == ["melanoma"]
+    assert "next_page_hash" not in saved["query"]
+
+    # The resumed query carries the token of the previous page
+    resumed = TrialQuery(
+        conditions=["melanoma"],
+        recruiting_status=RecruitingStatus.OPEN,
+        next_page_hash="NF0g5JGBlPMuwQY",
+    )
+    assert load_cursor(path, resumed) == "NF0g5JGBlPMuwQY"
+
+    # The last page removes the cursor file
+    save_cursor(path, resumed, None)
+    assert not path.exists()
+
+
+def test_resume_rejects_mismatched_query(tmp_path):
+    path = tmp_path / "cursor.json"
+    save_cursor(path, TrialQuery(conditions=["melanoma"]), "token")
+
+    with pytest.raises(ValueError, match="different query"):
+        load_cursor(path, TrialQuery(conditions=["glioma"]))
+
+
+def test_resume_rejects_unreadable_file(tmp_path):
+    with pytest.raises(ValueError, match="Cannot read cursor file"):
+        load_cursor(tmp_path / "missing.json", TrialQuery())
+
+    path = tmp_path / "cursor.json"
+    path.write_text(json.dumps({"query": {}}))
+    with pytest.raises(ValueError, match="no next page token"):
+        load_cursor(path, TrialQuery())
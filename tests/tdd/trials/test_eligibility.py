@@ -0,0 +1,181 @@
+"""Tests for best-effort trial eligibility matching."""
+
+import json
+
+import pytest
+
+from czechmedmcp.trials.eligibility import (
+    PatientProfile,
+    PatientSex,
+    Verdict,
+    check_trial_eligibility,
+    eligibility_keyword_in_text,
+    eligibility_keyword_negated,
+    evaluate_eligibility,
+    get_structured_eligibility,
+    parse_age_years,
+    split_eligibility_sections,
+)
+
+CRITERIA = """Inclusion Criteria:
+
+* Histologically confirmed NSCLC with EGFR mutation
+* Progression on osimertinib
+* ECOG 0-1
+
+Exclusion Criteria:
+
+* Known ALK rearrangement
+* Prior treatment with amivantamab
+"""
+
+
+def test_split_eligibility_sections():
+    inclusion, exclusion = split_eligibility_sections(CRITERIA)
+    assert inclusion == [
+        "Histologically confirmed NSCLC with EGFR mutation",
+        "Progression on osimertinib",
+        "ECOG 0-1",
+    ]
+    assert exclusion == [
+        "Known ALK rearrangement",
+        "Prior treatment with amivantamab",
+    ]
+
+
+def test_keyword_matching_is_whole_word():
+    assert eligibility_keyword_in_text("EGFR", "EGFR exon 19 deletion")
+    assert not eligibility_keyword_in_text("ALK", "Alkaline phosphatase")
+
+
+def test_keyword_negation_is_clause_scoped():
+    assert eligibility_keyword_negated("osimertinib", "No prior osimertinib")
+    assert eligibility_keyword_negated("EGFR", "Tumor without EGFR mutation")
+    assert not eligibility_keyword_negated(
+        "EGFR", "No brain metastases; EGFR mutation"
+    )
+    assert not eligibility_keyword_negated("EGFR", "EGFR mutation, not ALK")
+
+
+def test_evaluate_eligibility_negated_inclusion_is_mismatch():
+    module = {
+        "eligibilityCriteria": (
+            "Inclusion Criteria:\n"
+            "* No prior osimertinib\n"
+            "* Tumor without EGFR or ALK alteration\n"
+            "Exclusion Criteria:\n"
+            "* Patients not previously treated with osimertinib\n"
+        )
+    }
+    profile = PatientProfile(
+        mutations=["EGFR"], prior_therapies=["osimertinib"]
+    )
+
+    results = evaluate_eligibility(profile, module)
+    verdicts = {r.criterion: r.verdict for r in results}
+
+    assert verdicts["No prior osimertinib"] == Verdict.MISMATCH
+    assert verdicts["Tumor without EGFR or ALK alteration"] == (
+        Verdict.MISMATCH
+    )
+    assert verdicts["Patients not previously treated with osimertinib"] == (
+        Verdict.UNKNOWN
+    )
+    assert results[0].reason == "rules out osimertinib"
+
+
+def test_parse_age_years():
+    assert parse_age_years("18 Years") == 18
+    assert parse_age_years("6 Months") == 0.5
+    assert parse_age_years(None) is None
+
+
+def test_evaluate_eligibility_verdicts():
+    module = {
+        "eligibilityCriteria": CRITERIA,
+        "minimumAge": "18 Years",
+        "sex": "ALL",
+    }
+    profile = PatientProfile(
+        age=55, mutations=["EGFR", "ALK"], prior_therapies=["osimertinib"]
+    )
+
+    results = evaluate_eligibility(profile, module)
+    verdicts = {r.criterion: r.verdict for r in results}
+
+    assert verdicts["Age 18 Years - N/A"] == Verdict.MATCH
+    assert verdicts["Progression on osimertinib"] == Verdict.MATCH
+    assert verdicts["ECOG 0-1"] == Verdict.UNKNOWN
+    assert verdicts["Known ALK rearrangement"] == Verdict.MISMATCH
+    assert verdicts["Prior treatment with amivantamab"] == Verdict.UNKNOWN
+
+
+def test_evaluate_eligibility_sex_and_age_mismatch():
+    module = {"minimumAge": "18 Years", "maximumAge": "40 Years"}
+    module["sex"] = "FEMALE"
+    profile = PatientProfile(age=65, sex="male")
+
+    results = evaluate_eligibility(profile, module)
+
+    assert [r.verdict for r in results] == [Verdict.MISMATCH] * 2
+
+
+def test_patient_profile_rejects_unknown_sex():
+    assert PatientProfile(sex="FEMALE").sex == PatientSex.FEMALE
+    with pytest.raises(ValueError):
+        PatientProfile(sex="unknown")
+
+
+@pytest.mark.asyncio
+async def test_check_trial_eligibility_json(monkeypatch):
+    async def mock_get_trial(nct_id, module, output_json):
+        return json.dumps({
+            "protocolSection": {
+                "eligibilityModule": {"eligibilityCriteria": CRITERIA}
+            }
+        })
+
+    monkeypatch.setattr(
+        "czechmedmcp.trials.eligibility.get_trial", mock_get_trial
+    )
+
+    result = await check_trial_eligibility(
+        "NCT00000001",
+        PatientProfile(mutations=["EGFR"]),
+        output_json=True,
+    )
+    data = json.loads(result)
+
+    assert data["nct_id"] == "NCT00000001"
+    assert data["criteria"][0]["verdict"] == "match"
+    assert len(data["criteria"]) == 5
+
+
+@pytest.mark.asyncio
+async def test_get_structured_eligibility(monkeypatch):
+    async def mock_get_trial(nct_id, module, output_json):
+        return json.dumps({
+            "protocolSection": {
+                "eligibilityModule": {"eligibilityCriteria": CRITERIA}
+            }
+        })
+
+    monkeypatch.setattr(
+        "czechmedmcp.trials.eligibility.get_trial", mock_get_trial
+    )
+
+    data = json.loads(
+        await get_structured_eligibility("NCT00000001", output_json=True)
+    )
+    assert data["inclusion"] == [
+        "Histologically confirmed NSCLC with EGFR mutation",
+        "Progression on osimertinib",
+        "ECOG 0-1",
+    ]
+    assert data["exclusion"] == [
+        "Known ALK rearrangement",
+        "Prior treatment with amivantamab",
+    ]
+
+    text = await get_structured_eligibility("NCT00000001")
+    assert "## Exclusion Criteria\n\n1. Known ALK rearrangement" in text
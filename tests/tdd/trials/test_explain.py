@@ -0,0 +1,92 @@
+"""Tests for explaining eligibility-based trial search matches."""
+
+import json
+
+import pytest
+
+from czechmedmcp.trials.explain import (
+    MATCH_FIELD,
+    explain_keywords,
+    explain_trial_matches,
+    find_matching_criteria,
+)
+from czechmedmcp.trials.search import TrialQuery
+
+CRITERIA = """Inclusion Criteria:
+
+* Histologically confirmed melanoma with a documented BRAF V600E mutation
+* Prior treatment with pembrolizumab is allowed
+
+Exclusion Criteria:
+
+* Progression on dabrafenib within 3 months
+"""
+
+
+def test_explain_keywords():
+    query = TrialQuery(
+        required_mutations=["BRAF V600E"],
+        excluded_mutations=["KRAS"],
+        prior_therapies=["pembrolizumab"],
+        biomarker_expression={"PD-L1": ">=50%"},
+    )
+
+    assert explain_keywords(query) == ["BRAF V600E", "pembrolizumab", "PD-L1"]
+
+
+def test_find_matching_criteria():
+    matches = find_matching_criteria(
+        ["BRAF V600E", "dabrafenib", "NRAS"], CRITERIA
+    )
+
+    assert matches == [
+        'BRAF V600E (inclusion): "Histologically confirmed melanoma with a '
+        'documented BRAF V600E mutation"',
+        'dabrafenib (exclusion): "Progression on dabrafenib within 3 months"',
+    ]
+
+
+def test_find_matching_criteria_shortens_long_criteria():
+    text = "Inclusion Criteria:\n* BRAF V600E " + "positive tumour " * 30
+    (match,) = find_matching_criteria(["BRAF V600E"], text)
+
+    assert match.endswith('..."')
+    assert len(match) < 200
+
+
+@pytest.mark.asyncio
+async def test_explain_trial_matches(monkeypatch):
+    requested = []
+
+    async def fake_request_api(url, request, **kwargs):
+        nct_id = url.rsplit("/", 1)[-1]
+        requested.append(nct_id)
+        if nct_id == "NCT00000003":
+            return None, None
+        criteria = CRITERIA if nct_id == "NCT00000001" else "* Adults"
+        return {
+            "studies": [
+                {
+                    "protocolSection": {
+                        "eligibilityModule": {"eligibilityCriteria": criteria}
+                    }
+                }
+            ]
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    trials = [
+        {"NCT Number": "NCT00000001"},
+        {"NCT Number": "NCT00000002"},
+        {"NCT Number": "NCT00000003"},
+    ]
+
+    await explain_trial_matches(trials, ["BRAF V600E"])
+
+    assert sorted(requested) == ["NCT00000001", "NCT00000002", "NCT00000003"]
+    assert trials[0][MATCH_FIELD][0].startswith("BRAF V600E (inclusion)")
+    assert "No criterion mentions" in trials[1][MATCH_FIELD]
+    assert trials[2][MATCH_FIELD] == "Eligibility criteria unavailable"
+    json.dumps(trials)
@@ -0,0 +1,86 @@
+"""Tests for trial site distance annotation."""
+
+import json
+
+import pytest
+
+from czechmedmcp.trials.getter import Module, get_trial
+from czechmedmcp.trials.locations import (
+    annotate_location_distances,
+    haversine_miles,
+    location_distance,
+)
+
+BOSTON = (42.3601, -71.0589)
+
+
+def _study(*locations):
+    return {
+        "protocolSection": {
+            "contactsLocationsModule": {"locations": list(locations)}
+        }
+    }
+
+
+def test_haversine_miles_known_distance():
+    """Boston to New York is roughly 190 miles."""
+    distance = haversine_miles(*BOSTON, 40.7128, -74.0060)
+    assert 185 < distance < 195
+    assert haversine_miles(*BOSTON, *BOSTON) == 0
+
+
+def test_location_distance_without_geo_point():
+    """Sites without usable coordinates have no distance."""
+    assert location_distance({"city": "Nowhere"}, *BOSTON) is None
+    assert location_distance({"geoPoint": {"lat": 1}}, *BOSTON) is None
+
+
+def test_annotate_sorts_nearest_first_missing_last():
+    """Sites are sorted by distance; sites without geoPoint go last."""
+    study = _study(
+        {"facility": "NYC", "geoPoint": {"lat": 40.7128, "lon": -74.006}},
+        {"facility": "Unknown"},
+        {"facility": "Boston", "geoPoint": {"lat": 42.36, "lon": -71.06}},
+    )
+    annotate_location_distances(study, *BOSTON)
+    sites = study["protocolSection"]["contactsLocationsModule"]["locations"]
+
+    assert [s["facility"] for s in sites] == ["Boston", "NYC", "Unknown"]
+    assert sites[0]["distance_miles"] < 1
+    assert sites[2]["distance_miles"] is None
+
+
+def test_annotate_without_locations_is_noop():
+    """Studies without a locations module are returned unchanged."""
+    study = {"protocolSection": {}}
+    assert annotate_location_distances(study, *BOSTON) == {
+        "protocolSection": {}
+    }
+
+
+@pytest.mark.asyncio
+async def test_get_trial_near_adds_distance(monkeypatch):
+    """get_trial annotates locations in JSON output when near is set."""
+
+    async def mock_request_api(**kwargs):
+        return {
+            "studies": [
+                _study(
+                    {"facility": "Far", "geoPoint": {"lat": 34, "lon": -118}},
+                    {"facility": "Near", "geoPoint": {"lat": 42, "lon": -71}},
+                )
+            ]
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", mock_request_api
+    )
+
+    result = await get_trial(
+        "NCT00000001", Module.LOCATIONS, output_json=True, near=BOSTON
+    )
+    sites = json.loads(result)["protocolSection"]["contactsLocationsModule"][
+        "locations"
+    ]
+    assert [s["facility"] for s in sites] == ["Near", "Far"]
+    assert all("distance_miles" in s for s in sites)
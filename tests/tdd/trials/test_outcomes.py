@@ -0,0 +1,223 @@
+"""Tests for structured trial outcomes with posted results."""
+
+import json
+
+import pytest
+
+from czechmedmcp.trials.outcomes import (
+    format_trial_outcomes,
+    get_trial_outcomes,
+    parse_trial_outcomes,
+)
+
+STUDY = {
+    "protocolSection": {
+        "identificationModule": {"nctId": "NCT00000001"},
+        "outcomesModule": {
+            "primaryOutcomes": [
+                {
+                    "measure": "Overall Survival",
+                    "timeFrame": "Up to 5 years",
+                }
+            ],
+            "secondaryOutcomes": [
+                {"measure": "Objective Response Rate", "timeFrame": "1 year"}
+            ],
+        },
+    },
+    "hasResults": True,
+    "resultsSection": {
+        "participantFlowModule": {
+            "groups": [
+                {"id": "FG000", "title": "Drug A"},
+                {"id": "FG001", "title": "Placebo"},
+            ],
+            "periods": [
+                {
+                    "title": "Overall Study",
+                    "milestones": [
+                        {
+                            "type": "STARTED",
+                            "achievements": [
+                                {"groupId": "FG000", "numSubjects": "100"},
+                                {"groupId": "FG001", "numSubjects": "98"},
+                            ],
+                        },
+                        {
+                            "type": "COMPLETED",
+                            "achievements": [
+                                {"groupId": "FG000", "numSubjects": "80"},
+                                {"groupId": "FG001", "numSubjects": "75"},
+                            ],
+                        },
+                    ],
+                }
+            ],
+        },
+        "outcomeMeasuresModule": {
+            "outcomeMeasures": [
+                {
+                    "type": "PRIMARY",
+                    "title": "Overall  survival",
+                    "paramType": "MEDIAN",
+                    "dispersionType": "95% Confidence Interval",
+                    "unitOfMeasure": "months",
+                    "groups": [
+                        {"id": "OG000", "title": "Drug A"},
+                        {"id": "OG001", "title": "Placebo"},
+                    ],
+                    "denoms": [
+                        {
+                            "units": "Participants",
+                            "counts": [
+                                {"groupId": "OG000", "value": "100"},
+                                {"groupId": "OG001", "value": "98"},
+                            ],
+                        }
+                    ],
+                    "classes": [
+                        {
+                            "categories": [
+                                {
+                                    "measurements": [
+                                        {
+                                            "groupId": "OG000",
+                                            "value": "14.2",
+                                            "lowerLimit": "12.1",
+                                            "upperLimit": "16.0",
+                                        },
+                                        {
+                                            "groupId": "OG001",
+                                            "value": "9.8",
+                                            "lowerLimit": "8.0",
+                                            "upperLimit": "11.5",
+                                        },
+                                    ]
+                                }
+                            ]
+                        }
+                    ],
+                    "analyses": [
+                        {
+                            "groupIds": ["OG000", "OG001"],
+                            "pValue": "0.003",
+                            "statisticalMethod": "Log Rank",
+                            "paramType": "Hazard Ratio (HR)",
+                            "paramValue": "0.68",
+                            "ciLowerLimit": "0.52",
+                            "ciUpperLimit": "0.88",
+                        }
+                    ],
+                },
+                {
+                    "type": "SECONDARY",
+                    "title": "Time to Progression",
+                    "unitOfMeasure": "months",
+                    "groups": [{"id": "OG000", "title": "Drug A"}],
+                    "classes": [
+                        {
+                            "categories": [
+                                {
+                                    "measurements": [
+                                        {"groupId": "OG000", "value": "6.1"}
+                                    ]
+                                }
+                            ]
+                        }
+                    ],
+                },
+            ]
+        },
+    },
+}
+
+
+def test_outcomes_without_results_omit_result_fields():
+    trial = parse_trial_outcomes(STUDY)
+
+    assert trial.has_results
+    assert [o.measure for o in trial.outcomes] == [
+        "Overall Survival",
+        "Objective Response Rate",
+    ]
+    assert trial.outcomes[0].measurements is None
+    assert trial.participant_flow is None
+
+
+def test_reported_measures_attach_to_registered_outcomes():
+    trial = parse_trial_outcomes(STUDY, with_results=True)
+    survival = trial.outcomes[0]
+
+    assert survival.unit == "months"
+    assert survival.participants == {"Drug A": "100", "Placebo": "98"}
+    assert survival.measurements[0].group == "Drug A"
+    assert survival.measurements[0].upper_limit == "16.0"
+    assert survival.analyses[0].p_value == "0.003"
+    assert survival.analyses[0].groups == ["Drug A", "Placebo"]
+    # Registered but not reported
+    assert trial.outcomes[1].measurements is None
+    # Reported but not registered under that title
+    assert trial.outcomes[2].measure == "Time to Progression"
+    assert trial.outcomes[2].measurements[0].value == "6.1"
+
+
+def test_participant_flow_counts_by_group():
+    trial = parse_trial_outcomes(STUDY, with_results=True)
+    period = trial.participant_flow[0]
+
+    assert period.title == "Overall Study"
+    assert period.milestones[1].type == "COMPLETED"
+    assert period.milestones[1].counts == {"Drug A": "80", "Placebo": "75"}
+
+
+def test_format_renders_results_tables():
+    text = format_trial_outcomes(
+        parse_trial_outcomes(STUDY, with_results=True)
+    )
+
+    assert "## Primary outcome 1: Overall Survival" in text
+    assert "### Results (MEDIAN, 95% Confidence Interval, months)" in text
+    assert "| Drug A | 100 |  | 14.2 [12.1, 16.0] |" in text
+    assert (
+        "Drug A vs Placebo; Hazard Ratio (HR) 0.68 (CI 0.52 to 0.88); "
+        "p = 0.003; Log Rank"
+    ) in text
+    assert "| Milestone | Drug A | Placebo |" in text
+    assert "| STARTED | 100 | 98 |" in text
+
+
+def test_format_notes_missing_results():
+    study = {
+        "protocolSection": STUDY["protocolSection"],
+        "hasResults": False,
+    }
+    text = format_trial_outcomes(parse_trial_outcomes(study, True))
+
+    assert "No results have been posted for this trial." in text
+    assert "### Results" not in text
+
+
+@pytest.mark.asyncio
+async def test_get_trial_outcomes_json(monkeypatch):
+    captured = {}
+
+    async def fake_request_api(url, request, **kwargs):
+        captured["url"] = url
+        captured.update(request)
+        return STUDY, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    result = json.loads(
+        await get_trial_outcomes(
+            "NCT00000001", with_results=True, output_json=True
+        )
+    )
+
+    assert captured["url"].endswith("/NCT00000001")
+    assert "ResultsSection" in captured["fields"]
+    assert result["outcomes"][0]["analyses"][0]["param_value"] == "0.68"
+    assert result["participant_flow"][0]["milestones"][0]["type"] == (
+        "STARTED"
+    )
@@ -1,9 +1,15 @@
+from datetime import date
+
 import pytest
 
 from czechmedmcp.trials.search import (
     CLOSED_STATUSES,
     AgeGroup,
     DateField,
+    DesignAllocation,
+    DesignMasking,
+    FunderType,
+    InterventionModel,
     InterventionType,
     LineOfTherapy,
     PrimaryPurpose,
@@ -22,7 +28,16 @@ from czechmedmcp.trials.search import (
     _build_progression_essie,
     _build_required_mutations_essie,
     _inject_ids,
+    acronym_term,
+    collaborator_term,
     convert_query,
+    eligible_age_range,
+    funder_exclusion_term,
+    nct_prefix_range,
+    results_within_range,
+    sort_trials_by_status,
+    trial_search_query_summary,
+    updated_within_range,
 )
 
 
@@ -225,6 +240,231 @@ async def test_convert_query_intervention_type():
     assert "AREA[InterventionType]Drug" in params["filter.advanced"][0]
 
 
+@pytest.mark.asyncio
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        ("drug", "Drug"),
+        ("biological", "Biological"),
+        ("device", "Device"),
+        ("procedure", "Procedure"),
+        ("radiation", "Radiation"),
+        ("behavioral", "Behavioral"),
+        ("other", "Other"),
+    ],
+)
+async def test_convert_query_intervention_type_terms(value, expected):
+    """Each CLI intervention type maps to its AREA[InterventionType] term."""
+    query = TrialQuery(intervention_type=value)
+    params = await convert_query(query)
+
+    assert query.intervention_type == InterventionType(value.upper())
+    assert f"AREA[InterventionType]{expected}" in params["filter.advanced"][0]
+
+
+@pytest.mark.asyncio
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        (InterventionType.DRUG, "Drug"),
+        (InterventionType.BIOLOGICAL, "Biological/Vaccine"),
+        (InterventionType.PROCEDURE, "Procedure/Surgery"),
+    ],
+)
+async def test_convert_query_to_nci_intervention_type(value, expected):
+    """Intervention type is forwarded to the NCI CTS API."""
+    from czechmedmcp.trials.nci_search import convert_query_to_nci
+
+    params = await convert_query_to_nci(TrialQuery(intervention_type=value))
+
+    assert params["intervention_type"] == expected
+
+
+def test_intervention_type_rejects_unknown_value():
+    with pytest.raises(ValueError):
+        TrialQuery(intervention_type="hypnosis")
+
+
+def test_trial_search_query_summary_includes_intervention_type():
+    query = TrialQuery(
+        conditions=["melanoma"],
+        intervention_type=InterventionType.DEVICE,
+    )
+
+    summary = trial_search_query_summary(query)
+
+    assert summary.startswith("Conditions: melanoma")
+    assert "Intervention type: Device" in summary
+
+
+
+@pytest.mark.asyncio
+async def test_convert_query_enrollment_range():
+    """Enrollment bounds become an EnrollmentCount range filter."""
+    params = await convert_query(
+        TrialQuery(conditions=["melanoma"], min_enrollment=100)
+    )
+    assert "AREA[EnrollmentCount]RANGE[100,MAX]" in params["filter.advanced"][0]
+
+    params = await convert_query(
+        TrialQuery(conditions=["melanoma"], min_enrollment=50, max_enrollment=500)
+    )
+    assert "AREA[EnrollmentCount]RANGE[50,500]" in params["filter.advanced"][0]
+
+    params = await convert_query(TrialQuery(conditions=["melanoma"]))
+    assert "EnrollmentCount" not in params.get("filter.advanced", [""])[0]
+
+
+def test_enrollment_range_rejects_min_above_max():
+    with pytest.raises(ValueError, match="min_enrollment"):
+        TrialQuery(min_enrollment=500, max_enrollment=50)
+
+
+def test_trial_search_query_summary_includes_enrollment():
+    query = TrialQuery(conditions=["melanoma"], min_enrollment=100)
+    assert "Enrollment: >= 100" in trial_search_query_summary(query)
+
+    query = TrialQuery(conditions=["melanoma"], max_enrollment=40)
+    assert "Enrollment: <= 40" in trial_search_query_summary(query)
+
+
+def test_updated_within_range_uses_injected_today():
+    assert updated_within_range("90d", today=date(2025, 3, 31)) == (
+        "AREA[LastUpdatePostDate]RANGE[2024-12-31,MAX]"
+    )
+    # "mo" is months; "m" would be minutes
+    assert updated_within_range("6mo", today=date(2025, 3, 31)) == (
+        "AREA[LastUpdatePostDate]RANGE[2024-10-02,MAX]"
+    )
+
+
+@pytest.mark.asyncio
+async def test_convert_query_updated_within():
+    """A relative recency window becomes a last-update range filter."""
+    params = await convert_query(
+        TrialQuery(conditions=["melanoma"], updated_within="90d")
+    )
+    expected = updated_within_range("90d")
+    assert expected in params["filter.advanced"][0]
+
+    query = TrialQuery(conditions=["melanoma"], updated_within="90d")
+    assert "Updated within: 90d" in trial_search_query_summary(query)
+
+
+def test_updated_within_rejects_min_date():
+    with pytest.raises(ValueError, match="min_date"):
+        TrialQuery(updated_within="30d", min_date="2024-01-01")
+    with pytest.raises(ValueError, match="Invalid duration"):
+        TrialQuery(updated_within="sometime")
+
+
+def test_results_within_range_uses_injected_today():
+    assert results_within_range("30d", today=date(2025, 3, 31)) == (
+        "AREA[ResultsFirstPostDate]RANGE[2025-03-01,MAX]"
+    )
+
+
+@pytest.mark.asyncio
+async def test_convert_query_results_within():
+    """A results-posting window is a separate range from last update."""
+    query = TrialQuery(
+        conditions=["melanoma"], updated_within="90d", results_within="30d"
+    )
+    params = await convert_query(query)
+    advanced = params["filter.advanced"][0]
+    assert results_within_range("30d") in advanced
+    assert updated_within_range("90d") in advanced
+    assert "Results posted within: 30d" in trial_search_query_summary(query)
+
+    with pytest.raises(ValueError, match="Invalid duration"):
+        TrialQuery(results_within="lately")
+
+
+def test_eligible_age_range():
+    assert eligible_age_range(max_age=17) == [
+        "AREA[MaximumAge]RANGE[MIN,17 years]"
+    ]
+    assert eligible_age_range(min_age=65) == [
+        "AREA[MinimumAge]RANGE[65 years,MAX]"
+    ]
+    assert eligible_age_range(18, 40) == [
+        "AREA[MinimumAge]RANGE[18 years,MAX]",
+        "AREA[MaximumAge]RANGE[MIN,40 years]",
+    ]
+    assert eligible_age_range() == []
+
+
+@pytest.mark.asyncio
+async def test_convert_query_eligible_age_bounds():
+    query = TrialQuery(conditions=["leukemia"], max_age=17)
+    params = await convert_query(query)
+
+    assert "AREA[MaximumAge]RANGE[MIN,17 years]" in (
+        params["filter.advanced"][0]
+    )
+    assert "Max eligible age: <= 17 years" in trial_search_query_summary(
+        query
+    )
+    with pytest.raises(ValueError, match="min_age"):
+        TrialQuery(min_age=65, max_age=17)
+
+
+def test_acronym_term():
+    assert acronym_term("KEYNOTE-006") == 'AREA[Acronym]"KEYNOTE-006"'
+    assert acronym_term(' "CheckMate 067" ') == 'AREA[Acronym]"CheckMate 067"'
+
+
+def test_nct_prefix_range():
+    assert nct_prefix_range("NCT0428") == (
+        "AREA[NCTId]RANGE[NCT04280000,NCT04289999]"
+    )
+    assert nct_prefix_range("nct04280705") == (
+        "AREA[NCTId]RANGE[NCT04280705,NCT04280705]"
+    )
+    assert nct_prefix_range("0428") == nct_prefix_range("NCT0428")
+    with pytest.raises(ValueError, match="Invalid partial NCT number"):
+        TrialQuery(nct_prefix="NCT042807051")
+    with pytest.raises(ValueError, match="Invalid partial NCT number"):
+        TrialQuery(nct_prefix="KEYNOTE")
+
+
+@pytest.mark.asyncio
+async def test_convert_query_acronym_and_nct_prefix():
+    """Acronym and partial NCT filters become advanced essie terms."""
+    query = TrialQuery(acronym="KEYNOTE-006", nct_prefix="nct0156")
+    params = await convert_query(query)
+
+    assert params["filter.advanced"] == [
+        'AREA[Acronym]"KEYNOTE-006" AND '
+        "AREA[NCTId]RANGE[NCT01560000,NCT01569999]"
+    ]
+    assert trial_search_query_summary(query) == (
+        "Acronym: KEYNOTE-006 | NCT prefix: NCT0156"
+    )
+
+
+def test_collaborator_term():
+    assert collaborator_term(["Merck"]) == 'AREA[CollaboratorName]"Merck"'
+    assert collaborator_term(["NCI", ' "Pfizer" ']) == (
+        '(AREA[CollaboratorName]"NCI" OR AREA[CollaboratorName]"Pfizer")'
+    )
+
+
+@pytest.mark.asyncio
+async def test_convert_query_collaborators():
+    """Collaborators are matched on CollaboratorName, not the lead sponsor."""
+    query = TrialQuery(collaborators=["Merck Sharp & Dohme"])
+    params = await convert_query(query)
+
+    assert params["filter.advanced"] == [
+        'AREA[CollaboratorName]"Merck Sharp & Dohme"'
+    ]
+    assert "query.lead" not in params
+    assert trial_search_query_summary(query) == (
+        "Collaborators: Merck Sharp & Dohme"
+    )
+
+
 @pytest.mark.asyncio
 async def test_convert_query_sponsor_type():
     """Test conversion of sponsor type to API format."""
@@ -235,6 +475,27 @@ async def test_convert_query_sponsor_type():
     assert "AREA[SponsorType]Academic" in params["filter.advanced"][0]
 
 
+def test_funder_exclusion_term():
+    assert funder_exclusion_term([FunderType.INDUSTRY]) == (
+        "NOT AREA[LeadSponsorClass]INDUSTRY"
+    )
+    assert funder_exclusion_term([FunderType.NIH, FunderType.FED]) == (
+        "NOT AREA[LeadSponsorClass](NIH OR FED)"
+    )
+
+
+@pytest.mark.asyncio
+async def test_convert_query_funder_exclude():
+    """Excluded funder types negate the lead sponsor class."""
+    query = TrialQuery(conditions=["melanoma"], funder_exclude="industry")
+    params = await convert_query(query)
+
+    assert params["filter.advanced"] == ["NOT AREA[LeadSponsorClass]INDUSTRY"]
+    assert trial_search_query_summary(query) == (
+        "Conditions: melanoma | Excluding funders: industry"
+    )
+
+
 @pytest.mark.asyncio
 async def test_convert_query_study_design():
     """Test conversion of study design to API format."""
@@ -245,6 +506,68 @@ async def test_convert_query_study_design():
     assert "AREA[StudyDesign]Randomized" in params["filter.advanced"][0]
 
 
+@pytest.mark.asyncio
+async def test_convert_query_design_filters():
+    """Allocation, masking and model map to their design AREA terms."""
+    query = TrialQuery(
+        conditions=["melanoma"],
+        allocation="randomized",
+        masking=DesignMasking.DOUBLE,
+        intervention_model=InterventionModel.SINGLE_GROUP,
+    )
+    params = await convert_query(query)
+
+    advanced = params["filter.advanced"][0]
+    assert "AREA[DesignAllocation]RANDOMIZED" in advanced
+    assert "AREA[DesignMasking]DOUBLE" in advanced
+    assert "AREA[DesignInterventionModel]SINGLE_GROUP" in advanced
+    assert trial_search_query_summary(query) == (
+        "Conditions: melanoma | Allocation: randomized | Masking: double"
+        " | Model: single group"
+    )
+
+    query = TrialQuery(allocation=DesignAllocation.NON_RANDOMIZED)
+    params = await convert_query(query)
+    assert params["filter.advanced"] == [
+        "AREA[DesignAllocation]NON_RANDOMIZED"
+    ]
+    with pytest.raises(ValueError):
+        TrialQuery(masking="blind")
+
+
+@pytest.mark.asyncio
+async def test_sort_by_status_is_client_side():
+    """STATUS sorts each page client-side; the others stay server-side."""
+    query = TrialQuery(conditions=["melanoma"], sort="status")
+    params = await convert_query(query)
+
+    assert params["sort"] == ["@relevance"]
+    assert trial_search_query_summary(query) == (
+        "Conditions: melanoma | Sort: status (within page)"
+    )
+    trials = [
+        {"NCT Number": "NCT1", "Study Status": "COMPLETED"},
+        {"NCT Number": "NCT2", "Study Status": "UNKNOWN"},
+        {"NCT Number": "NCT3", "Study Status": "RECRUITING"},
+        {"NCT Number": "NCT4", "Study Status": "Not yet recruiting"},
+        {"NCT Number": "NCT5", "Study Status": "RECRUITING"},
+    ]
+    assert [t["NCT Number"] for t in sort_trials_by_status(trials)] == [
+        "NCT3",
+        "NCT5",
+        "NCT4",
+        "NCT1",
+        "NCT2",
+    ]
+
+    query = TrialQuery(sort=SortOrder.LAST_UPDATE)
+    params = await convert_query(query)
+    assert params["sort"] == ["LastUpdatePostDate:desc"]
+    assert trial_search_query_summary(query) == (
+        "All trials | Sort: last update"
+    )
+
+
 @pytest.mark.asyncio
 async def test_convert_query_age_group():
     """Test conversion of age group to API format."""
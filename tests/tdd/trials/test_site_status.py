@@ -0,0 +1,143 @@
+"""Tests for filtering trials by the status of their nearby sites."""
+
+import json
+
+import pytest
+
+from czechmedmcp.trials.search import (
+    LocationStatus,
+    TrialQuery,
+    search_trials,
+    trial_search_query_summary,
+)
+from czechmedmcp.trials.site_status import (
+    MATCH_FIELD,
+    filter_trials_by_site_status,
+    matching_sites,
+    site_status_note,
+)
+
+# Reference point: Cleveland, OH
+LAT, LON = 41.4993, -81.6944
+
+NEARBY_RECRUITING = {
+    "facility": "Cleveland Clinic",
+    "city": "Cleveland",
+    "state": "Ohio",
+    "status": "RECRUITING",
+    "geoPoint": {"lat": 41.5025, "lon": -81.6219},
+}
+NEARBY_COMPLETED = {**NEARBY_RECRUITING, "status": "COMPLETED"}
+FAR_RECRUITING = {
+    "facility": "UCSF",
+    "city": "San Francisco",
+    "status": "RECRUITING",
+    "geoPoint": {"lat": 37.7631, "lon": -122.4576},
+}
+
+
+def test_matching_sites():
+    locations = [NEARBY_COMPLETED, FAR_RECRUITING, NEARBY_RECRUITING]
+
+    (site,) = matching_sites(
+        locations, LocationStatus.RECRUITING, LAT, LON, 50
+    )
+    assert site.startswith("Cleveland Clinic, Cleveland, Ohio (")
+    assert site.endswith(" mi)")
+    assert not matching_sites(
+        locations, LocationStatus.WITHDRAWN, LAT, LON, 50
+    )
+    assert not matching_sites(
+        [{"status": "RECRUITING"}], LocationStatus.RECRUITING, LAT, LON, 50
+    )
+
+
+def test_location_status_query():
+    query = TrialQuery(
+        conditions=["melanoma"],
+        lat=LAT,
+        long=LON,
+        distance=25,
+        location_status="recruiting",
+    )
+    assert query.location_status == LocationStatus.RECRUITING
+    assert "Nearby site status: recruiting" in trial_search_query_summary(
+        query
+    )
+
+    with pytest.raises(ValueError, match="requires lat and long"):
+        TrialQuery(conditions=["melanoma"], location_status="recruiting")
+
+
+@pytest.mark.asyncio
+async def test_filter_trials_by_site_status(monkeypatch):
+    sites = {
+        "NCT00000001": [NEARBY_COMPLETED, NEARBY_RECRUITING],
+        "NCT00000002": [NEARBY_COMPLETED, FAR_RECRUITING],
+    }
+
+    async def fake_request_api(url, request, **kwargs):
+        nct_id = url.rsplit("/", 1)[-1]
+        if nct_id not in sites:
+            return None, None
+        module = {"locations": sites[nct_id]}
+        return {
+            "studies": [
+                {"protocolSection": {"contactsLocationsModule": module}}
+            ]
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    trials = [
+        {"NCT Number": "NCT00000001"},
+        {"NCT Number": "NCT00000002"},
+        {"NCT Number": "NCT00000003"},
+    ]
+    query = TrialQuery(lat=LAT, long=LON, location_status="RECRUITING")
+
+    kept, counts = await filter_trials_by_site_status(trials, query)
+
+    assert [trial["NCT Number"] for trial in kept] == ["NCT00000001"]
+    assert kept[0][MATCH_FIELD][0].startswith("Cleveland Clinic")
+    assert counts == {
+        "checked": 3,
+        "kept": 1,
+        "unmatched": 1,
+        "fetch_errors": 1,
+    }
+    note = site_status_note(counts)
+    assert "kept 1 of 3 trials" in note
+    assert "1 had no matching site nearby" in note
+    assert "1 could not be checked" in note
+
+
+@pytest.mark.asyncio
+async def test_search_trials_site_status_output(monkeypatch):
+    async def fake_request_api(url, request, **kwargs):
+        if url.endswith("/studies"):
+            return [
+                {"NCT Number": "NCT00000001"},
+                {"NCT Number": "NCT00000002"},
+            ], None
+        module = {"locations": [NEARBY_RECRUITING]}
+        if url.endswith("NCT00000002"):
+            module = {"locations": [FAR_RECRUITING]}
+        return {
+            "studies": [
+                {"protocolSection": {"contactsLocationsModule": module}}
+            ]
+        }, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    query = TrialQuery(lat=LAT, long=LON, location_status="RECRUITING")
+
+    # JSON stays a list of the kept trials
+    data = json.loads(await search_trials(query, output_json=True))
+    assert [trial["NCT Number"] for trial in data] == ["NCT00000001"]
+
+    text = await search_trials(query)
+    assert text.startswith("Nearby site status filter kept 1 of 2 trials")
@@ -0,0 +1,78 @@
+"""Tests for the minimal trial status card."""
+
+import json
+
+import pytest
+
+from czechmedmcp.trials.summary import (
+    SUMMARY_FIELDS,
+    format_trial_summary,
+    get_trial_summary,
+    parse_trial_summary,
+)
+
+STUDY = {
+    "protocolSection": {
+        "identificationModule": {
+            "nctId": "NCT00000001",
+            "briefTitle": "Drug A in Melanoma",
+        },
+        "statusModule": {"overallStatus": "RECRUITING"},
+        "designModule": {"phases": ["PHASE2", "PHASE3"]},
+        "conditionsModule": {"conditions": ["Melanoma"]},
+    }
+}
+
+
+def _fake_ctgov(monkeypatch, response, error=None):
+    calls = []
+
+    async def fake_request_api(url, request, **kwargs):
+        calls.append((url, request))
+        return response, error
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+def test_parse_and_format_trial_summary():
+    summary = parse_trial_summary(STUDY, "NCT00000001")
+
+    assert summary.status == "RECRUITING"
+    assert summary.phases == ["PHASE2", "PHASE3"]
+    text = format_trial_summary(summary)
+    assert text.startswith("# NCT00000001: Drug A in Melanoma")
+    assert "- **Phases**: PHASE2, PHASE3" in text
+    assert "- **Conditions**: Melanoma" in text
+
+    text = format_trial_summary(parse_trial_summary({}, "NCT00000002"))
+    assert "- **Status**: Unknown" in text
+    assert "- **Phases**: N/A" in text
+
+
+@pytest.mark.asyncio
+async def test_get_trial_summary_json(monkeypatch):
+    calls = _fake_ctgov(monkeypatch, {"studies": [STUDY]})
+
+    data = json.loads(await get_trial_summary("NCT00000001", True))
+
+    assert calls[0][1] == {"fields": SUMMARY_FIELDS}
+    assert data == {
+        "nct_id": "NCT00000001",
+        "title": "Drug A in Melanoma",
+        "status": "RECRUITING",
+        "phases": ["PHASE2", "PHASE3"],
+        "conditions": ["Melanoma"],
+        "url": "https://clinicaltrials.gov/study/NCT00000001",
+    }
+
+
+@pytest.mark.asyncio
+async def test_get_trial_summary_missing(monkeypatch):
+    _fake_ctgov(monkeypatch, {"studies": []})
+
+    text = await get_trial_summary("NCT00000003")
+
+    assert text == "Error: No data found for NCT00000003"
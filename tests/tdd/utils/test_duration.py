@@ -0,0 +1,33 @@
+"""Tests for duration parsing."""
+
+from datetime import timedelta
+
+import pytest
+
+from czechmedmcp.utils.duration import parse_duration
+
+
+@pytest.mark.parametrize(
+    "value,expected",
+    [
+        ("90s", timedelta(seconds=90)),
+        ("45m", timedelta(minutes=45)),
+        ("12h", timedelta(hours=12)),
+        ("30d", timedelta(days=30)),
+        ("2w", timedelta(weeks=2)),
+        ("6mo", timedelta(days=180)),
+        ("6MO", timedelta(days=180)),
+        ("1y", timedelta(days=365)),
+        (" 7D ", timedelta(days=7)),
+    ],
+)
+def test_parse_duration(value, expected):
+    assert parse_duration(value) == expected
+
+
+@pytest.mark.parametrize(
+    "value", ["", "30", "0d", "d", "1.5h", "-3d", "3x", "6mon"]
+)
+def test_parse_duration_rejects_invalid(value):
+    with pytest.raises(ValueError, match="Invalid duration"):
+        parse_duration(value)
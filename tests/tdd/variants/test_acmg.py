@@ -0,0 +1,128 @@
+"""Tests for advisory ACMG/AMP criterion hints."""
+
+import json
+
+import pytest
+
+from czechmedmcp.variants import getter
+from czechmedmcp.variants.acmg import (
+    ACMG_ADVISORY,
+    assess_acmg,
+    format_acmg,
+)
+
+BRAF = {
+    "_id": "chr7:g.140453136A>T",
+    "dbsnp": {"rsid": "rs113488022", "gene": {"symbol": "BRAF"}},
+    "dbnsfp": {
+        "genename": "BRAF",
+        "hgvsp": ["p.Val600Glu", "p.V600E"],
+        "revel": {"score": [0.931, 0.912]},
+    },
+    "cadd": {"phred": 32},
+    "clinvar": {
+        "rcv": [
+            {"accession": "RCV1", "clinical_significance": "Pathogenic"},
+            {
+                "accession": "RCV2",
+                "clinical_significance": "Likely pathogenic",
+            },
+        ]
+    },
+}
+
+
+def _flags(criteria):
+    return {c.code: c.met for c in criteria}
+
+
+def test_assess_acmg_for_rare_damaging_hotspot():
+    flags = _flags(assess_acmg(BRAF, hotspot_positions={600}))
+
+    assert flags == {
+        "BA1": False,
+        "BS1": False,
+        "PM2": True,
+        "PM1": True,
+        "PP3": True,
+        "BP4": False,
+        "PP5": True,
+        "BP6": False,
+    }
+
+
+def test_assess_acmg_for_common_benign_variant():
+    variant = {
+        "gnomad_exome": {"af": {"af": 0.02}},
+        "gnomad_genome": {"af": {"af": 0.08}},
+        "cadd": {"phred": 3.1},
+        "clinvar": {"rcv": {"clinical_significance": "Benign/Likely benign"}},
+    }
+
+    criteria = assess_acmg(variant)
+    flags = _flags(criteria)
+
+    assert flags["BA1"] is True
+    assert flags["BS1"] is False
+    assert flags["PM2"] is False
+    # CADD stands in for a missing REVEL score
+    assert flags["BP4"] is True
+    assert flags["BP6"] is True
+    # No hotspot data means PM1 is not assessed
+    assert flags["PM1"] is None
+
+
+def test_conflicting_clinvar_supports_neither_direction():
+    variant = {
+        "clinvar": {
+            "rcv": {
+                "clinical_significance": "Conflicting interpretations "
+                "of pathogenicity"
+            }
+        }
+    }
+
+    flags = _flags(assess_acmg(variant))
+
+    assert flags["PP5"] is False
+    assert flags["BP6"] is False
+    assert flags["PP3"] is None
+
+
+def test_format_acmg_is_labelled_advisory():
+    text = format_acmg("rs1", assess_acmg(BRAF, hotspot_positions=set()))
+
+    assert text.startswith("## ACMG Criteria Hints: rs1")
+    assert ACMG_ADVISORY in text
+    assert "| PM2 | Yes | absent from gnomAD (rare < 0.0001) |" in text
+    assert "| PP3 | Yes | REVEL 0.931 (>= 0.644) |" in text
+    assert "| PM1 | No |" in text
+
+
+@pytest.mark.asyncio
+async def test_get_variant_with_acmg(monkeypatch):
+    async def fake_request_api(url, request, **kwargs):
+        return BRAF, None
+
+    genes = []
+
+    async def fake_positions(gene):
+        genes.append(gene)
+        return {600}
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    monkeypatch.setattr(getter, "_get_hotspot_positions", fake_positions)
+
+    data = json.loads(
+        await getter.get_variant("rs113488022", output_json=True, acmg=True)
+    )
+    acmg = data[0]["acmg"]
+    assert acmg["advisory"] == ACMG_ADVISORY
+    assert acmg["criteria"]["PM1"]["met"] is True
+    assert "hotspot" in acmg["criteria"]["PM1"]["evidence"]
+    assert genes == ["BRAF"]
+
+    text = await getter.get_variant("rs113488022", acmg=True)
+    assert "## ACMG Criteria Hints: chr7:g.140453136A>T" in text
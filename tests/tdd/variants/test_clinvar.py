@@ -0,0 +1,83 @@
+"""Tests for per-record ClinVar interpretations."""
+
+from czechmedmcp.variants.clinvar import (
+    conflicting_significances,
+    extract_clinvar_submissions,
+    format_clinvar_submissions,
+)
+
+VARIANT = {
+    "_id": "chr17:g.7675088C>T",
+    "clinvar": {
+        "variant_id": 12347,
+        "rcv": [
+            {
+                "accession": "RCV000013144",
+                "clinical_significance": "Pathogenic",
+                "review_status": "criteria provided, single submitter",
+                "conditions": {"name": "Li-Fraumeni syndrome"},
+                "last_evaluated": "2019-03-01",
+                "origin": "germline",
+                "number_submitters": 1,
+            },
+            {
+                "accession": "RCV000206321",
+                "clinical_significance": "Uncertain significance",
+                "review_status": "no assertion criteria provided",
+                "conditions": [
+                    {"name": "Hereditary cancer"},
+                    {"name": "Breast neoplasm"},
+                ],
+                "last_evaluated": "2022-11-15",
+                "number_submitters": 2,
+            },
+        ],
+    },
+}
+
+
+def test_extract_clinvar_submissions():
+    submissions = extract_clinvar_submissions(VARIANT)
+
+    # Newest evaluation first
+    assert [s["accession"] for s in submissions] == [
+        "RCV000206321",
+        "RCV000013144",
+    ]
+    assert submissions[0]["condition"] == "Hereditary cancer; Breast neoplasm"
+    assert submissions[1] == {
+        "accession": "RCV000013144",
+        "clinical_significance": "Pathogenic",
+        "review_status": "criteria provided, single submitter",
+        "condition": "Li-Fraumeni syndrome",
+        "last_evaluated": "2019-03-01",
+        "origin": "germline",
+        "number_submitters": 1,
+    }
+
+
+def test_extract_clinvar_submissions_single_record_and_missing():
+    single = {"clinvar": {"rcv": {"accession": "RCV1", "conditions": {}}}}
+    assert extract_clinvar_submissions(single) == [{"accession": "RCV1"}]
+    assert extract_clinvar_submissions({"_id": "x"}) == []
+
+
+def test_conflicting_significances():
+    submissions = extract_clinvar_submissions(VARIANT)
+    assert conflicting_significances(submissions) == [
+        "pathogenic",
+        "uncertain significance",
+    ]
+    assert conflicting_significances(submissions[:1]) == []
+
+
+def test_format_clinvar_submissions():
+    submissions = extract_clinvar_submissions(VARIANT)
+    markdown = format_clinvar_submissions(VARIANT["_id"], submissions)
+
+    assert markdown.startswith("## ClinVar Submissions: chr17:g.7675088C>T")
+    assert "**Conflicting interpretations**" in markdown
+    assert "| RCV000013144 | Pathogenic |" in markdown
+
+    empty = format_clinvar_submissions("rs1", [])
+    assert "No ClinVar records" in empty
@@ -13,7 +13,7 @@ class TestGetVariant:
 
     @pytest.mark.asyncio
     async def test_get_variant_default_assembly(self):
-        """Test that get_variant defaults to hg19 assembly."""
+        """Test that get_variant defaults to hg38 assembly."""
         mock_response = {
             "_id": "rs113488022",
             "dbsnp": {"rsid": "rs113488022"},
@@ -26,7 +26,7 @@ class TestGetVariant:
 
             # Verify assembly parameter was passed with default value
             call_args = mock_request.call_args
-            assert call_args[1]["request"]["assembly"] == "hg19"
+            assert call_args[1]["request"]["assembly"] == "hg38"
 
     @pytest.mark.asyncio
     async def test_get_variant_hg38_assembly(self):
@@ -134,7 +134,7 @@ class TestVariantDetailsMCPTool:
 
     @pytest.mark.asyncio
     async def test_variant_details_default_assembly(self):
-        """Test that _variant_details defaults to hg19 assembly."""
+        """Test that _variant_details defaults to hg38 assembly."""
         with patch("czechmedmcp.variants.getter.get_variant") as mock_get:
             mock_get.return_value = "Variant details"
 
@@ -298,3 +298,30 @@ class TestCompactFormatting:
             data = json.loads(result)
             assert "_id" in data[0]
             assert "cadd" in data[0]
+
+    @pytest.mark.asyncio
+    async def test_get_variant_grch_alias_and_label(self):
+        """Test that GRCh build names map to MyVariant assemblies."""
+        mock_response = {
+            "_id": "rs113488022",
+            "dbsnp": {"rsid": "rs113488022"},
+        }
+
+        with patch("czechmedmcp.http_client.request_api") as mock_request:
+            mock_request.return_value = (mock_response, None)
+
+            result = await getter.get_variant(
+                "rs113488022", assembly="GRCh37", output_json=True
+            )
+
+            call_args = mock_request.call_args
+            assert call_args[1]["request"]["assembly"] == "hg19"
+            import json
+
+            assert json.loads(result)[0]["assembly"] == "GRCh37 (hg19)"
+
+    def test_normalize_assembly_rejects_unknown_build(self):
+        """Test that unsupported builds raise a clear error."""
+        assert getter.normalize_assembly("grch38") == "hg38"
+        with pytest.raises(ValueError, match="Invalid assembly"):
+            getter.normalize_assembly("hg18")
@@ -0,0 +1,50 @@
+"""Tests for per-population gnomAD allele frequencies."""
+
+from czechmedmcp.variants.gnomad import (
+    extract_gnomad_populations,
+    format_gnomad_populations,
+)
+
+VARIANT = {
+    "_id": "chr7:g.140453136A>T",
+    "gnomad_exome": {
+        "af": {"af": 3.98e-06, "af_nfe": 8.8e-06, "af_afr": 0},
+        "ac": {"ac": 1, "ac_nfe": 1, "ac_afr": 0},
+        "an": {"an": 251260, "an_nfe": 113650, "an_afr": 16256},
+        "hom": {"hom": 0, "hom_nfe": 0, "hom_afr": 0},
+    },
+}
+
+
+def test_extract_gnomad_populations():
+    datasets = extract_gnomad_populations(VARIANT)
+
+    assert list(datasets) == ["exome"]
+    records = {r["population"]: r for r in datasets["exome"]}
+    assert list(records)[:2] == ["all", "afr"]
+    assert len(records) == 9
+    assert records["nfe"] == {
+        "population": "nfe",
+        "name": "Non-Finnish European",
+        "af": 8.8e-06,
+        "ac": 1,
+        "an": 113650,
+        "hom": 0,
+    }
+    assert records["eas"]["af"] is None
+    assert extract_gnomad_populations({"_id": "x"}) == {}
+
+
+def test_format_gnomad_populations():
+    datasets = extract_gnomad_populations(VARIANT)
+    markdown = format_gnomad_populations(VARIANT["_id"], datasets)
+
+    assert markdown.startswith("## gnomAD Populations: chr7:g.140453136A>T")
+    assert "### gnomAD exome" in markdown
+    assert "| Non-Finnish European (nfe) | 8.8e-06 | 1 | 113650 | 0 |" in (
+        markdown
+    )
+    assert "| East Asian (eas) | - | - | - | - |" in markdown
+
+    empty = format_gnomad_populations("rs1", {})
+    assert "No gnomAD data" in empty
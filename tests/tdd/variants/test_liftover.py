@@ -0,0 +1,148 @@
+"""Tests for cross-assembly variant coordinates."""
+
+import json
+
+import pytest
+
+from czechmedmcp.variants import getter
+from czechmedmcp.variants.liftover import (
+    format_liftover,
+    lifted_snv_id,
+    liftover_variant,
+    variant_coordinates,
+)
+
+HG19 = {
+    "_id": "chr7:g.140453136A>T",
+    "chrom": "7",
+    "hg19": {"start": 140453136, "end": 140453136},
+    "vcf": {"ref": "A", "alt": "T", "position": "140453136"},
+    "dbsnp": {"rsid": "rs113488022"},
+    "clinvar": {"hg38": {"start": 140753336, "end": 140753336}},
+}
+
+HG38 = {
+    "_id": "chr7:g.140753336A>T",
+    "chrom": "7",
+    "hg38": {"start": 140753336, "end": 140753336},
+    "vcf": {"ref": "A", "alt": "T", "position": "140753336"},
+}
+
+
+def _fake_myvariant(monkeypatch, records):
+    calls = []
+
+    async def fake_request_api(url, request, **kwargs):
+        calls.append((url.rsplit("/", 1)[-1], request["assembly"]))
+        key = calls[-1]
+        if key in records:
+            return records[key], None
+        return None, None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    return calls
+
+
+def test_lifted_snv_id():
+    assert lifted_snv_id(HG19, "hg38") == "chr7:g.140753336A>T"
+    assert lifted_snv_id(HG19, "hg19") is None
+    indel = {**HG19, "vcf": {"ref": "AT", "alt": "A"}}
+    assert lifted_snv_id(indel, "hg38") is None
+
+
+@pytest.mark.asyncio
+async def test_liftover_uses_cross_assembly_position(monkeypatch):
+    calls = _fake_myvariant(
+        monkeypatch, {("chr7:g.140753336A>T", "hg38"): HG38}
+    )
+
+    target, note = await liftover_variant(HG19, "hg38")
+
+    assert note is None
+    assert calls == [("chr7:g.140753336A>T", "hg38")]
+    assert target == {
+        "assembly": "GRCh38 (hg38)",
+        "id": "chr7:g.140753336A>T",
+        "chrom": "7",
+        "start": 140753336,
+        "end": 140753336,
+        "ref": "A",
+        "alt": "T",
+    }
+
+
+@pytest.mark.asyncio
+async def test_liftover_falls_back_to_rsid(monkeypatch):
+    other_allele = {**HG38, "_id": "x", "vcf": {"ref": "A", "alt": "G"}}
+    variant = {key: HG19[key] for key in ("_id", "chrom", "vcf", "dbsnp")}
+    _fake_myvariant(
+        monkeypatch, {("rs113488022", "hg38"): [other_allele, HG38]}
+    )
+
+    target, note = await liftover_variant(variant, "hg38")
+
+    assert note is None
+    assert target["id"] == "chr7:g.140753336A>T"
+
+
+@pytest.mark.asyncio
+async def test_liftover_reports_missing_variant(monkeypatch):
+    _fake_myvariant(monkeypatch, {})
+
+    target, note = await liftover_variant(HG19, "hg38")
+
+    assert target is None
+    assert note == "Variant could not be located in GRCh38 (hg38)"
+    source = variant_coordinates(HG19, "hg19")
+    text = format_liftover(source, None, "hg38", note)
+    assert "## Liftover: GRCh37 (hg19) -> GRCh38 (hg38)" in text
+    assert "**Note**: Variant could not be located in GRCh38 (hg38)" in text
+
+
+def test_format_liftover_side_by_side():
+    text = format_liftover(
+        variant_coordinates(HG19, "hg19"),
+        variant_coordinates(HG38, "hg38"),
+        "hg38",
+    )
+
+    assert "| | GRCh37 (hg19) | GRCh38 (hg38) |" in text
+    assert "| Position | 140453136 | 140753336 |" in text
+    assert "| Ref>Alt | A>T | A>T |" in text
+
+
+@pytest.mark.asyncio
+async def test_get_variant_with_liftover(monkeypatch):
+    _fake_myvariant(
+        monkeypatch,
+        {
+            ("chr7:g.140453136A>T", "hg19"): HG19,
+            ("chr7:g.140753336A>T", "hg38"): HG38,
+        },
+    )
+
+    result = json.loads(
+        await getter.get_variant(
+            "chr7:g.140453136A>T",
+            output_json=True,
+            assembly="hg19",
+            liftover="GRCh38",
+        )
+    )
+
+    assert result[0]["liftover"]["id"] == "chr7:g.140753336A>T"
+    assert result[0]["liftover"]["assembly"] == "GRCh38 (hg38)"
+
+    result = json.loads(
+        await getter.get_variant(
+            "rs113488022", output_json=True, assembly="hg19", liftover="hg19"
+        )
+    )
+    assert result == [
+        {
+            "error": "Liftover target GRCh37 (hg19) is the same as the "
+            "primary assembly"
+        }
+    ]
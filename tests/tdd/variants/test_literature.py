@@ -0,0 +1,122 @@
+"""Tests for the literature section of variant get."""
+
+import json
+
+import pytest
+
+from czechmedmcp.articles.search import ResultItem
+from czechmedmcp.variants import getter
+from czechmedmcp.variants.literature import (
+    fetch_variant_literature,
+    format_variant_literature,
+    literature_terms,
+)
+
+BRAF = {
+    "_id": "chr7:g.140453136A>T",
+    "dbsnp": {"rsid": "rs113488022", "gene": {"symbol": "BRAF"}},
+}
+
+ARTICLES = [
+    {
+        "pmid": 12068308,
+        "title": "Mutations of the BRAF gene in human cancer.",
+        "journal": "Nature",
+        "date": "2002-06-27",
+        "authors": ["Davies H", "...", "Futreal PA"],
+        "abstract": "Cancers arise ...",
+        "pubmed_url": "https://pubmed.ncbi.nlm.nih.gov/12068308/",
+    },
+    {"title": "Preprint without a PMID"},
+]
+
+
+def _fake_search(monkeypatch, results, error=None):
+    calls = []
+
+    async def fake_fetch_article_page(request, limit, abstracts):
+        calls.append((request.genes, request.variants, limit, abstracts))
+        if error:
+            return None, error
+        return [ResultItem(**result) for result in results], None
+
+    monkeypatch.setattr(
+        "czechmedmcp.variants.literature.fetch_article_page",
+        fake_fetch_article_page,
+    )
+    return calls
+
+
+def test_literature_terms():
+    assert literature_terms(BRAF, "rs113488022") == ("BRAF", "rs113488022")
+    assert literature_terms(
+        {"dbnsfp": {"genename": ["TP53", "WRAP53"]}}, "chr17:g.7577121G>A"
+    ) == ("TP53", "chr17:g.7577121G>A")
+    assert literature_terms({}, "chr1:g.1A>G") == (None, "chr1:g.1A>G")
+
+
+@pytest.mark.asyncio
+async def test_fetch_variant_literature(monkeypatch):
+    calls = _fake_search(monkeypatch, ARTICLES)
+
+    articles, error = await fetch_variant_literature(
+        "BRAF", "rs113488022", limit=50
+    )
+
+    assert error is None
+    assert calls == [(["BRAF"], ["rs113488022"], 20, False)]
+    assert articles == [
+        {
+            "pmid": 12068308,
+            "title": "Mutations of the BRAF gene in human cancer.",
+            "journal": "Nature",
+            "date": "2002-06-27",
+            "pubmed_url": "https://pubmed.ncbi.nlm.nih.gov/12068308/",
+        }
+    ]
+
+
+@pytest.mark.asyncio
+async def test_fetch_variant_literature_error(monkeypatch):
+    _fake_search(monkeypatch, [], error="Error 500: down")
+
+    articles, error = await fetch_variant_literature(None, "rs1")
+
+    assert articles is None
+    assert error == "Error 500: down"
+    text = format_variant_literature("rs1", articles, error)
+    assert "Literature unavailable: Error 500: down" in text
+
+
+def test_format_variant_literature():
+    text = format_variant_literature("rs113488022 (BRAF)", ARTICLES[:1])
+
+    assert "## Literature: rs113488022 (BRAF)" in text
+    assert (
+        "- [PMID 12068308](https://pubmed.ncbi.nlm.nih.gov/12068308/) "
+        "Mutations of the BRAF gene in human cancer. (Nature, 2002-06-27)"
+    ) in text
+    assert "No articles found" in format_variant_literature("rs1", [])
+
+
+@pytest.mark.asyncio
+async def test_get_variant_embeds_literature(monkeypatch):
+    async def fake_request_api(url, request, **kwargs):
+        return [BRAF, {**BRAF, "_id": "chr7:g.140453136A>C"}], None
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    calls = _fake_search(monkeypatch, ARTICLES)
+
+    result = json.loads(
+        await getter.get_variant(
+            "rs113488022", output_json=True, literature_limit=3
+        )
+    )
+
+    assert calls == [(["BRAF"], ["rs113488022"], 3, False)]
+    assert [r["literature"][0]["pmid"] for r in result] == [
+        12068308,
+        12068308,
+    ]
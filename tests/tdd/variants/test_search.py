@@ -1,12 +1,25 @@
+import json
+
 import pytest
 
+from czechmedmcp.variants import search
 from czechmedmcp.variants.search import (
+    AlleleOrigin,
     ClinicalSignificance,
     PolyPhenPrediction,
     SiftPrediction,
     VariantQuery,
     build_query_string,
+    convert_query,
+    filter_hotspot_variants,
+    normalize_consequence,
+    normalize_clinvar_condition,
+    normalize_consequences,
+    normalize_hgvsc,
+    normalize_impacts,
     search_variants,
+    variant_protein_positions,
+    variant_search_query_summary,
 )
 
 
@@ -131,3 +144,305 @@ async def test_search_variants_with_limit(anyio_backend):
 
     # Result should be valid but limited
     assert not result.startswith("Error")
+
+
+@pytest.mark.asyncio
+async def test_convert_query_assembly_selection():
+    """Test that the requested genome build is sent to MyVariant.info."""
+    default = await convert_query(VariantQuery(rsid="rs113488022"))
+    grch38 = await convert_query(
+        VariantQuery(rsid="rs113488022", assembly="GRCh38")
+    )
+
+    grch37 = await convert_query(
+        VariantQuery(rsid="rs113488022", assembly="GRCh37")
+    )
+
+    assert default["assembly"] == "hg38"
+    assert grch38["assembly"] == "hg38"
+    assert grch37["assembly"] == "hg19"
+
+
+def test_assembly_alone_is_not_a_search_parameter():
+    """Test that assembly does not satisfy the required-parameter check."""
+    with pytest.raises(ValueError):
+        VariantQuery(assembly="GRCh38")
+
+
+def test_build_query_string_origin_filter():
+    """Test that the allele origin maps to the ClinVar origin field."""
+    query = VariantQuery(gene="TP53", hgvsp="p.R175H", origin="somatic")
+    assert 'clinvar.rcv.origin:"somatic"' in build_query_string(query)
+
+    query = VariantQuery(gene="TP53", cadd=20.0, origin=AlleleOrigin.GERMLINE)
+    assert 'clinvar.rcv.origin:"germline"' in build_query_string(query)
+
+    # The default (both) adds no origin clause
+    query = VariantQuery(gene="TP53", hgvsp="p.R175H")
+    assert query.origin == AlleleOrigin.BOTH
+    assert "clinvar.rcv.origin" not in build_query_string(query)
+
+
+def test_variant_search_query_summary_includes_origin():
+    """Test that the query summary reports the origin filter."""
+    query = VariantQuery(gene="TP53", hgvsp="p.R175H", origin="somatic")
+    summary = variant_search_query_summary(query)
+    assert "Gene: TP53" in summary
+    assert "Origin: somatic" in summary
+
+    query = VariantQuery(gene="TP53", hgvsp="p.R175H")
+    assert "Origin" not in variant_search_query_summary(query)
+
+
+def test_normalize_consequence():
+    """Test that consequence shorthands map to SO terms."""
+    assert normalize_consequence("missense_variant") == "missense_variant"
+    assert normalize_consequence("missense") == "missense_variant"
+    assert normalize_consequence("Stop-Gained") == "stop_gained"
+    assert normalize_consequence("5 prime UTR") == "5_prime_UTR_variant"
+    assert normalize_consequence("nmd_transcript") == "NMD_transcript_variant"
+
+
+def test_normalize_hgvsc():
+    """Test that coding HGVS gets a lower-case c. prefix."""
+    assert normalize_hgvsc("c.1799T>A") == "c.1799T>A"
+    assert normalize_hgvsc(" 1799T>A ") == "c.1799T>A"
+    assert normalize_hgvsc("C.1799T>A") == "c.1799T>A"
+    assert normalize_hgvsc("NM_004333.4:c.1799T>A") == "c.1799T>A"
+    assert normalize_hgvsc("c.4375+1G>A") == "c.4375+1G>A"
+    assert normalize_hgvsc("c.5266dupC") == "c.5266dupC"
+    assert normalize_hgvsc("c.68_69delAG") == "c.68_69delAG"
+    assert normalize_hgvsc("c.*32_*33insT") == "c.*32_*33insT"
+    for value in (
+        "",
+        "c.",
+        "p.V600E",
+        "V600E",
+        "g.140453136A>T",
+        "c.1799T>",
+        "c.1799X>A",
+        "c.abc",
+    ):
+        with pytest.raises(ValueError):
+            normalize_hgvsc(value)
+
+
+def test_gene_with_hgvsc_query():
+    """Test that --gene with --hgvsc queries the normalized cDNA change."""
+    query = VariantQuery(gene="BRAF", hgvsc="1799T>A")
+
+    assert query.hgvsc == "c.1799T>A"
+    assert 'dbnsfp.hgvsc:"c.1799T>A"' in build_query_string(query)
+    summary = variant_search_query_summary(query)
+    assert "Gene: BRAF" in summary
+    assert "HGVSc: c.1799T>A" in summary
+
+
+def test_normalize_clinvar_condition():
+    """Test that MedGen and OMIM condition IDs are normalized."""
+    assert normalize_clinvar_condition("C0025202") == "MedGen:C0025202"
+    assert normalize_clinvar_condition("medgen:cn517202") == "MedGen:CN517202"
+    assert normalize_clinvar_condition("OMIM:155600") == "OMIM:155600"
+    assert normalize_clinvar_condition(" mim:155600") == "OMIM:155600"
+    assert normalize_clinvar_condition("155600") == "OMIM:155600"
+    for value in ("melanoma", "C002520", "OMIM:15560", "MONDO:0005105"):
+        with pytest.raises(ValueError, match="Invalid ClinVar condition"):
+            normalize_clinvar_condition(value)
+
+
+def test_clinvar_condition_query():
+    """Test that conditions match ClinVar's structured identifiers."""
+    query = VariantQuery(gene="BRAF", clinvar_condition="C0025202")
+
+    assert build_query_string(query) == (
+        'dbnsfp.genename:"BRAF" AND '
+        'clinvar.rcv.conditions.identifiers.medgen:"C0025202"'
+    )
+    summary = variant_search_query_summary(query)
+    assert "ClinVar condition: MedGen:C0025202" in summary
+
+    query = VariantQuery(clinvar_condition="OMIM:155600")
+    assert build_query_string(query) == (
+        'clinvar.rcv.conditions.identifiers.omim:"155600"'
+    )
+
+
+def test_normalize_consequence_rejects_unknown_terms():
+    """Test that unknown terms fail with the list of valid terms."""
+    with pytest.raises(ValueError) as exc_info:
+        normalize_consequence("nonsense")
+    message = str(exc_info.value)
+    assert "Unknown consequence 'nonsense'" in message
+    assert "stop_gained" in message
+    assert "frameshift_variant" in message
+
+
+def test_normalize_consequences_dedupes():
+    """Test that repeated terms collapse while keeping order."""
+    assert normalize_consequences(["stop_gained", "missense", "Missense"]) == [
+        "stop_gained",
+        "missense_variant",
+    ]
+
+
+def test_build_query_string_consequences():
+    """Test that consequences are OR-ed on the SnpEff effect field."""
+    query = VariantQuery(gene="TP53", consequences=["missense"])
+    assert query.consequences == ["missense_variant"]
+    assert build_query_string(query) == (
+        'dbnsfp.genename:"TP53" AND snpeff.ann.effect:"missense_variant"'
+    )
+
+    query = VariantQuery(
+        gene="TP53", consequences=["stop_gained", "frameshift"]
+    )
+    assert build_query_string(query).endswith(
+        '(snpeff.ann.effect:"stop_gained" OR '
+        'snpeff.ann.effect:"frameshift_variant")'
+    )
+    summary = variant_search_query_summary(query)
+    assert "Consequence: stop_gained OR frameshift_variant" in summary
+
+
+def test_variant_query_rejects_unknown_consequence():
+    """Test that the query model validates consequence terms."""
+    with pytest.raises(ValueError, match="Unknown consequence"):
+        VariantQuery(gene="TP53", consequences=["bogus"])
+
+
+def test_build_query_string_impacts():
+    """Test that repeated impacts are OR-ed on the SnpEff impact field."""
+    query = VariantQuery(gene="BRCA2", impacts=["high"])
+    assert build_query_string(query) == (
+        'dbnsfp.genename:"BRCA2" AND snpeff.ann.putative_impact:"HIGH"'
+    )
+
+    query = VariantQuery(gene="BRCA2", impacts=["HIGH", "moderate", "High"])
+    assert build_query_string(query).endswith(
+        '(snpeff.ann.putative_impact:"HIGH" OR '
+        'snpeff.ann.putative_impact:"MODERATE")'
+    )
+    summary = variant_search_query_summary(query)
+    assert "Impact: HIGH OR MODERATE" in summary
+
+
+def test_build_query_string_civic_therapy_filters():
+    """Test that therapies are OR-ed and the level keeps stronger ones."""
+    query = VariantQuery(
+        gene="BRAF",
+        therapies=["vemurafenib", "Dabrafenib", "vemurafenib"],
+        therapy_evidence="b",
+    )
+    assert build_query_string(query) == (
+        'dbnsfp.genename:"BRAF" AND '
+        '(civic.molecularProfiles.evidenceItems.therapies.name:"vemurafenib"'
+        " OR "
+        'civic.molecularProfiles.evidenceItems.therapies.name:"Dabrafenib")'
+        " AND civic.molecularProfiles.evidenceItems.evidenceLevel:(A OR B)"
+    )
+    summary = variant_search_query_summary(query)
+    assert "CIViC therapy: vemurafenib OR Dabrafenib" in summary
+    assert "CIViC evidence level: A/B" in summary
+
+    query = VariantQuery(gene="BRAF", therapies=["vemurafenib"])
+    assert build_query_string(query).endswith(
+        'therapies.name:"vemurafenib"'
+    )
+
+
+def test_normalize_impacts_rejects_unknown_values():
+    """Test that impacts outside the four SnpEff classes are rejected."""
+    with pytest.raises(ValueError) as exc_info:
+        normalize_impacts(["HIGH", "severe"])
+    assert str(exc_info.value) == (
+        "Invalid SnpEff impact 'severe'. Expected one of: "
+        "HIGH, MODERATE, LOW, MODIFIER"
+    )
+
+
+def test_variant_protein_positions():
+    """Test that residue numbers come from the dbNSFP HGVSp annotations."""
+    variant = {"dbnsfp": {"hgvsp": ["p.V600E", "p.Val600Glu", "p.V640E"]}}
+    assert variant_protein_positions(variant) == {600, 640}
+    assert variant_protein_positions({"dbnsfp": {"hgvsp": "p.R175H"}}) == {
+        175
+    }
+    assert variant_protein_positions({}) == set()
+
+
+def test_filter_hotspot_variants():
+    """Test that only variants at hotspot residues are kept."""
+    variants = [
+        {"_id": "v600e", "dbnsfp": {"hgvsp": "p.V600E"}},
+        {"_id": "v600k", "dbnsfp": {"hgvsp": "p.V600K"}},
+        {"_id": "d594g", "dbnsfp": {"hgvsp": "p.D594G"}},
+        {"_id": "noncoding"},
+    ]
+    kept = filter_hotspot_variants(variants, {600, 469})
+    assert [variant["_id"] for variant in kept] == ["v600e", "v600k"]
+
+
+def test_hotspot_requires_gene_and_shows_in_summary():
+    """Test hotspot validation and query summary."""
+    with pytest.raises(ValueError, match="requires a gene"):
+        VariantQuery(rsid="rs113488022", hotspot=True)
+
+    query = VariantQuery(
+        gene="BRAF", consequences=["missense"], hotspot=True
+    )
+    assert "Hotspots only (cBioPortal)" in variant_search_query_summary(query)
+
+
+@pytest.mark.asyncio
+async def test_search_variants_hotspot_wiring(monkeypatch):
+    """Test that --hotspot over-fetches and filters on cBioPortal residues."""
+    captured = {}
+
+    async def fake_request_api(url, request, **kwargs):
+        captured.update(request)
+        return {
+            "hits": [
+                {"_id": "chr7:g.140453136A>T", "dbnsfp": {"hgvsp": "p.V600E"}},
+                {"_id": "chr7:g.140453193T>C", "dbnsfp": {"hgvsp": "p.N581S"}},
+            ]
+        }, None
+
+    async def fake_positions(gene):
+        assert gene == "BRAF"
+        return {600}
+
+    monkeypatch.setattr(
+        "czechmedmcp.http_client.request_api", fake_request_api
+    )
+    monkeypatch.setattr(search, "_get_hotspot_positions", fake_positions)
+
+    query = VariantQuery(
+        gene="BRAF", consequences=["missense"], hotspot=True, size=2
+    )
+    result = await search_variants(
+        query,
+        output_json=True,
+        include_cbioportal=False,
+        include_oncokb=False,
+    )
+
+    assert captured["size"] == 2 * search.HOTSPOT_OVERFETCH
+    assert captured["from"] == 0
+    assert [variant["_id"] for variant in json.loads(result)] == [
+        "chr7:g.140453136A>T"
+    ]
+
+    # Later pages come from the filtered variants of a wider window
+    query = VariantQuery(
+        gene="BRAF", consequences=["missense"], hotspot=True, size=2, offset=2
+    )
+    result = await search_variants(
+        query,
+        output_json=True,
+        include_cbioportal=False,
+        include_oncokb=False,
+    )
+
+    assert captured["size"] == 4 * search.HOTSPOT_OVERFETCH
+    assert captured["from"] == 0
+    assert json.loads(result) == []
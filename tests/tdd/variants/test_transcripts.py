@@ -0,0 +1,155 @@
+"""Tests for per-transcript annotation selection."""
+
+import json
+from unittest.mock import patch
+
+import pytest
+
+from czechmedmcp.variants import getter
+from czechmedmcp.variants.transcripts import (
+    SELECTED_BY_CLINVAR,
+    SELECTED_BY_REQUEST,
+    SELECTED_BY_SNPEFF,
+    clinvar_preferred_transcript,
+    extract_transcript_annotations,
+    format_selected_transcript,
+    select_transcript,
+)
+
+VARIANT = {
+    "_id": "chr7:g.140453136A>T",
+    "snpeff": {
+        "ann": [
+            {
+                "feature_id": "ENST00000496384.2",
+                "gene_name": "BRAF",
+                "effect": "non_coding_transcript_exon_variant",
+                "putative_impact": "MODIFIER",
+                "transcript_biotype": "processed_transcript",
+            },
+            {
+                "feature_id": "NM_004333.4",
+                "gene_name": "BRAF",
+                "hgvs_c": "c.1799T>A",
+                "hgvs_p": "p.Val600Glu",
+                "effect": "missense_variant",
+                "putative_impact": "MODERATE",
+                "transcript_biotype": "protein_coding",
+            },
+            {
+                "feature_id": "XM_005250045.1",
+                "gene_name": "BRAF",
+                "hgvs_c": "c.1862T>A",
+                "hgvs_p": "p.Val621Glu",
+                "effect": "missense_variant",
+                "transcript_biotype": "protein_coding",
+            },
+        ]
+    },
+    "clinvar": {
+        "rcv": [
+            {"preferred_name": "NM_004333.6(BRAF):c.1799T>A (p.Val600Glu)"}
+        ]
+    },
+}
+
+
+def _without_clinvar():
+    return {k: v for k, v in VARIANT.items() if k != "clinvar"}
+
+
+def test_extract_transcript_annotations():
+    annotations = extract_transcript_annotations(VARIANT)
+    assert [a["transcript"] for a in annotations] == [
+        "ENST00000496384.2",
+        "NM_004333.4",
+        "XM_005250045.1",
+    ]
+    assert annotations[1]["consequence"] == "missense_variant"
+    assert extract_transcript_annotations({"_id": "x"}) == []
+
+
+def test_clinvar_preferred_transcript():
+    assert clinvar_preferred_transcript(VARIANT) == "NM_004333.6"
+    assert clinvar_preferred_transcript(_without_clinvar()) is None
+
+
+def test_default_prefers_clinvar_transcript_across_versions():
+    selected, error = select_transcript(VARIANT)
+    assert error is None
+    assert selected["transcript"] == "NM_004333.4"
+    assert selected["selection"] == SELECTED_BY_CLINVAR
+
+
+def test_default_falls_back_to_first_protein_coding():
+    selected, error = select_transcript(_without_clinvar())
+    assert error is None
+    assert selected["transcript"] == "NM_004333.4"
+    assert selected["selection"] == SELECTED_BY_SNPEFF
+
+
+def test_requested_transcript_with_or_without_version():
+    selected, _ = select_transcript(VARIANT, "xm_005250045")
+    assert selected["hgvs_p"] == "p.Val621Glu"
+    assert selected["selection"] == SELECTED_BY_REQUEST
+
+    selected, _ = select_transcript(VARIANT, "XM_005250045.1")
+    assert selected["transcript"] == "XM_005250045.1"
+
+
+def test_unknown_transcript_lists_available():
+    selected, error = select_transcript(VARIANT, "NM_000000.1")
+    assert selected is None
+    assert "Transcript 'NM_000000.1' not found" in error
+    assert "ENST00000496384.2, NM_004333.4, XM_005250045.1" in error
+
+    _, error = select_transcript({"_id": "x"}, "NM_004333")
+    assert "No per-transcript annotations" in error
+
+
+def test_format_selected_transcript():
+    selected, _ = select_transcript(VARIANT)
+    section = format_selected_transcript(VARIANT["_id"], selected)
+    assert section.startswith("## Transcript: NM_004333.4 (BRAF)")
+    assert "- **HGVS p.**: p.Val600Glu" in section
+    assert "- **Consequence**: missense_variant" in section
+
+
+@pytest.mark.asyncio
+async def test_get_variant_reports_requested_transcript():
+    with patch("czechmedmcp.http_client.request_api") as mock_request:
+        mock_request.return_value = (VARIANT, None)
+        result = await getter.get_variant(
+            "rs113488022", output_json=True, transcript="XM_005250045"
+        )
+
+    data = json.loads(result)
+    assert data[0]["selected_transcript"]["hgvs_c"] == "c.1862T>A"
+
+
+@pytest.mark.asyncio
+async def test_get_variant_transcript_section_is_opt_in():
+    with patch("czechmedmcp.http_client.request_api") as mock_request:
+        mock_request.return_value = (VARIANT, None)
+        plain = await getter.get_variant("rs113488022", output_json=True)
+        shown = await getter.get_variant(
+            "rs113488022", output_json=True, show_transcript=True
+        )
+
+    assert "selected_transcript" not in json.loads(plain)[0]
+    selected = json.loads(shown)[0]["selected_transcript"]
+    assert selected["selection"] == SELECTED_BY_CLINVAR
+
+
+@pytest.mark.asyncio
+async def test_get_variant_unknown_transcript_is_reported_per_variant():
+    with patch("czechmedmcp.http_client.request_api") as mock_request:
+        mock_request.return_value = ([VARIANT, VARIANT], None)
+        result = await getter.get_variant(
+            "rs113488022", transcript="ENST00000000000"
+        )
+
+    assert not result.startswith("# Record 1\nError:")
+    assert result.count("## Transcript\n") == 2
+    assert "- **Error**: Transcript 'ENST00000000000' not found" in result
+    assert "Available transcripts" in result
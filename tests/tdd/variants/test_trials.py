@@ -0,0 +1,73 @@
+"""Tests for variant-to-trial matching."""
+
+import json
+
+import pytest
+
+from czechmedmcp.variants.trials import (
+    MAX_VARIANT_TRIAL_QUERIES,
+    merge_variant_trials,
+    search_variant_trials,
+    variant_trial_mutation_query,
+    variant_trial_representations,
+)
+
+
+def test_mutation_query_normalizes_protein_prefix():
+    assert variant_trial_mutation_query("braf", "p.V600E") == "BRAF V600E"
+
+
+def test_representations_are_unique_and_bounded():
+    reps = variant_trial_representations(
+        "BRAF", "V600E", rsid="rs113488022", hgvs="rs113488022"
+    )
+    assert reps == ["BRAF V600E", "rs113488022"]
+    assert len(reps) <= MAX_VARIANT_TRIAL_QUERIES
+
+
+def test_merge_dedupes_and_sorts_by_status():
+    merged = merge_variant_trials([
+        (
+            "BRAF V600E",
+            [
+                {"NCT Number": "NCT1", "Study Status": "COMPLETED"},
+                {"NCT Number": "NCT2", "Study Status": "RECRUITING"},
+            ],
+        ),
+        (
+            "rs113488022",
+            [
+                {"NCT Number": "NCT1", "Study Status": "COMPLETED"},
+                {"NCT Number": "NCT3", "Study Status": "NOT_YET_RECRUITING"},
+            ],
+        ),
+    ])
+
+    assert [t["NCT Number"] for t in merged] == ["NCT2", "NCT3", "NCT1"]
+    assert merged[2]["Matched Queries"] == ["BRAF V600E", "rs113488022"]
+
+
+@pytest.mark.asyncio
+async def test_search_variant_trials_queries_each_representation(
+    monkeypatch,
+):
+    queried = []
+
+    async def mock_search_trials(query, output_json=False):
+        queried.append(query.terms[0])
+        return json.dumps([
+            {"NCT Number": "NCT9", "Study Status": "RECRUITING"}
+        ])
+
+    monkeypatch.setattr(
+        "czechmedmcp.variants.trials.search_trials", mock_search_trials
+    )
+
+    result = await search_variant_trials(
+        "BRAF", "V600E", rsid="rs113488022", hgvs="c.1799T>A", output_json=True
+    )
+
+    assert queried == ["BRAF V600E", "rs113488022", "c.1799T>A"]
+    trials = json.loads(result)
+    assert len(trials) == 1
+    assert len(trials[0]["Matched Queries"]) == 3